@@ -0,0 +1,101 @@
+//! Derive macros for `bevy-intl`. Use them through the `derive` feature of
+//! `bevy-intl`, which re-exports them.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, LitStr, parse_macro_input};
+
+/// Derives `bevy_intl::Localize` (and `bevy_intl::DisplayName`) for an enum.
+///
+/// Each variant maps to the key `<enum>.<variant>` in snake case
+/// (`Weapon::FireSword` → `weapon.fire_sword`), looked up in the `enums`
+/// translation file. Both can be changed:
+///
+/// ```rust,ignore
+/// #[derive(Localize)]
+/// #[localize(file = "items", prefix = "weapons")]
+/// enum Weapon {
+///     Sword,
+///     #[localize(rename = "longbow")]
+///     Bow,
+/// }
+/// ```
+#[proc_macro_derive(Localize, attributes(localize))]
+pub fn derive_localize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(&input.ident, "`Localize` can only be derived for enums"));
+    };
+
+    let mut file = "enums".to_string();
+    let mut prefix = snake_case(&input.ident.to_string());
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("localize")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("file") {
+                file = meta.value()?.parse::<LitStr>()?.value();
+            } else if meta.path.is_ident("prefix") {
+                prefix = meta.value()?.parse::<LitStr>()?.value();
+            } else {
+                return Err(meta.error("expected `file` or `prefix`"));
+            }
+            Ok(())
+        })?;
+    }
+
+    let mut arms = Vec::new();
+    for variant in &data.variants {
+        let mut name = snake_case(&variant.ident.to_string());
+        for attr in variant.attrs.iter().filter(|a| a.path().is_ident("localize")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    name = meta.value()?.parse::<LitStr>()?.value();
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `rename`"))
+                }
+            })?;
+        }
+        let ident = &variant.ident;
+        let key = format!("{}.{}", prefix, name);
+        arms.push(quote! { Self::#ident { .. } => #key });
+    }
+
+    let ty = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::bevy_intl::DisplayName for #ty #ty_generics #where_clause {
+            fn name_file(&self) -> &str {
+                #file
+            }
+
+            fn key(&self) -> &str {
+                match *self {
+                    #(#arms,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::bevy_intl::Localize for #ty #ty_generics #where_clause {}
+    })
+}
+
+/// `FireSword` → `fire_sword`, `HTTPServer` → `http_server`.
+fn snake_case(ident: &str) -> String {
+    let chars: Vec<char> = ident.chars().collect();
+    let mut out = String::with_capacity(ident.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev != '_' && (prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_is_lower)) {
+                out.push('_');
+            }
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
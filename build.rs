@@ -3,6 +3,37 @@ use std::{ fs, path::Path, path::PathBuf };
 use serde_json::{ Value, Map };
 use anyhow::Result;
 
+// The merge of same-named files of the crate, shared to bundle
+// mixed-format folders as the loader reads them.
+#[path = "src/merge.rs"]
+mod merge;
+
+// The concatenation lint of the crate, shared to warn about bundled strings.
+#[path = "src/concat.rs"]
+mod concat;
+
+// The right-to-left languages of the crate, shared to lint them as
+// `Locale::direction` reports them.
+#[path = "src/direction.rs"]
+mod direction;
+
+// The reserved file names and annotations of the crate, shared to tell
+// translations from reserved entries.
+#[path = "src/reserved.rs"]
+#[allow(dead_code)]
+mod reserved;
+
+// The overlay rules of the crate, shared to bundle an overlay as it is
+// applied at runtime.
+#[path = "src/overlay.rs"]
+#[allow(dead_code)]
+mod overlay;
+
+// The generator of the `t!` macro, checking keys against the bundled
+// default language.
+#[path = "src/key_macro.rs"]
+mod key_macro;
+
 fn main() -> Result<(), Box<dyn Error>> {
     println!("cargo:warning=COUCOU");
 
@@ -14,18 +45,92 @@ fn main() -> Result<(), Box<dyn Error>> {
     let out_path = Path::new(&std::env::var("OUT_DIR")?).join("all_translations.json");
     println!("cargo:warning=COUCOU-{messages_dir:?} = {out_path:?}");
 
-    // Always create the file, even if empty, so include_str! works
+    // Language whose keys `t!` accepts.
+    println!("cargo:rerun-if-env-changed=BEVY_INTL_DEFAULT_LANG");
+    let default_lang = std::env::var("BEVY_INTL_DEFAULT_LANG").ok().filter(|l| !l.is_empty());
+    let default_lang = default_lang.as_deref().unwrap_or("en");
+    let keys_path = out_path.with_file_name("translation_keys.rs");
+    // One enum per file of the default language, for the `typed-keys`
+    // feature.
+    let typed_keys = std::env::var_os("CARGO_FEATURE_TYPED_KEYS").is_some();
+    let enums_path = out_path.with_file_name("typed_keys.rs");
+
+    // Always create the files, even if empty, so include_str! works
     if !messages_dir.exists() {
         println!("cargo:warning=No messages/ folder found in consuming project");
         println!("cargo:warning=This is normal when building bevy-intl itself");
         fs::write(out_path, "{}")?;
+        fs::write(keys_path, key_macro::key_macro(&Value::Null, default_lang))?;
+        if typed_keys {
+            fs::write(enums_path, key_macro::key_enums(&Value::Null, default_lang)?)?;
+        }
         return Ok(());
     }
 
-    let translations = build_translations(&messages_dir)?;
-    fs::write(out_path, serde_json::to_string_pretty(&translations)?)?;
+    let mut translations = build_translations(&messages_dir)?;
+
+    // Build flavor overlay, e.g. `BEVY_INTL_OVERLAY=messages-demo`, next to
+    // the messages folder.
+    println!("cargo:rerun-if-env-changed=BEVY_INTL_OVERLAY");
+    if let Some(overlay) = std::env::var("BEVY_INTL_OVERLAY").ok().filter(|o| !o.is_empty()) {
+        let overlay_dir = overlay::overlay_folder(&messages_dir, &overlay);
+        println!("cargo:rerun-if-changed={}", overlay_dir.display());
+        if overlay_dir.exists() {
+            overlay::merge_overlay(&mut translations, &build_translations(&overlay_dir)?, |issue| {
+                println!("cargo:warning=overlay {overlay:?} {issue}");
+            });
+            // For `I18n::bundle_drift`, which compares with the same overlay.
+            println!("cargo:rustc-env=BEVY_INTL_BUNDLE_OVERLAY={overlay}");
+        } else {
+            println!("cargo:warning=overlay folder {overlay_dir:?} not found");
+        }
+    }
+    check_max_lengths(&translations);
+    check_concatenation(&translations);
+
+    // Per-language packs for the web, e.g. `BEVY_INTL_SPLIT_BUNDLE=assets/i18n`
+    // next to the messages folder: every language gets its own file, fetched
+    // by `I18nSplitBundlePlugin`, and only the default language is bundled.
+    println!("cargo:rerun-if-env-changed=BEVY_INTL_SPLIT_BUNDLE");
+    let split = std::env::var("BEVY_INTL_SPLIT_BUNDLE").ok().filter(|s| !s.is_empty());
+    let bundle = match split {
+        Some(split) => {
+            let split_dir = messages_dir.parent().unwrap_or(Path::new(".")).join(split);
+            write_language_packs(&translations, &split_dir)?;
+            // For `I18n::bundle_drift`, which then ignores the other languages.
+            println!("cargo:rustc-env=BEVY_INTL_SPLIT_BUNDLE=1");
+            let default = translations.get(default_lang).cloned();
+            Value::Object(default.map(|files| Map::from_iter([(default_lang.to_string(), files)])).unwrap_or_default())
+        }
+        None => translations.clone(),
+    };
+    // Without the `bundled` feature the folder is only read for `t!` and the
+    // typed keys; translations are loaded at runtime.
+    if std::env::var_os("CARGO_FEATURE_BUNDLED").is_some() {
+        let bundled = serde_json::to_string_pretty(&bundle)?;
+        println!("cargo:rustc-env=BEVY_INTL_BUNDLE_HASH={}", bundle_hash(&bundled));
+        fs::write(out_path, bundled)?;
+    } else {
+        fs::write(out_path, "{}")?;
+    }
+    fs::write(keys_path, key_macro::key_macro(&translations, default_lang))?;
+    if typed_keys {
+        fs::write(enums_path, key_macro::key_enums(&translations, default_lang)?)?;
+    }
+
+    // Optional release label for the bundle, e.g. set by CI.
+    println!("cargo:rerun-if-env-changed=BEVY_INTL_BUNDLE_VERSION");
+    if let Ok(version) = std::env::var("BEVY_INTL_BUNDLE_VERSION") {
+        println!("cargo:rustc-env=BEVY_INTL_BUNDLE_VERSION={version}");
+    }
 
     println!("cargo:rerun-if-changed=messages");
+    println!("cargo:rerun-if-changed=src/merge.rs");
+    println!("cargo:rerun-if-changed=src/concat.rs");
+    println!("cargo:rerun-if-changed=src/direction.rs");
+    println!("cargo:rerun-if-changed=src/key_macro.rs");
+    println!("cargo:rerun-if-changed=src/reserved.rs");
+    println!("cargo:rerun-if-changed=src/overlay.rs");
     Ok(())
 }
 
@@ -39,30 +144,179 @@ fn build_translations(messages_dir: &Path) -> Result<Value> {
         }
 
         let lang_code = lang_dir.file_name().to_string_lossy().to_string();
-        let mut translation_files = Map::new();
+        let mut merged = merge::MergedFiles::default();
         println!("cargo:warning=ehhe{lang_dir:?}");
 
-        for file_entry in fs::read_dir(lang_dir.path())? {
-            let file = file_entry?;
-            let file_path = file.path(); // Store the path to extend its lifetime
+        // Sorted, so the same file wins key collisions as at runtime.
+        let mut paths = fs::read_dir(lang_dir.path())?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<std::io::Result<Vec<_>>>()?;
+        paths.sort();
 
-            if let Some("json") = file_path.extension().and_then(|e| e.to_str()) {
-                let file_stem = file_path
-                    .file_stem()
-                    .and_then(|s| s.to_str())
-                    .unwrap_or("unknown");
-
-                let content = fs::read_to_string(&file_path)?;
-                let json: Value = serde_json::from_str(&content)?;
-                translation_files.insert(file_stem.to_string(), json);
+        for file_path in paths {
+            let extension = file_path.extension().and_then(|e| e.to_str());
+            let yaml = matches!(extension, Some("yaml" | "yml"));
+            if extension != Some("json") && !(yaml && cfg!(feature = "yaml")) {
+                continue;
             }
+            let file_stem = file_path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("unknown");
+            let file_name = file_path.file_name().and_then(|s| s.to_str()).unwrap_or("unknown");
+
+            let content = fs::read_to_string(&file_path)?;
+            let mut json: Value = match extension {
+                // As `bevy_intl::parse_yaml` reads it: through YAML values,
+                // which reject duplicate keys.
+                #[cfg(feature = "yaml")]
+                Some("yaml" | "yml") => serde_yaml::from_str::<serde_yaml::Value>(&content)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|yaml| Ok(serde_json::to_value(yaml)?))
+                    .map_err(|e| anyhow::anyhow!("{lang_code}/{file_name}: {e}"))?,
+                _ => serde_json::from_str(&content)?,
+            };
+            inline_documents(&mut json, &lang_dir.path());
+            merged.add(&lang_code, file_stem, file_name, json, |issue| println!("cargo:warning={issue}"));
         }
-        translations.insert(lang_code, Value::Object(translation_files));
+        translations.insert(lang_code, Value::Object(merged.into_files().collect()));
     }
 
     Ok(Value::Object(translations))
 }
 
+// Write `<lang>.json` (`{ file: entries }`, the shape of a language pack) for
+// every language into `dir`, and a `manifest.json` listing their hashes in
+// the `PackManifest` format.
+fn write_language_packs(translations: &Value, dir: &Path) -> Result<()> {
+    fs::create_dir_all(dir)?;
+    let mut packs = Map::new();
+    for (lang, files) in translations.as_object().into_iter().flatten() {
+        let pack = serde_json::to_string(files)?;
+        packs.insert(lang.clone(), Value::String(bundle_hash(&pack)));
+        fs::write(dir.join(format!("{lang}.json")), pack)?;
+    }
+    let version = std::env::var("BEVY_INTL_BUNDLE_VERSION").unwrap_or_default();
+    let manifest = serde_json::json!({ "version": version, "packs": packs });
+    fs::write(dir.join("manifest.json"), serde_json::to_string_pretty(&manifest)?)?;
+    Ok(())
+}
+
+// Bundle `{"@file": "credits.txt"}` documents as an extra `"@text"` entry,
+// read from the language folder.
+fn inline_documents(json: &mut Value, lang_dir: &Path) {
+    let Some(obj) = json.as_object_mut() else { return };
+    for (key, value) in obj.iter_mut() {
+        let Some(doc) = value.as_object_mut() else { continue };
+        let Some(path) = doc.get("@file").and_then(Value::as_str).map(str::to_string) else { continue };
+        match fs::read_to_string(lang_dir.join(&path)) {
+            Ok(text) => {
+                doc.insert("@text".to_string(), Value::String(text));
+            }
+            Err(e) => println!("cargo:warning=document '{path}' for key '{key}' could not be read: {e}"),
+        }
+    }
+}
+
+// Enforce `"key@max": N` annotations for every language at build time.
+fn check_max_lengths(translations: &Value) {
+    let Some(langs) = translations.as_object() else { return };
+
+    // file -> key -> max, strictest declaration across languages
+    let mut limits: Map<String, Value> = Map::new();
+    for files in langs.values().filter_map(Value::as_object) {
+        for (file, sections) in files {
+            let Some(sections) = sections.as_object() else { continue };
+            for (key, value) in sections {
+                let (Some((base, "max")), Some(max)) = (key.rsplit_once('@'), value.as_u64()) else {
+                    continue;
+                };
+                let file_limits = limits
+                    .entry(file.clone())
+                    .or_insert_with(|| Value::Object(Map::new()));
+                if let Some(file_limits) = file_limits.as_object_mut() {
+                    let current = file_limits.get(base).and_then(Value::as_u64).unwrap_or(u64::MAX);
+                    file_limits.insert(base.to_string(), Value::from(current.min(max)));
+                }
+            }
+        }
+    }
+
+    for (lang, files) in langs {
+        let Some(files) = files.as_object() else { continue };
+        for (file, file_limits) in &limits {
+            let Some(file_limits) = file_limits.as_object() else { continue };
+            for (key, max) in file_limits {
+                let Some(max) = max.as_u64() else { continue };
+                let Some(value) = files.get(file).and_then(|s| s.get(key)) else { continue };
+                let len = longest_string(value);
+                if len as u64 > max {
+                    println!("cargo:warning=[{lang}] {file}.{key} is {len} characters long (max {max})");
+                }
+            }
+        }
+    }
+}
+
+// 64-bit FNV-1a as 16 hex digits — the same hash as `bevy_intl::pack_hash`.
+fn bundle_hash(content: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in content.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    format!("{:016x}", hash)
+}
+
+// Warn about strings that look like pieces of a concatenated sentence, as
+// `I18n::check_concatenation` does at runtime.
+fn check_concatenation(translations: &Value) {
+    let Some(langs) = translations.as_object() else { return };
+    for (lang, files) in langs {
+        let rtl = direction::is_right_to_left(lang);
+        // Reserved files, `_images.json` and `_audio.json` included, hold no
+        // sentences.
+        let files = files
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter(|(file, _)| reserved::is_translation_file(file));
+        for (file, sections) in files {
+            // Annotations and `"@file"` documents are not sentences.
+            let entries = sections.as_object().into_iter().flatten();
+            for (key, value) in entries.filter(|(key, value)| {
+                !key.starts_with('@') && reserved::split_annotation(key).is_none() && value.get("@file").is_none()
+            }) {
+                let mut strings = Vec::new();
+                collect_strings(value, &mut strings);
+                let mut smells: Vec<String> =
+                    strings.iter().flat_map(|s| concat::concatenation_smells(s, rtl)).collect();
+                smells.sort();
+                smells.dedup();
+                for smell in smells {
+                    println!("cargo:warning=[{lang}] {file}.{key}: {smell}");
+                }
+            }
+        }
+    }
+}
+
+fn collect_strings<'a>(value: &'a Value, strings: &mut Vec<&'a str>) {
+    match value {
+        Value::String(s) => strings.push(s),
+        Value::Object(obj) => obj.values().for_each(|value| collect_strings(value, strings)),
+        _ => {}
+    }
+}
+
+fn longest_string(value: &Value) -> usize {
+    match value {
+        Value::String(s) => s.chars().count(),
+        Value::Object(obj) => obj.values().map(longest_string).max().unwrap_or(0),
+        _ => 0,
+    }
+}
+
 fn find_messages_directory() -> Result<PathBuf> {
     println!("cargo:warning=try find");
 
@@ -4,7 +4,7 @@
 //! repository).
 
 use bevy::prelude::*;
-use bevy_intl::{I18n, I18nMode, I18nPlugin, I18nText, LanguageChanged};
+use bevy_intl::prelude::*;
 
 fn main() {
     App::new()
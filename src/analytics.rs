@@ -0,0 +1,157 @@
+//! Locale-tagged analytics events.
+//!
+//! Register an [`AnalyticsSink`] with [`I18n::set_analytics_sink`] to feed
+//! language switches, fallback hits and missing strings to an analytics
+//! pipeline. Every [`AnalyticsEvent`] carries the active locale and the
+//! session metadata set with [`I18n::set_analytics_session`] (build number,
+//! platform, player cohort…).
+//!
+//! Labels are re-rendered on every refresh and immediate-mode UI translates
+//! every frame, so a fallback or missing string is reported once per locale
+//! and key: counts measure how many strings a locale lacks, not how often
+//! they were drawn.
+//!
+//! ```rust,no_run
+//! use bevy_intl::{AnalyticsEvent, AnalyticsEventKind, I18n};
+//!
+//! # fn setup(i18n: &mut I18n) {
+//! i18n.set_analytics_session("build", "1.4.2");
+//! i18n.set_analytics_sink(|event: &AnalyticsEvent| {
+//!     if let AnalyticsEventKind::MissingKey { file, key } = &event.kind {
+//!         println!("[{}] missing {}.{} {:?}", event.locale, file, key, event.session);
+//!     }
+//! });
+//! # }
+//! ```
+
+use std::collections::{BTreeMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use crate::{I18n, MissingTranslations};
+
+/// Receives [`AnalyticsEvent`]s. Implemented for `Fn(&AnalyticsEvent)`
+/// closures.
+pub trait AnalyticsSink: Send + Sync + 'static {
+    fn record(&self, event: &AnalyticsEvent<'_>);
+}
+
+impl<F: Fn(&AnalyticsEvent<'_>) + Send + Sync + 'static> AnalyticsSink for F {
+    fn record(&self, event: &AnalyticsEvent<'_>) {
+        self(event)
+    }
+}
+
+/// An event reported to the [`AnalyticsSink`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AnalyticsEvent<'a> {
+    pub kind: AnalyticsEventKind<'a>,
+    /// Active language (the new one for
+    /// [`LanguageChanged`](AnalyticsEventKind::LanguageChanged)).
+    pub locale: &'a str,
+    /// Metadata set with [`I18n::set_analytics_session`].
+    pub session: &'a BTreeMap<String, String>,
+}
+
+/// What happened, see [`AnalyticsEvent`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnalyticsEventKind<'a> {
+    /// The active language changed from `from`.
+    LanguageChanged { from: &'a str },
+    /// `file.key` is missing from the active language and was served by
+    /// `served_by`, a language of the fallback chain.
+    FallbackUsed { file: &'a str, key: &'a str, served_by: &'a str },
+    /// `file.key` could not be resolved at all.
+    MissingKey { file: &'a str, key: &'a str },
+}
+
+/// The registered sink, the session metadata and the keys already reported,
+/// shared by snapshots.
+#[derive(Clone, Default)]
+pub(crate) struct I18nAnalytics {
+    sink: Option<Arc<dyn AnalyticsSink>>,
+    session: BTreeMap<String, String>,
+    reported: Arc<Mutex<HashSet<String>>>,
+    /// Report of fallback hits and missing keys, see
+    /// [`I18n::set_missing_translations`].
+    pub(crate) missing: Option<MissingTranslations>,
+}
+
+impl I18nAnalytics {
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.sink.is_some() || self.missing.is_some()
+    }
+
+    pub(crate) fn language_changed(&self, from: &str, to: &str) {
+        self.record(to, AnalyticsEventKind::LanguageChanged { from });
+    }
+
+    fn record(&self, locale: &str, kind: AnalyticsEventKind<'_>) {
+        if let Some(sink) = &self.sink {
+            sink.record(&AnalyticsEvent { kind, locale, session: &self.session });
+        }
+    }
+
+    /// Records `kind` unless it was already reported for `locale`.
+    fn record_once(&self, locale: &str, kind: AnalyticsEventKind<'_>) {
+        if self.sink.is_none() {
+            return;
+        }
+        let id = match kind {
+            AnalyticsEventKind::LanguageChanged { .. } => return self.record(locale, kind),
+            AnalyticsEventKind::FallbackUsed { file, key, .. } => format!("fallback\u{4}{}\u{4}{}.{}", locale, file, key),
+            AnalyticsEventKind::MissingKey { file, key } => format!("missing\u{4}{}\u{4}{}.{}", locale, file, key),
+        };
+        let mut reported = self.reported.lock().unwrap_or_else(|e| e.into_inner());
+        if reported.insert(id) {
+            drop(reported);
+            self.record(locale, kind);
+        }
+    }
+}
+
+/// Analytics of one `I18nPartial`: the file it translates, in which locale.
+pub(crate) struct AnalyticsScope<'a> {
+    pub(crate) analytics: &'a I18nAnalytics,
+    pub(crate) locale: &'a str,
+    pub(crate) file: String,
+}
+
+impl AnalyticsScope<'_> {
+    pub(crate) fn fallback_used(&self, key: &str, served_by: &str) {
+        if let Some(missing) = &self.analytics.missing {
+            missing.record(self.locale, &self.file, key, Some(served_by));
+        }
+        let kind = AnalyticsEventKind::FallbackUsed { file: &self.file, key, served_by };
+        self.analytics.record_once(self.locale, kind);
+    }
+
+    pub(crate) fn missing_key(&self, key: &str) {
+        if let Some(missing) = &self.analytics.missing {
+            missing.record(self.locale, &self.file, key, None);
+        }
+        self.analytics.record_once(self.locale, AnalyticsEventKind::MissingKey { file: &self.file, key });
+    }
+}
+
+impl I18n {
+    /// Registers the sink receiving [`AnalyticsEvent`]s, replacing the
+    /// previous one.
+    pub fn set_analytics_sink(&mut self, sink: impl AnalyticsSink) {
+        self.analytics.sink = Some(Arc::new(sink));
+    }
+
+    /// Removes the analytics sink.
+    pub fn clear_analytics_sink(&mut self) {
+        self.analytics.sink = None;
+    }
+
+    /// Sets a session metadata entry attached to every [`AnalyticsEvent`].
+    pub fn set_analytics_session(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.analytics.session.insert(name.into(), value.into());
+    }
+
+    /// Session metadata attached to every [`AnalyticsEvent`].
+    pub fn analytics_session(&self) -> &BTreeMap<String, String> {
+        &self.analytics.session
+    }
+}
@@ -0,0 +1,235 @@
+//! Translation loading through the Bevy asset pipeline.
+//!
+//! By default `I18nPlugin` reads the messages folder with `std::fs` when the
+//! resource is created. [`I18nAssetPlugin`] loads a folder of the `assets/`
+//! directory with the `AssetServer` instead — asynchronously, with its load
+//! state trackable like any other asset — and merges every file into the
+//! [`I18n`](crate::I18n) resource once the whole folder is loaded:
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_intl::{I18nAssetPlugin, I18nPlugin};
+//!
+//! App::new()
+//!     .add_plugins(DefaultPlugins)
+//!     .add_plugins(I18nPlugin::default())
+//!     // assets/messages/en/ui.json, assets/messages/fr/ui.yaml, …
+//!     .add_plugins(I18nAssetPlugin::default())
+//!     .run();
+//! ```
+//!
+//! Files are applied as [`I18nCommand::MergePack`]s, so `I18nText` labels
+//! refresh and malformed files are reported like rejected language packs.
+//! Until then, lookups use whatever `I18nPlugin` loaded (the bundled
+//! translations when there is no messages folder next to the executable).
+//! Loading screens can wait on the [`I18nAssetFolder`] handle:
+//!
+//! ```rust,no_run
+//! # use bevy::prelude::*;
+//! # use bevy_intl::I18nAssetFolder;
+//! fn translations_ready(folder: Res<I18nAssetFolder>, server: Res<AssetServer>) -> bool {
+//!     server.is_loaded_with_dependencies(&folder.0)
+//! }
+//! ```
+//!
+//! The loader reads the formats of [`I18nConfig::formats`](crate::I18nConfig::formats),
+//! custom parsers included (the built-in formats without `I18nPlugin`);
+//! `"@file"` documents are not inlined. The first folder under the loaded
+//! one names the language of a file: `messages/fr/menus/ui.yaml` is the `ui`
+//! file of `fr`. With Bevy's `file_watcher` feature,
+//! files edited after the folder was merged are merged again.
+
+use std::path::Path;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext, LoadedFolder};
+use bevy::prelude::*;
+use serde_json::Value;
+
+use crate::{I18nCommand, I18nConfig, TranslationFormats, apply_i18n_commands};
+
+/// Parsed content of one translation file, in the JSON shape of a `.json`
+/// file.
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct TranslationFile(pub Value);
+
+/// Error of [`TranslationFileLoader`].
+#[derive(Debug)]
+pub enum TranslationFileError {
+    Io(std::io::Error),
+    /// The file is not valid UTF-8 or not valid in its format.
+    Parse(String),
+}
+
+impl std::fmt::Display for TranslationFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TranslationFileError::Io(e) => write!(f, "could not read translation file: {}", e),
+            TranslationFileError::Parse(e) => write!(f, "invalid translation file: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for TranslationFileError {}
+
+impl From<std::io::Error> for TranslationFileError {
+    fn from(e: std::io::Error) -> Self {
+        TranslationFileError::Io(e)
+    }
+}
+
+/// Loads [`TranslationFile`]s with the parsers of the [`I18nConfig`]
+/// resource, or the built-in ones when there is none.
+#[derive(TypePath)]
+pub struct TranslationFileLoader {
+    formats: TranslationFormats,
+    /// Every extension of `formats`. The loader is created once per app, so
+    /// the names are leaked to be borrowed by [`AssetLoader::extensions`].
+    extensions: Vec<&'static str>,
+}
+
+impl FromWorld for TranslationFileLoader {
+    fn from_world(world: &mut World) -> Self {
+        let formats = world.get_resource::<I18nConfig>().map(|config| config.formats.clone()).unwrap_or_default();
+        let extensions = formats.extensions().map(|extension| &*String::leak(extension.to_string())).collect();
+        Self { formats, extensions }
+    }
+}
+
+impl AssetLoader for TranslationFileLoader {
+    type Asset = TranslationFile;
+    type Settings = ();
+    type Error = TranslationFileError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<TranslationFile, TranslationFileError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let extension = load_context.path().get_extension().unwrap_or("json");
+        let parsed = self.formats.parse(extension, &bytes).ok_or_else(|| {
+            TranslationFileError::Parse(format!("unsupported extension '{}'", extension))
+        })?;
+        parsed.map(TranslationFile).map_err(TranslationFileError::Parse)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &self.extensions
+    }
+}
+
+/// Loads the translations of an asset folder, see the [module docs](self).
+/// Add it after [`I18nPlugin`](crate::I18nPlugin).
+#[derive(Debug, Clone)]
+pub struct I18nAssetPlugin {
+    /// Folder of the asset directory holding one folder per language.
+    /// Default: `"messages"`.
+    pub folder: String,
+}
+
+impl Default for I18nAssetPlugin {
+    fn default() -> Self {
+        Self { folder: "messages".to_string() }
+    }
+}
+
+impl Plugin for I18nAssetPlugin {
+    fn build(&self, app: &mut App) {
+        let folder = self.folder.clone();
+        app.init_asset::<TranslationFile>()
+            .init_asset_loader::<TranslationFileLoader>()
+            .add_message::<I18nCommand>()
+            .add_systems(Startup, move |mut commands: Commands, server: Res<AssetServer>| {
+                commands.insert_resource(I18nAssetFolder(server.load_folder(folder.clone())));
+            })
+            .add_systems(
+                Update,
+                (merge_loaded_translations, merge_modified_translations).before(apply_i18n_commands),
+            );
+    }
+}
+
+/// Handle of the folder loaded by [`I18nAssetPlugin`].
+#[derive(Resource, Debug, Clone)]
+pub struct I18nAssetFolder(pub Handle<LoadedFolder>);
+
+/// Marks the asset folder as merged into the [`I18n`](crate::I18n) resource.
+#[derive(Resource)]
+struct I18nAssetsMerged;
+
+/// Once the asset folder is loaded, sends one [`I18nCommand::MergePack`] per
+/// translation file.
+fn merge_loaded_translations(
+    mut commands: Commands,
+    folder: Option<Res<I18nAssetFolder>>,
+    merged: Option<Res<I18nAssetsMerged>>,
+    server: Res<AssetServer>,
+    folders: Res<Assets<LoadedFolder>>,
+    files: Res<Assets<TranslationFile>>,
+    mut packs: MessageWriter<I18nCommand>,
+) {
+    let Some(folder) = folder else { return };
+    if merged.is_some() || !server.is_loaded_with_dependencies(&folder.0) {
+        return;
+    }
+    let (Some(loaded), Some(root)) = (folders.get(&folder.0), folder.0.path()) else { return };
+    for handle in &loaded.handles {
+        let (Some(path), Ok(handle)) = (handle.path(), handle.clone().try_typed::<TranslationFile>()) else {
+            continue;
+        };
+        let Some((lang, file)) = lang_and_file(root.path(), path.path()) else {
+            continue;
+        };
+        let Some(TranslationFile(json)) = files.get(&handle) else { continue };
+        let mut pack = serde_json::Map::new();
+        pack.insert(file, json.clone());
+        packs.write(I18nCommand::MergePack { lang, pack: Value::Object(pack) });
+    }
+    info!("loaded translations from the asset folder");
+    commands.insert_resource(I18nAssetsMerged);
+}
+
+/// Sends a [`I18nCommand::MergePack`] for every translation file reloaded
+/// by the asset server after the folder was merged.
+fn merge_modified_translations(
+    folder: Option<Res<I18nAssetFolder>>,
+    merged: Option<Res<I18nAssetsMerged>>,
+    mut events: MessageReader<AssetEvent<TranslationFile>>,
+    server: Res<AssetServer>,
+    files: Res<Assets<TranslationFile>>,
+    mut packs: MessageWriter<I18nCommand>,
+) {
+    let root = folder.as_ref().and_then(|folder| folder.0.path());
+    let (Some(root), Some(_)) = (root, merged) else {
+        events.clear();
+        return;
+    };
+    for event in events.read() {
+        let AssetEvent::Modified { id } = event else { continue };
+        let Some(path) = server.get_path(*id) else { continue };
+        let (Some((lang, file)), Some(TranslationFile(json))) = (lang_and_file(root.path(), path.path()), files.get(*id))
+        else {
+            continue;
+        };
+        info!("reloading '{}/{}' from the asset folder", lang, file);
+        let mut pack = serde_json::Map::new();
+        pack.insert(file, json.clone());
+        packs.write(I18nCommand::MergePack { lang, pack: Value::Object(pack) });
+    }
+}
+
+/// Language (first folder under `root`) and file name (stem) of a
+/// translation file path, e.g. `("fr", "ui")` for `messages/fr/ui.yaml` and
+/// `messages/fr/menus/ui.yaml`. Files directly in `root` have no language.
+fn lang_and_file(root: &Path, path: &Path) -> Option<(String, String)> {
+    let relative = path.strip_prefix(root).ok()?;
+    let lang = relative.components().next()?.as_os_str().to_str()?;
+    if relative.parent()? == Path::new("") {
+        return None;
+    }
+    let file = relative.file_stem()?.to_str()?;
+    Some((lang.to_string(), file.to_string()))
+}
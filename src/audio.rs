@@ -0,0 +1,160 @@
+//! Per-language audio: dubbed voice lines and localized jingles.
+//!
+//! Each language folder may hold an `_audio.json` mapping logical clip keys
+//! to asset paths:
+//!
+//! ```json
+//! { "intro.narrator": "audio/fr/intro_narrator.ogg", "jingle.victory": "audio/fr/victory.ogg" }
+//! ```
+//!
+//! Clips are picked in the audio language ([`I18n::audio_lang`]), which
+//! follows the current language unless set apart (voices in Japanese,
+//! subtitles in English), then in the fallback chain, so an undubbed line
+//! plays the default language's clip. With the `bevy` feature, a
+//! [`LocalizedAudioSource`] component sets the entity's `AudioPlayer` and
+//! swaps it when either language changes.
+
+#[cfg(feature = "bevy")]
+use bevy::audio::{AudioSink, AudioSinkPlayback, SpatialAudioSink};
+#[cfg(feature = "bevy")]
+use bevy::prelude::*;
+#[cfg(feature = "bevy")]
+use tracing::warn;
+
+#[cfg(feature = "bevy")]
+use crate::RefreshI18nText;
+use crate::reserved::AUDIO_FILE;
+use crate::{I18n, I18nError};
+
+impl I18n {
+    /// Language of localized audio: `I18nConfig::audio_lang`, or the current
+    /// language when it is not set.
+    pub fn audio_lang(&self) -> &str {
+        self.audio_lang.as_deref().unwrap_or(&self.current_lang)
+    }
+
+    /// Sets the language of localized audio, or makes it follow the current
+    /// language again with `None`. Returns [`I18nError::LocaleNotFound`] if
+    /// the locale is not part of the loaded translations. In Bevy, prefer
+    /// `I18nCommand::SetAudioLanguage` so `LocalizedAudioSource`s are
+    /// refreshed.
+    pub fn try_set_audio_lang(&mut self, locale: Option<&str>) -> Result<(), I18nError> {
+        if let Some(locale) = locale.filter(|locale| !self.locale_folders_list.iter().any(|l| l == locale)) {
+            return Err(I18nError::LocaleNotFound(locale.to_string()));
+        }
+        self.audio_lang = locale.map(str::to_string);
+        Ok(())
+    }
+
+    /// Asset path of the clip declared for `key` in `_audio.json`, in the
+    /// audio language or the first language it falls back to that has it —
+    /// parent locales, overlays and the fallback chain, like translations.
+    /// See the [module docs](self) for the file format.
+    ///
+    /// ```rust,no_run
+    /// # use bevy_intl::I18n;
+    /// # fn intro(i18n: &I18n) {
+    /// if let Some(path) = i18n.audio("intro.narrator") {
+    ///     println!("narration: {path}");
+    /// }
+    /// # }
+    /// ```
+    pub fn audio(&self, key: &str) -> Option<&str> {
+        let audio_lang = self.audio_lang();
+        std::iter::once(audio_lang)
+            .chain(self.fallback_chain_of(audio_lang))
+            .find_map(|lang| self.translations.raw.get(lang)?.get(AUDIO_FILE)?.get(key)?.as_str())
+    }
+}
+
+/// Plays the clip declared for `key` in `_audio.json` through the entity's
+/// `AudioPlayer`, inserted if missing, and swaps it when the audio language
+/// changes. A clip still playing is restarted in the new language; a
+/// finished one plays the new clip the next time it is started.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_intl::LocalizedAudioSource;
+///
+/// fn play_intro(mut commands: Commands) {
+///     commands.spawn((LocalizedAudioSource::new("intro.narrator"), PlaybackSettings::DESPAWN));
+/// }
+/// ```
+#[cfg(feature = "bevy")]
+#[derive(Component, Clone, Debug)]
+pub struct LocalizedAudioSource {
+    /// Key in `_audio.json`, e.g. `"intro.narrator"`.
+    pub key: String,
+}
+
+#[cfg(feature = "bevy")]
+impl LocalizedAudioSource {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self { key: key.into() }
+    }
+}
+
+#[cfg(feature = "bevy")]
+type AudioQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'static LocalizedAudioSource, Option<&'static AudioPlayer>, Option<&'static AudioSink>, Option<&'static SpatialAudioSink>),
+>;
+
+#[cfg(feature = "bevy")]
+fn apply(
+    commands: &mut Commands,
+    i18n: &I18n,
+    assets: Option<&AssetServer>,
+    (entity, source, player, sink, spatial_sink): (Entity, &LocalizedAudioSource, Option<&AudioPlayer>, Option<&AudioSink>, Option<&SpatialAudioSink>),
+) {
+    let Some(path) = i18n.audio(&source.key) else {
+        warn!("audio clip '{}' not found in _audio.json", source.key);
+        return;
+    };
+    let Some(assets) = assets else {
+        warn!("audio clip '{}' cannot be loaded without an AssetServer", source.key);
+        return;
+    };
+    let handle: Handle<AudioSource> = assets.load(path.to_string());
+    if player.is_some_and(|player| player.0 == handle) {
+        return;
+    }
+    let mut entity = commands.entity(entity);
+    entity.insert(AudioPlayer::new(handle));
+    // Bevy starts entities without a sink; dropping the old sink stops the
+    // clip of the previous language.
+    if sink.is_some_and(|sink| !sink.empty()) || spatial_sink.is_some_and(|sink| !sink.empty()) {
+        entity.remove::<(AudioSink, SpatialAudioSink)>();
+    }
+}
+
+/// Observer giving a [`LocalizedAudioSource`] its clip as soon as it is
+/// inserted.
+#[cfg(feature = "bevy")]
+pub(crate) fn play_inserted_localized_audio(
+    insert: On<Insert, LocalizedAudioSource>,
+    mut commands: Commands,
+    i18n: Res<I18n>,
+    assets: Option<Res<AssetServer>>,
+    query: AudioQuery,
+) {
+    if let Ok(item) = query.get(insert.entity) {
+        apply(&mut commands, &i18n, assets.as_deref(), item);
+    }
+}
+
+/// Observer swapping the clip of every [`LocalizedAudioSource`] on
+/// [`RefreshI18nText`].
+#[cfg(feature = "bevy")]
+pub(crate) fn refresh_localized_audio(
+    _refresh: On<RefreshI18nText>,
+    mut commands: Commands,
+    i18n: Res<I18n>,
+    assets: Option<Res<AssetServer>>,
+    query: AudioQuery,
+) {
+    for item in &query {
+        apply(&mut commands, &i18n, assets.as_deref(), item);
+    }
+}
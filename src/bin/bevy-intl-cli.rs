@@ -0,0 +1,243 @@
+//! Command-line tools for translation pipelines (`cli` feature).
+//!
+//! ```text
+//! bevy-intl-cli freeze <messages_folder> --version <v> [--lang en] [--out freeze.json]
+//! bevy-intl-cli check-freeze <messages_folder> --version <v> [--manifest freeze.json]
+//! bevy-intl-cli migrate-keys <messages_folder> [--lang en]
+//! bevy-intl-cli lint <messages_folder>
+//! bevy-intl-cli check-bundle <messages_folder> [--bundle all_translations.json] [--overlay <folder>]
+//! bevy-intl-cli migrate <messages_folder>
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+use bevy_intl::{
+    FreezeManifest, FrozenChangeKind, I18n, I18nConfig, KeyMigrations, TRANSLATION_SCHEMA, bundle_source, compare_bundle,
+    file_schema, migrate_file,
+};
+
+const USAGE: &str = "usage:
+  bevy-intl-cli freeze <messages_folder> --version <v> [--lang en] [--out freeze.json]
+  bevy-intl-cli check-freeze <messages_folder> --version <v> [--manifest freeze.json]
+  bevy-intl-cli migrate-keys <messages_folder> [--lang en]
+  bevy-intl-cli lint <messages_folder>
+  bevy-intl-cli check-bundle <messages_folder> [--bundle all_translations.json] [--overlay <folder>]
+  bevy-intl-cli migrate <messages_folder>";
+
+/// Positional arguments and `--name value` options.
+fn parse_args(args: &[String]) -> Result<(Vec<&str>, HashMap<&str, &str>), String> {
+    let mut positional = Vec::new();
+    let mut options = HashMap::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.strip_prefix("--") {
+            Some(name) => {
+                let value = args.next().ok_or_else(|| format!("--{} needs a value", name))?;
+                options.insert(name, value.as_str());
+            }
+            None => positional.push(arg.as_str()),
+        }
+    }
+    Ok((positional, options))
+}
+
+fn load(messages_folder: &str) -> Result<I18n, String> {
+    if !Path::new(messages_folder).is_dir() {
+        return Err(format!("'{}' is not a folder", messages_folder));
+    }
+    Ok(I18n::from_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: messages_folder.to_string(),
+        warn_unknown_locales: false,
+        ..Default::default()
+    }))
+}
+
+fn freeze(folder: &str, options: &HashMap<&str, &str>) -> Result<ExitCode, String> {
+    let version = options.get("version").ok_or("--version is required")?;
+    let lang = options.get("lang").copied().unwrap_or("en");
+    let out = options.get("out").copied().unwrap_or("freeze.json");
+    if let Ok(json) = fs::read_to_string(out) {
+        let previous: FreezeManifest = serde_json::from_str(&json).map_err(|e| format!("invalid '{}': {}", out, e))?;
+        if previous.compare_version(version) == Ordering::Less {
+            return Err(format!("'{}' freezes {}, which is newer than {}", out, previous.version, version));
+        }
+    }
+    let i18n = load(folder)?;
+    if !i18n.available_languages().iter().any(|l| l == lang) {
+        return Err(format!("language '{}' not found in '{}'", lang, folder));
+    }
+    let manifest = i18n.freeze_manifest(lang, version);
+    let json = serde_json::to_string_pretty(&manifest).map_err(|e| e.to_string())?;
+    fs::write(out, json + "\n").map_err(|e| format!("cannot write '{}': {}", out, e))?;
+    println!("froze {} '{}' strings for {} in {}", manifest.strings.len(), lang, version, out);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn check_freeze(folder: &str, options: &HashMap<&str, &str>) -> Result<ExitCode, String> {
+    let version = options.get("version").ok_or("--version is required")?;
+    let path = options.get("manifest").copied().unwrap_or("freeze.json");
+    let json = fs::read_to_string(path).map_err(|e| format!("cannot read '{}': {}", path, e))?;
+    let manifest: FreezeManifest = serde_json::from_str(&json).map_err(|e| format!("invalid '{}': {}", path, e))?;
+    match manifest.compare_version(version) {
+        Ordering::Greater => {
+            println!("version bumped ({} -> {}), freeze lifted", manifest.version, version);
+            return Ok(ExitCode::SUCCESS);
+        }
+        Ordering::Less => {
+            return Err(format!("'{}' freezes {}, which is newer than {}", path, manifest.version, version));
+        }
+        Ordering::Equal => {}
+    }
+    let changes = manifest.check(&load(folder)?, version);
+    if changes.is_empty() {
+        println!("{} frozen strings unchanged", manifest.strings.len());
+        return Ok(ExitCode::SUCCESS);
+    }
+    for change in &changes {
+        let kind = match change.kind {
+            FrozenChangeKind::Changed => "changed",
+            FrozenChangeKind::Removed => "removed",
+        };
+        eprintln!("{}.{}: frozen string {}", change.file, change.key, kind);
+    }
+    eprintln!("{} frozen strings changed without a version bump", changes.len());
+    Ok(ExitCode::FAILURE)
+}
+
+/// Updates `<lang>/_keys.json` with the current stable IDs, recording keys
+/// renamed since the previous run.
+fn migrate_keys(folder: &str, options: &HashMap<&str, &str>) -> Result<ExitCode, String> {
+    let lang = options.get("lang").copied().unwrap_or("en");
+    let i18n = load(folder)?;
+    if !i18n.available_languages().iter().any(|l| l == lang) {
+        return Err(format!("language '{}' not found in '{}'", lang, folder));
+    }
+    let path = Path::new(folder).join(lang).join("_keys.json");
+    let mut migrations: BTreeMap<String, KeyMigrations> = match fs::read_to_string(&path) {
+        Ok(json) => serde_json::from_str(&json).map_err(|e| format!("invalid '{}': {}", path.display(), e))?,
+        Err(_) => BTreeMap::new(),
+    };
+    let ids = i18n.stable_ids(lang);
+    migrations.retain(|file, _| ids.contains_key(file));
+    for (file, ids) in ids {
+        for (former, current) in migrations.entry(file.clone()).or_default().update(ids) {
+            println!("{}: {} -> {}", file, former, current);
+        }
+    }
+    let json = serde_json::to_string_pretty(&migrations).map_err(|e| e.to_string())?;
+    fs::write(&path, json + "\n").map_err(|e| format!("cannot write '{}': {}", path.display(), e))?;
+    println!("updated {}", path.display());
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Reports broken placeholders and strings that look like concatenated
+/// fragments; fails if there are any.
+fn lint(folder: &str) -> Result<ExitCode, String> {
+    let i18n = load(folder)?;
+    let errors = i18n.check_message_syntax();
+    let smells = i18n.check_concatenation();
+    for error in &errors {
+        eprintln!("{}", error);
+    }
+    for smell in &smells {
+        eprintln!("{}", smell);
+    }
+    if errors.is_empty() && smells.is_empty() {
+        println!("no problems found");
+        return Ok(ExitCode::SUCCESS);
+    }
+    eprintln!("{} syntax errors, {} concatenated fragments", errors.len(), smells.len());
+    Ok(ExitCode::FAILURE)
+}
+
+/// Compares bundled translations — `--bundle`, or the ones compiled into
+/// this binary — with the messages folder; fails if a file differs.
+/// `--overlay` names a folder next to the messages folder.
+fn check_bundle(folder: &str, options: &HashMap<&str, &str>) -> Result<ExitCode, String> {
+    if !Path::new(folder).is_dir() {
+        return Err(format!("'{}' is not a folder", folder));
+    }
+    let drift = match options.get("bundle") {
+        Some(path) => {
+            let json = fs::read_to_string(path).map_err(|e| format!("cannot read '{}': {}", path, e))?;
+            let bundled = serde_json::from_str(&json).map_err(|e| format!("invalid '{}': {}", path, e))?;
+            let on_disk = bundle_source(folder, options.get("overlay").copied()).map_err(|e| e.to_string())?;
+            compare_bundle(&bundled, &on_disk)
+        }
+        None if I18n::bundle_hash().is_none() => {
+            return Err("no translations are bundled in this build; pass --bundle".to_string());
+        }
+        None => I18n::bundle_drift(folder).map_err(|e| e.to_string())?,
+    };
+    if drift.is_empty() {
+        println!("the bundle matches '{}'", folder);
+        return Ok(ExitCode::SUCCESS);
+    }
+    for file in &drift {
+        eprintln!("{}", file);
+    }
+    eprintln!("{} files differ; rebuild to refresh the bundle", drift.len());
+    Ok(ExitCode::FAILURE)
+}
+
+/// Rewrites the `.json` translation files of older schemas to the current
+/// one. Other formats are migrated when they are loaded.
+fn migrate(folder: &str) -> Result<ExitCode, String> {
+    if !Path::new(folder).is_dir() {
+        return Err(format!("'{}' is not a folder", folder));
+    }
+    let mut paths = Vec::new();
+    for lang in fs::read_dir(folder).map_err(|e| format!("cannot read '{}': {}", folder, e))? {
+        let lang = lang.map_err(|e| e.to_string())?.path();
+        let Ok(files) = fs::read_dir(&lang) else { continue };
+        for file in files {
+            let path = file.map_err(|e| e.to_string())?.path();
+            let name = path.file_name().and_then(|name| name.to_str()).unwrap_or("_");
+            if name.ends_with(".json") && !name.starts_with('_') {
+                paths.push(path);
+            }
+        }
+    }
+    paths.sort();
+
+    // Every file is migrated before any is written, so a file of a newer
+    // schema leaves the folder untouched.
+    let mut migrated = Vec::new();
+    for path in &paths {
+        let json = fs::read_to_string(path).map_err(|e| format!("cannot read '{}': {}", path.display(), e))?;
+        let mut file = serde_json::from_str(&json).map_err(|e| format!("invalid '{}': {}", path.display(), e))?;
+        if file_schema(&file) != Ok(TRANSLATION_SCHEMA) {
+            let schema = migrate_file(&mut file).map_err(|e| format!("'{}': {}", path.display(), e))?;
+            migrated.push((path, schema, file));
+        }
+    }
+    for (path, schema, file) in &migrated {
+        let json = serde_json::to_string_pretty(file).map_err(|e| e.to_string())?;
+        fs::write(path, json + "\n").map_err(|e| format!("cannot write '{}': {}", path.display(), e))?;
+        println!("{}: schema {} -> {}", path.display(), schema, TRANSLATION_SCHEMA);
+    }
+    println!("{} of {} files migrated to schema {}", migrated.len(), paths.len(), TRANSLATION_SCHEMA);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = parse_args(&args).and_then(|(positional, options)| match positional.as_slice() {
+        ["freeze", folder] => freeze(folder, &options),
+        ["check-freeze", folder] => check_freeze(folder, &options),
+        ["migrate-keys", folder] => migrate_keys(folder, &options),
+        ["lint", folder] => lint(folder),
+        ["check-bundle", folder] => check_bundle(folder, &options),
+        ["migrate", folder] => migrate(folder),
+        _ => Err(USAGE.to_string()),
+    });
+    result.unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        ExitCode::from(2)
+    })
+}
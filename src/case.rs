@@ -0,0 +1,91 @@
+//! Capitalization of headings and menu entries.
+//!
+//! English headings are Title Case ("Sword of the Dawn"), while most other
+//! languages capitalize headings like sentences ("Épée de l'aube"). A
+//! translation can ask for the convention of its language with the `heading`
+//! transform, `"title": "{{item|heading}}"`, or code can call
+//! [`I18n::heading_case`].
+
+use crate::I18n;
+
+/// Capitalization convention of headings, see [`I18n::heading_style`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum HeadingCase {
+    /// Every word capitalized except short articles, conjunctions and
+    /// prepositions (English).
+    Title,
+    /// Only the first letter capitalized; the rest is kept as written.
+    #[default]
+    Sentence,
+}
+
+/// Words kept lowercase inside English Title Case.
+const MINOR_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "from", "in", "into", "nor", "of", "on", "or", "the", "to",
+    "with",
+];
+
+impl HeadingCase {
+    /// Convention of `lang`: Title Case for English, sentence case otherwise.
+    pub fn for_locale(lang: &str) -> Self {
+        match lang.split(['-', '_']).next().unwrap_or(lang) {
+            "en" => HeadingCase::Title,
+            _ => HeadingCase::Sentence,
+        }
+    }
+
+    /// Capitalizes `text` with this convention. Words that already contain
+    /// capitals ("iPhone", "NPC") are left alone.
+    pub fn apply(self, text: &str) -> String {
+        match self {
+            HeadingCase::Sentence => capitalize_first(text),
+            HeadingCase::Title => {
+                let words: Vec<&str> = text.split(' ').collect();
+                let last = words.iter().rposition(|word| !word.is_empty()).unwrap_or(0);
+                let first = words.iter().position(|word| !word.is_empty()).unwrap_or(0);
+                words
+                    .iter()
+                    .enumerate()
+                    .map(|(i, word)| {
+                        let lower = word.to_lowercase();
+                        if i != first && i != last && MINOR_WORDS.contains(&lower.as_str()) {
+                            lower
+                        } else if word.chars().any(char::is_uppercase) {
+                            word.to_string()
+                        } else {
+                            capitalize_first(word)
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            }
+        }
+    }
+}
+
+/// `text` with its first letter uppercased.
+fn capitalize_first(text: &str) -> String {
+    match text.char_indices().find(|(_, c)| c.is_alphabetic()) {
+        Some((i, c)) => {
+            let mut out = String::with_capacity(text.len());
+            out.push_str(&text[..i]);
+            out.extend(c.to_uppercase());
+            out.push_str(&text[i + c.len_utf8()..]);
+            out
+        }
+        None => text.to_string(),
+    }
+}
+
+impl I18n {
+    /// Heading capitalization convention of the current language.
+    pub fn heading_style(&self) -> HeadingCase {
+        HeadingCase::for_locale(self.get_lang())
+    }
+
+    /// Capitalizes `text` as a heading of the current language, see
+    /// [`HeadingCase`].
+    pub fn heading_case(&self, text: &str) -> String {
+        self.heading_style().apply(text)
+    }
+}
@@ -0,0 +1,111 @@
+//! Message-driven language management.
+//!
+//! Gameplay code and UI widgets write [`I18nCommand`]s instead of taking
+//! `ResMut<I18n>`; [`apply_i18n_commands`] applies them each frame, before
+//! [`update_i18n_text`](crate::update_i18n_text) re-renders the labels.
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_intl::I18nCommand;
+//!
+//! fn on_french_button(mut commands: MessageWriter<I18nCommand>) {
+//!     commands.write(I18nCommand::SetLanguage("fr".into()));
+//! }
+//! ```
+
+use bevy::prelude::*;
+use serde_json::Value;
+use tracing::warn;
+
+use crate::{I18n, I18nConfig, I18nDiagnostic, RefreshI18nText, SpeechRegister, UserTerm};
+
+/// A change to the [`I18n`] resource, applied by [`apply_i18n_commands`].
+#[derive(Message, Debug, Clone, PartialEq)]
+pub enum I18nCommand {
+    /// Switches the current language, see [`I18n::try_set_lang`].
+    SetLanguage(String),
+    /// Replaces the fallback chain, see [`I18n::try_set_fallback_chain`].
+    SetFallback(Vec<String>),
+    /// Reloads every translation from the [`I18nConfig`] resource, see
+    /// [`I18n::reload`].
+    Reload,
+    /// Merges a language pack into `lang`, see [`I18n::merge_pack`].
+    MergePack { lang: String, pack: Value },
+    /// Switches the politeness level, see [`I18n::set_speech_register`].
+    SetSpeechRegister(SpeechRegister),
+    /// Switches the language of localized audio, see
+    /// [`I18n::try_set_audio_lang`].
+    SetAudioLanguage(Option<String>),
+    /// Adds or replaces the player-defined term `id`, or removes it with
+    /// `None`, see [`I18n::user_dictionary_mut`].
+    SetUserTerm { id: String, term: Option<UserTerm> },
+}
+
+/// Bevy system applying pending [`I18nCommand`]s in order. Failing commands
+/// (unknown locale, malformed pack) log a warning and are skipped. Rejected
+/// packs and problems of a reload are also sent as [`I18nDiagnostic`]s.
+pub fn apply_i18n_commands(
+    mut messages: MessageReader<I18nCommand>,
+    mut i18n: ResMut<I18n>,
+    config: Res<I18nConfig>,
+    mut diagnostics: MessageWriter<I18nDiagnostic>,
+    mut commands: Commands,
+) {
+    let mut refresh = false;
+    for command in messages.read() {
+        let result = match command {
+            I18nCommand::SetLanguage(lang) => i18n.try_set_lang(lang),
+            I18nCommand::SetFallback(chain) => {
+                let chain: Vec<&str> = chain.iter().map(String::as_str).collect();
+                i18n.try_set_fallback_chain(&chain)
+            }
+            I18nCommand::Reload => {
+                i18n.reload(&config);
+                for diagnostic in i18n.diagnostics() {
+                    diagnostics.write(diagnostic.clone());
+                }
+                refresh = true;
+                Ok(())
+            }
+            I18nCommand::MergePack { lang, pack } => match i18n.merge_pack(lang, pack) {
+                Ok(()) => {
+                    refresh = true;
+                    Ok(())
+                }
+                Err(e) => {
+                    warn!("{}", e);
+                    diagnostics.write(I18nDiagnostic::PackRejected(e));
+                    continue;
+                }
+            },
+            I18nCommand::SetSpeechRegister(register) => {
+                refresh = true;
+                i18n.set_speech_register(*register);
+                Ok(())
+            }
+            I18nCommand::SetAudioLanguage(lang) => {
+                let result = i18n.try_set_audio_lang(lang.as_deref());
+                refresh |= result.is_ok();
+                result
+            }
+            I18nCommand::SetUserTerm { id, term } => {
+                let dictionary = i18n.user_dictionary_mut();
+                match term {
+                    Some(term) => dictionary.insert(id.clone(), term.clone()),
+                    None => dictionary.remove(id),
+                };
+                refresh = true;
+                Ok(())
+            }
+        };
+        if let Err(e) = result {
+            warn!("{}", e);
+        }
+    }
+    // Language switches are picked up by `update_i18n_text`; new strings in
+    // the current language, register and audio language switches and renamed
+    // user terms are not.
+    if refresh {
+        commands.trigger(RefreshI18nText);
+    }
+}
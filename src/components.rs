@@ -1,22 +1,41 @@
 //! Reactive translation component and supporting types.
 //!
 //! Spawn an [`I18nText`] alongside any entity that has a `Text` component;
-//! the [`update_i18n_text`] system (registered automatically by
-//! [`crate::I18nPlugin`]) keeps the rendered text in sync with the active
-//! language. When the language changes, every `I18nText` in the world is
-//! re-rendered and a [`LanguageChanged`] event is fired so other systems can
-//! react (e.g. reloading localized assets).
+//! observers registered by [`crate::I18nPlugin`] keep the rendered text in
+//! sync with the active language. Inserting an `I18nText` renders that one
+//! entity immediately; when the language changes, [`update_i18n_text`]
+//! triggers a [`RefreshI18nText`] event and fires a [`LanguageChanged`]
+//! message and event so other systems and observers can react (e.g.
+//! reloading localized assets).
+//! Nothing is scanned on frames where neither happens.
+//!
+//! Multi-style strings use [`I18nTextSpan`] on the child `TextSpan` entities
+//! of a text root; each span is translated independently, so a sentence split
+//! into differently-styled fragments keeps its styling across languages.
+//!
+//! Scenes with thousands of labels can spread a refresh over several frames
+//! with a [`RefreshBudget`], and leave hidden menus for later with
+//! [`HiddenLabels::Defer`].
 
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use bevy::platform::time::Instant;
 use bevy::prelude::*;
 
-use crate::I18n;
+use crate::{CheckedKey, I18n};
 
 /// Component describing a translation key to render into a sibling `Text`.
 ///
 /// The component owns its `file` / `key` strings to keep things `Send + Sync`
 /// without lifetimes; for hot UI text consider caching the
 /// [`I18nText`] entity rather than rebuilding it every frame.
+///
+/// `I18nText` is immutable: to change the key or mode of an existing label,
+/// re-insert it (`commands.entity(e).insert(I18nText { .. })`). The insert
+/// observer then re-renders exactly that entity.
 #[derive(Component, Clone, Debug)]
+#[component(immutable)]
 #[require(Text)]
 pub struct I18nText {
     /// Translation file (without the `.json` extension), e.g. `"ui"`.
@@ -36,9 +55,89 @@ impl I18nText {
             mode: I18nMode::Plain,
         }
     }
+
+    /// Convenience constructor for a translation with named placeholders,
+    /// taking the arguments built by [`i18n_args!`](crate::i18n_args):
+    ///
+    /// ```rust,no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_intl::{I18nText, i18n_args};
+    /// # fn setup(mut commands: Commands, player: &str) {
+    /// // "welcome": "Welcome, {{name}}!"
+    /// commands.spawn(I18nText::with_args("ui", "welcome", i18n_args! { name = player }));
+    /// # }
+    /// ```
+    pub fn with_args(file: impl Into<String>, key: impl Into<String>, args: &[(&str, &dyn ToString)]) -> Self {
+        Self {
+            file: file.into(),
+            key: key.into(),
+            mode: I18nMode::args(args),
+        }
+    }
+}
+
+impl From<CheckedKey> for I18nText {
+    fn from(key: CheckedKey) -> Self {
+        Self::new(key.file, key.key)
+    }
+}
+
+/// Component describing a translation key to render into a sibling `TextSpan`.
+///
+/// The span counterpart of [`I18nText`]: put it on the children of a `Text`
+/// (or `Text2d`) root to build rich, multi-style localized strings where each
+/// fragment carries its own `TextFont` / `TextColor`. Spans are refreshed
+/// together with `I18nText` when the language changes.
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intl::{I18nText, I18nTextSpan};
+/// fn setup(mut commands: Commands) {
+///     commands
+///         .spawn(I18nText::new("ui", "score_label"))
+///         .with_child((
+///             I18nTextSpan::new("ui", "score_value"),
+///             TextColor(Color::srgb(1.0, 0.8, 0.0)),
+///         ));
+/// }
+/// ```
+///
+/// Like `I18nText`, this component is immutable; re-insert it to change it.
+#[derive(Component, Clone, Debug)]
+#[component(immutable)]
+#[require(TextSpan)]
+pub struct I18nTextSpan {
+    /// Translation file (without the `.json` extension), e.g. `"ui"`.
+    pub file: String,
+    /// Translation key inside that file, e.g. `"score_value"`.
+    pub key: String,
+    /// How to render the translation (plain, plural, gender, …).
+    pub mode: I18nMode,
+}
+
+impl I18nTextSpan {
+    /// Convenience constructor for a plain translation.
+    pub fn new(file: impl Into<String>, key: impl Into<String>) -> Self {
+        Self {
+            file: file.into(),
+            key: key.into(),
+            mode: I18nMode::Plain,
+        }
+    }
+
+    /// Convenience constructor for a translation with named placeholders,
+    /// see [`I18nText::with_args`].
+    pub fn with_args(file: impl Into<String>, key: impl Into<String>, args: &[(&str, &dyn ToString)]) -> Self {
+        Self {
+            file: file.into(),
+            key: key.into(),
+            mode: I18nMode::args(args),
+        }
+    }
 }
 
-/// Selects which translation method to call when rendering an [`I18nText`].
+/// Selects which translation method to call when rendering an [`I18nText`]
+/// or [`I18nTextSpan`].
 #[derive(Clone, Debug)]
 pub enum I18nMode {
     /// `t(key)`
@@ -53,77 +152,298 @@ pub enum I18nMode {
     GenderArgs(String, Vec<(String, String)>),
     /// `t_with_gender_and_plural(key, gender, count)`
     GenderPlural(String, usize),
+    /// [`I18n::t_with_terms`]`(file, key, terms)` — placeholder and user term
+    /// ID pairs.
+    Terms(Vec<(String, String)>),
+}
+
+impl I18nMode {
+    /// [`Args`](Self::Args) with the values of `args` rendered to strings.
+    pub fn args(args: &[(&str, &dyn ToString)]) -> Self {
+        I18nMode::Args(args.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect())
+    }
 }
 
 /// Message broadcast by [`update_i18n_text`] when the active language changes.
 ///
 /// Useful for reacting to language changes outside of `I18nText` (e.g. swapping
 /// images, reloading audio, refreshing a custom widget). Read it with a
-/// `MessageReader<LanguageChanged>` system param.
+/// `MessageReader<LanguageChanged>` system param, or react in the same frame
+/// with an observer, as it is also triggered as an event:
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intl::LanguageChanged;
+/// # let mut app = App::new();
+/// app.add_observer(|changed: On<LanguageChanged>| {
+///     info!("language switched from {} to {}", changed.from, changed.to);
+/// });
+/// ```
 ///
-/// Bevy 0.18 renamed buffered events to *messages*, so this type derives
-/// `Message` rather than `Event` — the practical usage is the same.
-#[derive(Message, Debug, Clone)]
+/// Bevy 0.18 renamed buffered events to *messages*; this type derives both.
+#[derive(Message, Event, Debug, Clone)]
 pub struct LanguageChanged {
+    /// Language active before the change.
     pub from: String,
+    /// Newly active language.
     pub to: String,
 }
 
-/// Bevy system that keeps `Text` in sync with `I18nText`.
+/// Event that re-renders every [`I18nText`] and [`I18nTextSpan`] in the world.
 ///
-/// - When the active language changes, every `I18nText` is re-rendered and a
-///   `LanguageChanged` event is written.
-/// - Otherwise, only entities with `Added<I18nText>` or `Changed<I18nText>` are
-///   re-rendered (cheap incremental updates on spawn / edit).
+/// Triggered automatically by [`update_i18n_text`] when the language changes.
+/// Trigger it yourself (`commands.trigger(RefreshI18nText)`) after mutating
+/// the loaded translations so labels pick up the new strings.
+#[derive(Event, Debug, Clone, Copy, Default)]
+pub struct RefreshI18nText;
+
+/// Bevy system that detects language changes.
+///
+/// Returns early unless the `I18n` resource changed this frame. When the
+/// active language differs from the last one seen, a [`RefreshI18nText`]
+/// event is triggered and a `LanguageChanged` event is triggered and written
+/// as a message (the very first run only triggers the refresh).
 pub fn update_i18n_text(
     i18n: Res<I18n>,
-    mut sets: ParamSet<(
-        Query<(&I18nText, &mut Text), Or<(Changed<I18nText>, Added<I18nText>)>>,
-        Query<(&I18nText, &mut Text)>,
-    )>,
     mut last_lang: Local<Option<String>>,
     mut events: MessageWriter<LanguageChanged>,
+    mut commands: Commands,
 ) {
-    let current = i18n.get_lang().to_string();
-    let lang_changed = last_lang.as_deref() != Some(current.as_str());
+    if !i18n.is_changed() {
+        return;
+    }
+    let current = i18n.get_lang();
+    if last_lang.as_deref() == Some(current) {
+        return;
+    }
 
-    if lang_changed {
-        let prev = last_lang.replace(current.clone());
-        if let Some(prev) = prev {
-            events.write(LanguageChanged { from: prev, to: current.clone() });
+    let prev = last_lang.replace(current.to_string());
+    if let Some(prev) = prev {
+        let changed = LanguageChanged { from: prev, to: current.to_string() };
+        commands.trigger(changed.clone());
+        events.write(changed);
+    }
+    commands.trigger(RefreshI18nText);
+}
+
+/// Observer rendering a single entity as soon as its `I18nText` is inserted
+/// (spawn or re-insert).
+pub(crate) fn render_inserted_i18n_text(
+    insert: On<Insert, I18nText>,
+    i18n: Res<I18n>,
+    mut query: Query<(&I18nText, &mut Text)>,
+) {
+    if let Ok((it, mut text)) = query.get_mut(insert.entity) {
+        text.0 = render(&i18n, &it.file, &it.key, &it.mode);
+    }
+}
+
+/// Observer rendering a single span as soon as its `I18nTextSpan` is inserted.
+pub(crate) fn render_inserted_i18n_text_span(
+    insert: On<Insert, I18nTextSpan>,
+    i18n: Res<I18n>,
+    mut query: Query<(&I18nTextSpan, &mut TextSpan)>,
+) {
+    if let Ok((it, mut span)) = query.get_mut(insert.entity) {
+        span.0 = render(&i18n, &it.file, &it.key, &it.mode);
+    }
+}
+
+/// How much of a [`RefreshI18nText`] is done per frame.
+///
+/// With a budget, a refresh queues every label and
+/// [`process_i18n_refresh_queue`] renders them over the following frames,
+/// avoiding a frame spike when the language changes in a scene with
+/// thousands of labels. At least one label is rendered per frame.
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intl::RefreshBudget;
+/// # let mut app = App::new();
+/// app.insert_resource(RefreshBudget::Entities(500));
+/// ```
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq)]
+pub enum RefreshBudget {
+    /// Every label is rendered at once.
+    #[default]
+    Unlimited,
+    /// At most this many labels per frame.
+    Entities(usize),
+    /// Labels are rendered until this much time has been spent in a frame.
+    Time(Duration),
+}
+
+/// Labels waiting to be re-rendered under a [`RefreshBudget`].
+#[derive(Resource, Debug, Default)]
+pub struct I18nRefreshQueue {
+    entities: VecDeque<Entity>,
+}
+
+impl I18nRefreshQueue {
+    /// Number of labels still showing the previous translation.
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Whether the last refresh is complete.
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+}
+
+/// Whether a [`RefreshI18nText`] re-renders labels that are not visible.
+///
+/// With [`HiddenLabels::Defer`], labels whose `InheritedVisibility` is off
+/// (a closed menu, an inactive tab) are marked [`I18nStale`] instead, and
+/// rendered by [`render_revealed_i18n_text`] once they become visible. Only
+/// useful with Bevy's visibility propagation running: without it every
+/// label counts as hidden.
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_intl::HiddenLabels;
+/// # let mut app = App::new();
+/// app.insert_resource(HiddenLabels::Defer);
+/// ```
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HiddenLabels {
+    /// Hidden labels are rendered like visible ones.
+    #[default]
+    Refresh,
+    /// Hidden labels are rendered when they are next shown.
+    Defer,
+}
+
+/// Marker of a label still showing a previous translation because it was
+/// hidden during a refresh, see [`HiddenLabels::Defer`].
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct I18nStale;
+
+/// Observer re-rendering every `I18nText` and `I18nTextSpan` on
+/// [`RefreshI18nText`], or queueing them under a [`RefreshBudget`].
+#[allow(clippy::type_complexity)]
+pub(crate) fn refresh_i18n_text(
+    _refresh: On<RefreshI18nText>,
+    i18n: Res<I18n>,
+    (budget, hidden): (Res<RefreshBudget>, Res<HiddenLabels>),
+    mut queue: ResMut<I18nRefreshQueue>,
+    mut texts: Query<(Entity, &I18nText, &mut Text, Option<&InheritedVisibility>, Has<I18nStale>)>,
+    mut spans: Query<(Entity, &I18nTextSpan, &mut TextSpan, Option<&InheritedVisibility>, Has<I18nStale>)>,
+    mut commands: Commands,
+) {
+    // Hidden labels are marked stale; visible ones lose a stale marker.
+    let mut visible = |entity: Entity, visibility: Option<&InheritedVisibility>, stale: bool| {
+        let shown = *hidden == HiddenLabels::Refresh || visibility.is_none_or(|v| v.get());
+        if !shown {
+            commands.entity(entity).insert(I18nStale);
+        } else if stale {
+            commands.entity(entity).remove::<I18nStale>();
+        }
+        shown
+    };
+    queue.entities.clear();
+    if *budget != RefreshBudget::Unlimited {
+        let texts = texts.iter().filter(|(e, _, _, v, stale)| visible(*e, *v, *stale)).map(|(e, ..)| e);
+        queue.entities.extend(texts);
+        let spans = spans.iter().filter(|(e, _, _, v, stale)| visible(*e, *v, *stale)).map(|(e, ..)| e);
+        queue.entities.extend(spans);
+        return;
+    }
+    for (entity, it, mut text, visibility, stale) in &mut texts {
+        if visible(entity, visibility, stale) {
+            text.0 = render(&i18n, &it.file, &it.key, &it.mode);
+        }
+    }
+    for (entity, it, mut span, visibility, stale) in &mut spans {
+        if visible(entity, visibility, stale) {
+            span.0 = render(&i18n, &it.file, &it.key, &it.mode);
+        }
+    }
+}
+
+/// Bevy system rendering [`I18nStale`] labels that became visible. Runs in
+/// `Update`, so a revealed label shows its new translation from the frame
+/// after visibility propagation.
+#[allow(clippy::type_complexity)]
+pub fn render_revealed_i18n_text(
+    i18n: Res<I18n>,
+    mut texts: Query<(Entity, &I18nText, &mut Text, &InheritedVisibility), (With<I18nStale>, Changed<InheritedVisibility>)>,
+    mut spans: Query<
+        (Entity, &I18nTextSpan, &mut TextSpan, &InheritedVisibility),
+        (With<I18nStale>, Changed<InheritedVisibility>),
+    >,
+    mut commands: Commands,
+) {
+    for (entity, it, mut text, visibility) in &mut texts {
+        if visibility.get() {
+            text.0 = render(&i18n, &it.file, &it.key, &it.mode);
+            commands.entity(entity).remove::<I18nStale>();
+        }
+    }
+    for (entity, it, mut span, visibility) in &mut spans {
+        if visibility.get() {
+            span.0 = render(&i18n, &it.file, &it.key, &it.mode);
+            commands.entity(entity).remove::<I18nStale>();
         }
-        let mut q = sets.p1();
-        for (it, mut text) in &mut q {
-            text.0 = render(&i18n, it);
+    }
+}
+
+/// Bevy system rendering queued labels within the [`RefreshBudget`].
+pub fn process_i18n_refresh_queue(
+    i18n: Res<I18n>,
+    budget: Res<RefreshBudget>,
+    mut queue: ResMut<I18nRefreshQueue>,
+    mut texts: Query<(&I18nText, &mut Text)>,
+    mut spans: Query<(&I18nTextSpan, &mut TextSpan)>,
+) {
+    if queue.is_empty() {
+        return;
+    }
+    let start = Instant::now();
+    let mut rendered = 0;
+    while let Some(entity) = queue.entities.pop_front() {
+        if let Ok((it, mut text)) = texts.get_mut(entity) {
+            text.0 = render(&i18n, &it.file, &it.key, &it.mode);
+        } else if let Ok((it, mut span)) = spans.get_mut(entity) {
+            span.0 = render(&i18n, &it.file, &it.key, &it.mode);
         }
-    } else {
-        let mut q = sets.p0();
-        for (it, mut text) in &mut q {
-            text.0 = render(&i18n, it);
+        rendered += 1;
+        let exhausted = match *budget {
+            RefreshBudget::Unlimited => false,
+            RefreshBudget::Entities(max) => rendered >= max,
+            RefreshBudget::Time(max) => start.elapsed() >= max,
+        };
+        if exhausted {
+            break;
         }
     }
 }
 
-fn render(i18n: &I18n, it: &I18nText) -> String {
-    let t = i18n.translation(&it.file);
-    match &it.mode {
-        I18nMode::Plain => t.t(&it.key),
-        I18nMode::Plural(c) => t.t_with_plural(&it.key, *c),
-        I18nMode::Gender(g) => t.t_with_gender(&it.key, g),
+fn render(i18n: &I18n, file: &str, key: &str, mode: &I18nMode) -> String {
+    let t = i18n.translation(file);
+    let text = match mode {
+        I18nMode::Plain => t.t(key),
+        I18nMode::Plural(c) => t.t_with_plural(key, *c),
+        I18nMode::Gender(g) => t.t_with_gender(key, g),
         I18nMode::Args(args) => {
             let view: Vec<(&str, &dyn ToString)> = args
                 .iter()
                 .map(|(k, v)| (k.as_str(), v as &dyn ToString))
                 .collect();
-            t.t_with_args(&it.key, &view)
+            t.t_with_args(key, &view)
         }
         I18nMode::GenderArgs(g, args) => {
             let view: Vec<(&str, &dyn ToString)> = args
                 .iter()
                 .map(|(k, v)| (k.as_str(), v as &dyn ToString))
                 .collect();
-            t.t_with_gender_and_args(&it.key, g, &view)
+            t.t_with_gender_and_args(key, g, &view)
         }
-        I18nMode::GenderPlural(g, c) => t.t_with_gender_and_plural(&it.key, g, *c),
-    }
+        I18nMode::GenderPlural(g, c) => t.t_with_gender_and_plural(key, g, *c),
+        I18nMode::Terms(terms) => {
+            let view: Vec<(&str, &str)> = terms.iter().map(|(p, id)| (p.as_str(), id.as_str())).collect();
+            i18n.t_with_terms(file, key, &view)
+        }
+    };
+    i18n.prepare_for_layout(text)
 }
@@ -0,0 +1,67 @@
+//! Random names and phrases assembled from per-language fragment tables.
+//!
+//! Procedural content needs names that sound right in each language, which
+//! translating a generated English name cannot give. Each language lists its
+//! own fragments as string arrays:
+//!
+//! ```json
+//! {
+//!     "prefix": ["Grim", "Ash", "Storm"],
+//!     "suffix": ["hold", "vale", "reach"],
+//!     "town": ["{{prefix}}{{suffix}}", "Port {{prefix}}"]
+//! }
+//! ```
+//!
+//! [`I18nPartial::compose`] fills every placeholder of a pattern with a
+//! fragment of the list of that name. The crate has no random number
+//! generator: callers pass a function picking an index below a length, so
+//! any RNG (seeded for reproducible worlds) works.
+
+use crate::format::format_template;
+use crate::{I18nPartial, LogCategory};
+
+impl I18nPartial<'_> {
+    /// Strings of the array at `key`, looked up like translations (current
+    /// language, then the fallback chain). `None` if the key does not hold
+    /// an array; items that are not strings are skipped.
+    pub fn list(&self, key: &str) -> Option<Vec<&str>> {
+        let items = self.raw(key)?.as_array()?;
+        Some(items.iter().filter_map(|item| item.as_str()).collect())
+    }
+
+    /// Replaces each `{{name}}` of `pattern` with an item of the list `name`,
+    /// at the index `pick(len)` returns (`pick` must return less than `len`).
+    /// When `pattern` is itself a list key, one of its patterns is picked
+    /// first. Placeholders without a non-empty list are kept as is.
+    ///
+    /// ```rust,no_run
+    /// # use bevy_intl::I18n;
+    /// # fn name_town(i18n: &I18n, rng: &mut impl FnMut(usize) -> usize) {
+    /// let places = i18n.translation("places");
+    /// // "Grimvale", "Port Ash", …
+    /// let town = places.compose("town", &mut *rng);
+    /// let fort = places.compose("Fort {{prefix}}", rng);
+    /// # }
+    /// ```
+    pub fn compose(&self, pattern: &str, mut pick: impl FnMut(usize) -> usize) -> String {
+        let mut fragment = |key: &str| {
+            let list = self.list(key).filter(|list| !list.is_empty())?;
+            list.get(pick(list.len())).map(|item| item.to_string())
+        };
+        let pattern = match self.list(pattern) {
+            Some(_) => fragment(pattern).unwrap_or_default(),
+            None => pattern.to_string(),
+        };
+        format_template(&pattern, |name| {
+            let item = fragment(name);
+            if item.is_none() {
+                self.log.log(
+                    LogCategory::MissingKey,
+                    name,
+                    format_args!("no fragment list '{}' to compose {:?}", name, pattern),
+                );
+            }
+            item
+        })
+    }
+}
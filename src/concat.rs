@@ -0,0 +1,61 @@
+//! Lint for translations that look like fragments of a concatenated
+//! sentence.
+//!
+//! Building a sentence from pieces (`label + ": " + value`, `"You have " +
+//! count + " items"`) breaks in languages with another word order, and in
+//! right-to-left languages bidi reordering moves punctuation at the edge of
+//! a fragment to the wrong end. Each rule flags a string that is probably
+//! such a piece:
+//!
+//! - it ends with `"{{name}}:"`, a value used as a label;
+//! - it starts or ends with a space, or ends with `,` `;` or a dash, as if
+//!   glued to another string;
+//! - in a right-to-left language, it starts with punctuation.
+//!
+//! The module only depends on `std`, so `build.rs` shares it to warn about
+//! bundled translations.
+
+/// Punctuation that continues a sentence into the next string.
+const CONNECTORS: &[char] = &[',', ';', '-', '–', '—', '،', '؛'];
+
+/// Why `template` looks like a concatenated fragment, one message per rule
+/// it breaks. `rtl` enables the right-to-left rules.
+pub(crate) fn concatenation_smells(template: &str, rtl: bool) -> Vec<String> {
+    let mut smells = Vec::new();
+    let trimmed = template.trim_matches([' ', '\u{a0}']);
+    if trimmed.is_empty() {
+        return smells;
+    }
+
+    if let Some(name) = trimmed.strip_suffix(':').and_then(trailing_placeholder) {
+        smells.push(format!("ends with \"{{{{{}}}}}:\"; write the label and its value as one sentence", name));
+    }
+    if template.starts_with([' ', '\u{a0}']) {
+        smells.push("starts with a space, as if joined to another string".to_string());
+    }
+    if template.trim_end_matches('\n').ends_with([' ', '\u{a0}']) {
+        smells.push("ends with a space, as if joined to another string".to_string());
+    }
+    if let Some(last) = trimmed.chars().last().filter(|c| CONNECTORS.contains(c)) {
+        smells.push(format!("ends with '{}', as if continued by another string", last));
+    }
+    if let Some(first) = trimmed.chars().next().filter(|c| rtl && is_punctuation(*c)) {
+        smells.push(format!("starts with '{}', which bidi reordering moves to the end in right-to-left text", first));
+    }
+    smells
+}
+
+/// Name of the `{{name}}` placeholder `text` ends with.
+fn trailing_placeholder(text: &str) -> Option<&str> {
+    let inner = text.strip_suffix("}}")?;
+    let start = inner.rfind("{{")?;
+    let name = &inner[start + 2..];
+    (!name.is_empty() && !name.contains(['{', '}'])).then_some(name)
+}
+
+/// Punctuation whose position bidi reordering changes. Brackets and quotes
+/// are mirrored in pairs, and `{` starts a placeholder.
+fn is_punctuation(c: char) -> bool {
+    (c.is_ascii_punctuation() && !matches!(c, '{' | '(' | '[' | '"' | '\'' | '<'))
+        || matches!(c, '،' | '؛' | '؟' | '–' | '—' | '…')
+}
@@ -0,0 +1,118 @@
+//! Console commands for localization QA (`console` feature).
+//!
+//! Lines sent as [`I18nConsoleInput`] messages are executed by the
+//! [`run_i18n_console`] system; replies come back as [`I18nConsoleOutput`]
+//! messages and are logged. This works as a minimal built-in console (feed it
+//! from a debug text field or stdin) or behind an existing console such as
+//! `bevy_console`, by forwarding its lines:
+//!
+//! | command                 | effect                                           |
+//! |-------------------------|--------------------------------------------------|
+//! | `i18n lang fr`          | switches the language                            |
+//! | `i18n missing [lang]`   | lists keys the language lacks (current language) |
+//! | `i18n reload`           | reloads the translation files                    |
+//! | `i18n coverage`         | completeness of every available language         |
+
+use bevy::prelude::*;
+use tracing::info;
+
+use crate::{I18n, I18nCommand};
+
+/// A console line to execute, e.g. `"i18n lang fr"`. Lines that do not start
+/// with `i18n` are ignored, so every console line can be forwarded.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct I18nConsoleInput(pub String);
+
+/// Reply to an [`I18nConsoleInput`] line.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct I18nConsoleOutput(pub String);
+
+const USAGE: &str = "usage: i18n lang <code> | i18n missing [lang] | i18n reload | i18n coverage";
+
+/// Executes one console line against `i18n`. Returns `None` for lines that
+/// are not `i18n` commands, otherwise the reply and the [`I18nCommand`] to
+/// apply, if any.
+pub fn run_console_line(i18n: &I18n, line: &str) -> Option<(String, Option<I18nCommand>)> {
+    let mut words = line.split_whitespace();
+    if words.next() != Some("i18n") {
+        return None;
+    }
+    let args: Vec<&str> = words.collect();
+    let reply = match args.as_slice() {
+        ["lang"] => (format!("language: {}", i18n.get_lang()), None),
+        ["lang", lang] => {
+            if i18n.available_languages().iter().any(|l| l == lang) {
+                (format!("language: {}", lang), Some(I18nCommand::SetLanguage(lang.to_string())))
+            } else {
+                (
+                    format!("unknown language '{}' (available: {})", lang, i18n.available_languages().join(", ")),
+                    None,
+                )
+            }
+        }
+        ["missing"] => (missing_report(i18n, i18n.get_lang()), None),
+        ["missing", lang] => (missing_report(i18n, lang), None),
+        ["reload"] => ("reloading translations".to_string(), Some(I18nCommand::Reload)),
+        ["coverage"] => (coverage_report(i18n), None),
+        _ => (USAGE.to_string(), None),
+    };
+    Some(reply)
+}
+
+/// `file.key` of every key of the fallback language that `lang` lacks
+/// (keys an overlay leaves to its parent are not missing).
+fn missing_keys(i18n: &I18n, lang: &str) -> Vec<String> {
+    let Some(reference) = i18n.translations.langs.get(i18n.get_fallback_lang()) else {
+        return Vec::new();
+    };
+    let mut missing: Vec<String> = reference
+        .iter()
+        .flat_map(|(file, sections)| {
+            sections
+                .keys()
+                .filter(move |key| !i18n.has_translation(lang, file, key))
+                .map(move |key| format!("{}.{}", file, key))
+        })
+        .collect();
+    missing.sort();
+    missing
+}
+
+fn missing_report(i18n: &I18n, lang: &str) -> String {
+    let missing = missing_keys(i18n, lang);
+    if missing.is_empty() {
+        return format!("{}: no missing keys", lang);
+    }
+    format!("{}: {} missing\n{}", lang, missing.len(), missing.join("\n"))
+}
+
+fn coverage_report(i18n: &I18n) -> String {
+    i18n.available_languages()
+        .iter()
+        .map(|lang| {
+            let missing = missing_keys(i18n, lang).len();
+            format!("{} {:.1}% ({} missing)", lang, i18n.completeness(lang) * 100.0, missing)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Executes [`I18nConsoleInput`] lines. Language switches and reloads are
+/// sent as [`I18nCommand`]s, applied in the same frame.
+pub fn run_i18n_console(
+    mut input: MessageReader<I18nConsoleInput>,
+    mut output: MessageWriter<I18nConsoleOutput>,
+    mut commands: MessageWriter<I18nCommand>,
+    i18n: Res<I18n>,
+) {
+    for I18nConsoleInput(line) in input.read() {
+        let Some((reply, command)) = run_console_line(&i18n, line) else {
+            continue;
+        };
+        info!("{}", reply);
+        output.write(I18nConsoleOutput(reply));
+        if let Some(command) = command {
+            commands.write(command);
+        }
+    }
+}
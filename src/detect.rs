@@ -0,0 +1,121 @@
+//! Language detection for chat and user-generated content.
+//!
+//! Available with the `detect-language` feature, backed by `whatlang`.
+//! Multiplayer games can tag incoming chat with [`I18n::detect_language`] and
+//! offer a translation when it differs from the player's language.
+
+use whatlang::Lang;
+
+use crate::I18n;
+
+/// Result of [`I18n::detect_language`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DetectedLanguage {
+    /// Detected language as an ISO 639-1 code (`"en"`, `"fr"`, `"zh"`…), the
+    /// form used for translation folders.
+    pub lang: String,
+    /// Confidence between 0.0 and 1.0.
+    pub confidence: f64,
+    /// Whether the detector considers the result reliable. Short messages
+    /// rarely are.
+    pub reliable: bool,
+}
+
+impl I18n {
+    /// Detects the language of `text`, or `None` if nothing could be
+    /// detected (empty text, emoji only…).
+    ///
+    /// ```rust,no_run
+    /// # use bevy_intl::I18n;
+    /// # fn tag(i18n: &I18n, message: &str) {
+    /// if let Some(detected) = i18n.detect_language(message) {
+    ///     if detected.reliable && detected.lang != i18n.get_lang() {
+    ///         // offer "Translate from {detected.lang}?"
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn detect_language(&self, text: &str) -> Option<DetectedLanguage> {
+        let info = whatlang::detect(text)?;
+        Some(DetectedLanguage {
+            lang: iso_639_1(info.lang()).to_string(),
+            confidence: info.confidence(),
+            reliable: info.is_reliable(),
+        })
+    }
+}
+
+/// Two-letter code of a detected language (`whatlang` reports ISO 639-3).
+fn iso_639_1(lang: Lang) -> &'static str {
+    match lang {
+        Lang::Afr => "af",
+        Lang::Aka => "ak",
+        Lang::Amh => "am",
+        Lang::Ara => "ar",
+        Lang::Aze => "az",
+        Lang::Bel => "be",
+        Lang::Ben => "bn",
+        Lang::Bul => "bg",
+        Lang::Cat => "ca",
+        Lang::Ces => "cs",
+        Lang::Cmn => "zh",
+        Lang::Dan => "da",
+        Lang::Deu => "de",
+        Lang::Ell => "el",
+        Lang::Eng => "en",
+        Lang::Epo => "eo",
+        Lang::Est => "et",
+        Lang::Fin => "fi",
+        Lang::Fra => "fr",
+        Lang::Guj => "gu",
+        Lang::Heb => "he",
+        Lang::Hin => "hi",
+        Lang::Hrv => "hr",
+        Lang::Hun => "hu",
+        Lang::Hye => "hy",
+        Lang::Ind => "id",
+        Lang::Ita => "it",
+        Lang::Jav => "jv",
+        Lang::Jpn => "ja",
+        Lang::Kan => "kn",
+        Lang::Kat => "ka",
+        Lang::Khm => "km",
+        Lang::Kor => "ko",
+        Lang::Lat => "la",
+        Lang::Lav => "lv",
+        Lang::Lit => "lt",
+        Lang::Mal => "ml",
+        Lang::Mar => "mr",
+        Lang::Mkd => "mk",
+        Lang::Mya => "my",
+        Lang::Nep => "ne",
+        Lang::Nld => "nl",
+        Lang::Nob => "nb",
+        Lang::Ori => "or",
+        Lang::Pan => "pa",
+        Lang::Pes => "fa",
+        Lang::Pol => "pl",
+        Lang::Por => "pt",
+        Lang::Ron => "ro",
+        Lang::Rus => "ru",
+        Lang::Sin => "si",
+        Lang::Slk => "sk",
+        Lang::Slv => "sl",
+        Lang::Sna => "sn",
+        Lang::Spa => "es",
+        Lang::Srp => "sr",
+        Lang::Swe => "sv",
+        Lang::Tam => "ta",
+        Lang::Tel => "te",
+        Lang::Tgl => "tl",
+        Lang::Tha => "th",
+        Lang::Tuk => "tk",
+        Lang::Tur => "tr",
+        Lang::Ukr => "uk",
+        Lang::Urd => "ur",
+        Lang::Uzb => "uz",
+        Lang::Vie => "vi",
+        Lang::Yid => "yi",
+        Lang::Zul => "zu",
+    }
+}
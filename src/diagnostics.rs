@@ -0,0 +1,52 @@
+//! Recoverable problems with the translations.
+//!
+//! Problems that leave the game playable — translations loaded from a
+//! fallback source, a rejected language pack — are logged and reported as
+//! [`I18nDiagnostic`] messages, so a game can tell the player (e.g. "language
+//! files are damaged, please verify the installation") instead of failing
+//! silently. Without Bevy, read [`I18n::diagnostics`].
+
+#[cfg(feature = "bevy")]
+use bevy::prelude::*;
+
+use crate::{I18n, I18nError};
+
+/// A recoverable translation problem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy", derive(Message))]
+pub enum I18nDiagnostic {
+    /// The messages folder could not be read, so the translations bundled
+    /// at build time are used instead.
+    BundledFallback { messages_folder: String, error: String },
+    /// A language pack sent with `I18nCommand::MergePack` was rejected; the
+    /// translations are unchanged.
+    PackRejected(I18nError),
+}
+
+impl std::fmt::Display for I18nDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            I18nDiagnostic::BundledFallback { messages_folder, error } => write!(
+                f,
+                "could not load translations from '{}' ({}); using the bundled translations",
+                messages_folder, error
+            ),
+            I18nDiagnostic::PackRejected(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl I18n {
+    /// Recoverable problems of the last load or reload.
+    pub fn diagnostics(&self) -> &[I18nDiagnostic] {
+        &self.diagnostics
+    }
+}
+
+/// Startup system sending the [`I18nDiagnostic`]s of the initial load.
+#[cfg(feature = "bevy")]
+pub fn emit_load_diagnostics(i18n: Res<I18n>, mut diagnostics: MessageWriter<I18nDiagnostic>) {
+    for diagnostic in i18n.diagnostics() {
+        diagnostics.write(diagnostic.clone());
+    }
+}
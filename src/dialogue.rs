@@ -0,0 +1,131 @@
+//! Dialogue integration.
+//!
+//! Dialogue runners (yarn-spinner style) usually hand out a line id plus a
+//! few runtime parameters — who speaks, the player's gender, a counter, some
+//! named values. [`DialogueLine`] captures exactly that, and
+//! [`I18n::resolve_dialogue`] turns it into the localized speaker name and
+//! line text in one call, picking the right gender / plural variant.
+
+use crate::sentinel::Sentinel;
+use crate::{I18n, LogCategory, PluralCount, SubKey, replace_named_placeholders};
+
+/// A dialogue line to localize.
+///
+/// Built with a small builder API:
+///
+/// ```rust
+/// use bevy_intl::DialogueLine;
+///
+/// let line = DialogueLine::new("dialogue", "blacksmith_greeting")
+///     .speaker("characters", "blacksmith")
+///     .gender("female")
+///     .count(3)
+///     .arg("player", "Ana");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DialogueLine {
+    /// Translation file holding the line, e.g. `"dialogue"`.
+    pub file: String,
+    /// Line key inside `file`.
+    pub key: String,
+    /// Optional speaker as `(file, key)` of their display name.
+    pub speaker: Option<(String, String)>,
+    /// Gender selector for gendered lines.
+    pub gender: Option<String>,
+    /// Count for plural lines; also available as `{{count}}`. Stored signed,
+    /// like the counts of [`t_with_plural`](crate::I18nPartial::t_with_plural).
+    pub count: Option<i128>,
+    /// Named placeholder values.
+    pub args: Vec<(String, String)>,
+}
+
+impl DialogueLine {
+    pub fn new(file: impl Into<String>, key: impl Into<String>) -> Self {
+        Self { file: file.into(), key: key.into(), ..Default::default() }
+    }
+
+    /// Sets the speaker, whose display name is looked up as `file` / `key`.
+    pub fn speaker(mut self, file: impl Into<String>, key: impl Into<String>) -> Self {
+        self.speaker = Some((file.into(), key.into()));
+        self
+    }
+
+    pub fn gender(mut self, gender: impl Into<String>) -> Self {
+        self.gender = Some(gender.into());
+        self
+    }
+
+    pub fn count(mut self, count: impl PluralCount) -> Self {
+        self.count = Some(count.to_i128());
+        self
+    }
+
+    /// Adds a named placeholder value.
+    pub fn arg(mut self, name: impl Into<String>, value: impl ToString) -> Self {
+        self.args.push((name.into(), value.to_string()));
+        self
+    }
+}
+
+/// A [`DialogueLine`] resolved in the current language.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedDialogueLine {
+    /// Localized speaker name, if the line has a speaker.
+    pub speaker: Option<String>,
+    /// Localized line text with all placeholders substituted.
+    pub text: String,
+}
+
+impl I18n {
+    /// Resolves a [`DialogueLine`] into its localized speaker name and text.
+    ///
+    /// The translation variant is chosen from the line's parameters:
+    /// gender + count → gender × plural, count → plural, gender → gender,
+    /// otherwise plain text. Named args (and `count`, when set) are
+    /// substituted in a single pass afterwards.
+    pub fn resolve_dialogue(&self, line: &DialogueLine) -> ResolvedDialogueLine {
+        let speaker = line
+            .speaker
+            .as_ref()
+            .map(|(file, key)| self.translation(file).t(key));
+
+        let t = self.translation(&line.file);
+        let template = match (&line.gender, line.count) {
+            (Some(gender), Some(count)) => t.gender_plural_template(&line.key, gender, count),
+            (None, Some(count)) => t.plural_template(&line.key, count),
+            (Some(gender), None) => {
+                t.check_gender_key(gender);
+                t.get_nested_value(&line.key, &SubKey::from(gender.as_str()))
+            }
+            (None, None) => t.get_text_value(&line.key).map(str::to_string),
+        };
+
+        let text = match template {
+            Some(template) => {
+                let mut args: Vec<(&str, &dyn ToString)> = line
+                    .args
+                    .iter()
+                    .map(|(k, v)| (k.as_str(), v as &dyn ToString))
+                    .collect();
+                let count = line.count.map(|count| t.format_count(count));
+                if let Some(count) = &count {
+                    args.push(("count", count));
+                }
+                replace_named_placeholders(&template, &args, &t.placeholders())
+            }
+            None => {
+                t.log.log(
+                    LogCategory::MissingKey,
+                    &line.key,
+                    format_args!(
+                        "dialogue line '{}' not found in '{}' (gender {:?}, count {:?})",
+                        line.key, line.file, line.gender, line.count
+                    ),
+                );
+                t.missing(&line.key, Sentinel::MissingDialogueLine)
+            }
+        };
+
+        ResolvedDialogueLine { speaker, text }
+    }
+}
@@ -0,0 +1,163 @@
+//! Names chosen by the player: renamed units, cities, ships.
+//!
+//! A name the player types has no translation, and languages that inflect
+//! nouns need more than its text: Russian needs the genitive in "Жители
+//! {{city.genitive}}", French the gender in
+//! `"{{city}} est {{city.gender, select, f{prise} other{pris}}}"`. The rename
+//! dialog asks for what the language needs and stores it as a [`UserTerm`]
+//! in the [`UserDictionary`] of [`I18n`]; [`I18n::t_with_terms`] fills
+//! placeholders from it:
+//!
+//! ```rust,no_run
+//! # use bevy_intl::{I18n, UserTerm};
+//! # fn rename(i18n: &mut I18n) {
+//! let city = UserTerm::new("Тверь").with("gender", "f").with("genitive", "Твери");
+//! i18n.user_dictionary_mut().insert("city_12", city);
+//! // "celebrate": "Жители {{city.genitive}} празднуют"
+//! let text = i18n.t_with_terms("events", "celebrate", &[("city", "city_12")]);
+//! # }
+//! ```
+//!
+//! A grammar entry the player did not give reads as the term's text. With
+//! Bevy, send `I18nCommand::SetUserTerm` so labels rendered with
+//! `I18nMode::Terms` refresh. The dictionary belongs to the save game: it is
+//! serializable, and [`UserDictionary::save`] and [`UserDictionary::load`]
+//! use any [`PackStore`], e.g. `LocalStoragePackStore` on the web.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::format::{Segment, parse_template};
+use crate::{I18n, PackStore, replace_named_placeholders};
+
+/// Key of the dictionary in a [`PackStore`].
+const STORE_KEY: &str = "bevy-intl/dictionary";
+
+/// A name typed by the player and the grammar they chose for it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserTerm {
+    /// The name as typed.
+    pub text: String,
+    /// Grammar read as `{{placeholder.name}}`: `"gender"`, case forms such
+    /// as `"genitive"`…
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub grammar: BTreeMap<String, String>,
+}
+
+impl UserTerm {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into(), grammar: BTreeMap::new() }
+    }
+
+    /// Sets the grammar entry `name`, e.g. `.with("gender", "f")`.
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.grammar.insert(name.into(), value.into());
+        self
+    }
+}
+
+/// [`UserTerm`]s by ID, see the [module docs](self).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct UserDictionary {
+    terms: BTreeMap<String, UserTerm>,
+}
+
+impl UserDictionary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds or replaces the term `id`, returning the previous one.
+    pub fn insert(&mut self, id: impl Into<String>, term: UserTerm) -> Option<UserTerm> {
+        self.terms.insert(id.into(), term)
+    }
+
+    pub fn remove(&mut self, id: &str) -> Option<UserTerm> {
+        self.terms.remove(id)
+    }
+
+    pub fn get(&self, id: &str) -> Option<&UserTerm> {
+        self.terms.get(id)
+    }
+
+    /// Terms sorted by ID.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &UserTerm)> {
+        self.terms.iter().map(|(id, term)| (id.as_str(), term))
+    }
+
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Writes the dictionary to `store`, as JSON.
+    pub fn save(&self, store: &mut impl PackStore) {
+        match serde_json::to_string(self) {
+            Ok(json) => store.set_item(STORE_KEY, &json),
+            Err(e) => warn!("cannot save the user dictionary: {}", e),
+        }
+    }
+
+    /// The dictionary saved in `store`; empty when there is none. An
+    /// unreadable one is logged and ignored.
+    pub fn load(store: &impl PackStore) -> Self {
+        let Some(json) = store.get_item(STORE_KEY) else { return Self::default() };
+        serde_json::from_str(&json).unwrap_or_else(|e| {
+            warn!("ignoring the saved user dictionary: {}", e);
+            Self::default()
+        })
+    }
+}
+
+impl I18n {
+    pub fn user_dictionary(&self) -> &UserDictionary {
+        &self.user_dictionary
+    }
+
+    /// In Bevy, prefer `I18nCommand::SetUserTerm` so labels refresh.
+    pub fn user_dictionary_mut(&mut self) -> &mut UserDictionary {
+        &mut self.user_dictionary
+    }
+
+    /// Replaces the dictionary, e.g. with the one of a loaded save game.
+    pub fn set_user_dictionary(&mut self, dictionary: UserDictionary) {
+        self.user_dictionary = dictionary;
+    }
+
+    /// Translates `file` / `key`, replacing each `{{placeholder}}` listed in
+    /// `terms` (placeholder, term ID) with the text of its [`UserTerm`] and
+    /// `{{placeholder.name}}` with its grammar entry `name`, or its text when
+    /// the player gave none.
+    pub fn t_with_terms(&self, file: &str, key: &str, terms: &[(&str, &str)]) -> String {
+        let t = self.translation(file);
+        let template = t.text_template(key);
+        let mut values: Vec<(String, String)> = Vec::new();
+        for (placeholder, id) in terms {
+            let Some(term) = self.user_dictionary.get(id) else {
+                warn!("user term '{}' not found for placeholder '{}' of '{}'", id, placeholder, key);
+                continue;
+            };
+            values.push((placeholder.to_string(), term.text.clone()));
+            for (name, value) in &term.grammar {
+                values.push((format!("{}.{}", placeholder, name), value.clone()));
+            }
+            // Case forms the player skipped; selects fall to their `other`.
+            for segment in parse_template(&template) {
+                let (Segment::Placeholder(name) | Segment::Transformed { name, .. }) = segment else { continue };
+                let skipped = name.strip_prefix(placeholder).is_some_and(|rest| rest.starts_with('.'));
+                if skipped && !values.iter().any(|(known, _)| known == name) {
+                    values.push((name.to_string(), term.text.clone()));
+                }
+            }
+        }
+        let args: Vec<(&str, &dyn ToString)> =
+            values.iter().map(|(placeholder, value)| (placeholder.as_str(), value as &dyn ToString)).collect();
+        t.check_length(key, replace_named_placeholders(&template, &args, &t.placeholders()))
+    }
+}
@@ -0,0 +1,15 @@
+//! Languages written right to left.
+//!
+//! The module only depends on `std`, so `build.rs` shares it to apply the
+//! right-to-left rules of the concatenation lint to the same languages as
+//! [`Locale::direction`](crate::Locale::direction).
+
+/// Languages written right to left: Arabic, Hebrew, Persian, Urdu and other
+/// languages of RTL scripts.
+const RIGHT_TO_LEFT: [&str; 11] = ["ar", "he", "fa", "ur", "ps", "sd", "ug", "yi", "dv", "ckb", "ks"];
+
+/// Whether the language of the locale `code` is written right to left. `_`
+/// is accepted as a separator.
+pub(crate) fn is_right_to_left(code: &str) -> bool {
+    RIGHT_TO_LEFT.contains(&code.split(['-', '_']).next().unwrap_or(code))
+}
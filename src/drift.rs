@@ -0,0 +1,154 @@
+//! Bundled translations that no longer match the messages folder.
+//!
+//! `build.rs` bundles the messages folder when the game is compiled; a build
+//! that reads the folder at runtime but falls back to the bundle (or ships
+//! the bundle for the web) shows stale strings when the folder was edited
+//! without a rebuild. [`I18n::bundle_drift`] compares the hash of every
+//! bundled file with the same file on disk:
+//!
+//! ```rust,no_run
+//! # use bevy_intl::I18n;
+//! for drift in I18n::bundle_drift("messages").unwrap_or_default() {
+//!     eprintln!("{}", drift); // "fr/ui: changed since the bundle was built"
+//! }
+//! ```
+//!
+//! `I18nPlugin` runs the check at startup when
+//! [`I18nConfig::check_bundle`](crate::I18nConfig::check_bundle) is set (in
+//! debug builds by default), and `bevy-intl-cli check-bundle` in CI.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[cfg(all(feature = "bevy", not(target_arch = "wasm32")))]
+use bevy::prelude::*;
+use serde_json::Value;
+#[cfg(all(feature = "bevy", not(target_arch = "wasm32")))]
+use tracing::warn;
+
+#[cfg(all(feature = "bevy", not(target_arch = "wasm32")))]
+use crate::I18nConfig;
+use crate::{I18n, pack_hash};
+
+/// How a file differs, see [`BundleDrift`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DriftKind {
+    /// The file is in both, with different content.
+    Changed,
+    /// The file was bundled but is no longer on disk.
+    NotOnDisk,
+    /// The file is on disk but was not bundled.
+    NotBundled,
+}
+
+/// A translation file whose bundled copy differs from the one on disk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BundleDrift {
+    pub lang: String,
+    /// File name, without extension.
+    pub file: String,
+    pub kind: DriftKind,
+}
+
+impl fmt::Display for BundleDrift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let what = match self.kind {
+            DriftKind::Changed => "changed since the bundle was built",
+            DriftKind::NotOnDisk => "bundled but no longer on disk",
+            DriftKind::NotBundled => "on disk but not bundled",
+        };
+        write!(f, "{}/{}: {}", self.lang, self.file, what)
+    }
+}
+
+/// Files that differ between two `{ lang: { file: entries } }` trees, by
+/// language and file name.
+pub fn compare_bundle(bundled: &Value, on_disk: &Value) -> Vec<BundleDrift> {
+    let hashes = |tree: &Value| -> BTreeMap<(String, String), String> {
+        let langs = tree.as_object().into_iter().flatten();
+        langs
+            .flat_map(|(lang, files)| {
+                files.as_object().into_iter().flatten().map(move |(file, entries)| {
+                    ((lang.clone(), file.clone()), pack_hash(&entries.to_string()))
+                })
+            })
+            .collect()
+    };
+    let (bundled, on_disk) = (hashes(bundled), hashes(on_disk));
+    let mut drift: Vec<BundleDrift> = bundled
+        .iter()
+        .filter_map(|((lang, file), hash)| {
+            let kind = match on_disk.get(&(lang.clone(), file.clone())) {
+                None => DriftKind::NotOnDisk,
+                Some(disk) if disk != hash => DriftKind::Changed,
+                Some(_) => return None,
+            };
+            Some(BundleDrift { lang: lang.clone(), file: file.clone(), kind })
+        })
+        .collect();
+    drift.extend(on_disk.keys().filter(|name| !bundled.contains_key(*name)).map(|(lang, file)| BundleDrift {
+        lang: lang.clone(),
+        file: file.clone(),
+        kind: DriftKind::NotBundled,
+    }));
+    drift.sort_by(|a, b| (&a.lang, &a.file).cmp(&(&b.lang, &b.file)));
+    drift
+}
+
+/// The messages folder as `build.rs` bundles it, `{ lang: { file: entries }
+/// }`: JSON and YAML files, with the keys of the `overlay` folder (next to
+/// the messages folder, as `build.rs` resolves it) applied. A missing
+/// overlay folder is an error.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn bundle_source(messages_folder: &str, overlay: Option<&str>) -> std::io::Result<Value> {
+    crate::loader::read_bundle_source(messages_folder, overlay)
+}
+
+impl I18n {
+    /// Files of the bundled translations that differ from `messages_folder`,
+    /// read like `build.rs` does (JSON and YAML files, with the overlay the
+    /// bundle was built with, next to `messages_folder`). Empty when nothing
+    /// was bundled; only the bundled language is compared when the bundle
+    /// was split. Fails with [`NotFound`](std::io::ErrorKind::NotFound) only
+    /// when `messages_folder` is missing.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn bundle_drift(messages_folder: &str) -> std::io::Result<Vec<BundleDrift>> {
+        let bundled: Value = serde_json::from_str(crate::loader::BUNDLED_TRANSLATIONS)?;
+        if bundled.as_object().is_none_or(|langs| langs.is_empty()) {
+            return Ok(Vec::new());
+        }
+        let mut on_disk = bundle_source(messages_folder, option_env!("BEVY_INTL_BUNDLE_OVERLAY"))?;
+        // A split bundle only holds the default language; the others are
+        // language packs.
+        if option_env!("BEVY_INTL_SPLIT_BUNDLE").is_some() {
+            if let (Some(langs), Some(bundled)) = (on_disk.as_object_mut(), bundled.as_object()) {
+                langs.retain(|lang, _| bundled.contains_key(lang));
+            }
+        }
+        Ok(compare_bundle(&bundled, &on_disk))
+    }
+}
+
+/// Startup system warning about every [`BundleDrift`] of the messages
+/// folder, added by `I18nPlugin` when
+/// [`I18nConfig::check_bundle`](crate::I18nConfig::check_bundle) is set.
+#[cfg(all(feature = "bevy", not(target_arch = "wasm32")))]
+pub fn check_bundle_drift(config: Res<I18nConfig>) {
+    match I18n::bundle_drift(&config.messages_folder) {
+        Ok(drift) => {
+            for file in &drift {
+                warn!("stale bundle: {}", file);
+            }
+            if !drift.is_empty() {
+                warn!(
+                    "{} bundled translation files differ from '{}'; rebuild to refresh the bundle",
+                    drift.len(),
+                    config.messages_folder
+                );
+            }
+        }
+        // No folder to compare with, e.g. a shipped build.
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => warn!("cannot compare the bundle with '{}': {}", config.messages_folder, e),
+    }
+}
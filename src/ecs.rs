@@ -0,0 +1,177 @@
+//! Bevy integration: the plugin, the `App` extension and the `I18n`
+//! resource initialization.
+
+use bevy::prelude::*;
+
+#[cfg(feature = "console")]
+use crate::{I18nConsoleInput, I18nConsoleOutput, run_i18n_console};
+#[cfg(feature = "markdown")]
+use crate::MarkdownTheme;
+#[cfg(feature = "global")]
+use crate::sync_global_i18n;
+#[cfg(all(feature = "fs-loader", not(target_arch = "wasm32")))]
+use crate::watch::watch_translation_files;
+#[cfg(feature = "ecs-components")]
+use crate::{
+    ScreenshotMatrixStep, SpeakLocalized, ellipsize_i18n_text, fit_i18n_text, run_screenshot_matrix, speak_localized,
+    update_i18n_tooltips,
+};
+use crate::{
+    HiddenLabels, I18n, I18nCommand, I18nConfig, I18nDiagnostic, I18nRefreshQueue, LanguageChanged, RefreshBudget,
+    apply_i18n_commands, emit_load_diagnostics, preflight_i18n_messages, process_i18n_refresh_queue,
+    register_missing_translations, render_revealed_i18n_text, update_i18n_text, write_missing_translations_on_exit,
+};
+
+/// Main plugin for Bevy internationalization.
+///
+/// Handles language switching, loading translation files, and providing
+/// `I18n` resource for accessing localized strings.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_intl::{I18nPlugin, I18nConfig};
+///
+/// // Default configuration
+/// App::new().add_plugins(I18nPlugin::default());
+///
+/// // Custom configuration
+/// App::new().add_plugins(I18nPlugin::with_config(I18nConfig {
+///     default_lang: "fr".to_string(),
+///     fallback_chain: vec!["en".to_string()],
+///     ..Default::default()
+/// }));
+/// ```
+#[derive(Default)]
+pub struct I18nPlugin {
+    /// Configuration for the plugin
+    pub config: I18nConfig,
+}
+
+impl I18nPlugin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_config(config: I18nConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Plugin for I18nPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.config.clone())
+            .init_resource::<I18n>()
+            .init_resource::<RefreshBudget>()
+            .init_resource::<I18nRefreshQueue>()
+            .init_resource::<HiddenLabels>()
+            .add_message::<LanguageChanged>()
+            .add_message::<I18nCommand>()
+            .add_message::<I18nDiagnostic>()
+            .add_observer(crate::components::render_inserted_i18n_text)
+            .add_observer(crate::components::render_inserted_i18n_text_span)
+            .add_observer(crate::components::refresh_i18n_text)
+            .add_observer(crate::image::render_inserted_localized_image)
+            .add_observer(crate::image::refresh_localized_images)
+            .add_observer(crate::audio::play_inserted_localized_audio)
+            .add_observer(crate::audio::refresh_localized_audio)
+            .add_systems(Startup, emit_load_diagnostics)
+            .add_systems(PreStartup, register_missing_translations)
+            .add_systems(First, register_missing_translations)
+            .add_systems(Last, write_missing_translations_on_exit)
+            .add_systems(
+                Update,
+                (
+                    apply_i18n_commands.before(update_i18n_text),
+                    update_i18n_text,
+                    process_i18n_refresh_queue.after(update_i18n_text),
+                    render_revealed_i18n_text.after(update_i18n_text),
+                ),
+            );
+        #[cfg(feature = "ecs-components")]
+        app.add_message::<ScreenshotMatrixStep>().add_message::<SpeakLocalized>().add_systems(
+            Update,
+            (
+                run_screenshot_matrix.before(update_i18n_text),
+                speak_localized.after(apply_i18n_commands),
+                update_i18n_tooltips,
+                fit_i18n_text,
+                ellipsize_i18n_text,
+            ),
+        );
+        if self.config.preflight {
+            app.add_systems(Startup, preflight_i18n_messages);
+        }
+        #[cfg(all(feature = "tools", not(target_arch = "wasm32")))]
+        if self.config.check_bundle {
+            app.add_systems(Startup, crate::check_bundle_drift);
+        }
+        #[cfg(all(feature = "fs-loader", not(target_arch = "wasm32")))]
+        if self.config.hot_reload {
+            app.init_resource::<crate::watch::TranslationFileStamps>()
+                .add_systems(Update, watch_translation_files.before(apply_i18n_commands));
+        }
+        #[cfg(feature = "global")]
+        app.add_systems(Last, sync_global_i18n);
+        #[cfg(feature = "console")]
+        app.add_message::<I18nConsoleInput>()
+            .add_message::<I18nConsoleOutput>()
+            .add_systems(Update, run_i18n_console.before(apply_i18n_commands));
+        #[cfg(feature = "markdown")]
+        app.init_resource::<MarkdownTheme>()
+            .add_observer(crate::markdown::render_inserted_markdown)
+            .add_observer(crate::markdown::refresh_markdown);
+    }
+}
+
+impl FromWorld for I18n {
+    fn from_world(world: &mut World) -> Self {
+        let config = world.get_resource::<I18nConfig>().cloned().unwrap_or_default();
+        Self::from_config(config)
+    }
+}
+
+/// Extension trait for `App` to set languages at startup, before `run()`.
+///
+/// `App` is not a Bevy `Resource`, so these methods are intended to be called
+/// during plugin setup (build-time configuration), not from inside a system.
+/// For runtime changes, use [`I18n::set_lang`] / [`I18n::try_set_lang`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use bevy_intl::{I18nPlugin, LanguageAppExt};
+///
+/// App::new()
+///     .add_plugins(I18nPlugin::default())
+///     .set_lang_i18n("fr")
+///     .set_fallback_lang("en")
+///     .run();
+/// ```
+pub trait LanguageAppExt {
+    /// Sets the current language for translations. Logs a warning if the locale
+    /// is not available in the loaded translations. Returns `&mut Self` so it
+    /// chains with the rest of the `App` builder.
+    fn set_lang_i18n(&mut self, locale: &str) -> &mut Self;
+    /// Sets the fallback language for translations. Logs a warning if the locale
+    /// is not available in the loaded translations.
+    fn set_fallback_lang(&mut self, locale: &str) -> &mut Self;
+}
+
+impl LanguageAppExt for App {
+    fn set_lang_i18n(&mut self, locale: &str) -> &mut Self {
+        if let Some(mut i18n) = self.world_mut().get_resource_mut::<I18n>() {
+            i18n.set_lang(locale);
+        }
+        self
+    }
+
+    fn set_fallback_lang(&mut self, locale: &str) -> &mut Self {
+        if let Some(mut i18n) = self.world_mut().get_resource_mut::<I18n>() {
+            i18n.set_fallback_lang(locale);
+        }
+        self
+    }
+}
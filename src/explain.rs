@@ -0,0 +1,224 @@
+//! "Why am I seeing this string?": a trace of how one lookup resolves.
+//!
+//! [`I18n::explain`] replays a lookup without logging or analytics and
+//! reports every language it tried, the plural or gender branch it picked
+//! and the value of each placeholder:
+//!
+//! ```rust,no_run
+//! # use bevy_intl::{Explain, I18n};
+//! # fn debug(i18n: &I18n) {
+//! println!("{}", i18n.explain("ui", "items", Explain::Plural(3)));
+//! // ui.items (plural 3) → "3 objets"
+//! //   fr-CA: no key
+//! //   fr: found, branch "other"
+//! //   template: "{{count}} objets"
+//! //   {{count}} = "3"
+//! # }
+//! ```
+
+use std::fmt;
+
+use crate::format::{Segment, parse_template};
+use crate::logging::I18nLog;
+use crate::{I18n, I18nPartial, LogLevel, LogPolicy, SubKey};
+
+/// Lookup replayed by [`I18n::explain`], one per `t*` method.
+#[derive(Clone, Copy)]
+pub enum Explain<'a> {
+    /// [`t`](I18nPartial::t).
+    Text,
+    /// [`t_with_args`](I18nPartial::t_with_args).
+    Args(&'a [(&'a str, &'a dyn ToString)]),
+    /// [`t_with_plural`](I18nPartial::t_with_plural).
+    Plural(i128),
+    /// [`t_with_gender`](I18nPartial::t_with_gender).
+    Gender(&'a str),
+    /// [`t_with_gender_and_plural`](I18nPartial::t_with_gender_and_plural).
+    GenderPlural(&'a str, i128),
+}
+
+/// What a language of the lookup had, see [`ExplainStep`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepOutcome {
+    /// The file is not loaded in this language.
+    NoFile,
+    /// The file has no such key.
+    NoKey,
+    /// The key has no string for this lookup: no branch for the count or
+    /// gender, or another kind of value.
+    NoMatch,
+    /// The language provided the string.
+    Found,
+}
+
+/// A language tried by the lookup, in order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExplainStep {
+    pub lang: String,
+    pub outcome: StepOutcome,
+}
+
+/// Trace returned by [`I18n::explain`]. `Display` prints it on a few lines.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    pub file: String,
+    /// Key looked up, after stable IDs and renamed keys are resolved.
+    pub key: String,
+    /// The lookup, e.g. `plural 3`.
+    pub lookup: String,
+    /// Languages tried, up to the one that provided the string.
+    pub steps: Vec<ExplainStep>,
+    /// Language that provided the string; `None` when none did.
+    pub served_by: Option<String>,
+    /// Map key the string was read from: plural form or exact count
+    /// (`"one"`, `"3"`), gender, or `"gender/form"`.
+    pub branch: Option<String>,
+    /// The string before placeholders are substituted.
+    pub template: Option<String>,
+    /// Placeholders of the template and their values (`None`: kept as is).
+    pub placeholders: Vec<(String, Option<String>)>,
+    /// What the lookup returns, placeholder text included.
+    pub text: String,
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}.{} ({}) → {:?}", self.file, self.key, self.lookup, self.text)?;
+        for step in &self.steps {
+            match (step.outcome, &self.branch) {
+                (StepOutcome::NoFile, _) => writeln!(f, "  {}: no file", step.lang)?,
+                (StepOutcome::NoKey, _) => writeln!(f, "  {}: no key", step.lang)?,
+                (StepOutcome::NoMatch, _) => writeln!(f, "  {}: no match for this lookup", step.lang)?,
+                (StepOutcome::Found, Some(branch)) => writeln!(f, "  {}: found, branch {:?}", step.lang, branch)?,
+                (StepOutcome::Found, None) => writeln!(f, "  {}: found", step.lang)?,
+            }
+        }
+        if let Some(template) = &self.template {
+            writeln!(f, "  template: {:?}", template)?;
+        }
+        for (name, value) in &self.placeholders {
+            match value {
+                Some(value) => writeln!(f, "  {{{{{}}}}} = {:?}", name, value)?,
+                None => writeln!(f, "  {{{{{}}}}} has no value", name)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl I18n {
+    /// Replays the lookup of `file.key` and returns how it resolves, to debug
+    /// unexpected strings. Nothing is logged or reported to analytics.
+    ///
+    /// ```rust,no_run
+    /// # use bevy_intl::{Explain, I18n, StepOutcome};
+    /// # fn debug(i18n: &I18n) {
+    /// let explanation = i18n.explain("ui", "guests", Explain::GenderPlural("female", 2));
+    /// if explanation.steps.first().is_some_and(|step| step.outcome != StepOutcome::Found) {
+    ///     println!("served by {:?}", explanation.served_by);
+    /// }
+    /// # }
+    /// ```
+    pub fn explain(&self, file: &str, key: &str, lookup: Explain) -> Explanation {
+        let silent = I18nLog::new(LogPolicy {
+            missing_key: LogLevel::Off,
+            placeholder: LogLevel::Off,
+            length: LogLevel::Off,
+            gender: LogLevel::Off,
+            once_per_key: false,
+        });
+        let mut partial = self.translation(file);
+        partial.analytics = None;
+        partial.log = silent.scope(file);
+        let key = partial.resolve_key(key).to_string();
+
+        // The lookup itself, as the `t*` method does it.
+        let found = resolve(&partial, &key, lookup);
+        let served = found.as_ref().and_then(|_| partial.served_from.get());
+        let served_lang = served.and_then(|i| partial.lookup_chain(true).nth(i)).map(|(lang, _)| lang);
+        let text = match lookup {
+            Explain::Text => partial.t(&key),
+            Explain::Args(args) => partial.t_with_args(&key, args),
+            Explain::Plural(count) => partial.t_with_plural(&key, count),
+            Explain::Gender(gender) => partial.t_with_gender(&key, gender),
+            Explain::GenderPlural(gender, count) => partial.t_with_gender_and_plural(&key, gender, count),
+        };
+
+        // What each language of the chain had, up to the one that served.
+        let plan = self.file_plan(file);
+        let mut steps = Vec::new();
+        for lang in std::iter::once(self.current_lang.as_str()).chain(plan.fallbacks.iter().map(String::as_str)) {
+            let outcome = match self.translations.langs.get(lang).and_then(|files| files.get(file)) {
+                None => StepOutcome::NoFile,
+                Some(_) if Some(lang) == served_lang => StepOutcome::Found,
+                Some(sections) if partial.find_in(sections, &key, |sections, key| sections.get(key)).is_some() => {
+                    StepOutcome::NoMatch
+                }
+                Some(_) => StepOutcome::NoKey,
+            };
+            steps.push(ExplainStep { lang: lang.to_string(), outcome });
+            if outcome == StepOutcome::Found {
+                break;
+            }
+        }
+
+        let (lookup_name, count) = match lookup {
+            Explain::Text => ("text".to_string(), None),
+            Explain::Args(_) => ("args".to_string(), None),
+            Explain::Plural(count) => (format!("plural {}", count), Some(count)),
+            Explain::Gender(gender) => (format!("gender {}", gender), None),
+            Explain::GenderPlural(gender, count) => (format!("gender {} plural {}", gender, count), Some(count)),
+        };
+        let mut explanation = Explanation {
+            file: file.to_string(),
+            key,
+            lookup: lookup_name,
+            steps,
+            served_by: served_lang.map(str::to_string),
+            branch: None,
+            template: None,
+            placeholders: Vec::new(),
+            text,
+        };
+        let Some((branch, template)) = found else {
+            return explanation;
+        };
+
+        let count = count.map(|count| partial.format_count(count));
+        let value = |name: &str| match lookup {
+            Explain::Args(args) => args.iter().find(|(arg, _)| *arg == name).map(|(_, value)| value.to_string()),
+            Explain::Plural(_) | Explain::GenderPlural(..) if name == "count" => count.clone(),
+            _ => None,
+        };
+        for segment in parse_template(&template) {
+            let name = match segment {
+                Segment::Text(_) => continue,
+                Segment::Placeholder(name) | Segment::Transformed { name, .. } => name,
+                Segment::Select(select) => select.name,
+            };
+            if !explanation.placeholders.iter().any(|(known, _)| known == name) {
+                explanation.placeholders.push((name.to_string(), value(name)));
+            }
+        }
+        explanation.branch = branch;
+        explanation.template = Some(template);
+        explanation
+    }
+}
+
+/// The branch and template `lookup` finds, read by the functions the `t*`
+/// method uses.
+fn resolve(partial: &I18nPartial, key: &str, lookup: Explain) -> Option<(Option<String>, String)> {
+    match lookup {
+        Explain::Text | Explain::Args(_) => Some((None, partial.get_text_value(key)?.to_string())),
+        Explain::Plural(count) => {
+            let (form, template) = partial.plural_branch(key, count)?;
+            Some((Some(form.as_str().into_owned()), template))
+        }
+        Explain::Gender(gender) => Some((Some(gender.to_string()), partial.get_nested_value(key, &SubKey::from(gender))?)),
+        Explain::GenderPlural(gender, count) => {
+            let (form, template) = partial.gender_plural_branch(key, gender, count)?;
+            Some((Some(format!("{}/{}", gender, form.as_str())), template))
+        }
+    }
+}
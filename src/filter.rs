@@ -0,0 +1,137 @@
+//! Locale-sensitive word filtering.
+//!
+//! Each language folder may contain a reserved `_filter.json` listing words
+//! to mask in player names and chat:
+//!
+//! ```json
+//! {
+//!     "words": ["darn", "heck"],
+//!     "mask": "*",
+//!     "match": "word"
+//! }
+//! ```
+//!
+//! `mask` defaults to `*`. `match` is `"word"` (default: entries only match
+//! whole words) or `"substring"`, which languages written without spaces
+//! need. [`I18n::filter`] applies the list of the current language.
+
+use serde::Deserialize;
+
+use crate::I18n;
+
+/// Name of the reserved filter file inside each language folder.
+pub(crate) const FILTER_FILE: &str = "_filter";
+
+/// How filter entries are matched against text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FilterMatch {
+    /// Entries only match whole words.
+    #[default]
+    Word,
+    /// Entries match anywhere, including inside longer words.
+    Substring,
+}
+
+/// A word list loaded from a `_filter.json` file.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct WordFilter {
+    /// Words (or phrases) to mask; matched case-insensitively.
+    pub words: Vec<String>,
+    /// Character replacing each masked character.
+    #[serde(default = "default_mask")]
+    pub mask: char,
+    #[serde(default, rename = "match")]
+    pub match_mode: FilterMatch,
+}
+
+// Parses `_filter.json` once, when its language is loaded.
+pub(crate) fn parse_filter(json: &serde_json::Value) -> Result<WordFilter, serde_json::Error> {
+    WordFilter::deserialize(json)
+}
+
+fn default_mask() -> char {
+    '*'
+}
+
+impl WordFilter {
+    /// Returns `text` with every match masked, character for character.
+    pub fn apply(&self, text: &str) -> String {
+        let chars: Vec<char> = text.chars().collect();
+        let mut masked = vec![false; chars.len()];
+        for (start, end) in self.matches(&chars) {
+            masked[start..end].iter_mut().for_each(|m| *m = true);
+        }
+        chars
+            .iter()
+            .zip(masked)
+            .map(|(&c, m)| if m && !c.is_whitespace() { self.mask } else { c })
+            .collect()
+    }
+
+    /// Whether `text` contains at least one filtered word.
+    pub fn is_match(&self, text: &str) -> bool {
+        let chars: Vec<char> = text.chars().collect();
+        !self.matches(&chars).is_empty()
+    }
+
+    /// Char ranges of every match in `chars`.
+    fn matches(&self, chars: &[char]) -> Vec<(usize, usize)> {
+        let lowered: Vec<char> = chars.iter().map(|c| fold(*c)).collect();
+        let mut ranges = Vec::new();
+        for word in &self.words {
+            let pattern: Vec<char> = word.chars().map(fold).collect();
+            if pattern.is_empty() || pattern.len() > lowered.len() {
+                continue;
+            }
+            for start in 0..=lowered.len() - pattern.len() {
+                let end = start + pattern.len();
+                if lowered[start..end] != pattern[..] {
+                    continue;
+                }
+                if self.match_mode == FilterMatch::Word {
+                    let boundary = |c: Option<&char>| c.is_none_or(|c| !c.is_alphanumeric());
+                    let before = start.checked_sub(1).and_then(|i| chars.get(i));
+                    if !boundary(before) || !boundary(chars.get(end)) {
+                        continue;
+                    }
+                }
+                ranges.push((start, end));
+            }
+        }
+        ranges
+    }
+}
+
+/// Simple per-character case folding (keeps char positions aligned).
+fn fold(c: char) -> char {
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(l), None) => l,
+        _ => c,
+    }
+}
+
+impl I18n {
+    /// Word filter loaded for `lang`, if it has a valid `_filter.json`
+    /// (invalid files are reported with a warning when loaded).
+    pub fn word_filter(&self, lang: &str) -> Option<&WordFilter> {
+        self.translations.filters.get(lang)
+    }
+
+    /// Masks words from the current language's `_filter.json` in `text`.
+    /// Returns `text` unchanged when the language has no filter list.
+    pub fn filter(&self, text: &str) -> String {
+        match self.word_filter(self.get_lang()) {
+            Some(filter) => filter.apply(text),
+            None => text.to_string(),
+        }
+    }
+
+    /// Whether `text` contains a word from the current language's filter
+    /// list, e.g. to reject a player name.
+    pub fn is_filtered(&self, text: &str) -> bool {
+        self.word_filter(self.get_lang())
+            .is_some_and(|filter| filter.is_match(text))
+    }
+}
@@ -0,0 +1,87 @@
+//! Automatic text fitting for long translations.
+//!
+//! The same button label can be twice as long in German as in English. Add
+//! [`FitI18nText`] next to a text entity and [`fit_i18n_text`] shrinks its
+//! font until the rendered text fits `max_width`; if it still overflows at
+//! `min_font_size`, wrapping is enabled by capping the node's width instead.
+//! When the text changes (e.g. on language switch) the original font size is
+//! restored and fitting starts over. Only pixel font sizes
+//! ([`FontSize::Px`]) are fitted; viewport- and rem-relative sizes are left
+//! as they are.
+
+use bevy::prelude::*;
+use bevy::text::TextLayoutInfo;
+
+/// Shrink-to-fit constraints for a text entity, in logical pixels.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct FitI18nText {
+    /// Width the rendered text must fit in.
+    pub max_width: f32,
+    /// Smallest font size the text may shrink to before wrapping is enabled.
+    pub min_font_size: f32,
+}
+
+impl FitI18nText {
+    pub fn new(max_width: f32, min_font_size: f32) -> Self {
+        Self { max_width, min_font_size }
+    }
+}
+
+/// Font size the entity had before fitting, recorded by [`fit_i18n_text`] and
+/// restored whenever the text changes.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct FitBaseFontSize(pub FontSize);
+
+type FitQueryData<'a> = (
+    Entity,
+    &'a FitI18nText,
+    Ref<'a, Text>,
+    Ref<'a, TextLayoutInfo>,
+    &'a mut TextFont,
+    &'a mut Node,
+    Option<&'a FitBaseFontSize>,
+);
+
+/// Bevy system applying [`FitI18nText`] constraints.
+///
+/// Works from the previous frame's `TextLayoutInfo`, so a label converges
+/// within a couple of frames after its text changes.
+pub fn fit_i18n_text(
+    mut commands: Commands,
+    mut query: Query<FitQueryData>,
+) {
+    for (entity, fit, text, layout, mut font, mut node, base) in &mut query {
+        let Some(FitBaseFontSize(base)) = base.copied() else {
+            commands.entity(entity).insert(FitBaseFontSize(font.font_size));
+            continue;
+        };
+
+        if text.is_changed() {
+            if font.font_size != base {
+                font.font_size = base;
+            }
+            if node.max_width == Val::Px(fit.max_width) {
+                node.max_width = Val::Auto;
+            }
+            continue;
+        }
+
+        let FontSize::Px(size) = font.font_size else {
+            continue;
+        };
+        if !layout.is_changed() || layout.scale_factor <= 0.0 {
+            continue;
+        }
+        let width = layout.size.x / layout.scale_factor;
+        if width <= fit.max_width + 0.5 {
+            continue;
+        }
+
+        if size > fit.min_font_size {
+            let scaled = size * fit.max_width / width;
+            font.font_size = FontSize::Px(scaled.max(fit.min_font_size));
+        } else if node.max_width != Val::Px(fit.max_width) {
+            node.max_width = Val::Px(fit.max_width);
+        }
+    }
+}
@@ -0,0 +1,299 @@
+//! Fluent (`.ftl`) translation files.
+//!
+//! Available with the `fluent` feature, which registers [`parse_fluent`]
+//! for `.ftl` files in [`TranslationFormats::default`](crate::TranslationFormats).
+//! Files are parsed with `fluent-syntax` and converted to the JSON shape of
+//! a `.json` file:
+//!
+//! - each message is a key, each attribute a `message.attribute` key;
+//!   terms are not keys, but are inlined where they are referenced;
+//! - `{ $name }` becomes the `{{name}}` placeholder, also inside `NUMBER()`
+//!   and `DATETIME()`, whose options are ignored;
+//! - a select expression becomes a map of its variants, with the default
+//!   variant also stored as `other`; text around it is copied into every
+//!   variant. Selecting on `$count` gives a plural map (`[0]` is an exact
+//!   count), on `$gender` a gender map, and a gender select followed by a
+//!   count select the nested map of `t_with_gender_and_plural`;
+//! - message references are inlined, and selects on a term attribute
+//!   (`{ -brand.gender -> … }`) or on a term argument are resolved when the
+//!   file is loaded.
+//!
+//! Other functions and message references that select at runtime are
+//! rejected. Bundled translations (WASM, `bundle-only`) do not read `.ftl`
+//! files.
+
+use std::collections::HashMap;
+
+use fluent_syntax::ast::{CallArguments, Entry, Expression, InlineExpression, Message, Pattern, PatternElement, Term, VariantKey};
+use fluent_syntax::parser;
+use serde_json::{Map, Value};
+
+/// Depth of nested message and term references after which a reference
+/// cycle is assumed.
+const MAX_DEPTH: usize = 16;
+
+/// Parses a Fluent file into the JSON value a `.json` file with the same
+/// translations would give, see the [module docs](self).
+pub fn parse_fluent(source: &str) -> Result<Value, String> {
+    let resource = parser::parse(source).map_err(|(_, errors)| match errors.first() {
+        Some(error) => format!("line {}: {}", line_of(source, error.pos.start), error),
+        None => "invalid Fluent file".to_string(),
+    })?;
+
+    let mut converter = Converter { messages: HashMap::new(), terms: HashMap::new() };
+    for entry in &resource.body {
+        match entry {
+            Entry::Message(message) => {
+                converter.messages.insert(message.id.name, message);
+            }
+            Entry::Term(term) => {
+                converter.terms.insert(term.id.name, term);
+            }
+            _ => {}
+        }
+    }
+
+    let mut translations = Map::new();
+    for entry in &resource.body {
+        let Entry::Message(message) = entry else { continue };
+        let id = message.id.name;
+        let convert = |pattern| converter.pattern(pattern, &Scope::default()).map(Tree::into_json);
+        if let Some(value) = &message.value {
+            let value = convert(value).map_err(|e| format!("'{}': {}", id, e))?;
+            translations.insert(id.to_string(), value);
+        }
+        for attribute in &message.attributes {
+            let key = format!("{}.{}", id, attribute.id.name);
+            let value = convert(&attribute.value).map_err(|e| format!("'{}': {}", key, e))?;
+            translations.insert(key, value);
+        }
+    }
+    Ok(Value::Object(translations))
+}
+
+fn line_of(source: &str, offset: usize) -> usize {
+    source.get(..offset).map_or(1, |before| before.matches('\n').count() + 1)
+}
+
+/// A converted pattern: text, or the variants of a select expression.
+#[derive(Clone, Debug)]
+enum Tree {
+    Text(String),
+    Select(Vec<(String, Tree)>),
+}
+
+impl Tree {
+    /// `self` followed by `next`. Text is copied into every variant, and a
+    /// select followed by a select nests the second one in each variant.
+    fn append(self, next: Tree) -> Tree {
+        match (self, next) {
+            (Tree::Text(mut text), Tree::Text(next)) => {
+                text.push_str(&next);
+                Tree::Text(text)
+            }
+            (Tree::Text(text), Tree::Select(variants)) => Tree::Select(
+                variants.into_iter().map(|(key, tree)| (key, Tree::Text(text.clone()).append(tree))).collect(),
+            ),
+            (Tree::Select(variants), next) => {
+                Tree::Select(variants.into_iter().map(|(key, tree)| (key, tree.append(next.clone()))).collect())
+            }
+        }
+    }
+
+    fn into_json(self) -> Value {
+        match self {
+            Tree::Text(text) => Value::String(text),
+            Tree::Select(variants) => {
+                Value::Object(variants.into_iter().map(|(key, tree)| (key, tree.into_json())).collect())
+            }
+        }
+    }
+}
+
+/// Where a pattern is converted: the reference depth and, inside a term,
+/// the term's named arguments.
+#[derive(Default)]
+struct Scope {
+    depth: usize,
+    term_args: Option<HashMap<String, String>>,
+}
+
+impl Scope {
+    fn nested(&self, term_args: Option<HashMap<String, String>>) -> Result<Scope, String> {
+        if self.depth >= MAX_DEPTH {
+            return Err("reference cycle".to_string());
+        }
+        Ok(Scope { depth: self.depth + 1, term_args })
+    }
+}
+
+/// How a select expression picks its variant.
+enum Selector {
+    /// At lookup time, from the count or gender passed by the game.
+    Runtime,
+    /// When the file is loaded; `None` picks the default variant.
+    Static(Option<String>),
+}
+
+struct Converter<'s> {
+    messages: HashMap<&'s str, &'s Message<&'s str>>,
+    terms: HashMap<&'s str, &'s Term<&'s str>>,
+}
+
+impl<'s> Converter<'s> {
+    fn pattern(&self, pattern: &Pattern<&'s str>, scope: &Scope) -> Result<Tree, String> {
+        let mut tree = Tree::Text(String::new());
+        for element in &pattern.elements {
+            let next = match element {
+                PatternElement::TextElement { value } => Tree::Text(value.to_string()),
+                PatternElement::Placeable { expression } => self.expression(expression, scope)?,
+            };
+            tree = tree.append(next);
+        }
+        Ok(tree)
+    }
+
+    fn expression(&self, expression: &Expression<&'s str>, scope: &Scope) -> Result<Tree, String> {
+        let (selector, variants) = match expression {
+            Expression::Inline(inline) => return self.inline(inline, scope),
+            Expression::Select { selector, variants } => (selector, variants),
+        };
+        let key = |key: &VariantKey<&str>| match key {
+            VariantKey::Identifier { name } => name.to_string(),
+            VariantKey::NumberLiteral { value } => value.to_string(),
+        };
+        let default = variants.iter().find(|variant| variant.default).ok_or("select without a default variant")?;
+
+        match self.selector(selector, scope)? {
+            Selector::Static(value) => {
+                let picked = value
+                    .and_then(|value| variants.iter().find(|variant| key(&variant.key) == value))
+                    .unwrap_or(default);
+                self.pattern(&picked.value, scope)
+            }
+            Selector::Runtime => {
+                let mut converted = Vec::with_capacity(variants.len() + 1);
+                for variant in variants {
+                    converted.push((key(&variant.key), self.pattern(&variant.value, scope)?));
+                }
+                if !converted.iter().any(|(key, _)| key == "other") {
+                    converted.push(("other".to_string(), self.pattern(&default.value, scope)?));
+                }
+                Ok(Tree::Select(converted))
+            }
+        }
+    }
+
+    fn selector(&self, selector: &InlineExpression<&'s str>, scope: &Scope) -> Result<Selector, String> {
+        match selector {
+            InlineExpression::VariableReference { id } => match &scope.term_args {
+                Some(args) => Ok(Selector::Static(args.get(id.name).cloned())),
+                None => Ok(Selector::Runtime),
+            },
+            InlineExpression::FunctionReference { id, arguments } if scope.term_args.is_none() => {
+                variable_argument(id.name, arguments)?;
+                Ok(Selector::Runtime)
+            }
+            InlineExpression::StringLiteral { value } => Ok(Selector::Static(Some(unescape(value)?))),
+            InlineExpression::NumberLiteral { value } => Ok(Selector::Static(Some(value.to_string()))),
+            InlineExpression::TermReference { attribute: Some(_), .. } => match self.inline(selector, scope)? {
+                Tree::Text(value) => Ok(Selector::Static(Some(value))),
+                Tree::Select(_) => Err("term attributes used as selectors must be plain text".to_string()),
+            },
+            _ => Err("unsupported selector".to_string()),
+        }
+    }
+
+    fn inline(&self, inline: &InlineExpression<&'s str>, scope: &Scope) -> Result<Tree, String> {
+        match inline {
+            InlineExpression::StringLiteral { value } => Ok(Tree::Text(unescape(value)?)),
+            InlineExpression::NumberLiteral { value } => Ok(Tree::Text(value.to_string())),
+            InlineExpression::VariableReference { id } => match &scope.term_args {
+                Some(args) => args
+                    .get(id.name)
+                    .map(|value| Tree::Text(value.clone()))
+                    .ok_or_else(|| format!("term argument '${}' is not provided", id.name)),
+                None => Ok(Tree::Text(format!("{{{{{}}}}}", id.name))),
+            },
+            InlineExpression::FunctionReference { id, arguments } => {
+                if scope.term_args.is_some() {
+                    return Err(format!("function '{}' cannot be used in terms", id.name));
+                }
+                Ok(Tree::Text(format!("{{{{{}}}}}", variable_argument(id.name, arguments)?)))
+            }
+            InlineExpression::MessageReference { id, attribute } => {
+                let message = self.messages.get(id.name).ok_or_else(|| format!("unknown message '{}'", id.name))?;
+                let pattern = match attribute {
+                    Some(attribute) => message
+                        .attributes
+                        .iter()
+                        .find(|a| a.id.name == attribute.name)
+                        .map(|a| &a.value)
+                        .ok_or_else(|| format!("unknown attribute '{}.{}'", id.name, attribute.name))?,
+                    None => message.value.as_ref().ok_or_else(|| format!("message '{}' has no value", id.name))?,
+                };
+                let scope = scope.nested(None)?;
+                match self.pattern(pattern, &scope)? {
+                    Tree::Select(_) => Err(format!("message '{}' selects at runtime and cannot be referenced", id.name)),
+                    text => Ok(text),
+                }
+            }
+            InlineExpression::TermReference { id, attribute, arguments } => {
+                let term = self.terms.get(id.name).ok_or_else(|| format!("unknown term '-{}'", id.name))?;
+                let pattern = match attribute {
+                    Some(attribute) => term
+                        .attributes
+                        .iter()
+                        .find(|a| a.id.name == attribute.name)
+                        .map(|a| &a.value)
+                        .ok_or_else(|| format!("unknown attribute '-{}.{}'", id.name, attribute.name))?,
+                    None => &term.value,
+                };
+                let mut args = HashMap::new();
+                for argument in arguments.iter().flat_map(|arguments| &arguments.named) {
+                    let value = match &argument.value {
+                        InlineExpression::StringLiteral { value } => unescape(value)?,
+                        InlineExpression::NumberLiteral { value } => value.to_string(),
+                        _ => return Err(format!("arguments of '-{}' must be literals", id.name)),
+                    };
+                    args.insert(argument.name.name.to_string(), value);
+                }
+                self.pattern(pattern, &scope.nested(Some(args))?)
+            }
+            InlineExpression::Placeable { expression } => self.expression(expression, scope),
+        }
+    }
+}
+
+/// Variable passed to `NUMBER($count)` or `DATETIME($date)`.
+fn variable_argument<'s>(function: &str, arguments: &CallArguments<&'s str>) -> Result<&'s str, String> {
+    if !matches!(function, "NUMBER" | "DATETIME") {
+        return Err(format!("unsupported function '{}'", function));
+    }
+    match arguments.positional.as_slice() {
+        [InlineExpression::VariableReference { id }] => Ok(id.name),
+        _ => Err(format!("'{}' must be called with one variable", function)),
+    }
+}
+
+/// Value of a string literal, with its `\"`, `\\` and `\u` escapes resolved.
+fn unescape(literal: &str) -> Result<String, String> {
+    let mut value = String::with_capacity(literal.len());
+    let mut chars = literal.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some(escaped @ ('"' | '\\')) => value.push(escaped),
+            Some(u @ ('u' | 'U')) => {
+                let digits: String = chars.by_ref().take(if u == 'u' { 4 } else { 6 }).collect();
+                let code = u32::from_str_radix(&digits, 16).ok().and_then(char::from_u32);
+                value.push(code.ok_or_else(|| format!("invalid escape '\\{}{}'", u, digits))?);
+            }
+            _ => return Err(format!("invalid escape in \"{}\"", literal)),
+        }
+    }
+    Ok(value)
+}
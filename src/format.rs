@@ -0,0 +1,319 @@
+//! Placeholder parsing and substitution.
+//!
+//! This is the formatting core behind every `t_*` method. By default it
+//! parses templates by hand instead of with a regex, which keeps compile
+//! times and WASM size down. Like the rest of the crate, it needs `std`.
+//!
+//! A placeholder is `{{name}}`, where `name` is one or more word characters
+//! (letters, digits, `_`), possibly joined by single dots (`city.genitive`).
+//! Anything else — a lone `{`, `{{}}`, `{{a b}}`, `{{a.}}` — is literal
+//! text.
+//!
+//! A select placeholder, `{{item.gender, select, f{détruite} other{détruit}}}`,
+//! picks one of its cases from the value of `item.gender` (`other` when no
+//! case matches) so surrounding words can agree with an argument. Case texts
+//! may contain placeholders of their own.
+//!
+//! A transformed placeholder, `{{item|heading}}`, passes the value of `item`
+//! through the named transform (see [`format_template_with`]); `I18n` uses it
+//! for locale-aware capitalization.
+//!
+//! The optional `regex` feature matches placeholders with the
+//! `\{\{(\w+(?:\.\w+)*)\}\}` regex instead, for exact Unicode `\w` semantics
+//! (combining marks, connector punctuation). Both agree on ordinary names.
+
+/// A piece of a parsed translation template.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Segment<'a> {
+    /// Literal text, copied verbatim.
+    Text(&'a str),
+    /// A `{{name}}` placeholder; holds `name`.
+    Placeholder(&'a str),
+    /// A `{{name, select, a{…} other{…}}}` placeholder.
+    Select(Select<'a>),
+    /// A `{{name|transform}}` placeholder.
+    Transformed { name: &'a str, transform: &'a str },
+}
+
+/// A select placeholder, see [`Segment::Select`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Select<'a> {
+    /// Name of the value the case is chosen from; may contain dots
+    /// (`item.gender`).
+    pub name: &'a str,
+    cases: &'a str,
+    source: &'a str,
+}
+
+impl<'a> Select<'a> {
+    /// `(case, text)` pairs in template order.
+    pub fn cases(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+        let mut rest = self.cases;
+        core::iter::from_fn(move || {
+            let (case, text, len) = case_at(rest)?;
+            rest = &rest[len..];
+            Some((case, text))
+        })
+    }
+
+    /// Text of the case named `value`, else of the `other` case.
+    pub fn case(&self, value: Option<&str>) -> Option<&'a str> {
+        let mut other = None;
+        for (case, text) in self.cases() {
+            if Some(case) == value {
+                return Some(text);
+            }
+            if case == "other" {
+                other = Some(text);
+            }
+        }
+        other
+    }
+
+    /// The whole placeholder as written in the template.
+    pub fn source(&self) -> &'a str {
+        self.source
+    }
+}
+
+/// Iterator over the [`Segment`]s of a template, see [`parse_template`].
+#[derive(Clone, Debug)]
+pub struct Segments<'a> {
+    rest: &'a str,
+}
+
+/// Splits `template` into literal text, `{{name}}` placeholders and select
+/// placeholders.
+///
+/// ```rust
+/// use bevy_intl::{Segment, parse_template};
+///
+/// let segments: Vec<_> = parse_template("Hi {{name}}!").collect();
+/// assert_eq!(
+///     segments,
+///     [Segment::Text("Hi "), Segment::Placeholder("name"), Segment::Text("!")]
+/// );
+/// ```
+pub fn parse_template(template: &str) -> Segments<'_> {
+    Segments { rest: template }
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = Segment<'a>;
+
+    fn next(&mut self) -> Option<Segment<'a>> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        if let Some((name, len)) = placeholder_at(self.rest) {
+            self.rest = &self.rest[len..];
+            return Some(Segment::Placeholder(name));
+        }
+        if let Some((select, len)) = select_at(self.rest) {
+            self.rest = &self.rest[len..];
+            return Some(Segment::Select(select));
+        }
+        if let Some((name, transform, len)) = transformed_at(self.rest) {
+            self.rest = &self.rest[len..];
+            return Some(Segment::Transformed { name, transform });
+        }
+
+        // Literal text runs until the next position a placeholder starts at.
+        let mut end = self.rest.len();
+        for (i, _) in self.rest.char_indices().skip(1) {
+            if self.rest.as_bytes()[i] == b'{'
+                && (placeholder_at(&self.rest[i..]).is_some()
+                    || select_at(&self.rest[i..]).is_some()
+                    || transformed_at(&self.rest[i..]).is_some())
+            {
+                end = i;
+                break;
+            }
+        }
+        let (text, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(Segment::Text(text))
+    }
+}
+
+/// If `s` starts with a placeholder, returns its name and byte length.
+#[cfg(not(feature = "regex"))]
+fn placeholder_at(s: &str) -> Option<(&str, usize)> {
+    let inner = s.strip_prefix("{{")?;
+    let name_len = name_len(inner);
+    if name_len == 0 || !inner[name_len..].starts_with("}}") {
+        return None;
+    }
+    Some((&inner[..name_len], name_len + 4))
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Length of the leading run of word characters of `s`.
+fn word_len(s: &str) -> usize {
+    s.char_indices().find(|&(_, c)| !is_word_char(c)).map_or(s.len(), |(i, _)| i)
+}
+
+/// Length of the leading placeholder name of `s`: runs of word characters
+/// joined by single dots (`city.genitive`).
+fn name_len(s: &str) -> usize {
+    let mut len = word_len(s);
+    while len > 0 && s[len..].starts_with('.') {
+        let run = word_len(&s[len + 1..]);
+        if run == 0 {
+            break;
+        }
+        len += 1 + run;
+    }
+    len
+}
+
+/// If `s` starts with a transformed placeholder, returns its name, transform
+/// and byte length.
+fn transformed_at(s: &str) -> Option<(&str, &str, usize)> {
+    let inner = s.strip_prefix("{{")?;
+    let name_len = name_len(inner);
+    let transform = inner[name_len..].strip_prefix('|')?;
+    let transform_len = word_len(transform);
+    if name_len == 0 || transform_len == 0 || !transform[transform_len..].starts_with("}}") {
+        return None;
+    }
+    Some((&inner[..name_len], &transform[..transform_len], name_len + transform_len + 5))
+}
+
+/// If `s` starts with a select placeholder, returns it and its byte length.
+fn select_at(s: &str) -> Option<(Select<'_>, usize)> {
+    let inner = s.strip_prefix("{{")?;
+    let name_len = inner
+        .char_indices()
+        .find(|&(_, c)| !is_word_char(c) && c != '.')
+        .map_or(inner.len(), |(i, _)| i);
+    if name_len == 0 {
+        return None;
+    }
+    let rest = inner[name_len..].trim_start().strip_prefix(',')?.trim_start();
+    let rest = rest.strip_prefix("select")?.trim_start().strip_prefix(',')?;
+
+    let mut cases_len = 0;
+    while let Some((_, _, len)) = case_at(&rest[cases_len..]) {
+        cases_len += len;
+    }
+    let end = rest[cases_len..].trim_start();
+    end.strip_prefix("}}")?;
+    if cases_len == 0 {
+        return None;
+    }
+    let len = s.len() - end.len() + 2;
+    Some((Select { name: &inner[..name_len], cases: &rest[..cases_len], source: &s[..len] }, len))
+}
+
+/// If `s` starts with (optional whitespace and) a `case{text}`, returns the
+/// case, its text and the byte length consumed. Braces in the text must be
+/// balanced.
+fn case_at(s: &str) -> Option<(&str, &str, usize)> {
+    let trimmed = s.trim_start();
+    let case_len = trimmed
+        .char_indices()
+        .find(|&(_, c)| !is_word_char(c) && c != '-')
+        .map_or(trimmed.len(), |(i, _)| i);
+    if case_len == 0 || !trimmed[case_len..].starts_with('{') {
+        return None;
+    }
+    let text_start = case_len + 1;
+    let mut depth = 1usize;
+    for (i, c) in trimmed[text_start..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            _ => {}
+        }
+        if depth == 0 {
+            let text_end = text_start + i;
+            let len = s.len() - trimmed.len() + text_end + 1;
+            return Some((&trimmed[..case_len], &trimmed[text_start..text_end], len));
+        }
+    }
+    None
+}
+
+#[cfg(feature = "regex")]
+static PLACEHOLDER_RE: std::sync::LazyLock<regex::Regex> =
+    std::sync::LazyLock::new(|| regex::Regex::new(r"^\{\{(\w+(?:\.\w+)*)\}\}").unwrap());
+
+/// If `s` starts with a placeholder, returns its name and byte length.
+#[cfg(feature = "regex")]
+fn placeholder_at(s: &str) -> Option<(&str, usize)> {
+    let caps = PLACEHOLDER_RE.captures(s)?;
+    Some((caps.get(1)?.as_str(), caps.get(0)?.end()))
+}
+
+/// Replaces every placeholder with `value(name)`; placeholders for which it
+/// returns `None` are kept literally. Select placeholders are replaced by the
+/// case matching `value(name)`, itself formatted. Transforms are ignored:
+/// `{{name|heading}}` is replaced like `{{name}}`.
+pub fn format_template(template: &str, value: impl FnMut(&str) -> Option<String>) -> String {
+    format_template_with(template, value, |_, value| value)
+}
+
+/// Like [`format_template`], passing the value of each `{{name|transform}}`
+/// placeholder through `transform(transform, value)`.
+pub fn format_template_with(
+    template: &str,
+    mut value: impl FnMut(&str) -> Option<String>,
+    mut transform: impl FnMut(&str, String) -> String,
+) -> String {
+    let mut out = String::with_capacity(template.len());
+    format_into(&mut out, template, &mut value, &mut transform);
+    out
+}
+
+fn format_into(
+    out: &mut String,
+    template: &str,
+    value: &mut dyn FnMut(&str) -> Option<String>,
+    apply_transform: &mut dyn FnMut(&str, String) -> String,
+) {
+    for segment in parse_template(template) {
+        match segment {
+            Segment::Text(text) => out.push_str(text),
+            Segment::Placeholder(name) => match value(name) {
+                Some(v) => out.push_str(&v),
+                None => {
+                    out.push_str("{{");
+                    out.push_str(name);
+                    out.push_str("}}");
+                }
+            },
+            Segment::Select(select) => match select.case(value(select.name).as_deref()) {
+                Some(text) => format_into(out, text, value, apply_transform),
+                None => out.push_str(select.source()),
+            },
+            Segment::Transformed { name, transform } => match value(name) {
+                Some(v) => out.push_str(&apply_transform(transform, v)),
+                None => {
+                    out.push_str("{{");
+                    out.push_str(name);
+                    out.push('|');
+                    out.push_str(transform);
+                    out.push_str("}}");
+                }
+            },
+        }
+    }
+}
+
+/// Replaces placeholders by name from `args`; unknown names are kept.
+pub fn format_named(template: &str, args: &[(&str, &dyn ToString)]) -> String {
+    format_template(template, |name| {
+        args.iter().find(|(k, _)| *k == name).map(|(_, v)| v.to_string())
+    })
+}
+
+/// Replaces placeholders by order of appearance, ignoring their names.
+/// Placeholders beyond `args.len()` are kept.
+pub fn format_positional(template: &str, args: &[&dyn ToString]) -> String {
+    let mut next = args.iter();
+    format_template(template, |_| next.next().map(|v| v.to_string()))
+}
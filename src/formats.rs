@@ -0,0 +1,157 @@
+//! Translation file formats.
+//!
+//! A language folder may mix formats — `ui.json` next to `dialogue.yaml` —
+//! while a team migrates. Each file is parsed by the parser registered for
+//! its extension into the JSON shape of a `.json` file, so every feature
+//! (plurals, annotations, documents…) works the same in every format. Files
+//! with an unregistered extension are ignored.
+//!
+//! Files of one language with the same name in several formats (`ui.json`
+//! and `ui.yaml`) are merged into one translation file. A key defined in
+//! more than one of them is reported, and the first file in alphabetical
+//! order wins.
+//!
+//! ```rust
+//! use bevy_intl::{I18nConfig, TranslationFormats};
+//!
+//! // `.properties` files, with a hand-written parser.
+//! fn parse_properties(source: &str) -> Result<serde_json::Value, String> {
+//!     let entries = source
+//!         .lines()
+//!         .filter_map(|line| line.split_once('='))
+//!         .map(|(key, value)| (key.trim().to_string(), value.trim().into()));
+//!     Ok(serde_json::Value::Object(entries.collect()))
+//! }
+//!
+//! let config = I18nConfig {
+//!     formats: TranslationFormats::default().with("properties", parse_properties),
+//!     ..Default::default()
+//! };
+//! ```
+
+use serde_json::Value;
+
+/// Parses the content of a translation file into the JSON value a `.json`
+/// file with the same translations would hold.
+pub type FormatParser = fn(&str) -> Result<Value, String>;
+
+/// [`FormatParser`] of a binary format, e.g. compiled gettext `.mo` files.
+pub type BinaryFormatParser = fn(&[u8]) -> Result<Value, String>;
+
+#[derive(Clone, Copy, Debug)]
+enum Parser {
+    Text(FormatParser),
+    Binary(BinaryFormatParser),
+}
+
+/// Parser of each supported file extension, see the [module docs](self).
+///
+/// The default reads `.json`, with the `yaml` feature `.yaml` / `.yml` with
+/// [`parse_yaml`], with the `fluent` feature `.ftl` with [`parse_fluent`](crate::parse_fluent)
+/// and, with the `gettext` feature, `.po` and `.mo` with
+/// [`parse_po`](crate::parse_po) and [`parse_mo`](crate::parse_mo).
+/// Translations bundled at build time (WASM, `bundle-only`) support these
+/// built-in formats only.
+#[derive(Clone, Debug)]
+pub struct TranslationFormats {
+    parsers: Vec<(String, Parser)>,
+    /// Whether `.json` files use the built-in parser, which can read large
+    /// files entry by entry instead of as one string.
+    builtin_json: bool,
+}
+
+impl Default for TranslationFormats {
+    fn default() -> Self {
+        let formats = Self::bundled();
+        #[cfg(feature = "fluent")]
+        let formats = formats.with("ftl", crate::fluent::parse_fluent);
+        #[cfg(feature = "gettext")]
+        let formats = formats.with("po", crate::gettext::parse_po).with_binary("mo", crate::gettext::parse_mo);
+        formats
+    }
+}
+
+impl TranslationFormats {
+    /// Only `.json` files, the historical behavior.
+    pub fn json_only() -> Self {
+        Self { parsers: vec![("json".to_string(), Parser::Text(parse_json))], builtin_json: true }
+    }
+
+    /// The formats `build.rs` bundles: `.json` and, with the `yaml` feature,
+    /// `.yaml` / `.yml`.
+    pub(crate) fn bundled() -> Self {
+        let formats = Self::json_only();
+        #[cfg(feature = "yaml")]
+        let formats = formats.with("yaml", parse_yaml).with("yml", parse_yaml);
+        formats
+    }
+
+    /// Reads files ending in `.{extension}` with `parser`, replacing the
+    /// parser previously registered for it.
+    pub fn with(self, extension: impl Into<String>, parser: FormatParser) -> Self {
+        self.register(extension.into(), Parser::Text(parser))
+    }
+
+    /// Like [`with`](Self::with), for a format that is not text: `parser`
+    /// gets the bytes of the file.
+    pub fn with_binary(self, extension: impl Into<String>, parser: BinaryFormatParser) -> Self {
+        self.register(extension.into(), Parser::Binary(parser))
+    }
+
+    fn register(mut self, extension: String, parser: Parser) -> Self {
+        self.builtin_json &= extension != "json";
+        self.parsers.retain(|(registered, _)| *registered != extension);
+        self.parsers.push((extension, parser));
+        self
+    }
+
+    /// Text parser registered for `extension` (without the dot); `None` for
+    /// binary formats.
+    pub fn parser(&self, extension: &str) -> Option<FormatParser> {
+        self.parsers.iter().find_map(|(registered, parser)| match parser {
+            Parser::Text(parser) if registered == extension => Some(*parser),
+            _ => None,
+        })
+    }
+
+    /// Whether a parser, text or binary, is registered for `extension`.
+    pub fn supports(&self, extension: &str) -> bool {
+        self.parsers.iter().any(|(registered, _)| registered == extension)
+    }
+
+    /// Parses `bytes` with the parser registered for `extension`; text
+    /// formats must be UTF-8. `None` if no parser is registered.
+    pub fn parse(&self, extension: &str, bytes: &[u8]) -> Option<Result<Value, String>> {
+        let (_, parser) = self.parsers.iter().find(|(registered, _)| registered == extension)?;
+        Some(match parser {
+            Parser::Text(parser) => std::str::from_utf8(bytes).map_err(|e| e.to_string()).and_then(parser),
+            Parser::Binary(parser) => parser(bytes),
+        })
+    }
+
+    /// Whether `.json` files are read by the built-in parser.
+    #[cfg(all(feature = "fs-loader", not(target_arch = "wasm32")))]
+    pub(crate) fn builtin_json(&self) -> bool {
+        self.builtin_json
+    }
+
+    /// Every registered extension.
+    pub fn extensions(&self) -> impl Iterator<Item = &str> {
+        self.parsers.iter().map(|(extension, _)| extension.as_str())
+    }
+}
+
+/// The `.json` parser.
+pub fn parse_json(source: &str) -> Result<Value, String> {
+    serde_json::from_str(source).map_err(|e| e.to_string())
+}
+
+/// The `.yaml` / `.yml` parser. YAML files hold the same structure as the
+/// JSON ones; integers, booleans and `null` keep their type, so
+/// `"key@max": 12` works as in JSON. Keys defined twice are an error.
+#[cfg(feature = "yaml")]
+pub fn parse_yaml(source: &str) -> Result<Value, String> {
+    // Read as YAML values first: they reject duplicate keys.
+    let yaml: serde_yaml::Value = serde_yaml::from_str(source).map_err(|e| e.to_string())?;
+    serde_json::to_value(yaml).map_err(|e| e.to_string())
+}
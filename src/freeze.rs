@@ -0,0 +1,130 @@
+//! String freeze for late development.
+//!
+//! Once translation of a release is under way, source strings are "frozen":
+//! editing one silently invalidates every finished translation of it. A
+//! [`FreezeManifest`] records a hash of each source string at freeze time
+//! (`bevy-intl-cli freeze`, or [`I18n::freeze_manifest`]). Checking it later
+//! (`bevy-intl-cli check-freeze`, or [`FreezeManifest::check`]) lists frozen
+//! strings that were changed or removed. Bumping the version lifts the
+//! freeze: strings may change again until the next manifest is written. An
+//! older version does not. New keys are always allowed.
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{I18n, SectionValue, pack_hash};
+
+/// Hashes of the source strings of one release, stored as JSON:
+///
+/// ```json
+/// { "version": "1.4.0", "lang": "en", "strings": { "menu.play": "9f86d081884c7d65" } }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FreezeManifest {
+    /// Release the freeze applies to.
+    pub version: String,
+    /// Source language whose strings are frozen.
+    pub lang: String,
+    /// `file.key` → hash of the source string (all forms of plural and
+    /// gender maps).
+    pub strings: BTreeMap<String, String>,
+}
+
+/// How a frozen string was invalidated, see [`FreezeManifest::check`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FrozenChangeKind {
+    Changed,
+    Removed,
+}
+
+/// A frozen source string that no longer matches the manifest.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FrozenStringChange {
+    pub file: String,
+    pub key: String,
+    pub kind: FrozenChangeKind,
+}
+
+/// Every form of a value as one string, independent of map order.
+pub(crate) fn canonical_source(value: &SectionValue) -> String {
+    match value {
+        SectionValue::Text(text) => text.clone(),
+        SectionValue::Map(map) => {
+            let sorted: BTreeMap<_, _> = map.iter().map(|(k, v)| (k.as_str(), v)).collect();
+            serde_json::to_string(&sorted).unwrap_or_default()
+        }
+        SectionValue::Nested(nested) => {
+            let sorted: BTreeMap<_, BTreeMap<_, _>> = nested
+                .iter()
+                .map(|(k, forms)| (k.as_str(), forms.iter().map(|(form, v)| (form.as_str(), v)).collect()))
+                .collect();
+            serde_json::to_string(&sorted).unwrap_or_default()
+        }
+    }
+}
+
+impl I18n {
+    /// Freezes the current source strings of `lang` for `version`.
+    pub fn freeze_manifest(&self, lang: &str, version: &str) -> FreezeManifest {
+        let strings = self
+            .translations
+            .langs
+            .get(lang)
+            .into_iter()
+            .flatten()
+            .flat_map(|(file, sections)| {
+                sections.iter().map(move |(key, value)| (format!("{}.{}", file, key), pack_hash(&canonical_source(value))))
+            })
+            .collect();
+        FreezeManifest { version: version.to_string(), lang: lang.to_string(), strings }
+    }
+}
+
+impl FreezeManifest {
+    /// How `version` compares with the manifest's. Versions are compared
+    /// by dot-separated components, numerically when both are numbers
+    /// (`1.10` is newer than `1.9`); missing components count as 0.
+    pub fn compare_version(&self, version: &str) -> Ordering {
+        let mut ours = self.version.split('.');
+        let mut theirs = version.split('.');
+        loop {
+            let order = match (theirs.next(), ours.next()) {
+                (None, None) => return Ordering::Equal,
+                (theirs, ours) => {
+                    let (theirs, ours) = (theirs.unwrap_or("0"), ours.unwrap_or("0"));
+                    match (theirs.parse::<u64>(), ours.parse::<u64>()) {
+                        (Ok(theirs), Ok(ours)) => theirs.cmp(&ours),
+                        _ => theirs.cmp(ours),
+                    }
+                }
+            };
+            if order != Ordering::Equal {
+                return order;
+            }
+        }
+    }
+
+    /// Frozen strings of `i18n` that changed or disappeared, sorted by file
+    /// and key. Always empty when `version` is newer than the manifest's:
+    /// the version was bumped, so the freeze is over.
+    pub fn check(&self, i18n: &I18n, version: &str) -> Vec<FrozenStringChange> {
+        if self.compare_version(version) == Ordering::Greater {
+            return Vec::new();
+        }
+        let current = i18n.freeze_manifest(&self.lang, version);
+        self.strings
+            .iter()
+            .filter_map(|(id, hash)| {
+                let kind = match current.strings.get(id) {
+                    None => FrozenChangeKind::Removed,
+                    Some(current) if current != hash => FrozenChangeKind::Changed,
+                    Some(_) => return None,
+                };
+                let (file, key) = id.split_once('.').unwrap_or((id, ""));
+                Some(FrozenStringChange { file: file.to_string(), key: key.to_string(), kind })
+            })
+            .collect()
+    }
+}
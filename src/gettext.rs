@@ -0,0 +1,437 @@
+//! gettext (`.po` / `.mo`) catalogs.
+//!
+//! Available with the `gettext` feature, which registers [`parse_po`] for
+//! `.po` files and [`parse_mo`] for compiled `.mo` files in
+//! [`TranslationFormats::default`](crate::TranslationFormats). Catalogs are
+//! converted to the JSON shape of a `.json` file:
+//!
+//! - each `msgid` is a key and its `msgstr` the translation, whatever
+//!   characters it holds (`"Contact support@example.com"`, `"Loading..."`);
+//!   a `msgctxt` prefixes the key with gettext's own separator, so look it
+//!   up with [`gettext_key`] (`gettext_key("menu", "quit")`);
+//! - a plural entry (`msgid_plural`, `msgstr[N]`) becomes a plural map. The
+//!   header's `Plural-Forms` formula is evaluated for counts 0 to 199 to
+//!   find the CLDR category of each form in the catalog's `Language`. A
+//!   category reads the form most of its counts use, counts using another
+//!   form get an exact-count key, and the last form is `other` when no
+//!   count falls in it;
+//! - untranslated entries (an empty `msgstr`) and fuzzy ones are skipped, as
+//!   `msgfmt` does, so the fallback chain provides them.
+//!
+//! Strings are kept as they are: placeholders must use the `{{name}}`
+//! syntax, not `%s`. `.mo` files must be UTF-8. Bundled translations (WASM,
+//! `bundle-only`) do not read gettext files.
+
+use serde_json::{Map, Value};
+
+use crate::plural::{build_plural_rules, select_plural_form};
+
+/// Counts for which the `Plural-Forms` formula is compared with the CLDR
+/// rules.
+const EXACT_COUNTS: u64 = 200;
+
+/// Separator of a `msgctxt` and its `msgid`, as in `.mo` files.
+const CONTEXT_SEPARATOR: char = '\u{4}';
+
+/// Key of `msgid` under `msgctxt` `context` in a loaded catalog:
+/// `i18n.translation("ui").t(&gettext_key("menu", "quit"))`. Entries without
+/// a context are looked up by their `msgid`.
+pub fn gettext_key(context: &str, msgid: &str) -> String {
+    format!("{}{}{}", context, CONTEXT_SEPARATOR, msgid)
+}
+
+/// A catalog entry, from either format.
+#[derive(Default)]
+struct Entry {
+    context: Option<String>,
+    id: String,
+    /// Whether the entry has a `msgid_plural`.
+    plural: bool,
+    /// `msgstr`, or `msgstr[0]`, `msgstr[1]`…
+    translations: Vec<String>,
+}
+
+/// Field of a `.po` entry that continuation strings are appended to.
+#[derive(Clone, Copy)]
+enum Field {
+    Context,
+    Id,
+    Plural,
+    Translation(usize),
+}
+
+/// Parses a `.po` catalog into the JSON value a `.json` file with the same
+/// translations would give, see the [module docs](self).
+pub fn parse_po(source: &str) -> Result<Value, String> {
+    let mut catalog = Vec::new();
+    let mut entry = Entry::default();
+    let mut fuzzy = false;
+    let mut field = None;
+    for (number, line) in source.lines().enumerate() {
+        let line = line.trim();
+        let error = |e: String| format!("line {}: {}", number + 1, e);
+        // Comments and a new `msgctxt` / `msgid` start the next entry.
+        let starts_entry = line.starts_with('#') || line.starts_with("msgctxt") || line.starts_with("msgid ");
+        if starts_entry && !entry.translations.is_empty() {
+            let finished = std::mem::take(&mut entry);
+            if !fuzzy || finished.id.is_empty() {
+                catalog.push(finished);
+            }
+            fuzzy = false;
+            field = None;
+        }
+        if line.is_empty() || line.starts_with("#~") {
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            if let Some(flags) = comment.strip_prefix(',') {
+                fuzzy |= flags.split(',').any(|flag| flag.trim() == "fuzzy");
+            }
+            continue;
+        }
+
+        let (keyword, text) = match line.split_once(char::is_whitespace) {
+            _ if line.starts_with('"') => (None, line),
+            Some((keyword, text)) => (Some(keyword), text.trim()),
+            None => return Err(error(format!("expected a string after '{}'", line))),
+        };
+        let text = unquote(text).map_err(error)?;
+        if let Some(keyword) = keyword {
+            field = Some(match keyword {
+                "msgctxt" => Field::Context,
+                "msgid" => Field::Id,
+                "msgid_plural" => Field::Plural,
+                "msgstr" => Field::Translation(0),
+                _ => match keyword.strip_prefix("msgstr[").and_then(|k| k.strip_suffix(']')).and_then(|k| k.parse().ok()) {
+                    Some(index) => Field::Translation(index),
+                    None => return Err(error(format!("unknown keyword '{}'", keyword))),
+                },
+            });
+        }
+        match field {
+            Some(Field::Context) => entry.context.get_or_insert_with(String::new).push_str(&text),
+            Some(Field::Id) => entry.id.push_str(&text),
+            Some(Field::Plural) => entry.plural = true,
+            Some(Field::Translation(index)) => {
+                if entry.translations.len() <= index {
+                    entry.translations.resize(index + 1, String::new());
+                }
+                entry.translations[index].push_str(&text);
+            }
+            None => return Err(error("string outside of an entry".to_string())),
+        }
+    }
+    if !entry.translations.is_empty() && (!fuzzy || entry.id.is_empty()) {
+        catalog.push(entry);
+    }
+    into_json(catalog)
+}
+
+/// Parses a compiled `.mo` catalog, see the [module docs](self).
+pub fn parse_mo(bytes: &[u8]) -> Result<Value, String> {
+    let big_endian = match bytes.get(..4) {
+        Some([0xde, 0x12, 0x04, 0x95]) => false,
+        Some([0x95, 0x04, 0x12, 0xde]) => true,
+        _ => return Err("not a .mo file".to_string()),
+    };
+    let word = |at: usize| {
+        let word = bytes.get(at..at.checked_add(4)?)?.try_into().ok()?;
+        let word = if big_endian { u32::from_be_bytes(word) } else { u32::from_le_bytes(word) };
+        usize::try_from(word).ok()
+    };
+    // Length and offset of string `index` of the table at `table`.
+    let string = |table: usize, index: usize| {
+        let at = index.checked_mul(8)?.checked_add(table)?;
+        let (len, offset) = (word(at)?, word(at + 4)?);
+        bytes.get(offset..offset.checked_add(len)?)
+    };
+    let header = |at| word(at).ok_or_else(|| "truncated header".to_string());
+    let (count, originals, translations) = (header(8)?, header(12)?, header(16)?);
+
+    let mut catalog = Vec::with_capacity(count.min(bytes.len() / 16));
+    for index in 0..count {
+        let text = |table| {
+            let text = string(table, index).ok_or_else(|| format!("string {} is out of bounds", index))?;
+            std::str::from_utf8(text).map_err(|_| format!("string {} is not UTF-8", index))
+        };
+        let original = text(originals)?;
+        let (context, original) = match original.split_once(CONTEXT_SEPARATOR) {
+            Some((context, original)) => (Some(context.to_string()), original),
+            None => (None, original),
+        };
+        let (id, plural) = match original.split_once('\0') {
+            Some((id, _)) => (id, true),
+            None => (original, false),
+        };
+        let translations = text(translations)?.split('\0').map(str::to_string).collect();
+        catalog.push(Entry { context, id: id.to_string(), plural, translations });
+    }
+    into_json(catalog)
+}
+
+fn into_json(catalog: Vec<Entry>) -> Result<Value, String> {
+    let is_header = |entry: &Entry| entry.context.is_none() && entry.id.is_empty();
+    let header = catalog.iter().find(|entry| is_header(entry)).and_then(|entry| entry.translations.first());
+    let header_field = |name: &str| {
+        header?.lines().find_map(|line| line.strip_prefix(name)?.strip_prefix(':')).map(str::trim)
+    };
+    let plurals = match catalog.iter().any(|entry| entry.plural) {
+        true => Some(PluralMapping::new(header_field("Language"), header_field("Plural-Forms"))?),
+        false => None,
+    };
+
+    let mut translations = Map::new();
+    for entry in catalog.iter().filter(|entry| !is_header(entry)) {
+        if entry.translations.iter().any(String::is_empty) {
+            continue;
+        }
+        let key = match &entry.context {
+            Some(context) => gettext_key(context, &entry.id),
+            None => entry.id.clone(),
+        };
+        let value = match &plurals {
+            Some(plurals) if entry.plural => plurals.map(&entry.translations).map_err(|e| format!("'{}': {}", key, e))?,
+            _ => Value::String(entry.translations[0].clone()),
+        };
+        translations.insert(key, value);
+    }
+    Ok(Value::Object(translations))
+}
+
+/// Plural map key of each `msgstr[N]`.
+struct PluralMapping {
+    /// Plural category or exact count, and the form it reads.
+    keys: Vec<(String, usize)>,
+    forms: usize,
+}
+
+impl PluralMapping {
+    fn new(language: Option<&str>, plural_forms: Option<&str>) -> Result<Self, String> {
+        let language = language.filter(|l| !l.is_empty()).ok_or("plural entries need a 'Language' header")?;
+        let rules = build_plural_rules(&[language.to_string()]);
+        let rules = rules.get(language).ok_or_else(|| format!("no CLDR plural rules for '{}'", language))?;
+        let (forms, formula) = match plural_forms {
+            Some(header) => parse_plural_forms(header).map_err(|e| format!("'Plural-Forms': {}", e))?,
+            None => (2, Formula::parse("n != 1")?),
+        };
+
+        let samples = (0..EXACT_COUNTS)
+            .map(|count| {
+                let form = formula.eval(count).ok_or("'Plural-Forms' divides by zero")?;
+                let form = usize::try_from(form).ok().filter(|form| *form < forms).ok_or_else(|| {
+                    format!("'Plural-Forms' gives form {} for {}, but nplurals is {}", form, count, forms)
+                })?;
+                Ok((count, select_plural_form(Some(rules), i128::from(count)).key(), form))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        // Each category reads the form most of its counts use; the others
+        // get exact-count keys.
+        let mut keys: Vec<(String, usize)> = Vec::new();
+        for (_, category, _) in &samples {
+            if keys.iter().any(|(key, _)| key == category) {
+                continue;
+            }
+            let mut tally = vec![0; forms];
+            for (_, _, form) in samples.iter().filter(|(_, other, _)| other == category) {
+                tally[*form] += 1;
+            }
+            let form = (0..forms).max_by_key(|form| (tally[*form], std::cmp::Reverse(*form))).unwrap_or_default();
+            keys.push((category.to_string(), form));
+        }
+        for (count, category, form) in &samples {
+            if keys.iter().any(|(key, usual)| key == category && usual != form) {
+                keys.push((count.to_string(), *form));
+            }
+        }
+        if !keys.iter().any(|(key, _)| key == "other") {
+            keys.push(("other".to_string(), forms - 1));
+        }
+        Ok(Self { keys, forms })
+    }
+
+    fn map(&self, translations: &[String]) -> Result<Value, String> {
+        if translations.len() != self.forms {
+            return Err(format!("{} plural forms, but nplurals is {}", translations.len(), self.forms));
+        }
+        let forms = self.keys.iter().map(|(key, form)| (key.clone(), Value::String(translations[*form].clone())));
+        Ok(Value::Object(forms.collect()))
+    }
+}
+
+/// `nplurals=3; plural=(n==1 ? 0 : n<5 ? 1 : 2);`
+fn parse_plural_forms(header: &str) -> Result<(usize, Formula), String> {
+    let mut forms = None;
+    let mut formula = None;
+    for part in header.split(';').map(str::trim).filter(|part| !part.is_empty()) {
+        match part.split_once('=').map(|(name, value)| (name.trim(), value.trim())) {
+            Some(("nplurals", value)) => forms = value.parse::<usize>().ok().filter(|forms| *forms > 0),
+            Some(("plural", value)) => formula = Some(Formula::parse(value)?),
+            _ => return Err(format!("unexpected '{}'", part)),
+        }
+    }
+    Ok((forms.ok_or("missing or invalid nplurals")?, formula.ok_or("missing plural")?))
+}
+
+/// A `Plural-Forms` formula: a C expression of `n`.
+#[derive(Debug)]
+enum Formula {
+    N,
+    Number(u64),
+    Not(Box<Formula>),
+    Binary(Op, Box<Formula>, Box<Formula>),
+    Conditional(Box<Formula>, Box<Formula>, Box<Formula>),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Op {
+    Or,
+    And,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+/// Binary operators by decreasing precedence level; two-character operators
+/// come before their one-character prefix.
+const LEVELS: [&[(&str, Op)]; 6] = [
+    &[("*", Op::Mul), ("/", Op::Div), ("%", Op::Rem)],
+    &[("+", Op::Add), ("-", Op::Sub)],
+    &[("<=", Op::Le), (">=", Op::Ge), ("<", Op::Lt), (">", Op::Gt)],
+    &[("==", Op::Eq), ("!=", Op::Ne)],
+    &[("&&", Op::And)],
+    &[("||", Op::Or)],
+];
+
+impl Formula {
+    fn parse(source: &str) -> Result<Self, String> {
+        let mut rest = source;
+        let formula = Self::conditional(&mut rest)?;
+        match rest.trim_start() {
+            "" => Ok(formula),
+            extra => Err(format!("unexpected '{}' in '{}'", extra, source)),
+        }
+    }
+
+    fn conditional(rest: &mut &str) -> Result<Self, String> {
+        let condition = Self::binary(rest, LEVELS.len() - 1)?;
+        if !eat(rest, "?") {
+            return Ok(condition);
+        }
+        let then = Self::conditional(rest)?;
+        if !eat(rest, ":") {
+            return Err("expected ':'".to_string());
+        }
+        let otherwise = Self::conditional(rest)?;
+        Ok(Self::Conditional(Box::new(condition), Box::new(then), Box::new(otherwise)))
+    }
+
+    fn binary(rest: &mut &str, level: usize) -> Result<Self, String> {
+        let operand = |rest: &mut &str| match level {
+            0 => Self::unary(rest),
+            _ => Self::binary(rest, level - 1),
+        };
+        let mut left = operand(rest)?;
+        while let Some(&(_, op)) = LEVELS[level].iter().find(|(token, _)| eat(rest, token)) {
+            left = Self::Binary(op, Box::new(left), Box::new(operand(rest)?));
+        }
+        Ok(left)
+    }
+
+    fn unary(rest: &mut &str) -> Result<Self, String> {
+        if eat(rest, "!") {
+            return Ok(Self::Not(Box::new(Self::unary(rest)?)));
+        }
+        if eat(rest, "(") {
+            let inner = Self::conditional(rest)?;
+            return if eat(rest, ")") { Ok(inner) } else { Err("expected ')'".to_string()) };
+        }
+        if eat(rest, "n") {
+            return Ok(Self::N);
+        }
+        *rest = rest.trim_start();
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        let number = rest[..digits].parse().map_err(|_| match *rest {
+            "" => "unexpected end of formula".to_string(),
+            rest => format!("unexpected '{}'", rest),
+        })?;
+        *rest = &rest[digits..];
+        Ok(Self::Number(number))
+    }
+
+    /// Value for `n`, `None` on a division by zero.
+    fn eval(&self, n: u64) -> Option<u64> {
+        Some(match self {
+            Self::N => n,
+            Self::Number(number) => *number,
+            Self::Not(inner) => u64::from(inner.eval(n)? == 0),
+            Self::Conditional(condition, then, otherwise) => match condition.eval(n)? {
+                0 => otherwise.eval(n)?,
+                _ => then.eval(n)?,
+            },
+            Self::Binary(op, left, right) => {
+                let (left, right) = (left.eval(n)?, right.eval(n)?);
+                match op {
+                    Op::Or => u64::from(left != 0 || right != 0),
+                    Op::And => u64::from(left != 0 && right != 0),
+                    Op::Eq => u64::from(left == right),
+                    Op::Ne => u64::from(left != right),
+                    Op::Lt => u64::from(left < right),
+                    Op::Le => u64::from(left <= right),
+                    Op::Gt => u64::from(left > right),
+                    Op::Ge => u64::from(left >= right),
+                    Op::Add => left.wrapping_add(right),
+                    Op::Sub => left.wrapping_sub(right),
+                    Op::Mul => left.wrapping_mul(right),
+                    Op::Div => left.checked_div(right)?,
+                    Op::Rem => left.checked_rem(right)?,
+                }
+            }
+        })
+    }
+}
+
+/// Consumes `token` at the start of `rest`, after whitespace.
+fn eat(rest: &mut &str, token: &str) -> bool {
+    match rest.trim_start().strip_prefix(token) {
+        Some(after) => {
+            *rest = after;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Content of a quoted `.po` string, with C escapes resolved.
+fn unquote(text: &str) -> Result<String, String> {
+    let inner = text
+        .strip_prefix('"')
+        .and_then(|text| text.strip_suffix('"'))
+        .ok_or_else(|| format!("expected a quoted string, found '{}'", text))?;
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        out.push(match c {
+            '\\' => match chars.next() {
+                Some('n') => '\n',
+                Some('t') => '\t',
+                Some('r') => '\r',
+                Some('"') => '"',
+                Some('\\') => '\\',
+                Some(other) => return Err(format!("unsupported escape '\\{}'", other)),
+                None => return Err("unterminated string".to_string()),
+            },
+            '"' => return Err("unescaped '\"' in a string".to_string()),
+            c => c,
+        });
+    }
+    Ok(out)
+}
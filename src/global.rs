@@ -0,0 +1,55 @@
+//! Process-wide translation handle (`global` feature).
+//!
+//! Deep library code — `Display` impls, utility crates, error types — cannot
+//! easily reach `Res<I18n>`. [`global`] returns the most recently published
+//! [`I18nSnapshot`] from anywhere, without locking. `I18nPlugin` publishes
+//! one at startup and after every change to the resource; without Bevy, call
+//! [`set_global`] yourself.
+//!
+//! ```rust,no_run
+//! use std::fmt;
+//!
+//! struct Gold(u32);
+//!
+//! impl fmt::Display for Gold {
+//!     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+//!         match bevy_intl::global() {
+//!             Some(i18n) => f.write_str(&i18n.translation("ui").t_with_plural("gold", self.0)),
+//!             None => write!(f, "{} gold", self.0),
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::sync::Arc;
+
+use arc_swap::ArcSwapOption;
+#[cfg(feature = "bevy")]
+use bevy::prelude::{DetectChanges, Res};
+
+use crate::{I18n, I18nSnapshot};
+
+static GLOBAL: ArcSwapOption<I18nSnapshot> = ArcSwapOption::const_empty();
+
+/// The published translations, or `None` before the first [`set_global`].
+pub fn global() -> Option<I18nSnapshot> {
+    GLOBAL.load_full().map(|snapshot| I18nSnapshot::clone(&snapshot))
+}
+
+/// Publishes a snapshot of `i18n` for [`global`].
+pub fn set_global(i18n: &I18n) {
+    GLOBAL.store(Some(Arc::new(i18n.snapshot())));
+}
+
+/// Removes the published translations; [`global`] returns `None` again.
+pub fn clear_global() {
+    GLOBAL.store(None);
+}
+
+/// Bevy system publishing the [`I18n`] resource whenever it changes.
+#[cfg(feature = "bevy")]
+pub fn sync_global_i18n(i18n: Res<I18n>) {
+    if i18n.is_changed() {
+        set_global(&i18n);
+    }
+}
@@ -0,0 +1,184 @@
+//! Glossary and term consistency checks.
+//!
+//! Each language folder may contain a reserved `_glossary.json` mapping term
+//! ids to the canonical spelling of protected terms (item names, factions…)
+//! and the variants translators must not use:
+//!
+//! ```json
+//! {
+//!     "dawn_sword": {
+//!         "term": "Sword of Dawn",
+//!         "variants": ["Dawn Sword", "Sword of the Dawn"]
+//!     }
+//! }
+//! ```
+//!
+//! [`I18n::check_glossary`] flags every translation that uses a variant, and
+//! debug builds log those violations at startup.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+
+use serde::Deserialize;
+
+use crate::I18n;
+
+/// Name of the reserved glossary file inside each language folder.
+pub(crate) const GLOSSARY_FILE: &str = "_glossary";
+
+/// A protected term from a `_glossary.json` file.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct GlossaryTerm {
+    /// Canonical spelling of the term in this language.
+    pub term: String,
+    /// Non-canonical spellings that must not appear in translations.
+    #[serde(default)]
+    pub variants: Vec<String>,
+}
+
+/// A translation using a non-canonical variant of a glossary term.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlossaryViolation {
+    pub lang: String,
+    pub file: String,
+    pub key: String,
+    /// Id of the glossary entry, e.g. `"dawn_sword"`.
+    pub term_id: String,
+    /// Canonical spelling that should have been used.
+    pub canonical: String,
+    /// The variant found in the translation.
+    pub found: String,
+}
+
+impl fmt::Display for GlossaryViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{}] {}.{} uses '{}' instead of glossary term '{}' ({})",
+            self.lang, self.file, self.key, self.found, self.canonical, self.term_id
+        )
+    }
+}
+
+impl I18n {
+    /// Glossary entries loaded for `lang`, keyed by term id. Malformed
+    /// entries are skipped.
+    pub fn glossary(&self, lang: &str) -> HashMap<String, GlossaryTerm> {
+        self.translations.glossaries.get(lang).cloned().unwrap_or_default()
+    }
+
+    /// Canonical spelling of a glossary term in the current language, falling
+    /// back along the fallback chain.
+    pub fn glossary_term(&self, term_id: &str) -> Option<String> {
+        std::iter::once(self.get_lang())
+            .chain(self.fallback_chain().iter().map(String::as_str))
+            .find_map(|lang| self.translations.glossaries.get(lang)?.get(term_id))
+            .map(|t| t.term.clone())
+    }
+
+    /// Scans every translation for non-canonical variants of glossary terms.
+    ///
+    /// Matching is case-insensitive and on whole words: `"Dawn Sword"` is not
+    /// flagged in `"Dawn Swordsman"`, nor inside an occurrence of the
+    /// canonical term. Results are sorted by language, file and key.
+    pub fn check_glossary(&self) -> Vec<GlossaryViolation> {
+        let mut violations = Vec::new();
+
+        for lang in self.available_languages() {
+            let Some(glossary) = self.translations.glossaries.get(lang).filter(|glossary| !glossary.is_empty()) else {
+                continue;
+            };
+            let Some(files) = self.translations.langs.get(lang) else {
+                continue;
+            };
+            let mut terms: Vec<LoweredTerm> = glossary.iter().map(|(id, term)| LoweredTerm::new(id, term)).collect();
+            terms.sort_by(|a, b| a.id.cmp(b.id));
+
+            let mut file_names: Vec<&String> = files.keys().collect();
+            file_names.sort();
+            for file in file_names {
+                let sections = &files[file];
+                let mut keys: Vec<&String> = sections.keys().collect();
+                keys.sort();
+                for key in keys {
+                    for text in sections[key].strings() {
+                        collect_violations(lang, file, key, text, &terms, &mut violations);
+                    }
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+/// The glossary of a `_glossary.json` file. Malformed entries are skipped.
+pub(crate) fn parse_glossary(json: &serde_json::Value) -> HashMap<String, GlossaryTerm> {
+    json.as_object()
+        .into_iter()
+        .flatten()
+        .filter_map(|(id, v)| serde_json::from_value::<GlossaryTerm>(v.clone()).ok().map(|t| (id.clone(), t)))
+        .collect()
+}
+
+/// A glossary term and its variants, lowercased once per check.
+struct LoweredTerm<'a> {
+    id: &'a String,
+    entry: &'a GlossaryTerm,
+    canonical: String,
+    variants: Vec<(&'a String, String)>,
+}
+
+impl<'a> LoweredTerm<'a> {
+    fn new(id: &'a String, term: &'a GlossaryTerm) -> Self {
+        let variants = term
+            .variants
+            .iter()
+            .filter(|variant| !variant.is_empty())
+            .map(|variant| (variant, variant.to_lowercase()))
+            .collect();
+        Self { id, entry: term, canonical: term.term.to_lowercase(), variants }
+    }
+}
+
+fn collect_violations(
+    lang: &str,
+    file: &str,
+    key: &str,
+    text: &str,
+    terms: &[LoweredTerm<'_>],
+    out: &mut Vec<GlossaryViolation>,
+) {
+    let lowered = text.to_lowercase();
+    for term in terms {
+        let canonical: Vec<Range<usize>> = if term.canonical.is_empty() {
+            Vec::new()
+        } else {
+            word_matches(&lowered, &term.canonical).collect()
+        };
+        for (variant, lowered_variant) in &term.variants {
+            let mut found = word_matches(&lowered, lowered_variant)
+                .filter(|m| !canonical.iter().any(|c| c.start <= m.start && m.end <= c.end));
+            if found.next().is_some() {
+                out.push(GlossaryViolation {
+                    lang: lang.to_string(),
+                    file: file.to_string(),
+                    key: key.to_string(),
+                    term_id: term.id.clone(),
+                    canonical: term.entry.term.clone(),
+                    found: (*variant).clone(),
+                });
+            }
+        }
+    }
+}
+
+/// Occurrences of `word` in `text` that are not part of a longer word.
+fn word_matches<'t>(text: &'t str, word: &'t str) -> impl Iterator<Item = Range<usize>> + 't {
+    text.match_indices(word).map(|(start, found)| start..start + found.len()).filter(|range| {
+        let before = text[..range.start].chars().next_back();
+        let after = text[range.end..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
@@ -0,0 +1,169 @@
+//! Per-language images: stylized titles, logos and other text drawn as
+//! pictures.
+//!
+//! Each language folder may hold an `_images.json` mapping logical image keys
+//! to a texture path, an index into a texture atlas, or both:
+//!
+//! ```json
+//! {
+//!     "title": "images/fr/title.png",
+//!     "stamp": { "path": "images/fr/stamps.png", "index": 3 },
+//!     "flag": { "index": 12 }
+//! }
+//! ```
+//!
+//! Keys are looked up like translations (current language, then the
+//! fallback chain), so a language only lists the images it replaces.
+//! [`I18n::image`] resolves a key; with the `bevy` feature, a
+//! [`LocalizedImage`] component sets the image of its `Sprite` or
+//! `ImageNode` and swaps it when the language changes. An atlas index only
+//! changes the index of the entity's `TextureAtlas`, whose layout is set
+//! when spawning.
+
+#[cfg(feature = "bevy")]
+use bevy::prelude::*;
+use serde_json::Value;
+#[cfg(feature = "bevy")]
+use tracing::warn;
+
+use crate::I18n;
+#[cfg(feature = "bevy")]
+use crate::RefreshI18nText;
+use crate::reserved::IMAGES_FILE;
+
+/// Image of a key in `_images.json`, see [`I18n::image`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LocalizedImageSource {
+    /// Asset path of the texture; `None` keeps the entity's image.
+    pub path: Option<String>,
+    /// Index into the entity's texture atlas; `None` keeps the index.
+    pub atlas_index: Option<usize>,
+}
+
+impl LocalizedImageSource {
+    fn from_json(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(path) => Some(Self { path: Some(path.clone()), atlas_index: None }),
+            Value::Object(entry) => {
+                let path = entry.get("path").and_then(Value::as_str).map(str::to_string);
+                let atlas_index = entry.get("index").and_then(Value::as_u64).and_then(|i| usize::try_from(i).ok());
+                (path.is_some() || atlas_index.is_some()).then_some(Self { path, atlas_index })
+            }
+            _ => None,
+        }
+    }
+}
+
+impl I18n {
+    /// Image declared for `key` in `_images.json`, in the current language or
+    /// the first language of the fallback chain that has it. See the
+    /// [module docs](self) for the file format.
+    ///
+    /// ```rust,no_run
+    /// # use bevy_intl::I18n;
+    /// # fn title(i18n: &I18n) {
+    /// if let Some(path) = i18n.image("title").and_then(|image| image.path) {
+    ///     println!("title screen uses {path}");
+    /// }
+    /// # }
+    /// ```
+    pub fn image(&self, key: &str) -> Option<LocalizedImageSource> {
+        self.translation(IMAGES_FILE).raw(key).and_then(LocalizedImageSource::from_json)
+    }
+}
+
+/// Shows the image declared for `key` in `_images.json` on the entity's
+/// `Sprite` or `ImageNode`, and swaps it when the language changes.
+///
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy_intl::LocalizedImage;
+///
+/// fn spawn_title(mut commands: Commands) {
+///     commands.spawn((ImageNode::default(), LocalizedImage::new("title")));
+/// }
+/// ```
+#[cfg(feature = "bevy")]
+#[derive(Component, Clone, Debug)]
+pub struct LocalizedImage {
+    /// Key in `_images.json`, e.g. `"title"`.
+    pub key: String,
+}
+
+#[cfg(feature = "bevy")]
+impl LocalizedImage {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self { key: key.into() }
+    }
+}
+
+#[cfg(feature = "bevy")]
+fn apply(
+    i18n: &I18n,
+    assets: Option<&AssetServer>,
+    image: &LocalizedImage,
+    sprite: Option<Mut<Sprite>>,
+    node: Option<Mut<ImageNode>>,
+) {
+    let Some(source) = i18n.image(&image.key) else {
+        warn!("image '{}' not found in _images.json", image.key);
+        return;
+    };
+    let handle = match (&source.path, assets) {
+        (Some(path), Some(assets)) => Some(assets.load(path.clone())),
+        (Some(_), None) => {
+            warn!("image '{}' cannot be loaded without an AssetServer", image.key);
+            None
+        }
+        (None, _) => None,
+    };
+    let (target, atlas) = match (sprite, node) {
+        (Some(sprite), _) => {
+            let sprite = sprite.into_inner();
+            (&mut sprite.image, &mut sprite.texture_atlas)
+        }
+        (None, Some(node)) => {
+            let node = node.into_inner();
+            (&mut node.image, &mut node.texture_atlas)
+        }
+        (None, None) => {
+            warn!("LocalizedImage '{}' needs a Sprite or an ImageNode", image.key);
+            return;
+        }
+    };
+    if let Some(handle) = handle {
+        *target = handle;
+    }
+    match (source.atlas_index, atlas) {
+        (Some(index), Some(atlas)) => atlas.index = index,
+        (Some(_), None) => warn!("image '{}' has an atlas index but the entity has no TextureAtlas", image.key),
+        (None, _) => {}
+    }
+}
+
+/// Observer showing the image of a [`LocalizedImage`] as soon as it is
+/// inserted.
+#[cfg(feature = "bevy")]
+pub(crate) fn render_inserted_localized_image(
+    insert: On<Insert, LocalizedImage>,
+    i18n: Res<I18n>,
+    assets: Option<Res<AssetServer>>,
+    mut query: Query<(&LocalizedImage, Option<&mut Sprite>, Option<&mut ImageNode>)>,
+) {
+    if let Ok((image, sprite, node)) = query.get_mut(insert.entity) {
+        apply(&i18n, assets.as_deref(), image, sprite, node);
+    }
+}
+
+/// Observer updating every [`LocalizedImage`] on [`RefreshI18nText`].
+#[cfg(feature = "bevy")]
+pub(crate) fn refresh_localized_images(
+    _refresh: On<RefreshI18nText>,
+    i18n: Res<I18n>,
+    assets: Option<Res<AssetServer>>,
+    mut query: Query<(&LocalizedImage, Option<&mut Sprite>, Option<&mut ImageNode>)>,
+) {
+    for (image, sprite, node) in &mut query {
+        apply(&i18n, assets.as_deref(), image, sprite, node);
+    }
+}
@@ -0,0 +1,82 @@
+//! Keyboard and text-input conventions of locales.
+//!
+//! Default keybindings written for QWERTY put movement on keys that are
+//! scattered on a French AZERTY keyboard. [`I18n::keyboard_layout`] tells
+//! which physical layout players of the current language most likely have,
+//! so binding presets and on-screen key prompts can match it.
+//! [`I18n::requires_ime`] tells whether typed text goes through an input
+//! method editor, so text fields can show the composition (preedit) text.
+
+use crate::{I18n, Locale};
+
+/// Arrangement of the Latin letter keys on a physical keyboard. Languages
+/// with their own script (Russian, Greek, Arabic…) are sold with QWERTY
+/// Latin letters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum KeyboardLayout {
+    #[default]
+    Qwerty,
+    /// France and Belgium.
+    Azerty,
+    /// Germany, Austria, Switzerland and most of Central Europe.
+    Qwertz,
+}
+
+impl KeyboardLayout {
+    /// Letters at the physical positions of `W`, `A`, `S`, `D` on QWERTY
+    /// (up, left, down, right), for movement key presets and prompts.
+    pub fn movement_keys(self) -> [char; 4] {
+        match self {
+            KeyboardLayout::Qwerty | KeyboardLayout::Qwertz => ['W', 'A', 'S', 'D'],
+            KeyboardLayout::Azerty => ['Z', 'Q', 'S', 'D'],
+        }
+    }
+}
+
+impl Locale {
+    /// Usual keyboard layout in a locale; unknown codes use QWERTY.
+    pub fn keyboard_layout(code: &str) -> KeyboardLayout {
+        let mut parts = code.split(['-', '_']);
+        let language = parts.next().unwrap_or(code);
+        let region = parts.find(|p| p.len() == 2);
+
+        match (language, region) {
+            ("fr", Some("CA")) => KeyboardLayout::Qwerty,
+            ("fr" | "it", Some("CH" | "LU")) => KeyboardLayout::Qwertz,
+            ("fr" | "br" | "oc" | "co", _) | ("nl", Some("BE")) => KeyboardLayout::Azerty,
+            ("de" | "gsw" | "lb" | "rm" | "cs" | "sk" | "hu" | "sl" | "hr" | "bs" | "sq", _) => {
+                KeyboardLayout::Qwertz
+            }
+            _ => KeyboardLayout::Qwerty,
+        }
+    }
+
+    /// Whether text in a locale is usually typed through IME composition
+    /// (Chinese, Japanese, Korean).
+    pub fn requires_ime(code: &str) -> bool {
+        matches!(code.split(['-', '_']).next().unwrap_or(code), "ja" | "zh" | "ko" | "yue")
+    }
+}
+
+impl I18n {
+    /// Usual keyboard layout for the current language. Custom locales use
+    /// the layout of their [`base_locale`](crate::CustomLocale::base_locale).
+    pub fn keyboard_layout(&self) -> KeyboardLayout {
+        match self.custom_locale(&self.current_lang) {
+            Some(custom) => custom.base_locale().map_or_else(KeyboardLayout::default, Locale::keyboard_layout),
+            None => Locale::keyboard_layout(&self.current_lang),
+        }
+    }
+
+    /// Whether the current language is usually typed through an IME. Text
+    /// fields should then enable IME input (`Window::ime_enabled` in Bevy),
+    /// display the preedit text and keep the caret inside it while composing.
+    /// Custom locales follow their
+    /// [`base_locale`](crate::CustomLocale::base_locale).
+    pub fn requires_ime(&self) -> bool {
+        match self.custom_locale(&self.current_lang) {
+            Some(custom) => custom.base_locale().is_some_and(Locale::requires_ime),
+            None => Locale::requires_ime(&self.current_lang),
+        }
+    }
+}
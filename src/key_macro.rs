@@ -0,0 +1,169 @@
+//! Source of the compile-time checked `t!` macro and of the typed key enums.
+//!
+//! `build.rs` writes the macro next to the bundled translations, with one
+//! arm per text key of the default language, so a key missing from it is a
+//! compile error instead of a "Missing translation" on screen. With the
+//! `typed-keys` feature it also writes one enum per file, with one variant
+//! per text key. The module only depends on `std` and `serde_json`, so `build.rs`
+//! shares it.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use serde_json::Value;
+
+use crate::reserved::{GROUP_MARKER, is_translation_file, split_annotation};
+
+/// Keys of a register map (`SpeechRegister::key`), the only maps `t` reads
+/// as text.
+pub(crate) const REGISTER_KEYS: [&str; 3] = ["casual", "polite", "formal"];
+
+/// Whether `key` of a file or group is an entry: not a `"key@attr"`
+/// annotation nor a `"@schema"`-like setting.
+fn is_entry(key: &str) -> bool {
+    !key.starts_with('@') && split_annotation(key).is_none()
+}
+
+/// The keys at or below `prefix` that `t` reads as text: strings and
+/// register maps. Plural and gender maps and nested groups only hold their
+/// `key.case` keys. Annotations, documents and non-text values are left out.
+fn collect_keys(prefix: &str, value: &Value, keys: &mut BTreeSet<String>) {
+    match value {
+        Value::String(_) => {
+            keys.insert(prefix.to_string());
+        }
+        Value::Object(entries) if !entries.keys().any(|key| key.starts_with('@') && key != GROUP_MARKER) => {
+            let is_register_map = entries
+                .iter()
+                .any(|(key, value)| REGISTER_KEYS.contains(&key.as_str()) && value.is_string());
+            if is_register_map {
+                keys.insert(prefix.to_string());
+            }
+            for (key, value) in entries.iter().filter(|(key, _)| is_entry(key)) {
+                collect_keys(&format!("{}.{}", prefix, key), value, keys);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Text keys of each translation file of `lang` in `translations`, by file
+/// name (see [`collect_keys`]).
+fn file_keys<'a>(translations: &'a Value, lang: &str) -> BTreeMap<&'a str, BTreeSet<String>> {
+    let files = translations.get(lang).and_then(Value::as_object).into_iter().flatten();
+    files
+        .filter(|(file, _)| is_translation_file(file))
+        .map(|(file, entries)| {
+            let mut keys = BTreeSet::new();
+            for (key, value) in entries.as_object().into_iter().flatten().filter(|(key, _)| is_entry(key)) {
+                collect_keys(key, value, &mut keys);
+            }
+            (file.as_str(), keys)
+        })
+        .collect()
+}
+
+/// `name` as a Rust type or variant name: ASCII letters and digits, each run
+/// capitalized (`menu.quit_button` → `MenuQuitButton`). `None` when `name`
+/// has none.
+fn pascal_case(name: &str) -> Option<String> {
+    let mut ident = String::new();
+    for word in name.split(|c: char| !c.is_ascii_alphanumeric()).filter(|word| !word.is_empty()) {
+        let mut chars = word.chars();
+        ident.extend(chars.next().map(|c| c.to_ascii_uppercase()));
+        ident.push_str(chars.as_str());
+    }
+    match ident.chars().next() {
+        None => None,
+        Some(c) if c.is_ascii_digit() => Some(format!("K{ident}")),
+        Some(_) => Some(ident),
+    }
+}
+
+/// The name derived from `name` (see [`pascal_case`], `fallback` when it
+/// has no letters or digits), recorded in `taken` with `what` it names. Fails
+/// when another name of `taken` derives the same one: numbering them would
+/// rename existing items whenever a key is added.
+fn unique_ident(name: &str, fallback: &str, what: String, taken: &mut BTreeMap<String, String>) -> Result<String, String> {
+    let ident = pascal_case(name).unwrap_or_else(|| fallback.to_string());
+    match taken.get(&ident) {
+        Some(other) => Err(format!("{what} and {other} would both be named `{ident}`; rename one of them")),
+        None => {
+            taken.insert(ident.clone(), what);
+            Ok(ident)
+        }
+    }
+}
+
+/// Source of one enum per translation file of `lang` in `translations`
+/// (`ui` → `UiKey`), with one variant per text key (`menu.quit` →
+/// `MenuQuit`), implementing `TypedKey`. Fails when two files or two keys of
+/// a file would get the same name.
+pub(crate) fn key_enums(translations: &Value, lang: &str) -> Result<String, String> {
+    let mut source = String::new();
+    // `TypedKey` is the only other name of the module.
+    let mut types = BTreeMap::from([("TypedKey".to_string(), "the `TypedKey` trait".to_string())]);
+    for (file, keys) in file_keys(translations, lang) {
+        let name = unique_ident(&format!("{file}_key"), "FileKey", format!("file '{file}'"), &mut types)?;
+        let mut variants = BTreeMap::new();
+        let variants = keys
+            .iter()
+            .map(|key| Ok((unique_ident(key, "Key", format!("key '{key}' of '{file}'"), &mut variants)?, key.as_str())))
+            .collect::<Result<Vec<(String, &str)>, String>>()?;
+
+        let _ = writeln!(source, "/// Keys of `{file}`, in the {lang:?} messages.");
+        let _ = writeln!(source, "#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]");
+        let _ = writeln!(source, "pub enum {name} {{");
+        for (variant, key) in &variants {
+            let _ = writeln!(source, "    /// `{key}`\n    {variant},");
+        }
+        let _ = writeln!(source, "}}\n");
+        let _ = writeln!(source, "impl {name} {{");
+        let _ = writeln!(source, "    /// Every key of the file.");
+        let _ = write!(source, "    pub const ALL: &'static [Self] = &[");
+        for (i, (variant, _)) in variants.iter().enumerate() {
+            let _ = write!(source, "{}Self::{variant}", if i == 0 { "" } else { ", " });
+        }
+        let _ = writeln!(source, "];\n}}\n");
+        let _ = writeln!(source, "impl TypedKey for {name} {{");
+        let _ = writeln!(source, "    const FILE: &'static str = {file:?};\n");
+        let _ = writeln!(source, "    fn key(self) -> &'static str {{");
+        if variants.is_empty() {
+            let _ = writeln!(source, "        match self {{}}");
+        } else {
+            let _ = writeln!(source, "        match self {{");
+            for (variant, key) in &variants {
+                let _ = writeln!(source, "            Self::{variant} => {key:?},");
+            }
+            let _ = writeln!(source, "        }}");
+        }
+        let _ = writeln!(source, "    }}\n}}\n");
+    }
+    Ok(source)
+}
+
+/// Source of the `t!` macro accepting the keys of `lang` in `translations`,
+/// the bundled `{ lang: { file: { key: value } } }` JSON.
+pub(crate) fn key_macro(translations: &Value, lang: &str) -> String {
+    let mut arms = String::new();
+    for (file, keys) in file_keys(translations, lang) {
+        for key in keys {
+            let _ = writeln!(arms, "    ({file:?}, {key:?}) => {{ $crate::CheckedKey::new({file:?}, {key:?}) }};");
+        }
+    }
+    format!(
+        r#"/// A translation key checked at compile time against the default language
+/// ({lang:?}) of the bundled messages folder.
+///
+/// `t!("ui", "welcome")` is a [`CheckedKey`]; `t!(i18n, "ui", "welcome")`
+/// translates it. A key missing from the default language does not compile.
+#[macro_export]
+macro_rules! t {{
+{arms}    ($file:tt, $key:tt) => {{
+        ::core::compile_error!(::core::concat!("translation key '", $key, "' not found in '", $file, "' of the '{lang}' messages"))
+    }};
+    ($i18n:expr, $file:tt, $key:tt) => {{ $crate::t!($file, $key).text(&$i18n) }};
+}}
+"#
+    )
+}
@@ -0,0 +1,161 @@
+//! Stable, hash-based key IDs and key renames.
+//!
+//! Renaming a human-readable key (`btn_play` → `play_button`) in the source
+//! language orphans its translations in every other language. A key's
+//! [`stable_id`] hashes its source string with its context (the file name),
+//! so it survives renames. `bevy-intl-cli migrate-keys` keeps a reserved
+//! `_keys.json` file in the source language folder up to date:
+//!
+//! ```json
+//! { "ui": {
+//!     "ids": { "4c5d0bd5b3a3e0e7": "play_button" },
+//!     "renamed": { "btn_play": "play_button" }
+//! } }
+//! ```
+//!
+//! Lookups then accept stable IDs as keys (`t("4c5d0bd5b3a3e0e7")`), and a
+//! key missing from a language is looked up under its former names.
+//!
+//! Typos in keys are caught at compile time by the [`t!`](crate::t) macro,
+//! which only accepts keys of the default language of the bundled messages
+//! folder (`BEVY_INTL_DEFAULT_LANG` at build time, `en` by default):
+//!
+//! ```rust,ignore
+//! let title = t!(i18n, "ui", "title");
+//! let quit = t!("ui", "quti"); // error: translation key 'quti' not found in 'ui' of the 'en' messages
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::freeze::canonical_source;
+use crate::{I18n, pack_hash};
+
+include!(concat!(env!("OUT_DIR"), "/translation_keys.rs"));
+
+/// A key of the bundled default language, made by [`t!`](crate::t).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CheckedKey {
+    pub file: &'static str,
+    pub key: &'static str,
+}
+
+impl CheckedKey {
+    #[doc(hidden)]
+    pub const fn new(file: &'static str, key: &'static str) -> Self {
+        Self { file, key }
+    }
+
+    /// The key translated in the current language, like
+    /// `i18n.translation(file).t(key)`.
+    pub fn text(self, i18n: &I18n) -> String {
+        i18n.translation(self.file).t(self.key)
+    }
+}
+
+/// Reserved file holding the [`KeyMigrations`] of every file.
+pub(crate) const KEYS_FILE: &str = "_keys";
+
+/// Stable ID of a source string in `context` (the file name for keys
+/// managed by the CLI).
+pub fn stable_id(context: &str, source: &str) -> String {
+    pack_hash(&format!("{}\u{4}{}", context, source))
+}
+
+/// Stable IDs and renamed keys of one translation file, see the
+/// [module docs](self).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyMigrations {
+    /// Stable ID → current key.
+    #[serde(default)]
+    pub ids: BTreeMap<String, String>,
+    /// Former key → current key.
+    #[serde(default)]
+    pub renamed: BTreeMap<String, String>,
+}
+
+impl KeyMigrations {
+    /// Replaces the IDs with `ids` (stable ID → key, from
+    /// [`I18n::stable_ids`]), recording a rename for every ID whose key
+    /// changed. Earlier renames are redirected to the new key. Returns the
+    /// new renames as `(former, current)` pairs.
+    pub fn update(&mut self, ids: BTreeMap<String, String>) -> Vec<(String, String)> {
+        let mut renames = Vec::new();
+        for (id, key) in &ids {
+            match self.ids.get(id) {
+                Some(former) if former != key => renames.push((former.clone(), key.clone())),
+                _ => {}
+            }
+        }
+        for (former, key) in &renames {
+            for current in self.renamed.values_mut().filter(|current| *current == former) {
+                current.clone_from(key);
+            }
+            self.renamed.insert(former.clone(), key.clone());
+        }
+        // A key given back its old name is no longer renamed.
+        self.renamed.retain(|former, key| former != key);
+        self.ids = ids;
+        renames
+    }
+
+    /// Key a lookup of `key` resolves to: the current key of a stable ID,
+    /// otherwise `key` itself.
+    pub(crate) fn resolve<'a>(&'a self, key: &'a str) -> &'a str {
+        self.ids.get(key).map_or(key, String::as_str)
+    }
+
+    /// Former names of each renamed key, in alphabetical order, built once
+    /// at load so lookups of a missing key do not scan every rename.
+    pub(crate) fn former_keys(&self) -> FormerKeys {
+        let mut former_keys = FormerKeys::new();
+        for (former, current) in &self.renamed {
+            former_keys.entry(current.clone()).or_default().push(former.clone());
+        }
+        former_keys
+    }
+}
+
+/// Current key → its former names, see [`KeyMigrations::former_keys`].
+pub(crate) type FormerKeys = HashMap<String, Vec<String>>;
+
+impl I18n {
+    /// Stable ID → key of every key of `lang`, per file. Keys of a file with
+    /// the same source string share an ID, which maps to the first of them
+    /// in alphabetical order.
+    pub fn stable_ids(&self, lang: &str) -> BTreeMap<String, BTreeMap<String, String>> {
+        self.translations
+            .langs
+            .get(lang)
+            .into_iter()
+            .flatten()
+            .map(|(file, sections)| {
+                let sorted: BTreeMap<_, _> = sections.iter().collect();
+                let mut ids = BTreeMap::new();
+                for (key, value) in sorted {
+                    ids.entry(stable_id(file, &canonical_source(value))).or_insert_with(|| key.clone());
+                }
+                (file.clone(), ids)
+            })
+            .collect()
+    }
+
+    /// Key migrations of `file`, read from `_keys.json`.
+    pub fn key_migrations(&self, file: &str) -> Option<&KeyMigrations> {
+        self.translations.keys.get(file)
+    }
+}
+
+// Parses a `_keys.json` file into `translations.keys` (and
+// `translations.former_keys`).
+pub(crate) fn parse_key_migrations(json: &serde_json::Value) -> HashMap<String, KeyMigrations> {
+    match serde_json::from_value(json.clone()) {
+        Ok(keys) => keys,
+        Err(e) => {
+            warn!("Invalid {}.json: {}", KEYS_FILE, e);
+            HashMap::new()
+        }
+    }
+}
@@ -42,6 +42,25 @@
 //! - **Desktop**: Loads from `messages/` folder at runtime
 //! - **WASM**: Uses bundled translations (compiled at build time)
 //! - **Bundle-only**: Force bundled mode with `features = ["bundle-only"]`
+//! - **YAML**: With the default `yaml` feature, `.yaml` and `.yml` files are
+//!   read next to `.json` ones
+//! - **Fluent**: With `features = ["fluent"]`, `.ftl` files are read next to
+//!   `.json` and `.yaml` ones
+//! - **gettext**: With `features = ["gettext"]`, `.po` and `.mo` catalogs
+//!   are read too
+//!
+//! ### Word Segmentation
+//! With `features = ["segmentation"]`, Thai, Lao, Khmer and Burmese text is
+//! segmented with ICU and zero-width spaces are inserted at word boundaries so
+//! Bevy can wrap it.
+//!
+//! ### Language Detection
+//! With `features = ["detect-language"]`, `I18n::detect_language` guesses the
+//! language of chat messages and other user-generated text.
+//!
+//! ### Transliteration
+//! With `features = ["transliteration"]`, `I18n::transliterate` rewrites
+//! player names in the Latin, Cyrillic or Greek script for display.
 //!
 //! ### Advanced Plural Support
 //! Supports multiple plural forms with fallback priority:
@@ -50,22 +69,231 @@
 //! 3. Basic fallback: `"one"` vs `"other"`
 //!
 //! Perfect for complex languages like Polish, Russian, and Arabic.
+//!
+//! ### Without Bevy
+//! The Bevy integration lives behind the default `bevy` feature. With
+//! `default-features = false`, the crate is a plain localization library:
+//! build an [`I18n`] with [`I18n::from_config`] and use the same lookup and
+//! formatting API, e.g. from an Axum or Tokio game server.
 
+#[cfg(feature = "bevy")]
 use bevy::prelude::*;
 
+mod analytics;
+#[cfg(feature = "bevy")]
+mod asset;
+mod audio;
+mod case;
+#[cfg(feature = "bevy")]
+mod command;
+#[cfg(feature = "bevy")]
 mod components;
+mod compose;
+mod concat;
+#[cfg(feature = "console")]
+mod console;
+#[cfg(feature = "detect-language")]
+mod detect;
+mod diagnostics;
+mod direction;
+mod dictionary;
+#[cfg(feature = "tools")]
+mod drift;
+mod dialogue;
+#[cfg(feature = "bevy")]
+mod ecs;
+mod explain;
+mod filter;
+#[cfg(feature = "ecs-components")]
+mod fit;
+#[cfg(feature = "fluent")]
+mod fluent;
+mod format;
+mod formats;
+mod freeze;
+#[cfg(feature = "gettext")]
+mod gettext;
+#[cfg(feature = "global")]
+mod global;
+mod glossary;
+mod image;
+mod input;
+#[cfg(test)]
+mod key_macro;
+mod keys;
+#[cfg(feature = "live-edit")]
+mod live;
+mod loader;
 mod locales;
-
-pub use components::{I18nMode, I18nText, LanguageChanged, update_i18n_text};
-
-use serde::Deserialize;
+mod logging;
+#[cfg(all(feature = "fs-loader", not(target_arch = "wasm32")))]
+mod merge;
+mod missing;
+#[cfg(feature = "markdown")]
+mod markdown;
+mod names;
+mod number;
+#[cfg(feature = "ecs-components")]
+mod overflow;
+#[cfg(all(any(feature = "fs-loader", feature = "tools"), not(target_arch = "wasm32")))]
+mod overlay;
+mod pack_cache;
+mod plan;
+mod plural;
+pub mod prelude;
+mod qa;
+mod register;
+mod reserved;
+#[cfg(feature = "ecs-components")]
+mod screenshot;
+mod search;
+mod segment;
+mod schema;
+mod sentinel;
+mod snapshot;
+#[cfg(all(feature = "fs-loader", not(target_arch = "wasm32")))]
+mod stream;
+#[cfg(feature = "ecs-components")]
+mod speech;
+#[cfg(feature = "bevy")]
+mod split_bundle;
+mod store;
+mod subkey;
+#[cfg(feature = "testing")]
+mod testing;
+#[cfg(feature = "ecs-components")]
+mod tooltip;
+#[cfg(feature = "transliteration")]
+mod translit;
+#[cfg(feature = "typed-keys")]
+pub mod typed_keys;
+#[cfg(all(feature = "bevy", feature = "fs-loader", not(target_arch = "wasm32")))]
+mod watch;
+mod writing;
+
+pub use analytics::{AnalyticsEvent, AnalyticsEventKind, AnalyticsSink};
+use analytics::{AnalyticsScope, I18nAnalytics};
+#[cfg(feature = "bevy")]
+pub use asset::{I18nAssetFolder, I18nAssetPlugin, TranslationFile, TranslationFileError, TranslationFileLoader};
+#[cfg(feature = "bevy")]
+pub use audio::LocalizedAudioSource;
+pub use case::HeadingCase;
+#[cfg(feature = "bevy")]
+pub use command::{I18nCommand, apply_i18n_commands};
+#[cfg(feature = "bevy")]
+pub use components::{
+    HiddenLabels, I18nMode, I18nRefreshQueue, I18nStale, I18nText, I18nTextSpan, LanguageChanged, RefreshBudget,
+    RefreshI18nText, process_i18n_refresh_queue, render_revealed_i18n_text, update_i18n_text,
+};
+#[cfg(feature = "console")]
+pub use console::{I18nConsoleInput, I18nConsoleOutput, run_console_line, run_i18n_console};
+#[cfg(feature = "detect-language")]
+pub use detect::DetectedLanguage;
+pub use diagnostics::I18nDiagnostic;
+pub use dictionary::{UserDictionary, UserTerm};
+#[cfg(feature = "tools")]
+pub use drift::{BundleDrift, DriftKind, compare_bundle};
+#[cfg(all(feature = "tools", feature = "bevy", not(target_arch = "wasm32")))]
+pub use drift::check_bundle_drift;
+#[cfg(all(feature = "tools", not(target_arch = "wasm32")))]
+pub use drift::bundle_source;
+#[cfg(feature = "bevy")]
+pub use diagnostics::emit_load_diagnostics;
+pub use dialogue::{DialogueLine, ResolvedDialogueLine};
+#[cfg(feature = "bevy")]
+pub use ecs::{I18nPlugin, LanguageAppExt};
+pub use explain::{Explain, ExplainStep, Explanation, StepOutcome};
+pub use filter::{FilterMatch, WordFilter};
+#[cfg(feature = "ecs-components")]
+pub use fit::{FitBaseFontSize, FitI18nText, fit_i18n_text};
+pub use format::{
+    Select, Segment, Segments, format_named, format_positional, format_template, format_template_with,
+    parse_template,
+};
+pub use formats::{BinaryFormatParser, FormatParser, TranslationFormats, parse_json};
+#[cfg(feature = "yaml")]
+pub use formats::parse_yaml;
+#[cfg(feature = "fluent")]
+pub use fluent::parse_fluent;
+#[cfg(feature = "gettext")]
+pub use gettext::{gettext_key, parse_mo, parse_po};
+pub use freeze::{FreezeManifest, FrozenChangeKind, FrozenStringChange};
+#[cfg(feature = "global")]
+pub use global::{clear_global, global, set_global};
+#[cfg(all(feature = "global", feature = "bevy"))]
+pub use global::sync_global_i18n;
+pub use glossary::{GlossaryTerm, GlossaryViolation};
+#[cfg(feature = "bevy")]
+pub use image::LocalizedImage;
+pub use image::LocalizedImageSource;
+pub use input::KeyboardLayout;
+pub use keys::{CheckedKey, KeyMigrations, stable_id};
+use keys::FormerKeys;
+#[cfg(feature = "live-edit")]
+pub use live::{LiveEdit, LiveEditPlugin};
+use loader::{
+    OVERLAY_ENV, apply_overlay, insert_translation_file, load_bundled_translations, load_filesystem_translations,
+    validate_pack_file,
+};
+pub use locales::{CustomLocale, Locale, TextDirection};
+pub use logging::{LogCategory, LogLevel, LogPolicy};
+use logging::{I18nLog, LogScope};
+#[cfg(feature = "markdown")]
+pub use markdown::{I18nMarkdown, I18nMarkdownSpan, MarkdownSpan, MarkdownTheme, parse_markdown};
+pub use missing::{MissingTranslation, MissingTranslations};
+#[cfg(feature = "bevy")]
+pub use missing::{register_missing_translations, write_missing_translations_on_exit};
+pub use names::{DisplayName, I18nName, Localize, Localized, LocalizedDisplay, TranslationKey};
+pub use number::{FormatOverrides, NumberFormat, PluralCount, SignDisplay};
+#[cfg(feature = "derive")]
+pub use bevy_intl_derive::Localize;
+#[cfg(feature = "ecs-components")]
+pub use overflow::{I18nFullText, I18nOverflow, OverflowMode, ellipsize_i18n_text};
+#[cfg(target_arch = "wasm32")]
+pub use pack_cache::LocalStoragePackStore;
+pub use pack_cache::{MemoryPackStore, PackCache, PackManifest, PackStore, pack_hash};
+use plan::{FilePlan, PlanCache};
+use plural::{PluralRules, language_rules, select_plural_form};
+pub use qa::{ConcatenationSmell, LengthViolation, MessageSyntaxError};
+#[cfg(feature = "bevy")]
+pub use qa::preflight_i18n_messages;
+pub use register::SpeechRegister;
+#[cfg(feature = "ecs-components")]
+pub use screenshot::{ScreenshotMatrix, ScreenshotMatrixStep, run_screenshot_matrix};
+pub use schema::{TRANSLATION_SCHEMA, file_schema, migrate_file};
+pub use search::fold_for_search;
+#[cfg(feature = "segmentation")]
+pub use segment::insert_word_breaks;
+pub use segment::{ZERO_WIDTH_SPACE, needs_word_segmentation};
+use sentinel::Sentinel;
+pub use snapshot::I18nSnapshot;
+#[cfg(feature = "bevy")]
+pub use split_bundle::{I18nLanguagePacks, I18nSplitBundlePlugin, PackState, i18n_packs_ready};
+pub use store::{KeyAnnotations, SectionValue, Translations};
+use reserved::{META_FILE, is_translation_file};
+use store::{DocumentMap, GenderMap, RawSectionMap, SectionMap};
+pub use subkey::{Gender, PluralForm, SubKey};
+#[cfg(feature = "testing")]
+pub use testing::I18nTestApp;
+#[cfg(feature = "ecs-components")]
+pub use speech::{I18nSpeech, SpeakLocalized, TextToSpeech, speak_localized};
+#[cfg(feature = "ecs-components")]
+pub use tooltip::{I18nTooltip, I18nTooltipActive, I18nTooltipPopup, update_i18n_tooltips};
+#[cfg(feature = "transliteration")]
+pub use translit::{Script, transliterate};
+#[cfg(feature = "typed-keys")]
+pub use typed_keys::TypedKey;
+pub use writing::{
+    VerticalOrientation, WritingMode, is_small_kana, preferred_writing_mode, vertical_form,
+    vertical_orientation, vertical_writing_mode,
+};
+
+use std::borrow::Cow;
+use std::cell::Cell;
 use std::collections::HashMap;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 use serde_json::Value;
-use locales::LOCALES;
-use regex::Regex;
-use intl_pluralrules::{PluralRules, PluralRuleType, PluralCategory};
-use unic_langid::LanguageIdentifier;
+use tracing::warn;
 
 /// Build an argument slice for the named-placeholder translation methods.
 ///
@@ -101,14 +329,17 @@ macro_rules! i18n_args {
 /// let config = I18nConfig {
 ///     use_bundled_translations: false,
 ///     messages_folder: "locales".to_string(),
-///     default_lang: "fr".to_string(),
-///     fallback_lang: "en".to_string(),
+///     default_lang: "pt-BR".to_string(),
+///     fallback_chain: vec!["pt-PT".to_string(), "es".to_string(), "en".to_string()],
+///     ..Default::default()
 /// };
 /// ```
-#[derive(Debug, Clone, Resource)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "bevy", derive(Resource))]
 pub struct I18nConfig {
     /// Whether to use bundled translations (true) or filesystem loading (false).
-    /// Automatically set to `true` for WASM targets or when `bundle-only` feature is enabled.
+    /// Automatically set to `true` for WASM targets, when `bundle-only` feature is enabled
+    /// or when the `fs-loader` feature is not.
     pub use_bundled_translations: bool,
     /// Path to the messages folder containing translation files.
     /// Default: "messages"
@@ -116,123 +347,207 @@ pub struct I18nConfig {
     /// Default language code to use.
     /// Default: "en"
     pub default_lang: String,
-    /// Fallback language code when a translation is missing.
-    /// Default: "en"
-    pub fallback_lang: String,
+    /// Languages tried, in order, when a key is missing from the current
+    /// language, e.g. `pt-BR → pt-PT → es → en`.
+    /// Default: `["en"]`
+    pub fallback_chain: Vec<String>,
     /// Whether to warn when a folder name in the messages directory is not a
     /// recognized ISO/CLDR locale code. Default: `true`.
     ///
     /// Useful to disable when intentionally using non-standard locale codes
     /// (e.g. "test", "debug", custom dialects).
     pub warn_unknown_locales: bool,
+    /// Fictional / constructed languages used by the game. Their folders are
+    /// not reported as unknown locales, and their direction, plural rules and
+    /// base fallback are honored. Default: empty.
+    pub custom_locales: Vec<CustomLocale>,
+    /// Per-file lookup policies, keyed by file name (without `.json`).
+    /// Files not listed use [`FilePolicy::default`]. Default: empty.
+    pub file_policies: HashMap<String, FilePolicy>,
+    /// Whether `{{count}}` in plural translations is formatted with the
+    /// current language's digit grouping (`12,435` / `12 435`) rather than
+    /// as raw digits. Default: `true`.
+    pub group_counts: bool,
+    /// Whether a `"zero"` plural form, when present, is used for a count of
+    /// 0 even in languages whose CLDR category for 0 is `"other"` ("No
+    /// items" instead of "0 items"). An exact `"0"` key still wins.
+    /// Default: `false`.
+    pub zero_form: bool,
+    /// Sign shown in front of `{{count}}`: by default only negative counts
+    /// get one; [`SignDisplay::Always`] writes score deltas like `+5`.
+    pub count_sign: SignDisplay,
+    /// Number conventions fixed regardless of the language, e.g. always
+    /// `,` between digit groups. Default: none, every part follows the
+    /// locale.
+    pub format_overrides: FormatOverrides,
+    /// Politeness level picked from register maps (`{"casual": …,
+    /// "polite": …, "formal": …}`). Default: [`SpeechRegister::Polite`].
+    pub speech_register: SpeechRegister,
+    /// Levels of lookup warnings (missing keys, placeholders, lengths) and
+    /// whether each key is reported only once. Default: every category at
+    /// `warn`, once per key.
+    pub log_policy: LogPolicy,
+    /// Selectors accepted by gender maps and `t_with_gender`, for games
+    /// whose "gender" axis is a species, a faction… Gender maps using other
+    /// keys are reported at load.
+    /// Default: `["male", "female", "neutral"]`.
+    pub gender_keys: Vec<String>,
+    /// Whether `I18nPlugin` checks the placeholder syntax of every loaded
+    /// string at startup and logs all errors, see
+    /// [`I18n::check_message_syntax`]. Default: on in debug builds.
+    pub preflight: bool,
+    /// Whether `I18nPlugin` compares the bundled translations with the
+    /// messages folder at startup and warns about files that changed since
+    /// the bundle was built, see [`I18n::bundle_drift`]. Needs the `tools`
+    /// feature. Default: on in debug builds.
+    pub check_bundle: bool,
+    /// Parser of each translation file extension; the default reads
+    /// `.json` files, `.yaml` and `.yml` with the `yaml` feature, `.ftl` with the `fluent` feature,
+    /// and `.po` and `.mo` with the `gettext` feature.
+    /// Default: [`TranslationFormats::default`].
+    pub formats: TranslationFormats,
+    /// Folder laid out like `messages_folder` whose keys replace the loaded
+    /// ones, e.g. `"messages-demo"` for a demo build. A relative path is
+    /// looked up next to `messages_folder`, as `build.rs` does. Keys and
+    /// languages the messages folder lacks are reported and ignored. When
+    /// `None`, the `BEVY_INTL_OVERLAY` environment variable is used if set.
+    /// Default: `None`.
+    pub overlay: Option<String>,
+    /// How translations identical to the source (first fallback) language's
+    /// — most likely copied, not translated — are treated.
+    /// Default: [`SourceCopyPolicy::Translated`].
+    pub source_copies: SourceCopyPolicy,
+    /// Language of voice-overs and other clips of `_audio.json`, when it
+    /// differs from the text language (e.g. Japanese voices with English
+    /// subtitles). Default: `None`, following the current language.
+    pub audio_lang: Option<String>,
+    /// Whether `I18nPlugin` watches the messages folder and reloads the
+    /// translations when a file changes (desktop only, with the `fs-loader`
+    /// feature). Meant for development; reloading drops merged language
+    /// packs. Default: `false`.
+    pub hot_reload: bool,
 }
 
-impl Default for I18nConfig {
-    fn default() -> Self {
-        Self {
-            use_bundled_translations: cfg!(target_arch = "wasm32") || cfg!(feature = "bundle-only"),
-            messages_folder: "messages".to_string(),
-            default_lang: "en".to_string(),
-            fallback_lang: "en".to_string(),
-            warn_unknown_locales: true,
-        }
-    }
-}
-
-// ---------- Bevy Plugin ----------
-
-/// Main plugin for Bevy internationalization.
-///
-/// Handles language switching, loading translation files, and providing
-/// `I18n` resource for accessing localized strings.
-///
-/// # Example
+/// How lookups behave for one translation file, see
+/// [`I18nConfig::file_policies`].
 ///
 /// ```rust
-/// use bevy::prelude::*;
-/// use bevy_intl::{I18nPlugin, I18nConfig};
-///
-/// // Default configuration
-/// App::new().add_plugins(I18nPlugin::default());
+/// use bevy_intl::{FilePolicy, I18nConfig, MissingKeyStrategy};
 ///
-/// // Custom configuration
-/// App::new().add_plugins(I18nPlugin::with_config(I18nConfig {
-///     default_lang: "fr".to_string(),
-///     fallback_lang: "en".to_string(),
+/// let config = I18nConfig {
+///     // Legal text must never show another language.
+///     file_policies: [("legal".to_string(), FilePolicy {
+///         fallback: false,
+///         missing_key: MissingKeyStrategy::Empty,
+///     })]
+///     .into(),
 ///     ..Default::default()
-/// }));
+/// };
 /// ```
-#[derive(Default)]
-pub struct I18nPlugin {
-    /// Configuration for the plugin
-    pub config: I18nConfig,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FilePolicy {
+    /// Whether missing keys are looked up along the fallback chain.
+    /// Default: `true`.
+    pub fallback: bool,
+    /// What is returned when a key is missing everywhere it is looked up.
+    pub missing_key: MissingKeyStrategy,
 }
 
-impl I18nPlugin {
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    pub fn with_config(config: I18nConfig) -> Self {
-        Self { config }
+impl Default for FilePolicy {
+    fn default() -> Self {
+        Self { fallback: true, missing_key: MissingKeyStrategy::default() }
     }
 }
 
-impl Plugin for I18nPlugin {
-    fn build(&self, app: &mut App) {
-        app.insert_resource(self.config.clone())
-            .init_resource::<I18n>()
-            .add_message::<LanguageChanged>()
-            .add_systems(Update, update_i18n_text);
-    }
+/// Text returned for a key that could not be resolved.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MissingKeyStrategy {
+    /// A "Missing translation" style marker, in the current language when
+    /// the crate ships one (the historical behavior).
+    #[default]
+    Placeholder,
+    /// The key itself, e.g. `"tos_heading"`.
+    Key,
+    /// An empty string.
+    Empty,
 }
 
-/// Represents a value in a translation file.
-/// 
-/// Can be either a simple text string or a nested map for plurals/genders.
-/// 
-/// # Examples
-/// 
-/// Simple text:
-/// ```json
-/// "greeting": "Hello"
-/// ```
-/// 
-/// Nested map for plurals:
-/// ```json
-/// "items": {
-///   "one": "One item",
-///   "many": "{{count}} items"
-/// }
+/// Options of a single lookup, see [`I18nPartial::t_opts`].
+///
+/// ```rust,no_run
+/// # use bevy_intl::{I18n, LookupOptions, MissingKeyStrategy};
+/// # fn badge(i18n: &I18n) {
+/// // Only the current language, to show a "partially translated" badge.
+/// let exact = LookupOptions { use_fallback: false, missing_key: Some(MissingKeyStrategy::Empty), ..Default::default() };
+/// let translated = !i18n.translation("quests").t_opts("intro", exact).is_empty();
+/// # }
 /// ```
-#[derive(Debug, Deserialize, Clone)]
-#[serde(untagged)]
-pub enum SectionValue {
-    /// A simple text value
-    Text(String),
-    /// A two-level nested map for combining gender and plural (or any other
-    /// two-axis discriminator), e.g. `{ "male": { "one": "...", "few": "..." } }`.
-    /// `untagged` deserialization tries this variant before [`Self::Map`], so
-    /// pure-string objects fall through to `Map` automatically.
-    Nested(HashMap<String, HashMap<String, String>>),
-    /// A single-level map of key-value pairs (for plurals OR genders alone)
-    Map(HashMap<String, String>),
+#[derive(Clone, Copy)]
+pub struct LookupOptions<'a> {
+    /// Whether the key is looked up along the fallback chain. Cannot enable
+    /// a fallback disabled by the file's [`FilePolicy`]. Default: `true`.
+    pub use_fallback: bool,
+    /// What is returned if the key is not found, overriding the file's
+    /// [`FilePolicy::missing_key`]. Default: `None`.
+    pub missing_key: Option<MissingKeyStrategy>,
+    /// Named placeholder values, as for
+    /// [`t_with_args`](I18nPartial::t_with_args). Default: none.
+    pub args: &'a [(&'a str, &'a dyn ToString)],
+    /// Whether placeholders are substituted; `false` returns the
+    /// translation as written, e.g. for a translation editor.
+    /// Default: `true`.
+    pub format: bool,
 }
 
-/// A mapping of translation keys to their values within a file.
-type SectionMap = HashMap<String, SectionValue>;
-/// A mapping of file names to their section maps.
-type FileMap = HashMap<String, SectionMap>;
-/// A mapping of language codes to file maps.
-type LangMap = HashMap<String, FileMap>;
+impl Default for LookupOptions<'_> {
+    fn default() -> Self {
+        Self { use_fallback: true, missing_key: None, args: &[], format: true }
+    }
+}
 
-/// Contains all translations loaded from filesystem or bundled data.
-/// 
-/// Organized as: `languages -> files -> keys -> values`
-#[derive(Debug, Deserialize)]
-pub struct Translations {
-    /// Map of language codes to their translation data
-    pub langs: LangMap,
+/// Treatment of translations identical to the source language's, see
+/// [`I18nConfig::source_copies`] and [`I18n::is_source_copy`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SourceCopyPolicy {
+    /// Counted as translated, like any other string.
+    #[default]
+    Translated,
+    /// Displayed, but counted as missing by [`I18n::completeness`] and the
+    /// console's `missing` / `coverage` reports.
+    Untranslated,
+    /// Dropped at load, so lookups go through the fallback chain like
+    /// missing keys.
+    Fallback,
+}
+
+impl Default for I18nConfig {
+    fn default() -> Self {
+        Self {
+            use_bundled_translations: cfg!(target_arch = "wasm32")
+                || cfg!(feature = "bundle-only")
+                || cfg!(not(feature = "fs-loader")),
+            messages_folder: "messages".to_string(),
+            default_lang: "en".to_string(),
+            fallback_chain: vec!["en".to_string()],
+            warn_unknown_locales: true,
+            custom_locales: Vec::new(),
+            file_policies: HashMap::new(),
+            group_counts: true,
+            zero_form: false,
+            count_sign: SignDisplay::default(),
+            format_overrides: FormatOverrides::default(),
+            speech_register: SpeechRegister::default(),
+            log_policy: LogPolicy::default(),
+            gender_keys: Gender::ALL.iter().map(|gender| gender.key().to_string()).collect(),
+            preflight: cfg!(debug_assertions),
+            check_bundle: cfg!(debug_assertions),
+            formats: TranslationFormats::default(),
+            overlay: None,
+            source_copies: SourceCopyPolicy::default(),
+            audio_lang: None,
+            hot_reload: false,
+        }
+    }
 }
 
 /// Main resource for accessing translations in Bevy systems.
@@ -252,37 +567,94 @@ pub struct Translations {
 ///     println!("{}", text);
 /// }
 /// ```
-#[derive(Resource)]
+#[cfg_attr(feature = "bevy", derive(Resource))]
 pub struct I18n {
-    /// All loaded translations
-    translations: Translations,
+    /// All loaded translations, shared with [`I18nSnapshot`]s
+    translations: Arc<Translations>,
     /// Currently active language
     current_lang: String,
     /// List of available languages
     locale_folders_list: Vec<String>,
-    /// Fallback language when translation is missing
-    fallback_lang: String,
+    /// Fallback languages, in order, when a translation is missing
+    fallback_chain: Vec<String>,
     /// Per-locale CLDR plural rules. Locales for which no rules could be
     /// resolved (custom dialects, unknown codes) are absent from this map and
     /// fall back to anglo-centric defaults inside `t_with_plural`.
     plural_rules: HashMap<String, PluralRules>,
+    /// Per-locale CLDR ordinal rules; languages without any give `Other`.
+    ordinal_rules: HashMap<String, PluralRules>,
+    /// Fictional / constructed languages declared in the config.
+    custom_locales: Vec<CustomLocale>,
+    /// Per-file lookup policies declared in the config.
+    file_policies: HashMap<String, FilePolicy>,
+    /// Whether plural counts are formatted with digit grouping.
+    group_counts: bool,
+    /// Whether `"zero"` forms always win for a count of 0.
+    zero_form: bool,
+    /// Sign display of plural counts.
+    count_sign: SignDisplay,
+    /// Number conventions that trump the locale's.
+    format_overrides: FormatOverrides,
+    /// Variant picked from register maps.
+    speech_register: SpeechRegister,
+    /// Lookup warning policy and the keys already reported.
+    log: I18nLog,
+    /// Recoverable problems of the last load.
+    diagnostics: Vec<I18nDiagnostic>,
+    /// Analytics sink and session metadata.
+    analytics: I18nAnalytics,
+    /// Selectors accepted by gender maps.
+    gender_keys: Vec<String>,
+    /// Treatment of translations identical to the source language's.
+    source_copies: SourceCopyPolicy,
+    /// Language of localized audio (`None`: the current language).
+    audio_lang: Option<String>,
+    /// Names chosen by the player, see [`I18n::t_with_terms`].
+    user_dictionary: UserDictionary,
+    /// Lookup plans of [`translation`](Self::translation), per file.
+    plans: PlanCache,
 }
 
-impl FromWorld for I18n {
-    fn from_world(world: &mut World) -> Self {
-        let config = world.get_resource::<I18nConfig>().cloned().unwrap_or_default();
-
-        let (translations, locale_folders_list) = if config.use_bundled_translations {
-            load_bundled_translations()
+impl I18n {
+    /// Loads translations as described by `config`, without a Bevy `App`.
+    ///
+    /// This is what `I18nPlugin` uses to build the resource; call it directly
+    /// on a game server to localize push notifications or emails.
+    ///
+    /// ```rust,no_run
+    /// use bevy_intl::{I18n, I18nConfig};
+    ///
+    /// let mut i18n = I18n::from_config(I18nConfig::default());
+    /// i18n.set_lang("fr");
+    /// let subject = i18n.translation("emails").t("welcome_subject");
+    /// ```
+    pub fn from_config(config: I18nConfig) -> Self {
+        let mut diagnostics = Vec::new();
+        let (mut translations, locale_folders_list) = if config.use_bundled_translations {
+            load_bundled_translations(&config.formats, &mut diagnostics)
         } else {
-            load_filesystem_translations(&config.messages_folder)
+            load_filesystem_translations(&config.messages_folder, &config.formats, &mut diagnostics)
         };
+        let overlay = config
+            .overlay
+            .clone()
+            .or_else(|| std::env::var(OVERLAY_ENV).ok().filter(|folder| !folder.is_empty()));
+        if let Some(overlay) = overlay {
+            apply_overlay(&mut translations, &config.messages_folder, &overlay, &config.formats);
+        }
+        if config.source_copies == SourceCopyPolicy::Fallback {
+            if let Some(source) = config.fallback_chain.first() {
+                qa::drop_source_copies(&mut translations, source);
+            }
+        }
 
         if config.warn_unknown_locales {
             for locale in &locale_folders_list {
-                if !locale_exists_as_international_standard(locale) {
+                let is_custom = config.custom_locales.iter().any(|c| &c.name == locale);
+                if !is_custom && !Locale::is_standard(locale) {
                     warn!(
-                        "Locale folder '{}' is not a recognized ISO/CLDR locale code",
+                        "Locale folder '{}' is not a recognized ISO/CLDR locale code \
+                         (declare it in `custom_locales` or set `warn_unknown_locales: false`)",
                         locale
                     );
                 }
@@ -295,274 +667,111 @@ impl FromWorld for I18n {
                 config.default_lang, locale_folders_list
             );
         }
-        if !locale_folders_list.contains(&config.fallback_lang) {
-            warn!(
-                "Fallback language '{}' not found in loaded translations (available: {:?})",
-                config.fallback_lang, locale_folders_list
-            );
-        }
-
-        let plural_rules = build_plural_rules(&locale_folders_list);
-
-        Self {
-            current_lang: config.default_lang,
-            fallback_lang: config.fallback_lang,
-            translations,
-            locale_folders_list,
-            plural_rules,
-        }
-    }
-}
-
-fn build_plural_rules(locales: &[String]) -> HashMap<String, PluralRules> {
-    let mut map = HashMap::new();
-    for lang in locales {
-        match lang.parse::<LanguageIdentifier>() {
-            Ok(langid) => match PluralRules::create(langid, PluralRuleType::CARDINAL) {
-                Ok(rules) => {
-                    map.insert(lang.clone(), rules);
-                }
-                Err(e) => warn!("no CLDR plural rules for '{}': {}", lang, e),
-            },
-            Err(e) => warn!("could not parse '{}' as a language identifier: {}", lang, e),
-        }
-    }
-    map
-}
-
-fn cldr_category_to_str(cat: PluralCategory) -> &'static str {
-    match cat {
-        PluralCategory::ZERO => "zero",
-        PluralCategory::ONE => "one",
-        PluralCategory::TWO => "two",
-        PluralCategory::FEW => "few",
-        PluralCategory::MANY => "many",
-        PluralCategory::OTHER => "other",
-    }
-}
-
-// ---------- Loaders ----------
-
-// Loading from filesystem (dev/desktop mode)
-#[cfg(not(target_arch = "wasm32"))]
-fn load_filesystem_translations(messages_folder: &str) -> (Translations, Vec<String>) {
-    match load_translation_from_fs(messages_folder) {
-        Ok(langs) => build_translations(langs),
-        Err(e) => {
-            warn!("Failed to load translations from '{}': {}", messages_folder, e);
-            create_error_translations()
-        }
-    }
-}
-
-#[cfg(target_arch = "wasm32")]
-fn load_filesystem_translations(_messages_folder: &str) -> (Translations, Vec<String>) {
-    // Filesystem loading is unavailable on WASM. Returning error_translations
-    // here (rather than calling load_bundled_translations) avoids the infinite
-    // recursion that would occur if bundled data is also empty.
-    warn!("Filesystem loading not available on WASM");
-    create_error_translations()
-}
-
-// Loading from bundled translations (bundled at build time)
-fn load_bundled_translations() -> (Translations, Vec<String>) {
-    match load_bundled_data() {
-        Ok(langs) => {
-            if langs.is_empty() {
-                #[cfg(not(target_arch = "wasm32"))]
-                {
-                    warn!("Bundled translations empty, falling back to filesystem");
-                    return load_filesystem_translations("messages");
-                }
-                #[cfg(target_arch = "wasm32")]
-                {
-                    warn!("Bundled translations empty on WASM (no fallback available)");
-                    return create_error_translations();
+        for (lang, meta) in &translations.meta {
+            let overlay_of = meta.get(META_FILE).and_then(|m| m.get("overlay_of")).and_then(Value::as_str);
+            if let Some(parent) = overlay_of {
+                if !locale_folders_list.iter().any(|l| Locale::same(l, parent)) {
+                    warn!("'{}' is an overlay of '{}', which is not loaded", lang, parent);
                 }
             }
-            build_translations(langs)
         }
-        Err(e) => {
-            warn!("Failed to load bundled translations: {}", e);
-            create_error_translations()
-        }
-    }
-}
-
-// Shared helper to convert a LangMap into the Translations struct + sorted locale list
-fn build_translations(langs: LangMap) -> (Translations, Vec<String>) {
-    let mut locale_list: Vec<String> = langs.keys().cloned().collect();
-    locale_list.sort();
-    (Translations { langs }, locale_list)
-}
-
-// Load bundled data (generated by build.rs)
-fn load_bundled_data() -> Result<LangMap, Box<dyn std::error::Error>> {
-    const BUNDLED_TRANSLATIONS: &str = include_str!(
-        concat!(env!("OUT_DIR"), "/all_translations.json")
-    );
-    
-    // Check if bundled translations are empty (happens when bevy-intl is built standalone)
-    let value: Value = serde_json::from_str(BUNDLED_TRANSLATIONS)?;
-    if !matches!(value.as_object(), Some(obj) if !obj.is_empty()) {
-        // Return empty translation map - will fall back to filesystem loading
-        return Ok(HashMap::new());
-    }
-    
-    parse_translation_value(value)
-}
-
-// Parse a JSON Value to LangMap
-fn parse_translation_value(value: Value) -> Result<LangMap, Box<dyn std::error::Error>> {
-    let mut lang_map = HashMap::new();
-
-    if let Some(langs_obj) = value.as_object() {
-        for (lang_code, files_value) in langs_obj {
-            let mut file_map = HashMap::new();
-
-            if let Some(files_obj) = files_value.as_object() {
-                for (file_name, sections_value) in files_obj {
-                    let mut section_map = HashMap::new();
-
-                    if let Some(sections_obj) = sections_value.as_object() {
-                        for (key, val) in sections_obj {
-                            if let Some(section_value) = parse_section_value(val) {
-                                section_map.insert(key.clone(), section_value);
-                            }
-                        }
-                    }
-                    file_map.insert(file_name.clone(), section_map);
-                }
+        for fallback in &config.fallback_chain {
+            if !locale_folders_list.contains(fallback) {
+                warn!(
+                    "Fallback language '{}' not found in loaded translations (available: {:?})",
+                    fallback, locale_folders_list
+                );
             }
-            lang_map.insert(lang_code.clone(), file_map);
         }
-    }
-
-    Ok(lang_map)
-}
-
-// Filesystem version
-#[cfg(not(target_arch = "wasm32"))]
-fn load_translation_from_fs(messages_folder: &str) -> std::io::Result<LangMap> {
-    use std::fs;
-    use std::path::Path;
-
-    let message_dir = Path::new(messages_folder);
-
-    if !message_dir.exists() {
-        return Err(
-            std::io::Error::new(
-                std::io::ErrorKind::NotFound,
-                format!("{} folder not found", messages_folder)
-            )
-        );
-    }
-
-    let mut lang_map = HashMap::new();
 
-    for folder_entry in fs::read_dir(message_dir)? {
-        let folder = folder_entry?;
-        let lang_code = folder.file_name().to_string_lossy().to_string();
-        let mut file_map = HashMap::new();
+        let custom_names = config.custom_locales.iter().map(|custom| &custom.name);
+        let (plural_rules, ordinal_rules) =
+            language_rules(locale_folders_list.iter().chain(custom_names), &config.custom_locales);
 
-        for file_entry in fs::read_dir(folder.path())? {
-            let file = file_entry?;
-            let path = file.path();
-
-            if path.is_file() && path.extension().and_then(|e| e.to_str()) == Some("json") {
-                let file_name = path
-                    .file_stem()
-                    .and_then(|s| s.to_str())
-                    .unwrap_or("unknown")
-                    .to_string();
-
-                let content = fs::read_to_string(&path)?;
-                let json: Value = serde_json
-                    ::from_str(&content)
-                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
-
-                let mut section_map = HashMap::new();
-
-                if let Some(obj) = json.as_object() {
-                    for (key, value) in obj {
-                        if let Some(section_value) = parse_section_value(value) {
-                            section_map.insert(key.clone(), section_value);
-                        }
-                    }
-                }
+        let i18n = Self {
+            current_lang: config.default_lang,
+            fallback_chain: config.fallback_chain,
+            translations: Arc::new(translations),
+            locale_folders_list,
+            plural_rules,
+            ordinal_rules,
+            custom_locales: config.custom_locales,
+            file_policies: config.file_policies,
+            group_counts: config.group_counts,
+            zero_form: config.zero_form,
+            count_sign: config.count_sign,
+            format_overrides: config.format_overrides,
+            speech_register: config.speech_register,
+            log: I18nLog::new(config.log_policy),
+            diagnostics,
+            analytics: I18nAnalytics::default(),
+            gender_keys: config.gender_keys,
+            source_copies: config.source_copies,
+            audio_lang: config.audio_lang,
+            user_dictionary: UserDictionary::default(),
+            plans: PlanCache::default(),
+        };
 
-                file_map.insert(file_name, section_map);
+        if cfg!(debug_assertions) {
+            for violation in i18n.check_glossary() {
+                warn!("glossary: {}", violation);
             }
-        }
-
-        lang_map.insert(lang_code, file_map);
-    }
-
-    Ok(lang_map)
-}
-
-/// Convert a `serde_json::Value` into a [`SectionValue`], picking the best
-/// variant based on shape:
-///
-/// - String → [`SectionValue::Text`]
-/// - Object whose values are *all* objects → [`SectionValue::Nested`] (gender × plural)
-/// - Otherwise object → [`SectionValue::Map`] (single-axis: plural OR gender)
-/// - Anything else (number, array, null) → `None` (entry is skipped)
-fn parse_section_value(val: &Value) -> Option<SectionValue> {
-    if let Some(text) = val.as_str() {
-        return Some(SectionValue::Text(text.to_string()));
-    }
-    let obj = val.as_object()?;
-
-    let has_only_object_values = !obj.is_empty()
-        && obj.values().all(|v| v.is_object());
-
-    if has_only_object_values {
-        let mut nested = HashMap::new();
-        for (k, v) in obj {
-            if let Some(inner_obj) = v.as_object() {
-                let mut inner = HashMap::new();
-                for (ik, iv) in inner_obj {
-                    if let Some(s) = iv.as_str() {
-                        inner.insert(ik.clone(), s.to_string());
-                    }
-                }
-                nested.insert(k.clone(), inner);
+            for violation in i18n.check_max_lengths() {
+                warn!("max length: {}", violation);
             }
         }
-        return Some(SectionValue::Nested(nested));
-    }
+        let files = i18n.translations.langs.iter().flat_map(|(lang, files)| {
+            files.iter().map(move |(file, sections)| (lang.as_str(), file.as_str(), sections))
+        });
+        subkey::warn_unknown_gender_keys(files, &i18n.gender_keys);
 
-    let mut map = HashMap::new();
-    for (k, v) in obj {
-        if let Some(s) = v.as_str() {
-            map.insert(k.clone(), s.to_string());
-        }
+        i18n
     }
-    Some(SectionValue::Map(map))
-}
-
-// Default error translations
-fn create_error_translations() -> (Translations, Vec<String>) {
-    let mut section_map = HashMap::new();
-    section_map.insert("error".to_string(), SectionValue::Text("Translation Error".to_string()));
-
-    let mut file_map = HashMap::new();
-    file_map.insert("error".to_string(), section_map);
-
-    let mut lang_map = HashMap::new();
-    lang_map.insert("en".to_string(), file_map);
-
-    (Translations { langs: lang_map }, vec!["en".to_string()])
 }
 
 // ---------- API ----------
 
 /// Errors returned by fallible operations on [`I18n`].
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum I18nError {
     /// The requested locale was not found in the loaded translations.
     LocaleNotFound(String),
+    /// A language pack passed to [`I18n::merge_pack`] is not a JSON object
+    /// of files.
+    InvalidPack(String),
+    /// A file of a language pack passed to [`I18n::merge_pack`] is malformed.
+    InvalidPackFile { lang: String, file: String, reason: String },
+    /// The translation file is loaded in neither the current language nor
+    /// its fallback chain.
+    MissingFile(String),
+    /// The key is in neither the current language nor its fallback chain.
+    MissingKey { file: String, key: String },
+    /// The key holds another kind of value than the one asked for, e.g. a
+    /// plural map read with [`I18nPartial::try_t`].
+    WrongValueKind { file: String, key: String, found: ValueKind },
+}
+
+/// Kind of a value that is not a text, see [`I18nError::WrongValueKind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValueKind {
+    /// A plural or gender map ([`SectionValue::Map`]).
+    Map,
+    /// A gender and plural map ([`SectionValue::Nested`]).
+    NestedMap,
+    /// Any other JSON value: an array, a number, a document…
+    Json,
+}
+
+impl std::fmt::Display for ValueKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ValueKind::Map => "plural or gender map",
+            ValueKind::NestedMap => "gender and plural map",
+            ValueKind::Json => "non-text JSON value",
+        })
+    }
 }
 
 impl std::fmt::Display for I18nError {
@@ -571,58 +780,40 @@ impl std::fmt::Display for I18nError {
             I18nError::LocaleNotFound(loc) => {
                 write!(f, "locale '{}' not found in loaded translations", loc)
             }
+            I18nError::InvalidPack(loc) => {
+                write!(f, "language pack for '{}' must be a JSON object of files", loc)
+            }
+            I18nError::InvalidPackFile { lang, file, reason } => {
+                write!(f, "language pack for '{}': file '{}' {}", lang, file, reason)
+            }
+            I18nError::MissingFile(file) => {
+                write!(f, "translation file '{}' is not loaded", file)
+            }
+            I18nError::MissingKey { file, key } => {
+                write!(f, "translation key '{}' not found in file '{}' (no fallback either)", key, file)
+            }
+            I18nError::WrongValueKind { file, key, found } => {
+                write!(f, "translation '{}' in file '{}' is a {}, not a text", key, file, found)
+            }
         }
     }
 }
 
 impl std::error::Error for I18nError {}
 
-/// Extension trait for `App` to set languages at startup, before `run()`.
-///
-/// `App` is not a Bevy `Resource`, so these methods are intended to be called
-/// during plugin setup (build-time configuration), not from inside a system.
-/// For runtime changes, use [`I18n::set_lang`] / [`I18n::try_set_lang`].
-///
-/// # Example
-///
-/// ```rust,no_run
-/// use bevy::prelude::*;
-/// use bevy_intl::{I18nPlugin, LanguageAppExt};
-///
-/// App::new()
-///     .add_plugins(I18nPlugin::default())
-///     .set_lang_i18n("fr")
-///     .set_fallback_lang("en")
-///     .run();
-/// ```
-pub trait LanguageAppExt {
-    /// Sets the current language for translations. Logs a warning if the locale
-    /// is not available in the loaded translations. Returns `&mut Self` so it
-    /// chains with the rest of the `App` builder.
-    fn set_lang_i18n(&mut self, locale: &str) -> &mut Self;
-    /// Sets the fallback language for translations. Logs a warning if the locale
-    /// is not available in the loaded translations.
-    fn set_fallback_lang(&mut self, locale: &str) -> &mut Self;
-}
-
-impl LanguageAppExt for App {
-    fn set_lang_i18n(&mut self, locale: &str) -> &mut Self {
-        if let Some(mut i18n) = self.world_mut().get_resource_mut::<I18n>() {
-            i18n.set_lang(locale);
-        }
-        self
-    }
+// ---------- Translation Handling ----------
 
-    fn set_fallback_lang(&mut self, locale: &str) -> &mut Self {
-        if let Some(mut i18n) = self.world_mut().get_resource_mut::<I18n>() {
-            i18n.set_fallback_lang(locale);
-        }
-        self
-    }
+/// Whether the file of an [`I18nPartial`] is loaded; the error is only built
+/// when a fallible lookup asks for it.
+#[derive(Clone, Copy)]
+enum PartialFile<'a> {
+    Loaded(&'a str),
+    /// The current language is not loaded.
+    LocaleNotFound,
+    /// The file is loaded in neither the current language nor its fallbacks.
+    Missing,
 }
 
-// ---------- Translation Handling ----------
-
 /// Represents translations for a single file.
 /// 
 /// Provides methods to access translated text with support for
@@ -651,12 +842,54 @@ impl LanguageAppExt for App {
 /// }
 /// ```
 pub struct I18nPartial<'a> {
+    /// Current language (borrowed from `I18n`)
+    lang: &'a str,
+    /// Name of this file, or why it cannot be read
+    file: PartialFile<'a>,
     /// Translations for the current language (borrowed from `I18n`)
     file_translations: &'a SectionMap,
-    /// Fallback translations when current language is missing a key (borrowed from `I18n`)
-    fallback_translation: &'a SectionMap,
+    /// Fallback translations, in chain order, when the current language is
+    /// missing a key (borrowed from `I18n`)
+    fallback_translations: Vec<(&'a str, &'a SectionMap)>,
     /// CLDR plural rules for the current language (`None` for unknown locales)
     plural_rules: Option<&'a PluralRules>,
+    /// Key annotations declared for this file (borrowed from `I18n`)
+    annotations: Option<&'a HashMap<String, KeyAnnotations>>,
+    /// What to return for keys that cannot be resolved
+    missing_key: MissingKeyStrategy,
+    /// Format of `{{count}}` in plural translations
+    #[cfg(feature = "formatting")]
+    count_format: NumberFormat,
+    /// Whether `"zero"` forms always win for a count of 0
+    zero_form: bool,
+    /// Sign display of `{{count}}`
+    #[cfg(feature = "formatting")]
+    count_sign: SignDisplay,
+    /// Variant picked from register maps
+    speech_register: SpeechRegister,
+    /// Original JSON of non-string values, in lookup order (current
+    /// language, then the fallback chain)
+    raw_values: Vec<&'a RawSectionMap>,
+    /// `"@file"` documents, in lookup order
+    documents: Vec<&'a DocumentMap>,
+    /// `"key@gender"` genders, in lookup order
+    genders: Vec<&'a GenderMap>,
+    /// Stable IDs and renamed keys of this file (borrowed from `I18n`)
+    key_migrations: Option<&'a KeyMigrations>,
+    /// Former names of its renamed keys (borrowed from `I18n`)
+    former_keys: Option<&'a FormerKeys>,
+    /// Lookup warning policy (borrowed from `I18n`), scoped to this file
+    log: LogScope<'a>,
+    /// Selectors accepted by gender maps (borrowed from `I18n`)
+    gender_keys: &'a [String],
+    /// Capitalization applied by `{{name|heading}}`
+    heading_case: HeadingCase,
+    /// Where fallback hits and missing keys are reported (`None` without an
+    /// analytics sink)
+    analytics: Option<AnalyticsScope<'a>>,
+    /// Position in the lookup chain (0: the current language) of the
+    /// language the last successful lookup read, for `I18n::explain`
+    served_from: Cell<Option<usize>>,
 }
 
 /// An empty section map used as a sentinel when a requested translation file
@@ -690,14 +923,83 @@ impl I18n {
             .and_then(|lang| lang.get(translation_file))
             .unwrap_or(&EMPTY_SECTION_MAP);
 
-        let fallback_translation = self.translations.langs
-            .get(&self.fallback_lang)
-            .and_then(|lang| lang.get(translation_file))
-            .unwrap_or(&EMPTY_SECTION_MAP);
+        let plan = self.file_plan(translation_file);
+        let fallback_translations = plan
+            .fallbacks
+            .iter()
+            .filter_map(|lang| {
+                let (lang, files) = self.translations.langs.get_key_value(lang)?;
+                Some((lang.as_str(), files.get(translation_file)?))
+            })
+            .collect();
+        let lookup_langs = || std::iter::once(&self.current_lang).chain(&plan.fallbacks);
+        let raw_values = lookup_langs()
+            .filter_map(|lang| self.translations.raw.get(lang)?.get(translation_file))
+            .collect();
+        let documents = lookup_langs()
+            .filter_map(|lang| self.translations.documents.get(lang)?.get(translation_file))
+            .collect();
+        let genders = lookup_langs()
+            .filter_map(|lang| self.translations.genders.get(lang)?.get(translation_file))
+            .collect();
+        let file = if !self.translations.langs.contains_key(&self.current_lang) {
+            PartialFile::LocaleNotFound
+        } else {
+            lookup_langs()
+                .find_map(|lang| {
+                    let sections = self.translations.langs.get(lang)?.get_key_value(translation_file).map(|(name, _)| name);
+                    sections.or_else(|| Some(self.translations.raw.get(lang)?.get_key_value(translation_file)?.0))
+                })
+                .map_or(PartialFile::Missing, |name| PartialFile::Loaded(name))
+        };
 
         let plural_rules = self.plural_rules.get(&self.current_lang);
 
-        I18nPartial { file_translations, fallback_translation, plural_rules }
+        let annotations = self.translations.annotations.get(translation_file);
+        let log = self.log.scope(match file {
+            PartialFile::Loaded(name) => Cow::Borrowed(name),
+            _ => Cow::Owned(translation_file.to_string()),
+        });
+
+        I18nPartial {
+            lang: &self.current_lang,
+            file,
+            file_translations,
+            fallback_translations,
+            plural_rules,
+            annotations,
+            missing_key: plan.missing_key,
+            #[cfg(feature = "formatting")]
+            count_format: self.count_format(),
+            zero_form: self.zero_form,
+            #[cfg(feature = "formatting")]
+            count_sign: self.count_sign,
+            speech_register: self.speech_register,
+            raw_values,
+            documents,
+            genders,
+            key_migrations: self.key_migrations(translation_file),
+            former_keys: self.translations.former_keys.get(translation_file),
+            log,
+            gender_keys: &self.gender_keys,
+            heading_case: HeadingCase::for_locale(&self.current_lang),
+            analytics: self.analytics.is_enabled().then(|| AnalyticsScope {
+                analytics: &self.analytics,
+                locale: &self.current_lang,
+                file: translation_file.to_string(),
+            }),
+            served_from: Cell::new(None),
+        }
+    }
+
+    /// Languages `file` is looked up in after the current one, and what its
+    /// missing keys return.
+    pub(crate) fn file_plan(&self, file: &str) -> Arc<FilePlan> {
+        self.plans.get(&self.current_lang, file, || {
+            let policy = self.file_policy(file);
+            let fallbacks = if policy.fallback { self.effective_fallback_chain() } else { Vec::new() };
+            FilePlan { fallbacks: fallbacks.into_iter().map(str::to_string).collect(), missing_key: policy.missing_key }
+        })
     }
 
     /// Sets the current language. Logs a warning when the locale is unknown.
@@ -725,30 +1027,217 @@ impl I18n {
         if !self.locale_folders_list.iter().any(|l| l == locale) {
             return Err(I18nError::LocaleNotFound(locale.to_string()));
         }
-        self.current_lang = locale.to_string();
+        self.change_lang(locale.to_string());
         Ok(())
     }
 
-    /// Sets the fallback language. Logs a warning when the locale is unknown.
+    /// Translates several keys of `file` in one call, see
+    /// [`I18nPartial::t_many`].
+    pub fn translate_batch<K: AsRef<str>>(&self, file: &str, keys: &[K]) -> Vec<String> {
+        self.translation(file).t_many(keys)
+    }
+
+    /// Replaces the fallback chain with a single language. Logs a warning
+    /// when the locale is unknown.
     pub fn set_fallback_lang(&mut self, locale: &str) {
         if let Err(e) = self.try_set_fallback_lang(locale) {
             warn!("{}", e);
         }
     }
 
-    /// Sets the fallback language, returning [`I18nError::LocaleNotFound`] if
-    /// the locale is not part of the loaded translations.
+    /// Replaces the fallback chain with a single language, returning
+    /// [`I18nError::LocaleNotFound`] if the locale is not part of the loaded
+    /// translations.
     pub fn try_set_fallback_lang(&mut self, locale: &str) -> Result<(), I18nError> {
-        if !self.locale_folders_list.iter().any(|l| l == locale) {
-            return Err(I18nError::LocaleNotFound(locale.to_string()));
+        self.try_set_fallback_chain(&[locale])
+    }
+
+    /// Sets the ordered fallback chain. Logs a warning when a locale is
+    /// unknown.
+    pub fn set_fallback_chain(&mut self, chain: &[&str]) {
+        if let Err(e) = self.try_set_fallback_chain(chain) {
+            warn!("{}", e);
+        }
+    }
+
+    /// Sets the ordered fallback chain, returning
+    /// [`I18nError::LocaleNotFound`] for the first locale that is not part of
+    /// the loaded translations. The chain is left unchanged on error.
+    pub fn try_set_fallback_chain(&mut self, chain: &[&str]) -> Result<(), I18nError> {
+        let loaded = |lang: &str| self.locale_folders_list.iter().any(|l| l == lang);
+        if let Some(missing) = chain.iter().find(|lang| !loaded(lang)) {
+            return Err(I18nError::LocaleNotFound(missing.to_string()));
         }
-        self.fallback_lang = locale.to_string();
+        self.fallback_chain = chain.iter().map(|l| l.to_string()).collect();
+        self.plans.clear();
         Ok(())
     }
 
-    /// Gets the current fallback language code.
+    /// Gets the first language of the fallback chain (empty if the chain is
+    /// empty).
     pub fn get_fallback_lang(&self) -> &str {
-        &self.fallback_lang
+        self.fallback_chain.first().map_or("", String::as_str)
+    }
+
+    /// Gets the ordered fallback chain.
+    pub fn fallback_chain(&self) -> &[String] {
+        &self.fallback_chain
+    }
+
+    /// Selectors accepted by gender maps, see [`I18nConfig::gender_keys`].
+    pub fn gender_keys(&self) -> &[String] {
+        &self.gender_keys
+    }
+
+    /// Plural category of `count` in the current language — the form
+    /// `t_with_plural` picks when no exact-count key matches. Lets game code
+    /// branch on the same rules, e.g. to choose an icon variant.
+    ///
+    /// ```rust,no_run
+    /// # use bevy_intl::{I18n, PluralForm};
+    /// # fn icon(i18n: &I18n, coins: u32) -> &'static str {
+    /// match i18n.plural_category(coins) {
+    ///     PluralForm::One => "coin.png",
+    ///     _ => "coins.png",
+    /// }
+    /// # }
+    /// ```
+    pub fn plural_category(&self, count: impl PluralCount) -> PluralForm {
+        select_plural_form(self.plural_rules.get(&self.current_lang), count.to_i128())
+    }
+
+    /// CLDR ordinal category of `count` in the current language: in English
+    /// `1` is [`PluralForm::One`] (1st), `2` is `Two` (2nd), `3` is `Few`
+    /// (3rd) and `4` is `Other` (4th). Languages without ordinal rules always
+    /// give `Other`.
+    pub fn ordinal_category(&self, count: impl PluralCount) -> PluralForm {
+        match self.ordinal_rules.get(&self.current_lang) {
+            Some(rules) => select_plural_form(Some(rules), count.to_i128()),
+            None => PluralForm::Other,
+        }
+    }
+
+    /// Languages tried after the current one: a custom locale's own fallback
+    /// when it declares one, the locales an overlay extends, the loaded
+    /// parents of a regional variant (`es-MX` → `es-419` → `es`), then the
+    /// fallback chain, without duplicates.
+    fn effective_fallback_chain(&self) -> Vec<&str> {
+        self.fallback_chain_of(&self.current_lang)
+    }
+
+    /// Languages looked up after `lang`, in order: its custom-locale
+    /// fallback, overlay chain and parent locales, then the fallback chain.
+    pub(crate) fn fallback_chain_of(&self, lang: &str) -> Vec<&str> {
+        let custom = self.custom_locale(lang).and_then(|c| c.fallback.as_deref());
+        let overlays = self.overlay_chain(lang);
+        let parents = self.parent_locales(lang);
+        let mut chain: Vec<&str> = Vec::with_capacity(self.fallback_chain.len() + overlays.len() + parents.len() + 1);
+        let inherited = custom.into_iter().chain(overlays).chain(parents);
+        for fallback in inherited.chain(self.fallback_chain.iter().map(String::as_str)) {
+            if fallback != lang && !chain.contains(&fallback) {
+                chain.push(fallback);
+            }
+        }
+        chain
+    }
+
+    /// Loaded languages `lang` inherits from, closest first, see
+    /// [`Locale::parent`]: with `es-419` and `es` folders, `es-MX` gives
+    /// `["es-419", "es"]`. A regional folder can therefore ship only the
+    /// keys that differ from its parent.
+    pub fn parent_locales(&self, lang: &str) -> Vec<&str> {
+        let mut parents = Vec::new();
+        let mut code = lang.to_string();
+        while let Some(parent) = Locale::parent(&code) {
+            if let Some(loaded) = self.locale_folders_list.iter().find(|l| Locale::same(l, &parent)) {
+                parents.push(loaded.as_str());
+            }
+            code = parent;
+        }
+        parents
+    }
+
+    /// Locale that `lang` is declared an overlay of, with
+    /// `{"overlay_of": "en"}` in its `_meta.json`. An overlay only holds the
+    /// strings that differ from its parent (e.g. a pirate-speak pack over
+    /// `en`): its missing keys resolve to the parent first and are not
+    /// counted by [`completeness`](Self::completeness).
+    pub fn overlay_of(&self, lang: &str) -> Option<&str> {
+        let meta = self.translations.meta.get(lang)?.get(META_FILE)?;
+        meta.get("overlay_of")?.as_str()
+    }
+
+    /// Loaded locales `lang` overlays, closest first (an overlay can extend
+    /// another overlay).
+    fn overlay_chain(&self, lang: &str) -> Vec<&str> {
+        let mut chain: Vec<&str> = Vec::new();
+        let mut current = lang;
+        while let Some(parent) = self.overlay_of(current) {
+            let Some(loaded) = self.locale_folders_list.iter().find(|l| Locale::same(l, parent)) else {
+                break;
+            };
+            if loaded == lang || chain.contains(&loaded.as_str()) {
+                break;
+            }
+            chain.push(loaded);
+            current = loaded;
+        }
+        chain
+    }
+
+    /// Whether `lang` translates `file` / `key`, itself or through the
+    /// locales it overlays.
+    pub(crate) fn has_translation(&self, lang: &str, file: &str, key: &str) -> bool {
+        let former_keys = self.translations.former_keys.get(file).and_then(|former| former.get(key));
+        std::iter::once(lang)
+            .chain(self.overlay_chain(lang))
+            .filter_map(|l| self.translations.langs.get(l)?.get(file))
+            .any(|sections| {
+                sections.contains_key(key)
+                    || former_keys.is_some_and(|formers| formers.iter().any(|former| sections.contains_key(former)))
+            })
+            && !(self.source_copies == SourceCopyPolicy::Untranslated && self.is_source_copy(lang, file, key))
+    }
+
+    /// Best loaded language for a list of requested locales in preference
+    /// order (e.g. the OS or browser languages). Each request is matched
+    /// exactly, then by its closest loaded parent (`es-AR` → `es-419`),
+    /// then by another variant of the same language (`fr-CA` → `fr-FR`)
+    /// before the next request is tried.
+    pub fn negotiate_lang(&self, requested: &[&str]) -> Option<&str> {
+        requested.iter().find_map(|request| {
+            let available = &self.locale_folders_list;
+            available
+                .iter()
+                .find(|l| Locale::same(l, request))
+                .map(String::as_str)
+                .or_else(|| self.parent_locales(request).first().copied())
+                .or_else(|| {
+                    let language = request.split(['-', '_']).next().unwrap_or(request);
+                    available
+                        .iter()
+                        .find(|l| Locale::same(l.split(['-', '_']).next().unwrap_or(l), language))
+                        .map(String::as_str)
+                })
+        })
+    }
+
+    /// Lookup policy of a translation file.
+    pub fn file_policy(&self, file: &str) -> FilePolicy {
+        self.file_policies.get(file).copied().unwrap_or_default()
+    }
+
+    /// The custom locale declared under `name`, if any.
+    pub fn custom_locale(&self, name: &str) -> Option<&CustomLocale> {
+        self.custom_locales.iter().find(|c| c.name == name)
+    }
+
+    /// Reading direction of the current language.
+    pub fn text_direction(&self) -> TextDirection {
+        match self.custom_locale(&self.current_lang) {
+            Some(custom) => custom.direction,
+            None => Locale::direction(&self.current_lang),
+        }
     }
 
     /// Gets the current language code.
@@ -792,38 +1281,382 @@ impl I18n {
     pub fn available_languages(&self) -> &[String] {
         &self.locale_folders_list
     }
-}
 
-// ---------- Text helpers ----------
-static ARG_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\{\{(\w+)\}\}").unwrap());
+    /// Name of `lang` in that language, for language pickers: the `"name"`
+    /// of its `_meta.json` if set, else [`Locale::endonym`], else the code
+    /// itself.
+    pub fn language_name(&self, lang: &str) -> String {
+        let meta_name = self
+            .translations
+            .meta
+            .get(lang)
+            .and_then(|meta| meta.get(META_FILE))
+            .and_then(|meta| meta.get("name"))
+            .and_then(Value::as_str);
+        meta_name.or_else(|| Locale::endonym(lang)).unwrap_or(lang).to_string()
+    }
 
-impl I18nPartial<'_> {
-    /// Gets a translated string for the given key.
-    /// 
-    /// Falls back to the fallback language if the key is not found
-    /// in the current language.
-    /// 
-    /// # Arguments
-    /// 
-    /// * `key` - Translation key to look up
-    /// 
-    /// # Returns
-    /// 
-    /// The translated string, or "Missing translation" if not found.
-    /// 
-    /// # Example
-    /// 
-    /// ```rust
-    /// let text = i18n.translation("ui").t("hello");
-    /// ```
-    pub fn t(&self, key: &str) -> String {
-        match self.get_text_value(key) {
-            Some(s) => s,
-            None => {
-                warn!("translation key '{}' not found (no fallback either)", key);
-                "Missing translation".to_string()
-            }
-        }
+    /// [`available_languages`](Self::available_languages) sorted by their
+    /// [`language_name`](Self::language_name), ignoring case and accents as
+    /// the current language does, so a picker lists "Deutsch", "English",
+    /// "Français" rather than `de`, `en`, `fr` in folder order.
+    pub fn available_languages_sorted(&self) -> Vec<&str> {
+        let mut langs: Vec<(String, &str)> = self
+            .locale_folders_list
+            .iter()
+            .map(|lang| (self.language_name(lang), lang.as_str()))
+            .collect();
+        langs.sort_by_cached_key(|(name, _)| (fold_for_search(name, self.get_lang()), name.clone()));
+        langs.into_iter().map(|(_, lang)| lang).collect()
+    }
+
+    /// Every loaded translation as `(lang, file, key, value)`, in no
+    /// particular order, for tools built on the crate (editors, exporters,
+    /// validators) that should not load the files again.
+    ///
+    /// ```rust,no_run
+    /// # use bevy_intl::I18n;
+    /// # fn export(i18n: &I18n) {
+    /// for (lang, file, key, value) in i18n.iter() {
+    ///     println!("{lang}\t{file}.{key}\t{}", value.strings().join(" | "));
+    /// }
+    /// # }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str, &str, &SectionValue)> {
+        self.translations.langs.iter().flat_map(|(lang, files)| {
+            files.iter().flat_map(move |(file, sections)| {
+                sections
+                    .iter()
+                    .map(move |(key, value)| (lang.as_str(), file.as_str(), key.as_str(), value))
+            })
+        })
+    }
+
+    /// Release label of the translations compiled into this build, taken from
+    /// the `BEVY_INTL_BUNDLE_VERSION` environment variable at build time
+    /// (`None` when it was not set).
+    pub fn bundle_version() -> Option<&'static str> {
+        option_env!("BEVY_INTL_BUNDLE_VERSION")
+    }
+
+    /// Content hash of the translations compiled into this build (see
+    /// [`pack_hash`]), or `None` when no `messages/` folder was bundled. Use it
+    /// in bug reports, OTA update checks and cache keys.
+    pub fn bundle_hash() -> Option<&'static str> {
+        option_env!("BEVY_INTL_BUNDLE_HASH")
+    }
+
+    /// Reloads every translation from the source described by `config`
+    /// (bundle or messages folder). The current language and fallback chain
+    /// are kept.
+    pub fn reload(&mut self, config: &I18nConfig) {
+        let mut reloaded = Self::from_config(config.clone());
+        reloaded.current_lang = std::mem::take(&mut self.current_lang);
+        reloaded.fallback_chain = std::mem::take(&mut self.fallback_chain);
+        reloaded.audio_lang = std::mem::take(&mut self.audio_lang);
+        reloaded.user_dictionary = std::mem::take(&mut self.user_dictionary);
+        reloaded.analytics = std::mem::take(&mut self.analytics);
+        *self = reloaded;
+    }
+
+    /// Merges a language pack into `lang`. The pack has the shape of a
+    /// language folder — `{ "file": { "key": ... } }` — and its keys override
+    /// the loaded ones; other keys of the same file are kept. An unknown
+    /// `lang` becomes available.
+    ///
+    /// The whole pack is validated before anything is merged: a malformed
+    /// pack (e.g. a corrupted download) returns an error and leaves the
+    /// translations untouched.
+    ///
+    /// ```rust,no_run
+    /// # use bevy_intl::I18n;
+    /// # fn dlc(i18n: &mut I18n) -> Result<(), bevy_intl::I18nError> {
+    /// let pack = serde_json::json!({ "items": { "dragon_egg": "Œuf de dragon" } });
+    /// i18n.merge_pack("fr", &pack)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn merge_pack(&mut self, lang: &str, pack: &Value) -> Result<(), I18nError> {
+        let files = pack
+            .as_object()
+            .ok_or_else(|| I18nError::InvalidPack(lang.to_string()))?;
+        let translation_files = files.iter().filter(|(file_name, _)| is_translation_file(file_name));
+        for (file_name, json) in translation_files {
+            validate_pack_file(json).map_err(|reason| I18nError::InvalidPackFile {
+                lang: lang.to_string(),
+                file: file_name.clone(),
+                reason,
+            })?;
+        }
+        // Copies the translations only if a snapshot still shares them.
+        let translations = Arc::make_mut(&mut self.translations);
+        // New languages and overlays change fallback chains.
+        self.plans.clear();
+        for (file_name, json) in files {
+            insert_translation_file(translations, lang, file_name, json);
+        }
+        let merged = files.keys().filter_map(|file_name| {
+            let sections = translations.langs.get(lang)?.get(file_name)?;
+            Some((lang, file_name.as_str(), sections))
+        });
+        subkey::warn_unknown_gender_keys(merged, &self.gender_keys);
+
+        if !self.locale_folders_list.iter().any(|l| l == lang) {
+            self.locale_folders_list.push(lang.to_string());
+            self.locale_folders_list.sort();
+            let (plural_rules, ordinal_rules) = language_rules([&lang.to_string()], &self.custom_locales);
+            self.plural_rules.extend(plural_rules);
+            self.ordinal_rules.extend(ordinal_rules);
+        }
+        Ok(())
+    }
+
+    /// Switches to the next language of [`available_languages`](Self::available_languages),
+    /// wrapping around, and returns it — what a settings-screen
+    /// "< Français >" spinner needs.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*; use bevy_intl::I18n;
+    /// fn on_right_arrow(mut i18n: ResMut<I18n>) {
+    ///     let lang = i18n.next_lang().to_string();
+    ///     info!("language is now {lang}");
+    /// }
+    /// ```
+    pub fn next_lang(&mut self) -> &str {
+        self.cycle_lang(1, 0.0)
+    }
+
+    /// Switches to the previous available language, wrapping around.
+    pub fn prev_lang(&mut self) -> &str {
+        self.cycle_lang(-1, 0.0)
+    }
+
+    /// Like [`next_lang`](Self::next_lang), skipping languages whose
+    /// [`completeness`](Self::completeness) is below `min_completeness`.
+    pub fn next_complete_lang(&mut self, min_completeness: f32) -> &str {
+        self.cycle_lang(1, min_completeness)
+    }
+
+    /// Like [`prev_lang`](Self::prev_lang), skipping languages whose
+    /// [`completeness`](Self::completeness) is below `min_completeness`.
+    pub fn prev_complete_lang(&mut self, min_completeness: f32) -> &str {
+        self.cycle_lang(-1, min_completeness)
+    }
+
+    /// Share of the (first) fallback language's keys (across all files) that `lang`
+    /// translates, from 0.0 to 1.0. The fallback language itself, and any
+    /// language when the fallback has no keys, is 1.0. Keys an overlay leaves
+    /// to the locale it extends count as translated when that locale has them.
+    /// Copies of the source string (other than `"key@same_ok"` ones) count as
+    /// missing when [`I18nConfig::source_copies`] is
+    /// [`SourceCopyPolicy::Untranslated`].
+    pub fn completeness(&self, lang: &str) -> f32 {
+        let Some(reference) = self.translations.langs.get(self.get_fallback_lang()) else {
+            return 1.0;
+        };
+        let mut total = 0usize;
+        let mut present = 0usize;
+        for (file, sections) in reference {
+            total += sections.len();
+            present += sections
+                .keys()
+                .filter(|key| self.has_translation(lang, file, key))
+                .count();
+        }
+        if total == 0 {
+            return 1.0;
+        }
+        present as f32 / total as f32
+    }
+
+    fn cycle_lang(&mut self, step: isize, min_completeness: f32) -> &str {
+        let langs = &self.locale_folders_list;
+        let len = langs.len() as isize;
+        let start = langs
+            .iter()
+            .position(|l| *l == self.current_lang)
+            .map_or(-step.signum(), |i| i as isize);
+        let next = (1..=len)
+            .map(|i| (start + step * i).rem_euclid(len) as usize)
+            .find(|&i| self.completeness(&langs[i]) >= min_completeness);
+        if let Some(i) = next {
+            let lang = langs[i].clone();
+            self.change_lang(lang);
+        }
+        &self.current_lang
+    }
+
+    fn change_lang(&mut self, locale: String) {
+        let from = std::mem::replace(&mut self.current_lang, locale);
+        if from != self.current_lang {
+            self.analytics.language_changed(&from, &self.current_lang);
+        }
+    }
+}
+
+// ---------- Text helpers ----------
+
+impl<'a> I18nPartial<'a> {
+    /// Gets a translated string for the given key.
+    /// 
+    /// Falls back to the fallback language if the key is not found
+    /// in the current language.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `key` - Translation key to look up
+    /// 
+    /// # Returns
+    /// 
+    /// The translated string, or "Missing translation" (in the current
+    /// language) if not found.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// let text = i18n.translation("ui").t("hello");
+    /// ```
+    pub fn t(&self, key: &str) -> String {
+        self.try_t(key).unwrap_or_else(|error| self.missing_text(key, &error))
+    }
+
+    /// Gets a translated string for the given key, like [`t`](Self::t), or
+    /// the reason it cannot be resolved instead of a placeholder text:
+    /// [`I18nError::LocaleNotFound`] when the current language is not loaded,
+    /// [`I18nError::MissingFile`], [`I18nError::MissingKey`], or
+    /// [`I18nError::WrongValueKind`] for a plural or gender map.
+    ///
+    /// ```rust,no_run
+    /// # use bevy_intl::{I18n, I18nError};
+    /// # fn title(i18n: &I18n) -> Result<String, I18nError> {
+    /// let title = i18n.translation("ui").try_t("title")?;
+    /// # Ok(title)
+    /// # }
+    /// ```
+    pub fn try_t(&self, key: &str) -> Result<String, I18nError> {
+        self.try_text_template(key).map(|text| self.check_length(key, text))
+    }
+
+    /// [`t`](Self::t) without copying the translation: the string is
+    /// borrowed from the [`I18n`] this partial was made from, so labels
+    /// looked up every frame (HUD counters, tooltips) do not allocate. Only
+    /// the missing-key text is owned.
+    ///
+    /// ```rust,no_run
+    /// # use bevy_intl::I18n;
+    /// # fn hud(i18n: &I18n, label: &mut String) {
+    /// let ammo = i18n.translation("hud").t_str("ammo");
+    /// if *label != ammo {
+    ///     *label = ammo.into_owned();
+    /// }
+    /// # }
+    /// ```
+    pub fn t_str(&self, key: &str) -> Cow<'a, str> {
+        match self.try_text_str(key) {
+            Ok(text) => {
+                self.check_len(key, text);
+                Cow::Borrowed(text)
+            }
+            Err(error) => Cow::Owned(self.missing_text(key, &error)),
+        }
+    }
+
+    /// Translates several keys of this file at once, in order.
+    ///
+    /// Meant for table-like UI rebuilds (inventory rows, settings lists): the
+    /// file and fallback maps are resolved once instead of once per key.
+    ///
+    /// ```rust,no_run
+    /// # use bevy_intl::I18n;
+    /// # fn rows(i18n: &I18n) {
+    /// let [name, weight, value]: [String; 3] = i18n
+    ///     .translation("inventory")
+    ///     .t_many(&["name", "weight", "value"])
+    ///     .try_into()
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn t_many<K: AsRef<str>>(&self, keys: &[K]) -> Vec<String> {
+        keys.iter().map(|key| self.t(key.as_ref())).collect()
+    }
+
+    /// Gets a translated string with per-call [`LookupOptions`]: whether the
+    /// fallback chain is used, what a missing key returns and whether
+    /// placeholders are substituted.
+    ///
+    /// ```rust,no_run
+    /// # use bevy_intl::{I18n, LookupOptions, i18n_args};
+    /// # fn editor(i18n: &I18n) {
+    /// let quests = i18n.translation("quests");
+    /// // "Find {{item}}", as the translator wrote it.
+    /// let raw = quests.t_opts("fetch", LookupOptions { format: false, ..Default::default() });
+    /// let preview = quests.t_opts("fetch", LookupOptions { args: i18n_args! { item = "the key" }, ..Default::default() });
+    /// # }
+    /// ```
+    pub fn t_opts(&self, key: &str, options: LookupOptions) -> String {
+        let found = self.lookup_sections(key, options.use_fallback, |sections, key| self.find_text(sections, key));
+        let Some(template) = found else {
+            // Without fallback, a missing key is the expected answer.
+            if options.use_fallback {
+                self.log.log(
+                    LogCategory::MissingKey,
+                    key,
+                    format_args!("translation key '{}' not found (no fallback either)", key),
+                );
+            }
+            return self.missing_as(key, Sentinel::Missing, options.missing_key.unwrap_or(self.missing_key));
+        };
+        if !options.format {
+            return template.to_string();
+        }
+        let text = if options.args.is_empty() {
+            template.to_string()
+        } else {
+            replace_named_placeholders(template, options.args, &self.placeholders())
+        };
+        self.check_length(key, text)
+    }
+
+    fn text_template(&self, key: &str) -> Cow<'a, str> {
+        match self.try_text_str(key) {
+            Ok(text) => Cow::Borrowed(text),
+            Err(error) => Cow::Owned(self.missing_text(key, &error)),
+        }
+    }
+
+    fn try_text_template(&self, key: &str) -> Result<String, I18nError> {
+        self.try_text_str(key).map(str::to_string)
+    }
+
+    // The stored string of `key`, borrowed.
+    fn try_text_str(&self, key: &str) -> Result<&'a str, I18nError> {
+        if let Some(text) = self.get_text_value(key) {
+            return Ok(text);
+        }
+        let file = match self.file {
+            PartialFile::Loaded(name) => name.to_string(),
+            PartialFile::LocaleNotFound => return Err(I18nError::LocaleNotFound(self.lang.to_string())),
+            PartialFile::Missing => return Err(I18nError::MissingFile(self.log.file().to_string())),
+        };
+        let found = self.lookup(key, |v| match v {
+            SectionValue::Text(_) => None,
+            SectionValue::Map(_) => Some(ValueKind::Map),
+            SectionValue::Nested(_) => Some(ValueKind::NestedMap),
+        });
+        let found = found.or_else(|| self.raw(key).map(|_| ValueKind::Json));
+        Err(match found {
+            Some(found) => I18nError::WrongValueKind { file, key: key.to_string(), found },
+            None => I18nError::MissingKey { file, key: key.to_string() },
+        })
+    }
+
+    fn placeholders(&self) -> PlaceholderContext<'_> {
+        PlaceholderContext { log: &self.log, heading_case: self.heading_case }
+    }
+
+    fn missing_text(&self, key: &str, error: &I18nError) -> String {
+        self.log.log(LogCategory::MissingKey, key, format_args!("{}", error));
+        self.missing(key, Sentinel::Missing)
     }
 
     /// Gets a translated string with **named** placeholder replacement.
@@ -847,8 +1680,8 @@ impl I18nPartial<'_> {
     /// // Result: "Hello John, you have 5 messages"
     /// ```
     pub fn t_with_args(&self, key: &str, args: &[(&str, &dyn ToString)]) -> String {
-        let template = self.t(key);
-        replace_named_placeholders(&template, args)
+        let template = self.text_template(key);
+        self.check_length(key, replace_named_placeholders(&template, args, &self.placeholders()))
     }
 
     /// Gets a translated string with positional placeholder replacement.
@@ -866,16 +1699,17 @@ impl I18nPartial<'_> {
         note = "use `t_with_args` with named tuples (or the `i18n_args!` macro) for proper named placeholder substitution"
     )]
     pub fn t_with_arg(&self, key: &str, args: &[&dyn ToString]) -> String {
-        let template = self.t(key);
-        replace_positional_placeholders(&template, args)
+        let template = self.text_template(key);
+        self.check_length(key, replace_positional_placeholders(&template, args))
     }
 
     /// Gets a pluralized translation based on count.
     /// 
     /// Uses advanced plural rules with fallback priority:
     /// 1. Exact count ("0", "1", "2", etc.)
-    /// 2. ICU categories ("zero", "one", "two", "few", "many")
-    /// 3. Basic fallback ("one" vs "other")
+    /// 2. "zero" for a count of 0, if [`I18nConfig::zero_form`] is set
+    /// 3. ICU categories ("zero", "one", "two", "few", "many")
+    /// 4. Basic fallback ("one" vs "other")
     /// 
     /// # Arguments
     /// 
@@ -893,48 +1727,69 @@ impl I18nPartial<'_> {
     /// let text = i18n.translation("ui").t_with_plural("items", 5);
     /// // Result: "5 items"
     /// ```
-    pub fn t_with_plural(&self, key: &str, count: usize) -> String {
-        // 1. Try exact count first (e.g., "0", "1", "2"...) — most specific.
-        let count_str = count.to_string();
-        if let Some(template) = self.get_nested_value(key, &count_str) {
-            return replace_named_placeholders(&template, &[("count", &count)]);
-        }
-
-        // 2. Try the plural category for the active language. The category is
-        //    resolved through CLDR rules when an `I18n` was provided to this
-        //    `I18nPartial` (default path); otherwise the basic anglo-centric
-        //    fallback below applies.
-        if let Some(category) = self.plural_category(count) {
-            if let Some(template) = self.get_nested_value(key, category) {
-                return replace_named_placeholders(&template, &[("count", &count)]);
+    pub fn t_with_plural(&self, key: &str, count: impl PluralCount) -> String {
+        let count = count.to_i128();
+        match self.plural_template(key, count) {
+            Some(template) => {
+                let count = self.format_count(count);
+                let text = replace_named_placeholders(&template, &[("count", &count)], &self.placeholders());
+                self.check_length(key, text)
+            }
+            None => {
+                self.log.log(
+                    LogCategory::MissingKey,
+                    key,
+                    format_args!("plural translation '{}' not found for count {}", key, count),
+                );
+                self.missing(key, Sentinel::MissingPlural)
             }
         }
+    }
 
-        // 3. Fallback to basic English rules ("one" / "other").
-        let basic_key = if count == 1 { "one" } else { "other" };
-        if let Some(template) = self.get_nested_value(key, basic_key) {
-            return replace_named_placeholders(&template, &[("count", &count)]);
+    /// Pick the raw (unsubstituted) plural template for `count`, following
+    /// the priority documented on [`t_with_plural`](Self::t_with_plural).
+    pub(crate) fn plural_template(&self, key: &str, count: i128) -> Option<String> {
+        self.plural_branch(key, count).map(|(_, template)| template)
+    }
+
+    /// [`plural_template`](Self::plural_template), with the key of the
+    /// branch it was read from.
+    pub(crate) fn plural_branch(&self, key: &str, count: i128) -> Option<(SubKey, String)> {
+        let branch = |sub_key: SubKey| Some((sub_key.clone(), self.get_nested_value(key, &sub_key)?));
+
+        // 1. Try exact count first (e.g., "0", "1", "-1"...) — most specific.
+        if let Some(found) = branch(SubKey::Exact(count)) {
+            return Some(found);
         }
 
-        // 4. Last resort: "many".
-        if let Some(template) = self.get_nested_value(key, "many") {
-            return replace_named_placeholders(&template, &[("count", &count)]);
+        // 1b. "zero" for 0, when the config prefers it to the CLDR category.
+        if count == 0 && self.zero_form {
+            if let Some(found) = branch(PluralForm::Zero.into()) {
+                return Some(found);
+            }
         }
 
-        warn!("plural translation '{}' not found for count {}", key, count);
-        "Missing plural translation".to_string()
-    }
+        // 2. Try the plural category for the active language, from the
+        //    absolute value of `count`. The category is resolved through CLDR
+        //    rules when the language has them; otherwise the basic
+        //    anglo-centric fallback below applies.
+        let category = self.plural_category(count);
+        if let Some(found) = branch(category.into()) {
+            return Some(found);
+        }
 
-    /// Resolve a plural category for `count` in the active language using
-    /// CLDR rules when available, falling back to anglo-centric defaults.
-    fn plural_category(&self, count: usize) -> Option<&'static str> {
-        if let Some(rules) = self.plural_rules {
-            match rules.select(count) {
-                Ok(cat) => return Some(cldr_category_to_str(cat)),
-                Err(e) => warn!("CLDR plural rule selection failed: {}", e),
-            }
+        // 3. Fallback to basic English rules ("one" / "other").
+        let basic_form = if count.unsigned_abs() == 1 { PluralForm::One } else { PluralForm::Other };
+        if let Some(found) = branch(basic_form.into()) {
+            return Some(found);
         }
-        Some(basic_plural_category(count))
+
+        // 4. Last resort: "many".
+        branch(PluralForm::Many.into())
+    }
+
+    fn plural_category(&self, count: i128) -> PluralForm {
+        select_plural_form(self.plural_rules, count)
     }
 
     /// Gets a translation that varies by **both** gender and plural count.
@@ -952,41 +1807,68 @@ impl I18nPartial<'_> {
     /// Plural-category resolution uses the same CLDR rules as
     /// [`t_with_plural`](Self::t_with_plural), with exact-count keys taking
     /// priority.
-    pub fn t_with_gender_and_plural(&self, key: &str, gender: &str, count: usize) -> String {
-        let count_str = count.to_string();
-        if let Some(template) = self.get_gender_plural_value(key, gender, &count_str) {
-            return replace_named_placeholders(&template, &[("count", &count)]);
-        }
-        if let Some(category) = self.plural_category(count) {
-            if let Some(template) = self.get_gender_plural_value(key, gender, category) {
-                return replace_named_placeholders(&template, &[("count", &count)]);
+    pub fn t_with_gender_and_plural(&self, key: &str, gender: &str, count: impl PluralCount) -> String {
+        let count = count.to_i128();
+        match self.gender_plural_template(key, gender, count) {
+            Some(template) => {
+                let count = self.format_count(count);
+                let text = replace_named_placeholders(&template, &[("count", &count)], &self.placeholders());
+                self.check_length(key, text)
+            }
+            None => {
+                self.log.log(
+                    LogCategory::MissingKey,
+                    key,
+                    format_args!("gender+plural translation '{}' missing for gender '{}' count {}", key, gender, count),
+                );
+                self.missing(key, Sentinel::MissingGenderPlural)
             }
         }
-        let basic_key = if count == 1 { "one" } else { "other" };
-        if let Some(template) = self.get_gender_plural_value(key, gender, basic_key) {
-            return replace_named_placeholders(&template, &[("count", &count)]);
-        }
-
-        warn!(
-            "gender+plural translation '{}' missing for gender '{}' count {}",
-            key, gender, count
-        );
-        "Missing gender+plural translation".to_string()
     }
 
-    fn get_gender_plural_value(
+    /// Raw (unsubstituted) gender × plural template, see
+    /// [`t_with_gender_and_plural`](Self::t_with_gender_and_plural).
+    pub(crate) fn gender_plural_template(
         &self,
         key: &str,
         gender: &str,
-        plural_key: &str,
+        count: i128,
     ) -> Option<String> {
-        let pick = |sm: &SectionMap| -> Option<String> {
-            match sm.get(key)? {
-                SectionValue::Nested(map) => map.get(gender)?.get(plural_key).cloned(),
-                _ => None,
+        self.gender_plural_branch(key, gender, count).map(|(_, template)| template)
+    }
+
+    /// [`gender_plural_template`](Self::gender_plural_template), with the
+    /// plural key of the branch it was read from.
+    pub(crate) fn gender_plural_branch(&self, key: &str, gender: &str, count: i128) -> Option<(SubKey, String)> {
+        self.check_gender_key(gender);
+        let gender = SubKey::from(gender);
+        let branch = |form: SubKey| Some((form.clone(), self.get_gender_plural_value(key, &gender, &form)?));
+        if let Some(found) = branch(SubKey::Exact(count)) {
+            return Some(found);
+        }
+        if count == 0 && self.zero_form {
+            if let Some(found) = branch(PluralForm::Zero.into()) {
+                return Some(found);
             }
-        };
-        pick(self.file_translations).or_else(|| pick(self.fallback_translation))
+        }
+        let category = self.plural_category(count);
+        if let Some(found) = branch(category.into()) {
+            return Some(found);
+        }
+        let basic_form = if count.unsigned_abs() == 1 { PluralForm::One } else { PluralForm::Other };
+        branch(basic_form.into())
+    }
+
+    fn get_gender_plural_value(
+        &self,
+        key: &str,
+        gender: &SubKey,
+        plural_key: &SubKey,
+    ) -> Option<String> {
+        self.lookup(key, |v| match v {
+            SectionValue::Nested(map) => map.get(gender)?.get(plural_key).cloned(),
+            _ => None,
+        })
     }
 
     /// Gets a gendered translation.
@@ -1008,9 +1890,13 @@ impl I18nPartial<'_> {
     /// // Result: "Ms."
     /// ```
     pub fn t_with_gender(&self, key: &str, gender: &str) -> String {
-        self.get_nested_value(key, gender).unwrap_or_else(||
-            "Missing gender translation".to_string()
-        )
+        self.check_length(key, self.gender_template(key, gender))
+    }
+
+    fn gender_template(&self, key: &str, gender: &str) -> String {
+        self.check_gender_key(gender);
+        self.get_nested_value(key, &SubKey::from(gender))
+            .unwrap_or_else(|| self.missing(key, Sentinel::MissingGender))
     }
 
     /// Gets a gendered translation with **named** placeholder replacement.
@@ -1032,8 +1918,8 @@ impl I18nPartial<'_> {
         gender: &str,
         args: &[(&str, &dyn ToString)],
     ) -> String {
-        let template = self.t_with_gender(key, gender);
-        replace_named_placeholders(&template, args)
+        let template = self.gender_template(key, gender);
+        self.check_length(key, replace_named_placeholders(&template, args, &self.placeholders()))
     }
 
     /// Gets a gendered translation with positional placeholder replacement.
@@ -1049,110 +1935,266 @@ impl I18nPartial<'_> {
         gender: &str,
         args: &[&dyn ToString],
     ) -> String {
-        let template = self.t_with_gender(key, gender);
-        replace_positional_placeholders(&template, args)
+        let template = self.gender_template(key, gender);
+        self.check_length(key, replace_positional_placeholders(&template, args))
+    }
+
+    /// Original JSON of a non-string value — auxiliary data such as credits
+    /// line arrays or per-locale numbers — looked up like translations
+    /// (current language, then the fallback chain). `None` for translations,
+    /// strings and plural or gender maps, which are read with [`t`](Self::t)
+    /// and its variants.
+    ///
+    /// ```rust,no_run
+    /// # use bevy_intl::I18n;
+    /// # fn credits(i18n: &I18n) {
+    /// // "lines": ["Direction", "Ana Souza", ...], "scroll_speed": 40
+    /// let t = i18n.translation("credits");
+    /// let lines = t.raw("lines").and_then(|v| v.as_array());
+    /// let speed = t.raw("scroll_speed").and_then(|v| v.as_f64());
+    /// # }
+    /// ```
+    pub fn raw(&self, key: &str) -> Option<&Value> {
+        self.raw_values.iter().find_map(|values| values.get(key))
+    }
+
+    /// Text of a long-form document — credits, EULA, patch notes — declared
+    /// as `"key": { "@file": "credits.txt" }` and read from the language
+    /// folder (bundled by `build.rs` on WASM). Looked up like translations.
+    pub fn document(&self, key: &str) -> Option<&str> {
+        self.documents.iter().find_map(|docs| docs.get(key)).map(String::as_str)
+    }
+
+    /// Grammatical gender of the word at `key`, declared per language with
+    /// `"key@gender": "f"`. Looked up like translations.
+    pub fn gender(&self, key: &str) -> Option<&str> {
+        self.genders.iter().find_map(|genders| genders.get(key)).map(String::as_str)
+    }
+
+    /// Maximum display length declared for `key` with a `"key@max"` annotation.
+    pub fn max_len(&self, key: &str) -> Option<usize> {
+        self.annotations?.get(key)?.max_len
+    }
+
+    // Debug builds verify rendered strings against `key@max` annotations.
+    fn check_length(&self, key: &str, text: String) -> String {
+        self.check_len(key, &text);
+        text
+    }
+
+    fn check_len(&self, key: &str, text: &str) {
+        if cfg!(debug_assertions) {
+            if let Some(max) = self.max_len(key) {
+                let len = text.chars().count();
+                if len > max {
+                    self.log.log(
+                        LogCategory::Length,
+                        key,
+                        format_args!(
+                            "translation '{}' is {} characters long, exceeding its max of {}: {:?}",
+                            key, len, max, text
+                        ),
+                    );
+                }
+            }
+        }
     }
 
     // Private utility methods
-    fn get_text_value(&self, key: &str) -> Option<String> {
-        self.file_translations
-            .get(key)
-            .and_then(|v| if let SectionValue::Text(s) = v { Some(s.clone()) } else { None })
-            .or_else(|| {
-                self.fallback_translation
-                    .get(key)
-                    .and_then(|v| (
-                        if let SectionValue::Text(s) = v {
-                            Some(s.clone())
-                        } else {
-                            None
-                        }
-                    ))
-            })
+
+    // Selectors outside `I18nConfig::gender_keys` are still looked up, but
+    // are most likely typos.
+    fn check_gender_key(&self, gender: &str) {
+        if !self.gender_keys.iter().any(|key| key == gender) {
+            self.log.log(
+                LogCategory::Gender,
+                gender,
+                format_args!("gender selector '{}' is not one of the configured gender keys {:?}", gender, self.gender_keys),
+            );
+        }
+    }
+
+    fn missing(&self, key: &str, marker: Sentinel) -> String {
+        self.missing_as(key, marker, self.missing_key)
+    }
+
+    fn missing_as(&self, key: &str, marker: Sentinel, strategy: MissingKeyStrategy) -> String {
+        if let Some(analytics) = &self.analytics {
+            analytics.missing_key(key);
+        }
+        match strategy {
+            MissingKeyStrategy::Placeholder => marker.text(self.lang).to_string(),
+            MissingKeyStrategy::Key => key.to_string(),
+            MissingKeyStrategy::Empty => String::new(),
+        }
+    }
+
+    /// `count` as substituted for `{{count}}`: plain digits without the
+    /// `formatting` feature.
+    pub(crate) fn format_count(&self, count: i128) -> String {
+        #[cfg(feature = "formatting")]
+        return self.count_format.format_signed(count, self.count_sign);
+        #[cfg(not(feature = "formatting"))]
+        return count.to_string();
+    }
+
+    /// Walks the current language, then the fallback chain, returning the
+    /// first value `pick` accepts for `key`.
+    // Stable IDs resolve to their key; a language lacking the key is looked
+    // up under the key's former names.
+    fn lookup<T>(&self, key: &str, pick: impl Fn(&SectionValue) -> Option<T>) -> Option<T> {
+        self.lookup_in(key, true, pick)
+    }
+
+    /// [`lookup`](Self::lookup), restricted to the current language unless
+    /// `use_fallback` is set.
+    fn lookup_in<T>(&self, key: &str, use_fallback: bool, pick: impl Fn(&SectionValue) -> Option<T>) -> Option<T> {
+        self.lookup_sections(key, use_fallback, |sections, key| sections.get(key).and_then(&pick))
+    }
+
+    /// [`lookup_in`](Self::lookup_in), with `find` reading `key` from each
+    /// language's section map.
+    fn lookup_sections<T>(&self, key: &str, use_fallback: bool, find: impl Fn(&'a SectionMap, &str) -> Option<T>) -> Option<T> {
+        let key = self.resolve_key(key);
+        self.lookup_chain(use_fallback).enumerate().find_map(|(i, (lang, sections))| {
+            let picked = self.find_in(sections, key, &find)?;
+            if let Some(analytics) = self.analytics.as_ref().filter(|_| i > 0) {
+                analytics.fallback_used(key, lang);
+            }
+            self.served_from.set(Some(i));
+            Some(picked)
+        })
+    }
+
+    /// The current language, then the fallback chain unless `use_fallback`
+    /// is unset, with their sections of the file.
+    fn lookup_chain(&self, use_fallback: bool) -> impl Iterator<Item = (&'a str, &'a SectionMap)> + '_ {
+        let fallbacks = if use_fallback { self.fallback_translations.as_slice() } else { &[] };
+        std::iter::once((self.lang, self.file_translations)).chain(fallbacks.iter().copied())
+    }
+
+    /// `key` after stable IDs and renamed keys are resolved.
+    fn resolve_key<'k>(&self, key: &'k str) -> &'k str
+    where
+        'a: 'k,
+    {
+        self.key_migrations.map_or(key, |m| m.resolve(key))
     }
 
-    fn get_nested_value(&self, key: &str, nested_key: &str) -> Option<String> {
-        self.file_translations
-            .get(key)
-            .and_then(|v| (
-                if let SectionValue::Map(m) = v {
-                    m.get(nested_key).cloned()
-                } else {
-                    None
+    /// `find` applied to the (resolved) `key` in one language's sections,
+    /// then to the key's former names.
+    fn find_in<T>(&self, sections: &'a SectionMap, key: &str, find: impl Fn(&'a SectionMap, &str) -> Option<T>) -> Option<T> {
+        find(sections, key).or_else(|| self.former_keys?.get(key)?.iter().find_map(|former| find(sections, former)))
+    }
+
+    // Plain strings, or the current register's variant of a register map.
+    fn get_text_value(&self, key: &str) -> Option<&'a str> {
+        self.lookup_sections(key, true, |sections, key| self.find_text(sections, key))
+    }
+
+    fn find_text(&self, sections: &'a SectionMap, key: &str) -> Option<&'a str> {
+        sections.get(key).and_then(|v| self.pick_text(v)).or_else(|| grouped_text(sections, key))
+    }
+
+    fn pick_text(&self, value: &'a SectionValue) -> Option<&'a str> {
+        match value {
+            SectionValue::Text(s) => Some(s),
+            SectionValue::Map(m) => self.speech_register.pick(m).map(String::as_str),
+            _ => None,
+        }
+    }
+
+    // A group of plural maps loads like a gender × plural map, so `a.b` is
+    // also read as gender `b` of the map at `a`.
+    fn get_nested_value(&self, key: &str, nested_key: &SubKey) -> Option<String> {
+        self.lookup_sections(key, true, |sections, key| match sections.get(key) {
+            Some(SectionValue::Map(m)) => m.get(nested_key).cloned(),
+            Some(_) => None,
+            None => {
+                let (parent, middle) = key.rsplit_once('.')?;
+                match sections.get(parent)? {
+                    SectionValue::Nested(nested) => nested.get(&SubKey::from(middle))?.get(nested_key).cloned(),
+                    _ => None,
                 }
-            ))
-            .or_else(|| {
-                self.fallback_translation
-                    .get(key)
-                    .and_then(|v| (
-                        if let SectionValue::Map(m) = v {
-                            m.get(nested_key).cloned()
-                        } else {
-                            None
-                        }
-                    ))
-            })
+            }
+        })
     }
 
 }
 
+// A group of strings (`"audio": { "volume": …, "mute": … }`) loads like a
+// gender map, so `a.b` also reads case `b` of the map at `a`, and `a.b.c`
+// case `c` of gender `b` of the gender × plural map at `a`.
+fn grouped_text<'a>(sections: &'a SectionMap, key: &str) -> Option<&'a str> {
+    let (parent, last) = key.rsplit_once('.')?;
+    let last = SubKey::from(last);
+    if let Some(SectionValue::Map(map)) = sections.get(parent) {
+        return map.get(&last).map(String::as_str);
+    }
+    let (grandparent, middle) = parent.rsplit_once('.')?;
+    match sections.get(grandparent)? {
+        SectionValue::Nested(nested) => nested.get(&SubKey::from(middle))?.get(&last).map(String::as_str),
+        _ => None,
+    }
+}
+
 // ---------- Placeholder helpers ----------
 
+/// What named placeholders are formatted with, besides their values.
+struct PlaceholderContext<'a> {
+    /// Where missing values and unknown transforms are reported
+    log: &'a LogScope<'a>,
+    /// Capitalization applied by `{{name|heading}}`
+    #[cfg_attr(not(feature = "formatting"), allow(dead_code))]
+    heading_case: HeadingCase,
+}
+
 /// Replace `{{name}}` placeholders by looking up the matching `(name, value)`
 /// pair in `args`. Unknown names are kept literally and a warning is logged.
-fn replace_named_placeholders(template: &str, args: &[(&str, &dyn ToString)]) -> String {
-    ARG_RE
-        .replace_all(template, |caps: &regex::Captures<'_>| {
-            let name = &caps[1];
-            match args.iter().find(|(k, _)| *k == name) {
-                Some((_, v)) => v.to_string(),
-                None => {
-                    warn!("missing value for placeholder '{{{{{}}}}}'", name);
-                    caps[0].to_string()
-                }
+/// `{{name|heading}}` capitalizes the value with the context's heading case
+/// (inserts it unchanged without the `formatting` feature); other transforms
+/// log a warning and insert the value unchanged.
+fn replace_named_placeholders(
+    template: &str,
+    args: &[(&str, &dyn ToString)],
+    context: &PlaceholderContext<'_>,
+) -> String {
+    let log = context.log;
+    format_template_with(
+        template,
+        |name| match args.iter().find(|(k, _)| *k == name) {
+            Some((_, v)) => Some(v.to_string()),
+            None => {
+                log.log(
+                    LogCategory::Placeholder,
+                    name,
+                    format_args!("missing value for placeholder '{{{{{}}}}}'", name),
+                );
+                None
             }
-        })
-        .into_owned()
+        },
+        |transform, value| match transform {
+            #[cfg(feature = "formatting")]
+            "heading" => context.heading_case.apply(&value),
+            #[cfg(not(feature = "formatting"))]
+            "heading" => value,
+            _ => {
+                log.log(
+                    LogCategory::Placeholder,
+                    transform,
+                    format_args!("unknown placeholder transform '{}'", transform),
+                );
+                value
+            }
+        },
+    )
 }
 
 /// Replace `{{...}}` placeholders **by order of appearance** (positional).
 /// Used by the deprecated `t_with_arg` / `t_with_gender_and_arg` API to keep
 /// existing callers working until they migrate to the named API.
 fn replace_positional_placeholders(template: &str, args: &[&dyn ToString]) -> String {
-    let counter = std::cell::Cell::new(0usize);
-    ARG_RE
-        .replace_all(template, |caps: &regex::Captures<'_>| {
-            let i = counter.get();
-            counter.set(i + 1);
-            match args.get(i) {
-                Some(v) => v.to_string(),
-                None => caps[0].to_string(),
-            }
-        })
-        .into_owned()
-}
-
-/// Anglo-centric plural category fallback used when no per-language CLDR
-/// rules are available. The CLDR-correct path is registered at runtime via
-/// [`I18n`]'s plural rules; this function only acts as a last resort.
-fn basic_plural_category(count: usize) -> &'static str {
-    match count {
-        0 => "zero",
-        1 => "one",
-        2 => "two",
-        3..=10 => "few",
-        _ => "many",
-    }
-}
-
-// ---------- Utils ----------
-
-/// Checks if a locale string exists as an international standard.
-///
-/// Uses the built-in LOCALES list to validate locale codes against
-/// international standards (ISO 639-1, ISO 3166-1, etc.).
-fn locale_exists_as_international_standard(locale: &str) -> bool {
-    LOCALES.binary_search(&locale).is_ok()
+    format_positional(template, args)
 }
 
 // ---------- Tests ----------
@@ -1160,6 +2202,14 @@ fn locale_exists_as_international_standard(locale: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(all(feature = "fs-loader", not(target_arch = "wasm32")))]
+    use crate::loader::{load_filesystem_translations_or, parse_translation_value};
+    use crate::plural::basic_plural_category;
+    #[cfg(feature = "plurals-cldr")]
+    use intl_pluralrules::PluralRuleType;
+    #[cfg(feature = "plurals-cldr")]
+    use unic_langid::LanguageIdentifier;
+    use crate::store::{FileMap, LangMap, parse_section_value};
 
     fn make_section(pairs: &[(&str, SectionValue)]) -> SectionMap {
         pairs
@@ -1171,13 +2221,29 @@ mod tests {
     fn make_i18n(current: &str, fallback: &str, langs: LangMap) -> I18n {
         let mut locale_folders_list: Vec<String> = langs.keys().cloned().collect();
         locale_folders_list.sort();
-        let plural_rules = build_plural_rules(&locale_folders_list);
+        let (plural_rules, ordinal_rules) = language_rules(&locale_folders_list, &[]);
         I18n {
             current_lang: current.to_string(),
-            fallback_lang: fallback.to_string(),
-            translations: Translations { langs },
+            fallback_chain: vec![fallback.to_string()],
+            translations: Arc::new(Translations { langs, ..Default::default() }),
             locale_folders_list,
             plural_rules,
+            ordinal_rules,
+            custom_locales: Vec::new(),
+            file_policies: HashMap::new(),
+            group_counts: true,
+            zero_form: false,
+            count_sign: SignDisplay::Auto,
+            format_overrides: FormatOverrides::default(),
+            speech_register: SpeechRegister::Polite,
+            log: I18nLog::default(),
+            diagnostics: Vec::new(),
+            analytics: I18nAnalytics::default(),
+            gender_keys: I18nConfig::default().gender_keys,
+            source_copies: SourceCopyPolicy::default(),
+            audio_lang: None,
+            user_dictionary: UserDictionary::default(),
+            plans: PlanCache::default(),
         }
     }
 
@@ -1191,9 +2257,15 @@ mod tests {
 
     // --- Placeholder helpers ---
 
+    fn replace_named(template: &str, args: &[(&str, &dyn ToString)]) -> String {
+        let log = I18nLog::default();
+        let log = log.scope("ui");
+        replace_named_placeholders(template, args, &PlaceholderContext { log: &log, heading_case: HeadingCase::Sentence })
+    }
+
     #[test]
     fn replace_named_basic() {
-        let out = replace_named_placeholders("Hi {{name}}", &[("name", &"John")]);
+        let out = replace_named("Hi {{name}}", &[("name", &"John")]);
         assert_eq!(out, "Hi John");
     }
 
@@ -1201,16 +2273,22 @@ mod tests {
     fn replace_named_two_args_any_order() {
         // The whole point of named placeholders: insertion order in the args
         // slice does not matter — we look up by name.
-        let out = replace_named_placeholders(
+        let out = replace_named(
             "{{name}} has {{count}} apples",
             &[("count", &5), ("name", &"John")],
         );
         assert_eq!(out, "John has 5 apples");
     }
 
+    #[test]
+    fn replace_named_reuses_an_argument() {
+        let out = replace_named("{{name}}? {{name}}! It's {{name}}", &[("name", &"Mario")]);
+        assert_eq!(out, "Mario? Mario! It's Mario");
+    }
+
     #[test]
     fn replace_named_missing_arg_keeps_literal() {
-        let out = replace_named_placeholders("Hi {{name}}", &[]);
+        let out = replace_named("Hi {{name}}", &[]);
         assert_eq!(out, "Hi {{name}}");
     }
 
@@ -1230,6 +2308,18 @@ mod tests {
         assert_eq!(out, "1 and {{b}}");
     }
 
+    #[test]
+    fn placeholder_parser_edge_cases() {
+        let out = replace_named("{{{name}}} {{}} {{a b}} {name}", &[("name", &"x")]);
+        assert_eq!(out, "{x} {{}} {{a b}} {name}");
+        let out = replace_named("{{café_2}}", &[("café_2", &"ok")]);
+        assert_eq!(out, "ok");
+        assert_eq!(
+            parse_template("a{{b}}").collect::<Vec<_>>(),
+            vec![Segment::Text("a"), Segment::Placeholder("b")]
+        );
+    }
+
     // --- Macro ---
 
     #[test]
@@ -1251,9 +2341,10 @@ mod tests {
 
     #[test]
     fn locale_iso_check() {
-        assert!(locale_exists_as_international_standard("fr"));
-        assert!(locale_exists_as_international_standard("fr-BE"));
-        assert!(!locale_exists_as_international_standard("klingon"));
+        assert!(Locale::is_standard("fr"));
+        assert!(Locale::is_standard("fr-BE"));
+        assert!(Locale::is_standard("fr_BE"));
+        assert!(!Locale::is_standard("klingon"));
     }
 
     // --- parse_section_value ---
@@ -1272,8 +2363,8 @@ mod tests {
         let v: Value = serde_json::from_str(r#"{"one":"a","other":"b"}"#).unwrap();
         match parse_section_value(&v) {
             Some(SectionValue::Map(m)) => {
-                assert_eq!(m.get("one").map(String::as_str), Some("a"));
-                assert_eq!(m.get("other").map(String::as_str), Some("b"));
+                assert_eq!(m.get(&PluralForm::One.into()).map(String::as_str), Some("a"));
+                assert_eq!(m.get(&PluralForm::Other.into()).map(String::as_str), Some("b"));
             }
             other => panic!("expected Map, got {:?}", other),
         }
@@ -1285,8 +2376,9 @@ mod tests {
             serde_json::from_str(r#"{"male":{"one":"a"},"female":{"one":"b"}}"#).unwrap();
         match parse_section_value(&v) {
             Some(SectionValue::Nested(m)) => {
-                assert_eq!(m.get("male").and_then(|i| i.get("one")).map(String::as_str), Some("a"));
-                assert_eq!(m.get("female").and_then(|i| i.get("one")).map(String::as_str), Some("b"));
+                let one = SubKey::Plural(PluralForm::One);
+                assert_eq!(m.get(&SubKey::Gender(Gender::Male)).and_then(|i| i.get(&one)).map(String::as_str), Some("a"));
+                assert_eq!(m.get(&SubKey::Gender(Gender::Female)).and_then(|i| i.get(&one)).map(String::as_str), Some("b"));
             }
             other => panic!("expected Nested, got {:?}", other),
         }
@@ -1302,41 +2394,44 @@ mod tests {
 
     #[test]
     fn basic_plural_category_buckets() {
-        assert_eq!(basic_plural_category(0), "zero");
-        assert_eq!(basic_plural_category(1), "one");
-        assert_eq!(basic_plural_category(2), "two");
-        assert_eq!(basic_plural_category(5), "few");
-        assert_eq!(basic_plural_category(10), "few");
-        assert_eq!(basic_plural_category(11), "many");
+        assert_eq!(basic_plural_category(0), PluralForm::Zero);
+        assert_eq!(basic_plural_category(1), PluralForm::One);
+        assert_eq!(basic_plural_category(2), PluralForm::Two);
+        assert_eq!(basic_plural_category(5), PluralForm::Few);
+        assert_eq!(basic_plural_category(10), PluralForm::Few);
+        assert_eq!(basic_plural_category(11), PluralForm::Many);
     }
 
     #[test]
+    #[cfg(feature = "plurals-cldr")]
     fn cldr_polish_categories() {
         // Polish: 1 → one, 2/3/4 → few, 5..=21 → many, 22..=24 → few, …
         let langid: LanguageIdentifier = "pl".parse().unwrap();
         let pr = PluralRules::create(langid, PluralRuleType::CARDINAL).unwrap();
-        assert_eq!(cldr_category_to_str(pr.select(1usize).unwrap()), "one");
-        assert_eq!(cldr_category_to_str(pr.select(2usize).unwrap()), "few");
-        assert_eq!(cldr_category_to_str(pr.select(5usize).unwrap()), "many");
+        assert_eq!(PluralForm::from(pr.select(1usize).unwrap()), PluralForm::One);
+        assert_eq!(PluralForm::from(pr.select(2usize).unwrap()), PluralForm::Few);
+        assert_eq!(PluralForm::from(pr.select(5usize).unwrap()), PluralForm::Many);
     }
 
     #[test]
+    #[cfg(feature = "plurals-cldr")]
     fn cldr_russian_categories() {
         let langid: LanguageIdentifier = "ru".parse().unwrap();
         let pr = PluralRules::create(langid, PluralRuleType::CARDINAL).unwrap();
-        assert_eq!(cldr_category_to_str(pr.select(1usize).unwrap()), "one");
-        assert_eq!(cldr_category_to_str(pr.select(2usize).unwrap()), "few");
-        assert_eq!(cldr_category_to_str(pr.select(5usize).unwrap()), "many");
-        assert_eq!(cldr_category_to_str(pr.select(11usize).unwrap()), "many");
+        assert_eq!(PluralForm::from(pr.select(1usize).unwrap()), PluralForm::One);
+        assert_eq!(PluralForm::from(pr.select(2usize).unwrap()), PluralForm::Few);
+        assert_eq!(PluralForm::from(pr.select(5usize).unwrap()), PluralForm::Many);
+        assert_eq!(PluralForm::from(pr.select(11usize).unwrap()), PluralForm::Many);
     }
 
     #[test]
+    #[cfg(feature = "plurals-cldr")]
     fn cldr_arabic_categories() {
         let langid: LanguageIdentifier = "ar".parse().unwrap();
         let pr = PluralRules::create(langid, PluralRuleType::CARDINAL).unwrap();
-        assert_eq!(cldr_category_to_str(pr.select(0usize).unwrap()), "zero");
-        assert_eq!(cldr_category_to_str(pr.select(1usize).unwrap()), "one");
-        assert_eq!(cldr_category_to_str(pr.select(2usize).unwrap()), "two");
+        assert_eq!(PluralForm::from(pr.select(0usize).unwrap()), PluralForm::Zero);
+        assert_eq!(PluralForm::from(pr.select(1usize).unwrap()), PluralForm::One);
+        assert_eq!(PluralForm::from(pr.select(2usize).unwrap()), PluralForm::Two);
     }
 
     // --- I18nPartial end-to-end ---
@@ -1378,6 +2473,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "plurals-cldr")]
     fn t_with_plural_polish() {
         let mut sections = make_section(&[(
             "apples",
@@ -1395,7 +2491,7 @@ mod tests {
         sections.insert(
             "free".into(),
             SectionValue::Map(
-                [("0".into(), "Brak".into()), ("other".into(), "{{count}} szt".into())]
+                [("=0".into(), "Brak".into()), ("other".into(), "{{count}} szt".into())]
                     .into_iter()
                     .collect(),
             ),
@@ -1416,6 +2512,32 @@ mod tests {
         assert_eq!(t.t_with_plural("free", 0), "Brak");
     }
 
+    #[test]
+    #[cfg(feature = "plurals-cldr")]
+    fn t_with_plural_follows_french_zero_and_russian_last_digit() {
+        let mut i18n = make_i18n("fr", "fr", LangMap::new());
+        i18n.merge_pack("fr", &serde_json::json!({ "ui": {
+            "lives": { "one": "{{count}} vie", "many": "{{count}} de vies", "other": "{{count}} vies" }
+        } }))
+        .unwrap();
+        i18n.merge_pack("ru", &serde_json::json!({ "ui": {
+            "lives": { "one": "{{count}} жизнь", "few": "{{count}} жизни", "many": "{{count}} жизней" }
+        } }))
+        .unwrap();
+
+        // CLDR French: 0 and 1 → one.
+        let t = i18n.translation("ui");
+        assert_eq!(t.t_with_plural("lives", 0), "0 vie");
+        assert_eq!(t.t_with_plural("lives", 1), "1 vie");
+        assert_eq!(t.t_with_plural("lives", 2), "2 vies");
+
+        // CLDR Russian: the category depends on n % 10 and n % 100.
+        i18n.set_lang("ru");
+        let t = i18n.translation("ui");
+        let lives = [1, 3, 5, 11, 21, 22, 25].map(|n| t.t_with_plural("lives", n));
+        assert_eq!(lives, ["1 жизнь", "3 жизни", "5 жизней", "11 жизней", "21 жизнь", "22 жизни", "25 жизней"]);
+    }
+
     #[test]
     fn t_with_gender_and_plural() {
         let mut male = HashMap::new();
@@ -1487,6 +2609,75 @@ mod tests {
         assert_eq!(i18n.get_lang(), "fr");
     }
 
+    #[test]
+    fn cached_lookup_plans_follow_language_and_chain_changes() {
+        let mut i18n = make_i18n("es-MX", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({ "hud": { "ammo": "Ammo", "lives": "Lives" } })).unwrap();
+        i18n.merge_pack("es-MX", &serde_json::json!({ "hud": {} })).unwrap();
+        i18n.merge_pack("fr", &serde_json::json!({ "hud": { "ammo": "Munitions" } })).unwrap();
+        assert_eq!(i18n.translation("hud").t("ammo"), "Ammo");
+        let plan = |i18n: &I18n| i18n.plans.get(&i18n.current_lang, "hud", || unreachable!());
+        assert!(Arc::ptr_eq(&plan(&i18n), &plan(&i18n)));
+
+        // A parent locale loaded after the first lookup joins the chain.
+        i18n.merge_pack("es", &serde_json::json!({ "hud": { "ammo": "Munición" } })).unwrap();
+        assert_eq!(i18n.translation("hud").t("ammo"), "Munición");
+
+        i18n.set_fallback_chain(&["fr"]);
+        assert_eq!(i18n.translation("hud").t("lives"), "Traducción faltante");
+        i18n.set_lang("en");
+        assert_eq!(i18n.translation("hud").t("ammo"), "Ammo");
+        i18n.set_lang("es-MX");
+        assert_eq!(i18n.translation("hud").t("ammo"), "Munición");
+        assert_eq!(i18n.translation("hud").t("lives"), "Traducción faltante");
+    }
+
+    #[test]
+    fn t_str_borrows_stored_translations() {
+        let mut i18n = make_i18n("fr", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({ "hud": { "ammo": "Ammo", "lives": { "one": "life", "other": "lives" } } }))
+            .unwrap();
+        i18n.merge_pack("fr", &serde_json::json!({ "hud": { "ammo": "Munitions" } })).unwrap();
+
+        let hud = i18n.translation("hud");
+        let stored = match &i18n.translations.langs["fr"]["hud"]["ammo"] {
+            SectionValue::Text(text) => text.as_str(),
+            _ => unreachable!(),
+        };
+        let ammo = hud.t_str("ammo");
+        assert!(matches!(ammo, Cow::Borrowed(text) if std::ptr::eq(text, stored)));
+        assert!(matches!(hud.t_str("lives.other"), Cow::Borrowed("lives")));
+        assert!(matches!(hud.t_str("quit"), Cow::Owned(text) if text == "Traduction manquante"));
+        assert_eq!(hud.t_str("ammo"), hud.t("ammo"));
+    }
+
+    #[test]
+    fn try_t_reports_why_a_lookup_fails() {
+        let mut i18n = make_i18n("fr", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({ "ui": {
+            "play": "Play",
+            "items": { "one": "{{count}} item", "other": "{{count}} items" },
+            "credits": ["Ana", "Bo"]
+        } }))
+        .unwrap();
+        i18n.merge_pack("fr", &serde_json::json!({ "ui": { "play": "Jouer" } })).unwrap();
+
+        let ui = i18n.translation("ui");
+        assert_eq!(ui.try_t("play"), Ok("Jouer".to_string()));
+        assert_eq!(ui.try_t("quit"), Err(I18nError::MissingKey { file: "ui".into(), key: "quit".into() }));
+        assert_eq!(
+            ui.try_t("items"),
+            Err(I18nError::WrongValueKind { file: "ui".into(), key: "items".into(), found: ValueKind::Map })
+        );
+        assert!(matches!(ui.try_t("credits"), Err(I18nError::WrongValueKind { found: ValueKind::Json, .. })));
+        assert_eq!(ui.t("quit"), "Traduction manquante");
+        assert_eq!(i18n.translation("hud").try_t("hp"), Err(I18nError::MissingFile("hud".into())));
+
+        i18n.current_lang = "de".into();
+        assert_eq!(i18n.translation("ui").try_t("quit"), Err(I18nError::LocaleNotFound("de".into())));
+        assert_eq!(i18n.translation("ui").try_t("play"), Ok("Play".to_string()));
+    }
+
     #[test]
     fn available_languages_sorted() {
         let mut langs = LangMap::new();
@@ -1497,4 +2688,1788 @@ mod tests {
         let avail: Vec<&str> = i18n.available_languages().iter().map(String::as_str).collect();
         assert_eq!(avail, vec!["en", "fr", "zh"]);
     }
+
+    #[test]
+    fn word_segmentation_languages() {
+        assert!(needs_word_segmentation("th"));
+        assert!(needs_word_segmentation("km-KH"));
+        assert!(!needs_word_segmentation("en"));
+        assert!(!needs_word_segmentation("zh"));
+    }
+
+    #[cfg(feature = "segmentation")]
+    #[test]
+    fn thai_text_gets_zero_width_breaks() {
+        let text = "ภาษาไทยง่ายนิดเดียว";
+        let out = insert_word_breaks(text);
+        assert!(out.contains(ZERO_WIDTH_SPACE));
+        assert_eq!(out.replace(ZERO_WIDTH_SPACE, ""), text);
+        // Existing spaces are not doubled up.
+        assert!(!insert_word_breaks("สวัสดี ครับ").contains(" \u{200B}"));
+    }
+
+    #[test]
+    fn vertical_writing_metadata() {
+        assert_eq!(preferred_writing_mode("ja"), WritingMode::HorizontalTb);
+        assert_eq!(vertical_writing_mode("ja"), Some(WritingMode::VerticalRl));
+        assert_eq!(vertical_writing_mode("zh-Hant"), Some(WritingMode::VerticalRl));
+        assert_eq!(vertical_writing_mode("mn-Mong"), Some(WritingMode::VerticalLr));
+        assert_eq!(vertical_writing_mode("en"), None);
+
+        assert_eq!(vertical_orientation('漢'), VerticalOrientation::Upright);
+        assert_eq!(vertical_orientation('A'), VerticalOrientation::Rotated);
+        assert_eq!(vertical_orientation('。'), VerticalOrientation::TransformedUpright);
+        assert_eq!(vertical_orientation('「'), VerticalOrientation::TransformedRotated);
+        assert_eq!(vertical_orientation('ー'), VerticalOrientation::TransformedRotated);
+        assert_eq!(vertical_orientation('ッ'), VerticalOrientation::TransformedUpright);
+        assert_eq!(vertical_form('、'), Some('︑'));
+        assert_eq!(vertical_form('a'), None);
+    }
+
+    #[cfg(feature = "detect-language")]
+    #[test]
+    fn detects_chat_language() {
+        let i18n = make_i18n("en", "en", LangMap::new());
+        let detected = i18n
+            .detect_language("Bonjour tout le monde, est-ce que quelqu'un veut faire une partie ce soir ?")
+            .unwrap();
+        assert_eq!(detected.lang, "fr");
+        assert!(detected.confidence > 0.5);
+        assert!(i18n.detect_language("").is_none());
+    }
+
+    #[cfg(feature = "transliteration")]
+    #[test]
+    fn player_names_are_transliterated_between_scripts() {
+        let i18n = make_i18n("en", "en", LangMap::new());
+        assert_eq!(i18n.transliterate("Владимир", Script::Latin), "Vladimir");
+        assert_eq!(i18n.transliterate("Αθηνά", Script::Latin), "Athena");
+        assert_eq!(i18n.transliterate("東京", Script::Latin), "Dong Jing");
+        assert_eq!(i18n.transliterate("José #1", Script::Latin), "José #1");
+        assert_eq!(i18n.transliterate("Sasha", Script::Cyrillic), "Саша");
+        assert_eq!(i18n.transliterate("Zhenya и Олег", Script::Cyrillic), "Женя и Олег");
+        assert_eq!(i18n.transliterate("Theodoros", Script::Greek), "Θεοδορος");
+    }
+
+    #[test]
+    fn cycling_languages_wraps_and_skips_incomplete() {
+        let mut en = SectionMap::new();
+        en.insert("a".into(), SectionValue::Text("A".into()));
+        en.insert("b".into(), SectionValue::Text("B".into()));
+        let mut fr = SectionMap::new();
+        fr.insert("a".into(), SectionValue::Text("A".into()));
+        let mut langs = single_lang("en", "ui", en.clone());
+        langs.extend(single_lang("de", "ui", en));
+        langs.extend(single_lang("fr", "ui", fr));
+        let mut i18n = make_i18n("en", "en", langs);
+
+        assert_eq!(i18n.next_lang(), "fr");
+        assert_eq!(i18n.next_lang(), "de");
+        assert_eq!(i18n.prev_lang(), "fr");
+        assert_eq!(i18n.completeness("fr"), 0.5);
+        assert_eq!(i18n.next_complete_lang(1.0), "de");
+        assert_eq!(i18n.next_complete_lang(1.0), "en");
+        assert_eq!(i18n.next_complete_lang(1.0), "de");
+    }
+
+    #[test]
+    fn lookups_walk_the_fallback_chain() {
+        let text = |v: &str| SectionValue::Text(v.into());
+        let mut langs = single_lang("pt-BR", "ui", SectionMap::from([("a".into(), text("a-BR"))]));
+        langs.extend(single_lang(
+            "pt-PT",
+            "ui",
+            SectionMap::from([("a".into(), text("a-PT")), ("b".into(), text("b-PT"))]),
+        ));
+        langs.extend(single_lang("es", "ui", SectionMap::from([("c".into(), text("c-ES"))])));
+        langs.extend(single_lang("en", "ui", SectionMap::from([("d".into(), text("d-EN"))])));
+        let mut i18n = make_i18n("pt-BR", "en", langs);
+        i18n.set_fallback_chain(&["pt-PT", "es", "en"]);
+
+        let t = i18n.translation("ui");
+        assert_eq!(t.t("a"), "a-BR");
+        assert_eq!(t.t("b"), "b-PT");
+        assert_eq!(t.t("c"), "c-ES");
+        assert_eq!(t.t("d"), "d-EN");
+        assert_eq!(i18n.get_fallback_lang(), "pt-PT");
+        assert_eq!(
+            i18n.try_set_fallback_chain(&["es", "xx"]),
+            Err(I18nError::LocaleNotFound("xx".into()))
+        );
+        assert_eq!(i18n.fallback_chain(), ["pt-PT", "es", "en"]);
+    }
+
+    #[test]
+    fn file_policy_disables_fallback() {
+        let text = |v: &str| SectionValue::Text(v.into());
+        let mut langs = single_lang("fr", "ui", SectionMap::new());
+        langs.get_mut("fr").unwrap().insert("legal".into(), SectionMap::new());
+        let mut en = FileMap::new();
+        en.insert("ui".into(), SectionMap::from([("ok".into(), text("OK"))]));
+        en.insert("legal".into(), SectionMap::from([("tos".into(), text("Terms"))]));
+        langs.insert("en".into(), en);
+        let mut i18n = make_i18n("fr", "en", langs);
+        i18n.file_policies.insert(
+            "legal".into(),
+            FilePolicy { fallback: false, missing_key: MissingKeyStrategy::Key },
+        );
+
+        assert_eq!(i18n.translation("ui").t("ok"), "OK");
+        assert_eq!(i18n.translation("legal").t("tos"), "tos");
+    }
+
+    #[test]
+    fn placeholder_texts_follow_the_current_language() {
+        let mut langs = LangMap::new();
+        for lang in ["en", "de", "zh-TW", "pt-BR", "tlh"] {
+            langs.insert(lang.to_string(), FileMap::new());
+        }
+        let mut i18n = make_i18n("de", "en", langs);
+        assert_eq!(i18n.translation("ui").t("nope"), "Fehlende Übersetzung");
+        assert_eq!(i18n.translation("ui").t_with_plural("nope", 2), "Fehlende Pluralübersetzung");
+
+        i18n.set_lang("zh-TW");
+        assert_eq!(i18n.translation("ui").t_with_gender("nope", "female"), "缺少性別翻譯");
+        i18n.set_lang("pt-BR");
+        assert_eq!(i18n.translation("ui").t("nope"), "Tradução ausente");
+        i18n.set_lang("tlh");
+        assert_eq!(i18n.translation("ui").t("nope"), "Missing translation");
+    }
+
+    #[test]
+    fn lookup_options_apply_to_a_single_call() {
+        let text = |v: &str| SectionValue::Text(v.into());
+        let mut langs = single_lang("fr", "ui", SectionMap::from([("find".into(), text("Trouvez {{item}}"))]));
+        langs.insert("en".into(), FileMap::from([("ui".into(), SectionMap::from([("ok".into(), text("OK"))]))]));
+        let i18n = make_i18n("fr", "en", langs);
+        let ui = i18n.translation("ui");
+        let exact = LookupOptions { use_fallback: false, missing_key: Some(MissingKeyStrategy::Empty), ..Default::default() };
+
+        assert_eq!(ui.t_opts("ok", LookupOptions::default()), "OK");
+        assert_eq!(ui.t_opts("ok", exact), "");
+        assert_eq!(ui.t_opts("ok", LookupOptions { use_fallback: false, ..Default::default() }), "Traduction manquante");
+        assert_eq!(ui.t("ok"), "OK");
+        assert_eq!(ui.t_opts("find", LookupOptions { args: i18n_args! { item = "la clé" }, ..exact }), "Trouvez la clé");
+        assert_eq!(
+            ui.t_opts("find", LookupOptions { args: i18n_args! { item = "la clé" }, format: false, ..Default::default() }),
+            "Trouvez {{item}}"
+        );
+    }
+
+    #[test]
+    fn batch_translation_keeps_key_order() {
+        let mut sections = SectionMap::new();
+        sections.insert("a".into(), SectionValue::Text("A".into()));
+        sections.insert("b".into(), SectionValue::Text("B".into()));
+        let i18n = make_i18n("en", "en", single_lang("en", "ui", sections));
+
+        assert_eq!(i18n.translate_batch("ui", &["b", "a", "zz"]), ["B", "A", "Missing translation"]);
+        let owned = vec!["a".to_string()];
+        assert_eq!(i18n.translation("ui").t_many(&owned), ["A"]);
+    }
+
+    #[test]
+    #[cfg(feature = "plurals-cldr")]
+    fn merge_pack_overrides_keys_and_adds_languages() {
+        let mut sections = SectionMap::new();
+        sections.insert("ok".into(), SectionValue::Text("OK".into()));
+        sections.insert("cancel".into(), SectionValue::Text("Cancel".into()));
+        let mut i18n = make_i18n("en", "en", single_lang("en", "ui", sections));
+
+        i18n.merge_pack("en", &serde_json::json!({ "ui": { "ok": "Okay" } })).unwrap();
+        assert_eq!(i18n.translate_batch("ui", &["ok", "cancel"]), ["Okay", "Cancel"]);
+
+        i18n.merge_pack("pl", &serde_json::json!({ "ui": { "files": { "few": "{{count}} pliki", "many": "{{count}} plików" } } }))
+            .unwrap();
+        assert_eq!(i18n.available_languages(), ["en", "pl"]);
+        i18n.set_lang("pl");
+        assert_eq!(i18n.translation("ui").t_with_plural("files", 5), "5 plików");
+        assert_eq!(i18n.translation("ui").t("cancel"), "Cancel");
+
+        assert_eq!(
+            i18n.merge_pack("de", &serde_json::json!(["ui"])),
+            Err(I18nError::InvalidPack("de".into()))
+        );
+    }
+
+    #[test]
+    fn pack_cache_follows_the_manifest() {
+        let mut cache = PackCache::new(MemoryPackStore::default());
+        let v1 = r#"{ "ui": { "ok": "D'accord" } }"#;
+        let v2 = r#"{ "ui": { "ok": "OK" } }"#;
+        let mut manifest = PackManifest { version: "1".into(), packs: HashMap::new() };
+        manifest.packs.insert("fr".into(), pack_hash(v1));
+
+        assert_eq!(cache.get(&manifest, "fr"), None);
+        assert_eq!(cache.insert(v1), pack_hash(v1));
+        assert_eq!(cache.get(&manifest, "fr"), Some(serde_json::from_str(v1).unwrap()));
+
+        // A new release points to another hash: the old pack is a miss, then pruned.
+        manifest.packs.insert("fr".into(), pack_hash(v2));
+        assert_eq!(cache.get(&manifest, "fr"), None);
+        cache.insert(v2);
+        cache.prune(&manifest);
+        assert_eq!(cache.store().keys().len(), 1);
+        assert_eq!(cache.get(&manifest, "fr"), Some(serde_json::from_str(v2).unwrap()));
+    }
+
+    #[test]
+    fn bundle_hash_matches_the_bundled_json() {
+        let bundled = include_str!(concat!(env!("OUT_DIR"), "/all_translations.json"));
+        let expected = (bundled != "{}").then(|| pack_hash(bundled));
+        assert_eq!(I18n::bundle_hash().map(str::to_string), expected);
+    }
+
+    #[test]
+    #[cfg(all(feature = "tools", feature = "yaml"))]
+    #[tracing_test::traced_test]
+    fn bundle_drift_reports_files_edited_since_the_build() {
+        let temp = tempfile::tempdir().unwrap();
+        for (path, content) in [
+            ("messages/en/ui.json", r#"{ "title": "Dawnforge", "play": "Play" }"#),
+            ("messages/en/items.yaml", "sword: Sword\n"),
+            ("messages/fr/ui.json", r#"{ "title": "L'Aube-Forge", "play": "Jouer !" }"#),
+            ("messages/fr/new.json", r#"{ "hint": "Astuce" }"#),
+            ("demo/en/ui.json", r#"{ "title": "Dawnforge Demo", "buy": "Buy the full game" }"#),
+        ] {
+            let path = temp.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        let folder = |name: &str| temp.path().join(name).to_string_lossy().into_owned();
+        let bundled = serde_json::json!({
+            "en": {
+                "ui": { "title": "Dawnforge Demo", "play": "Play" },
+                "items": { "sword": "Sword" },
+                "removed": { "old": "Old" }
+            },
+            "fr": { "ui": { "title": "L'Aube-Forge", "play": "Jouer" } }
+        });
+
+        let on_disk = bundle_source(&folder("messages"), Some(&folder("demo"))).unwrap();
+        let drift = compare_bundle(&bundled, &on_disk);
+        let found: Vec<(&str, &str, DriftKind)> =
+            drift.iter().map(|d| (d.lang.as_str(), d.file.as_str(), d.kind)).collect();
+        assert_eq!(
+            found,
+            [("en", "removed", DriftKind::NotOnDisk), ("fr", "new", DriftKind::NotBundled), ("fr", "ui", DriftKind::Changed)]
+        );
+        assert_eq!(drift[2].to_string(), "fr/ui: changed since the bundle was built");
+
+        // The overlay is looked up next to the messages folder, as build.rs
+        // does; its keys the messages folder lacks are ignored.
+        assert_eq!(bundle_source(&folder("messages"), Some("demo")).unwrap(), on_disk);
+        assert!(logs_contain("overrides 'en/ui.buy', which the messages folder does not define"));
+        let missing_overlay = bundle_source(&folder("messages"), Some("messages-demo")).unwrap_err();
+        assert_ne!(missing_overlay.kind(), std::io::ErrorKind::NotFound);
+
+        // Without the overlay the bundle was built with, the title differs too.
+        let on_disk = bundle_source(&folder("messages"), None).unwrap();
+        assert!(compare_bundle(&bundled, &on_disk).iter().any(|d| d.lang == "en" && d.file == "ui"));
+        assert_eq!(bundle_source(&folder("missing"), None).unwrap_err().kind(), std::io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    #[cfg(feature = "fs-loader")]
+    #[tracing_test::traced_test]
+    fn overlays_replace_only_keys_the_messages_folder_defines() {
+        let temp = tempfile::tempdir().unwrap();
+        for (path, content) in [
+            ("messages/en/shop.json", r#"{ "buy": "Buy now", "menu": { "back": "Back" } }"#),
+            ("messages-demo/en/shop.json", r#"{ "buy": "Get the full game", "menu": { "back": "Exit" }, "trial": "Trial" }"#),
+            ("messages-demo/de/shop.json", r#"{ "buy": "Vollversion kaufen" }"#),
+        ] {
+            let path = temp.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        let i18n = I18n::from_config(I18nConfig {
+            use_bundled_translations: false,
+            messages_folder: temp.path().join("messages").to_string_lossy().into_owned(),
+            // Next to the messages folder, as build.rs resolves it.
+            overlay: Some("messages-demo".to_string()),
+            ..Default::default()
+        });
+        let shop = i18n.translation("shop");
+        assert_eq!(shop.t("buy"), "Get the full game");
+        assert_eq!(shop.t("menu.back"), "Exit");
+        assert!(!i18n.translations.langs["en"]["shop"].contains_key("trial"));
+        assert!(!i18n.translations.langs.contains_key("de"));
+        assert!(logs_contain("overrides 'en/shop.trial', which the messages folder does not define; ignored"));
+        assert!(logs_contain("has a 'de' folder"));
+    }
+
+    #[test]
+    fn display_names_are_localized_before_substitution() {
+        let text = |v: &str| SectionValue::Text(v.into());
+        let mut langs = single_lang("fr", "items", make_section(&[("sword", text("l'Épée de l'aube"))]));
+        langs.get_mut("fr").unwrap().insert(
+            "messages".into(),
+            make_section(&[("obtained", text("Vous obtenez {{item}} !"))]),
+        );
+        let i18n = make_i18n("fr", "fr", langs);
+        let sword = I18nName::new("items", "sword");
+
+        assert_eq!(i18n.display_name(&sword), "l'Épée de l'aube");
+        assert_eq!(
+            i18n.t_with_names("messages", "obtained", &[("item", &sword)]),
+            "Vous obtenez l'Épée de l'aube !"
+        );
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "plurals-cldr", feature = "formatting")))]
+    fn minimal_builds_pick_one_or_other_and_print_plain_counts() {
+        let mut i18n = make_i18n("fr", "fr", LangMap::new());
+        i18n.merge_pack(
+            "fr",
+            &serde_json::json!({ "ui": { "items": { "one": "{{count}} objet", "other": "{{count}} objets" }, "title": "{{s|heading}}" } }),
+        )
+        .unwrap();
+        let ui = i18n.translation("ui");
+        assert_eq!(ui.t_with_plural("items", 1), "1 objet");
+        assert_eq!(ui.t_with_plural("items", 12435), "12435 objets");
+        assert_eq!(ui.t_with_args("title", &[("s", &"épée")]), "épée");
+        assert_eq!(i18n.ordinal_category(2), PluralForm::Other);
+    }
+
+    #[test]
+    #[cfg(feature = "formatting")]
+    fn counts_use_locale_digit_grouping() {
+        assert_eq!(Locale::number_format("en").format_integer(12435), "12,435");
+        assert_eq!(Locale::number_format("fr-CA").format_integer(-12435), "-12\u{202f}435");
+        assert_eq!(Locale::number_format("de-CH").format_integer(1234567), "1\u{2019}234\u{2019}567");
+        assert_eq!(Locale::number_format("es").format_integer(1234), "1234");
+        assert_eq!(Locale::number_format("es").format_integer(12345), "12.345");
+        assert_eq!(Locale::number_format("hi").format_integer(1234567), "12,34,567");
+        assert_eq!(Locale::number_format("xx").format_integer(999), "999");
+
+        let items = SectionValue::Map(HashMap::from([(PluralForm::Other.into(), "{{count}} objets".to_string())]));
+        let mut i18n = make_i18n("fr", "fr", single_lang("fr", "ui", make_section(&[("items", items)])));
+        assert_eq!(i18n.translation("ui").t_with_plural("items", 12435), "12\u{202f}435 objets");
+        i18n.group_counts = false;
+        assert_eq!(i18n.translation("ui").t_with_plural("items", 12435), "12435 objets");
+
+        // Custom locales group like their base locale, not like the locale
+        // of their plural rules.
+        i18n.custom_locales = vec![CustomLocale::new("elvish").plural_rules_from("en").fallback("fr")];
+        i18n.current_lang = "elvish".into();
+        assert_eq!(i18n.format_integer(12435), "12\u{202f}435");
+    }
+
+    #[test]
+    #[cfg(feature = "formatting")]
+    fn format_overrides_trump_locale_conventions() {
+        let items = SectionValue::Map(HashMap::from([(PluralForm::Other.into(), "{{count}} Punkte".to_string())]));
+        let mut i18n = make_i18n("de", "de", single_lang("de", "ui", make_section(&[("items", items)])));
+        assert_eq!(i18n.translation("ui").t_with_plural("items", 12435), "12.435 Punkte");
+
+        i18n.format_overrides = FormatOverrides { group_separator: Some(','), minus_sign: Some('\u{2212}'), ..Default::default() };
+        assert_eq!(i18n.format_integer(-1234567), "\u{2212}1,234,567");
+        assert_eq!(i18n.translation("ui").t_with_plural("items", 12435), "12,435 Punkte");
+
+        // Without grouping, only the signs are overridden.
+        i18n.group_counts = false;
+        assert_eq!(i18n.translation("ui").t_with_plural("items", -12435), "\u{2212}12435 Punkte");
+    }
+
+    #[test]
+    #[cfg(feature = "plurals-cldr")]
+    fn zero_form_overrides_cldr_category_when_enabled() {
+        let forms = |pairs: &[(&str, &str)]| {
+            SectionValue::Map(pairs.iter().map(|(k, v)| (SubKey::from(*k), v.to_string())).collect())
+        };
+        let sections = make_section(&[
+            ("items", forms(&[("zero", "No items"), ("one", "One item"), ("other", "{{count}} items")])),
+            ("lives", forms(&[("=0", "Game over"), ("zero", "No lives"), ("other", "{{count}} lives")])),
+        ]);
+        let mut i18n = make_i18n("en", "en", single_lang("en", "ui", sections));
+
+        // English puts 0 in "other".
+        assert_eq!(i18n.translation("ui").t_with_plural("items", 0), "0 items");
+
+        i18n.zero_form = true;
+        let t = i18n.translation("ui");
+        assert_eq!(t.t_with_plural("items", 0), "No items");
+        assert_eq!(t.t_with_plural("items", 2), "2 items");
+        // Exact-count keys keep priority.
+        assert_eq!(t.t_with_plural("lives", 0), "Game over");
+    }
+
+    #[test]
+    #[cfg(feature = "formatting")]
+    fn negative_counts_pick_forms_from_the_absolute_value() {
+        let forms = |pairs: &[(&str, &str)]| {
+            SectionValue::Map(pairs.iter().map(|(k, v)| (SubKey::from(*k), v.to_string())).collect())
+        };
+        let sections = make_section(&[
+            ("points", forms(&[("one", "{{count}} point"), ("other", "{{count}} points")])),
+            ("gold", forms(&[("=-1", "You owe a coin"), ("other", "{{count}} gold")])),
+        ]);
+        let mut i18n = make_i18n("en", "en", single_lang("en", "ui", sections));
+
+        let t = i18n.translation("ui");
+        assert_eq!(t.t_with_plural("points", -1), "-1 point");
+        assert_eq!(t.t_with_plural("points", -1500i64), "-1,500 points");
+        assert_eq!(t.t_with_plural("points", 3u8), "3 points");
+        assert_eq!(t.t_with_plural("gold", -1), "You owe a coin");
+
+        i18n.count_sign = SignDisplay::ExceptZero;
+        let t = i18n.translation("ui");
+        assert_eq!(t.t_with_plural("points", 5), "+5 points");
+        assert_eq!(t.t_with_plural("points", 0), "0 points");
+        assert_eq!(t.t_with_plural("points", -5), "-5 points");
+        assert_eq!(Locale::number_format("sv").format_signed(-5, SignDisplay::Always), "\u{2212}5");
+    }
+
+    #[test]
+    fn compose_assembles_names_from_language_lists() {
+        let mut i18n = make_i18n("fr", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({ "places": {
+            "prefix": ["Grim", "Ash"],
+            "suffix": ["hold", "vale"],
+            "town": ["{{prefix}}{{suffix}}", "Port {{prefix}}"],
+        }}))
+        .unwrap();
+        i18n.merge_pack("fr", &serde_json::json!({ "places": { "prefix": ["Sombre", "Cendre"], "suffix": ["val"] } }))
+            .unwrap();
+
+        let t = i18n.translation("places");
+        assert_eq!(t.list("suffix"), Some(vec!["val"]));
+        assert_eq!(t.list("nope"), None);
+        // The pattern list falls back to English, the fragments do not.
+        let mut picks = [0, 1, 0].into_iter();
+        assert_eq!(t.compose("town", |_| picks.next().unwrap()), "Cendreval");
+        assert_eq!(t.compose("{{prefix}} et {{mystery}}", |len| len - 1), "Cendre et {{mystery}}");
+    }
+
+    #[test]
+    fn glossary_variants_match_whole_words_only() {
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        let pack = serde_json::json!({
+            "_glossary": {
+                "dawn_sword": { "term": "Sword of Dawn", "variants": ["Dawn Sword", "Sword"] },
+                "broken": { "variants": "not a list" }
+            },
+            "items": {
+                "good": "You found the Sword of Dawn!",
+                "bad": "You found the dawn sword!",
+                "longer": "A Dawn Swordsman"
+            }
+        });
+        i18n.merge_pack("en", &pack).unwrap();
+
+        assert_eq!(i18n.glossary("en").len(), 1);
+        assert_eq!(i18n.glossary_term("dawn_sword").as_deref(), Some("Sword of Dawn"));
+        // "Sword" is part of the canonical term of "good", "Dawn Sword" of a
+        // longer word in "longer".
+        let violations = i18n.check_glossary();
+        let flagged: Vec<(&str, &str)> = violations.iter().map(|v| (v.key.as_str(), v.found.as_str())).collect();
+        assert_eq!(flagged, [("bad", "Dawn Sword"), ("bad", "Sword")]);
+    }
+
+    #[test]
+    fn raw_keeps_non_string_values() {
+        let mut i18n = make_i18n("fr", "en", LangMap::new());
+        let en = serde_json::json!({ "credits": {
+            "lines": ["Direction", "Ana"],
+            "speed": 40,
+            "title": "Credits",
+            "roles": { "one": "{{count}} role", "other": "{{count}} roles" }
+        } });
+        let fr = serde_json::json!({ "credits": { "title": "Générique" } });
+        i18n.merge_pack("en", &en).unwrap();
+        i18n.merge_pack("fr", &fr).unwrap();
+        i18n.set_lang("fr");
+
+        let t = i18n.translation("credits");
+        assert_eq!(t.raw("lines"), Some(&serde_json::json!(["Direction", "Ana"])));
+        assert_eq!(t.raw("speed").and_then(Value::as_u64), Some(40));
+        assert_eq!(t.raw("title"), None);
+        assert_eq!(t.raw("roles"), None);
+        assert_eq!(t.t("title"), "Générique");
+        assert_eq!(t.t_with_plural("roles", 2), "2 roles");
+    }
+
+    #[test]
+    fn keyboard_layout_follows_the_locale() {
+        assert_eq!(Locale::keyboard_layout("fr"), KeyboardLayout::Azerty);
+        assert_eq!(Locale::keyboard_layout("fr-CA"), KeyboardLayout::Qwerty);
+        assert_eq!(Locale::keyboard_layout("fr_CH"), KeyboardLayout::Qwertz);
+        assert_eq!(Locale::keyboard_layout("nl-BE"), KeyboardLayout::Azerty);
+        assert_eq!(Locale::keyboard_layout("de-AT"), KeyboardLayout::Qwertz);
+        assert_eq!(Locale::keyboard_layout("ru"), KeyboardLayout::Qwerty);
+        assert_eq!(KeyboardLayout::Azerty.movement_keys(), ['Z', 'Q', 'S', 'D']);
+
+        let mut i18n = make_i18n("de", "en", LangMap::new());
+        assert_eq!(i18n.keyboard_layout(), KeyboardLayout::Qwertz);
+        i18n.custom_locales = vec![CustomLocale::new("elvish").plural_rules_from("en").fallback("fr")];
+        i18n.current_lang = "elvish".into();
+        assert_eq!(i18n.keyboard_layout(), KeyboardLayout::Azerty);
+    }
+
+    #[test]
+    fn requires_ime_for_cjk_languages() {
+        assert!(Locale::requires_ime("ja"));
+        assert!(Locale::requires_ime("zh-Hant-TW"));
+        assert!(Locale::requires_ime("ko_KR"));
+        assert!(!Locale::requires_ime("fr"));
+
+        let mut i18n = make_i18n("zh", "en", LangMap::new());
+        assert!(i18n.requires_ime());
+        i18n.current_lang = "en".into();
+        assert!(!i18n.requires_ime());
+        i18n.custom_locales = vec![CustomLocale::new("kanji-elvish").plural_rules_from("en").fallback("ja")];
+        i18n.current_lang = "kanji-elvish".into();
+        assert!(i18n.requires_ime());
+    }
+
+    #[test]
+    fn select_placeholders_agree_with_argument_gender() {
+        let template = "{{item}} {{item.gender, select, f{détruite} other{détruit}}} ({{mode, select, solo{seul} other{{{mode}} joueurs}}})";
+        let select = parse_template(template)
+            .find_map(|segment| match segment {
+                Segment::Select(select) => Some(select),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(select.name, "item.gender");
+        assert_eq!(select.cases().collect::<Vec<_>>(), vec![("f", "détruite"), ("other", "détruit")]);
+        assert_eq!(
+            format_named(template, &[("item", &"L'épée"), ("item.gender", &"f"), ("mode", &4)]),
+            "L'épée détruite (4 joueurs)"
+        );
+        assert_eq!(format_named("{{x, select, a{A}}}", &[]), "{{x, select, a{A}}}");
+
+        let mut i18n = make_i18n("fr", "en", LangMap::new());
+        let fr = serde_json::json!({
+            "items": { "sword": "L'épée", "sword@gender": "f", "shield": "Le bouclier", "shield@gender": "m" },
+            "log": { "destroyed": "{{item}} a été {{item.gender, select, f{détruite} other{détruit}}}" },
+        });
+        i18n.merge_pack("fr", &fr).unwrap();
+        i18n.set_lang("fr");
+
+        let sword = I18nName::new("items", "sword");
+        let shield = I18nName::new("items", "shield");
+        assert_eq!(i18n.t_with_names("log", "destroyed", &[("item", &sword)]), "L'épée a été détruite");
+        assert_eq!(i18n.t_with_names("log", "destroyed", &[("item", &shield)]), "Le bouclier a été détruit");
+        assert_eq!(i18n.display_gender(&sword).as_deref(), Some("f"));
+    }
+
+    #[test]
+    fn speech_register_picks_the_closest_variant() {
+        let registers = |pairs: &[(&str, &str)]| {
+            SectionValue::Map(pairs.iter().map(|(k, v)| (SubKey::from(*k), v.to_string())).collect())
+        };
+        let sections = make_section(&[
+            ("greeting", registers(&[("casual", "やあ、{{name}}！"), ("polite", "こんにちは、{{name}}さん。")])),
+            ("farewell", registers(&[("formal", "失礼いたします。")])),
+            ("title", SectionValue::Text("冒険".into())),
+        ]);
+        let mut i18n = make_i18n("ja", "ja", single_lang("ja", "ui", sections));
+        assert_eq!(i18n.speech_register(), SpeechRegister::Polite);
+        assert_eq!(i18n.translation("ui").t_with_args("greeting", &[("name", &"ユキ")]), "こんにちは、ユキさん。");
+
+        i18n.set_speech_register(SpeechRegister::Casual);
+        let t = i18n.translation("ui");
+        assert_eq!(t.t_with_args("greeting", &[("name", &"ユキ")]), "やあ、ユキ！");
+        assert_eq!(t.t("farewell"), "失礼いたします。");
+        assert_eq!(t.t("title"), "冒険");
+
+        i18n.set_speech_register(SpeechRegister::Formal);
+        assert_eq!(i18n.translation("ui").t_with_args("greeting", &[("name", &"ユキ")]), "こんにちは、ユキさん。");
+    }
+
+    #[test]
+    fn regional_variants_fall_back_to_their_parents() {
+        let mut langs = LangMap::new();
+        for (lang, sections) in [
+            ("en", make_section(&[("car", SectionValue::Text("car".into())), ("hello", SectionValue::Text("Hello".into()))])),
+            ("es", make_section(&[("car", SectionValue::Text("coche".into())), ("bye", SectionValue::Text("Adiós".into()))])),
+            ("es-419", make_section(&[("car", SectionValue::Text("carro".into()))])),
+            ("es_MX", make_section(&[("bye", SectionValue::Text("Nos vemos".into()))])),
+        ] {
+            langs.extend(single_lang(lang, "ui", sections));
+        }
+        let mut i18n = make_i18n("es_MX", "en", langs);
+        i18n.locale_folders_list = vec!["en".into(), "es".into(), "es-419".into(), "es_MX".into()];
+
+        assert_eq!(i18n.parent_locales("es_MX"), vec!["es-419", "es"]);
+        assert_eq!(i18n.parent_locales("es-AR"), vec!["es-419", "es"]);
+        assert_eq!(Locale::parent("pt-AO").as_deref(), Some("pt-PT"));
+        assert_eq!(Locale::parent("zh-TW").as_deref(), Some("zh-Hant"));
+        assert_eq!(Locale::parent("zh-Hant"), None);
+        assert_eq!(Locale::parent("de-AT").as_deref(), Some("de"));
+
+        let t = i18n.translation("ui");
+        assert_eq!(t.t("bye"), "Nos vemos");
+        assert_eq!(t.t("car"), "carro");
+        assert_eq!(t.t("hello"), "Hello");
+
+        assert_eq!(i18n.negotiate_lang(&["es-AR", "en"]), Some("es-419"));
+        assert_eq!(i18n.negotiate_lang(&["es-mx"]), Some("es_MX"));
+        assert_eq!(i18n.negotiate_lang(&["fr-CA", "en-GB"]), Some("en"));
+        assert_eq!(i18n.negotiate_lang(&["de"]), None);
+    }
+
+    #[test]
+    fn overlays_resolve_missing_keys_to_their_parent() {
+        let mut i18n = make_i18n("en", "fr", LangMap::new());
+        let fr = serde_json::json!({ "ui": { "ship": "Navire", "gold": "Or", "hello": "Bonjour" } });
+        let en = serde_json::json!({ "ui": { "ship": "Ship", "gold": "Gold" } });
+        let pirate = serde_json::json!({ "_meta": { "overlay_of": "en" }, "ui": { "gold": "Doubloons" } });
+        i18n.merge_pack("fr", &fr).unwrap();
+        i18n.merge_pack("en", &en).unwrap();
+        i18n.merge_pack("en-pirate", &pirate).unwrap();
+        i18n.set_lang("en-pirate");
+
+        assert_eq!(i18n.overlay_of("en-pirate"), Some("en"));
+        let t = i18n.translation("ui");
+        assert_eq!(t.t("gold"), "Doubloons");
+        assert_eq!(t.t("ship"), "Ship");
+        assert_eq!(t.t("hello"), "Bonjour");
+        assert_eq!(i18n.completeness("en-pirate"), i18n.completeness("en"));
+        assert!((i18n.completeness("en-pirate") - 2.0 / 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn localized_values_display_in_the_current_language() {
+        struct Achievement(u32);
+
+        impl LocalizedDisplay for Achievement {
+            fn fmt_localized(&self, i18n: &I18n, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let name = i18n.translation("achievements").t("explorer");
+                write!(f, "{} ({}%)", name, self.0)
+            }
+        }
+
+        let sections = make_section(&[
+            ("explorer", SectionValue::Text("Explorateur".into())),
+            ("shield", SectionValue::Text("Bouclier".into())),
+        ]);
+        let mut langs = single_lang("fr", "achievements", sections);
+        langs.get_mut("fr").unwrap().insert("items".into(), make_section(&[("shield", SectionValue::Text("Bouclier".into()))]));
+        let i18n = make_i18n("fr", "fr", langs);
+
+        assert_eq!(format!("Succès : {}", Achievement(40).localized(&i18n)), "Succès : Explorateur (40%)");
+        let shield = I18nName::new("items", "shield");
+        assert_eq!(shield.localized(&i18n).to_string(), "Bouclier");
+        let named: &dyn DisplayName = &shield;
+        assert_eq!(format!("{:>10}|", named.localized(&i18n)), "  Bouclier|");
+    }
+
+    #[test]
+    fn freeze_manifest_flags_frozen_strings_changed_without_a_version_bump() {
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        let pack = serde_json::json!({ "ui": {
+            "play": "Play",
+            "quit": "Quit",
+            "coins": { "one": "{{count}} coin", "other": "{{count}} coins" }
+        } });
+        i18n.merge_pack("en", &pack).unwrap();
+        let manifest = i18n.freeze_manifest("en", "1.0");
+        assert_eq!(manifest.strings.len(), 3);
+        assert!(manifest.check(&i18n, "1.0").is_empty());
+
+        i18n.merge_pack("en", &serde_json::json!({ "ui": {
+            "play": "Start",
+            "coins": { "other": "{{count}} coins", "one": "{{count}} coin" },
+            "credits": "Credits"
+        } }))
+        .unwrap();
+        // Reordered plural forms and new keys do not break the freeze.
+        assert_eq!(
+            manifest.check(&i18n, "1.0"),
+            [FrozenStringChange { file: "ui".into(), key: "play".into(), kind: FrozenChangeKind::Changed }]
+        );
+        assert!(manifest.check(&i18n, "1.1").is_empty());
+        // A downgrade does not lift the freeze.
+        assert_eq!(manifest.check(&i18n, "0.9").len(), 1);
+        assert_eq!(manifest.compare_version("1.0.0"), std::cmp::Ordering::Equal);
+        assert_eq!(manifest.compare_version("1.10"), std::cmp::Ordering::Greater);
+        let newer = FreezeManifest { version: "1.10".into(), ..manifest.clone() };
+        assert_eq!(newer.compare_version("1.9"), std::cmp::Ordering::Less);
+
+        let json = serde_json::to_string(&manifest).unwrap();
+        assert_eq!(serde_json::from_str::<FreezeManifest>(&json).unwrap(), manifest);
+        let mut with_removed = manifest.clone();
+        with_removed.strings.insert("ui.options".into(), "0".into());
+        let kinds: Vec<_> = with_removed.check(&i18n, "1.0").into_iter().map(|c| (c.key, c.kind)).collect();
+        assert_eq!(kinds, [("options".into(), FrozenChangeKind::Removed), ("play".into(), FrozenChangeKind::Changed)]);
+    }
+
+    #[test]
+    fn renamed_keys_and_stable_ids_keep_translations() {
+        let mut i18n = make_i18n("fr", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({ "ui": { "btn_play": "Play", "quit": "Quit" } })).unwrap();
+        i18n.merge_pack("fr", &serde_json::json!({ "ui": { "btn_play": "Jouer", "quit": "Quitter" } })).unwrap();
+        let mut migrations = KeyMigrations::default();
+        assert!(migrations.update(i18n.stable_ids("en").remove("ui").unwrap()).is_empty());
+
+        // The source key is renamed; French is not updated yet.
+        let mut renamed = make_i18n("fr", "en", LangMap::new());
+        renamed.merge_pack("en", &serde_json::json!({ "ui": { "play_button": "Play", "quit": "Quit" } })).unwrap();
+        renamed.merge_pack("fr", &serde_json::json!({ "ui": { "btn_play": "Jouer", "quit": "Quitter" } })).unwrap();
+        let renames = migrations.update(renamed.stable_ids("en").remove("ui").unwrap());
+        assert_eq!(renames, [("btn_play".to_string(), "play_button".to_string())]);
+        assert_eq!(renamed.translation("ui").t("play_button"), "Play");
+
+        let keys = serde_json::to_value(std::collections::BTreeMap::from([("ui", &migrations)])).unwrap();
+        renamed.merge_pack("en", &serde_json::json!({ "_keys": keys })).unwrap();
+        assert_eq!(renamed.translations.former_keys["ui"]["play_button"], ["btn_play"]);
+        let t = renamed.translation("ui");
+        assert_eq!(t.t("play_button"), "Jouer");
+        assert_eq!(t.t(&stable_id("ui", "Play")), "Jouer");
+        assert_eq!(t.t(&stable_id("ui", "Quit")), "Quitter");
+        assert_eq!(renamed.completeness("fr"), 1.0);
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn word_filters_are_parsed_once_and_invalid_ones_reported() {
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({ "_filter": { "words": ["heck"] } })).unwrap();
+        assert_eq!(i18n.word_filter("en").map(|filter| filter.words.len()), Some(1));
+        assert_eq!(i18n.filter("heck"), "****");
+
+        i18n.merge_pack("en", &serde_json::json!({ "_filter": { "words": "heck" } })).unwrap();
+        assert!(logs_contain("Ignoring invalid _filter.json of 'en'"));
+        assert!(i18n.word_filter("en").is_none());
+        assert_eq!(i18n.filter("heck"), "heck");
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn map_keys_are_interned_and_misspelled_plural_forms_reported() {
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        let pack = serde_json::json!({ "ui": {
+            "apples": { "1": "An apple", "one": "{{count}} apple", "ohter": "{{count}} apples" },
+            "mode": { "easy": "Easy", "hard": "Hard" },
+            "title": { "male": "Mr.", "female": "Ms." }
+        } });
+        i18n.merge_pack("en", &pack).unwrap();
+
+        assert!(logs_contain("Unknown plural form 'ohter' in 'ui.apples'"));
+        assert!(!logs_contain("'easy'"));
+        let Some(SectionValue::Map(apples)) = i18n.translations.langs["en"]["ui"].get("apples") else {
+            panic!("expected a plural map");
+        };
+        assert!(apples.contains_key(&SubKey::Exact(1)));
+        assert!(apples.contains_key(&SubKey::Plural(PluralForm::One)));
+        assert_eq!(SubKey::from("=01"), SubKey::Other("=01".into()));
+        // Bare numbers are case keys; schema 1 counts are migrated at load.
+        assert_eq!(SubKey::from("1"), SubKey::Other("1".into()));
+        assert_eq!(SubKey::from("=1"), SubKey::Exact(1));
+        assert_eq!(SubKey::from("female"), SubKey::Gender(Gender::Female));
+        let t = i18n.translation("ui");
+        assert_eq!(t.t_with_plural("apples", 1), "An apple");
+        assert_eq!(t.t_with_gender("title", "female"), "Ms.");
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn files_of_every_schema_load_and_migrate_to_the_current_one() {
+        let v1 = serde_json::json!({
+            "apples": { "0": "No apples", "one": "{{count}} apple", "other": "{{count}} apples" },
+            "levels": { "1": "Forest", "2": "Cave" },
+            "hero": { "female": { "0": "She has none", "other": "She has {{count}}" } }
+        });
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({ "v1": v1.clone() })).unwrap();
+        let mut v2 = v1.clone();
+        assert_eq!(migrate_file(&mut v2), Ok(1));
+        assert_eq!(v2["apples"]["=0"], "No apples");
+        assert_eq!(v2["hero"]["female"]["=0"], "She has none");
+        assert_eq!(v2["levels"]["1"], "Forest");
+        assert_eq!(file_schema(&v2), Ok(TRANSLATION_SCHEMA));
+        assert_eq!(migrate_file(&mut v2.clone()), Ok(TRANSLATION_SCHEMA));
+        i18n.merge_pack("en", &serde_json::json!({ "v2": v2 })).unwrap();
+
+        for file in ["v1", "v2"] {
+            let t = i18n.translation(file);
+            assert_eq!(t.t_with_plural("apples", 0), "No apples");
+            assert_eq!(t.t_with_plural("apples", 3), "3 apples");
+            assert_eq!(t.t_with_gender_and_plural("hero", "female", 0), "She has none");
+            assert_eq!(t.t("levels.2"), "Cave");
+        }
+        // In schema 2, a bare number is a case key, not an exact count.
+        let cases = serde_json::json!({ "@schema": 2, "floors": { "1": "Ground floor", "one": "{{count}} floor", "other": "{{count}} floors" } });
+        i18n.merge_pack("en", &serde_json::json!({ "cases": cases })).unwrap();
+        let t = i18n.translation("cases");
+        assert_eq!(t.t_with_plural("floors", 1), "1 floor");
+        assert_eq!(t.t("floors.1"), "Ground floor");
+        assert!(!i18n.translations.annotations.contains_key("v2"));
+
+        i18n.merge_pack("en", &serde_json::json!({ "v9": { "@schema": 9, "ok": "OK" } })).unwrap();
+        assert!(logs_contain("translation schema 9 is newer"));
+        assert_eq!(i18n.translation("v9").t("ok"), "OK");
+        let mut v9 = serde_json::json!({ "@schema": 9, "ok": "OK" });
+        assert!(migrate_file(&mut v9).is_err());
+        assert_eq!(file_schema(&serde_json::json!({ "@schema": "two" })), Err("'@schema' must be a positive integer, not \"two\"".into()));
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn lookup_warnings_follow_the_log_policy() {
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({ "ui": { "hi": "Hi {{name}}" }, "menu": {} })).unwrap();
+        let t = i18n.translation("ui");
+        for _ in 0..3 {
+            t.t("nope");
+            t.t_with_args("hi", &[]);
+        }
+        logs_assert(|lines| match lines.iter().filter(|l| l.contains("'nope' not found")).count() {
+            1 => Ok(()),
+            n => Err(format!("missing key reported {} times", n)),
+        });
+        assert!(logs_contain("missing value for placeholder '{{name}}'"));
+
+        // The same key is reported for each file.
+        i18n.translation("menu").t("nope");
+        logs_assert(|lines| match lines.iter().filter(|l| l.contains("'nope' not found")).count() {
+            2 => Ok(()),
+            n => Err(format!("missing key reported {} times", n)),
+        });
+
+        i18n.set_log_policy(LogPolicy { missing_key: LogLevel::Off, ..Default::default() });
+        i18n.translation("ui").t("silent");
+        assert!(!logs_contain("silent"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "fs-loader", not(target_arch = "wasm32")))]
+    fn unreadable_messages_folder_falls_back_to_the_bundle() {
+        let bundle = || parse_translation_value(serde_json::json!({ "en": { "ui": { "ok": "OK" } } })).ok();
+        let mut diagnostics = Vec::new();
+        let formats = TranslationFormats::default();
+        let (translations, langs) = load_filesystem_translations_or("no/such/folder", &formats, bundle, &mut diagnostics);
+        assert_eq!(langs, ["en"]);
+        assert!(matches!(translations.langs["en"]["ui"]["ok"], SectionValue::Text(ref ok) if ok == "OK"));
+        assert!(matches!(
+            &diagnostics[..],
+            [I18nDiagnostic::BundledFallback { messages_folder, .. }] if messages_folder == "no/such/folder"
+        ));
+
+        // Without a bundle, the error translations are still used.
+        let mut diagnostics = Vec::new();
+        let (translations, langs) = load_filesystem_translations_or(
+            "no/such/folder",
+            &TranslationFormats::default(),
+            || None,
+            &mut diagnostics,
+        );
+        assert!(translations.langs["en"].contains_key("error"));
+        assert_eq!(langs, ["en"]);
+        let fr = translations.langs["fr"]["error"].get("error");
+        assert!(matches!(fr, Some(SectionValue::Text(text)) if text == "Erreur de traduction"));
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn malformed_packs_are_rejected_without_partial_merges() {
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        i18n.merge_pack("fr", &serde_json::json!({ "ui": { "ok": "OK" } })).unwrap();
+
+        let truncated = serde_json::json!({
+            "ui": { "ok": "D'accord", "cancel": "Annuler" },
+            "items": { "sword": { "one": "épée", "other": null } }
+        });
+        assert_eq!(
+            i18n.merge_pack("fr", &truncated),
+            Err(I18nError::InvalidPackFile {
+                lang: "fr".into(),
+                file: "items".into(),
+                reason: "has a malformed value for 'sword'".into()
+            })
+        );
+        assert_eq!(
+            i18n.merge_pack("de", &serde_json::json!({ "ui": "OK" })).unwrap_err().to_string(),
+            "language pack for 'de': file 'ui' must be a JSON object of translations"
+        );
+        i18n.set_lang("fr");
+        assert_eq!(i18n.translation("ui").t("ok"), "OK");
+        assert!(!i18n.translations.langs["fr"].contains_key("items"));
+        assert_eq!(i18n.available_languages(), ["fr"]);
+    }
+
+    #[test]
+    fn analytics_sink_reports_language_changes_fallbacks_and_missing_keys() {
+        let text = |v: &str| SectionValue::Text(v.into());
+        let mut langs = single_lang("en", "ui", SectionMap::from([("a".into(), text("A")), ("b".into(), text("B"))]));
+        langs.extend(single_lang("fr", "ui", SectionMap::from([("a".into(), text("A-fr"))])));
+        let mut i18n = make_i18n("en", "en", langs);
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        i18n.set_analytics_session("build", "42");
+        i18n.set_analytics_sink(move |event: &AnalyticsEvent| {
+            assert_eq!(event.session.get("build").map(String::as_str), Some("42"));
+            sink.lock().unwrap().push(format!("{}: {:?}", event.locale, event.kind));
+        });
+
+        i18n.set_lang("fr");
+        i18n.set_lang("fr");
+        for _ in 0..2 {
+            let t = i18n.translation("ui");
+            assert_eq!(t.t("a"), "A-fr");
+            assert_eq!(t.t("b"), "B");
+            assert_eq!(t.t("c"), "Traduction manquante");
+        }
+        assert_eq!(
+            *events.lock().unwrap(),
+            [
+                r#"fr: LanguageChanged { from: "en" }"#,
+                r#"fr: FallbackUsed { file: "ui", key: "b", served_by: "en" }"#,
+                r#"fr: MissingKey { file: "ui", key: "c" }"#,
+            ]
+        );
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn dialogue_lines_take_signed_counts_and_check_gender_keys() {
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({ "ui": {
+            "score": { "one": "{{count}} point", "other": "{{count}} points" },
+            "hail": { "male": "Hail, sir.", "female": "Hail, lady." }
+        } }))
+        .unwrap();
+
+        let score = i18n.resolve_dialogue(&DialogueLine::new("ui", "score").count(-1i32));
+        assert_eq!(score.text, "-1 point");
+        assert_eq!(DialogueLine::new("ui", "score").count(3u8).count, Some(3));
+
+        let hail = i18n.resolve_dialogue(&DialogueLine::new("ui", "hail").gender("femal"));
+        assert!(logs_contain("gender selector 'femal' is not one of the configured gender keys"));
+        assert_ne!(hail.text, "Hail, lady.");
+    }
+
+    #[test]
+    fn missing_translations_report_fallbacks_and_missing_keys() {
+        let mut i18n = make_i18n("fr", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({ "ui": { "play": "Play", "quit": "Quit" } })).unwrap();
+        i18n.merge_pack("fr", &serde_json::json!({ "ui": { "play": "Jouer" } })).unwrap();
+        let report = MissingTranslations::new();
+        i18n.set_missing_translations(report.clone());
+
+        let ui = i18n.translation("ui");
+        for _ in 0..2 {
+            ui.t("play");
+            ui.t("quit");
+            ui.t("tutorial");
+        }
+        i18n.resolve_dialogue(&DialogueLine::new("ui", "intro"));
+        let entry = |key: &str, served_by: Option<&str>| MissingTranslation {
+            lang: "fr".into(),
+            file: "ui".into(),
+            key: key.into(),
+            served_by: served_by.map(str::to_string),
+        };
+        assert_eq!(report.entries(), [entry("intro", None), entry("quit", Some("en")), entry("tutorial", None)]);
+        assert_eq!(
+            report.to_json(),
+            serde_json::json!([
+                { "lang": "fr", "file": "ui", "key": "intro", "served_by": null },
+                { "lang": "fr", "file": "ui", "key": "quit", "served_by": "en" },
+                { "lang": "fr", "file": "ui", "key": "tutorial", "served_by": null }
+            ])
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing.json");
+        report.write_json(&path).unwrap();
+        let written: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(written, report.to_json());
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn configured_gender_keys_are_accepted_and_validated() {
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        i18n.gender_keys = ["orc", "elf"].map(String::from).to_vec();
+        let pack = serde_json::json!({ "ui": {
+            "greet": { "orc": "Zug zug", "elf": "Mae govannen", "dwarf": "Well met" },
+            "guests": { "orc": { "one": "{{count}} orc", "other": "{{count}} orcs" } },
+            "mode": { "easy": "Easy", "hard": "Hard" }
+        } });
+        i18n.merge_pack("en", &pack).unwrap();
+
+        assert!(logs_contain("Unknown gender 'dwarf' in 'en/ui.greet'"));
+        assert!(!logs_contain("'easy'"));
+        let t = i18n.translation("ui");
+        assert_eq!(t.t_with_gender("greet", "orc"), "Zug zug");
+        assert_eq!(t.t_with_gender_and_plural("guests", "orc", 3), "3 orcs");
+        assert!(!logs_contain("gender selector 'orc'"));
+        assert_eq!(t.t_with_gender("greet", "dwarf"), "Well met");
+        assert!(logs_contain("gender selector 'dwarf' is not one of the configured gender keys"));
+    }
+
+    #[test]
+    #[cfg(feature = "plurals-cldr")]
+    fn plural_and_ordinal_categories_follow_the_current_language() {
+        use PluralForm::{Few, Many, One, Other, Two};
+        let mut langs = single_lang("en", "ui", SectionMap::new());
+        langs.extend(single_lang("pl", "ui", SectionMap::new()));
+        let mut i18n = make_i18n("en", "en", langs);
+        assert_eq!(i18n.plural_category(1), One);
+        assert_eq!(i18n.plural_category(-1), One);
+        assert_eq!(i18n.plural_category(5u8), Other);
+        let ordinals = [1, 2, 3, 4, 11, 22].map(|n| i18n.ordinal_category(n));
+        assert_eq!(ordinals, [One, Two, Few, Other, Other, Two]);
+
+        i18n.set_lang("pl");
+        assert_eq!([1, 2, 5, 22].map(|n| i18n.plural_category(n)), [One, Few, Many, Few]);
+        assert_eq!(i18n.ordinal_category(2), Other);
+    }
+
+    #[test]
+    fn message_syntax_errors_are_reported_with_their_location() {
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        let pack = serde_json::json!({ "ui": {
+            "ok": "Hi {{name}}, {{n, select, one{a {{thing}}} other{things}}} { }",
+            "spaces": "Hi {{ name }}",
+            "unclosed": { "one": "{{count} item", "other": "{{count}} items" },
+            "stray": "Done}}",
+            "select": "{{mode, select, solo{alone} duo{{{who}}}}}"
+        } });
+        i18n.merge_pack("en", &pack).unwrap();
+
+        let errors: Vec<String> = i18n.check_message_syntax().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            errors,
+            [
+                r#"[en] ui.select: select 'mode' has no 'other' case"#,
+                r#"[en] ui.spaces: malformed placeholder "{{ name }}""#,
+                r#"[en] ui.stray: unmatched '}}'"#,
+                r#"[en] ui.unclosed: malformed placeholder "{{count} item""#,
+            ]
+        );
+    }
+
+    #[test]
+    fn concatenated_fragments_are_reported() {
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({ "ui": {
+            "full": "You have {{count}} items.",
+            "label": "Price {{item}}:",
+            "prefix": "You have ",
+            "joined": { "one": "{{count}} apple,", "other": "{{count}} apples" },
+            "colon": "Name:",
+            "question": "?"
+        } }))
+        .unwrap();
+        i18n.merge_pack("ar", &serde_json::json!({ "ui": { "full": "لديك {{count}} عناصر.", "question": "؟ هل أنت متأكد" } }))
+            .unwrap();
+
+        let smells: Vec<String> = i18n.check_concatenation().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            smells,
+            [
+                "[ar] ui.question: starts with '؟', which bidi reordering moves to the end in right-to-left text",
+                "[en] ui.joined: ends with ',', as if continued by another string",
+                r#"[en] ui.label: ends with "{{item}}:"; write the label and its value as one sentence"#,
+                "[en] ui.prefix: ends with a space, as if joined to another string",
+            ]
+        );
+    }
+
+    #[test]
+    fn images_resolve_per_language_with_fallback() {
+        let mut i18n = make_i18n("fr", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({
+            "ui": { "play": "Play" },
+            "_images": { "title": "images/en/title.png", "stamp": { "path": "images/stamps.png", "index": 0 } }
+        }))
+        .unwrap();
+        i18n.merge_pack("fr", &serde_json::json!({
+            "ui": { "play": "Jouer" },
+            "_images": { "title": "images/fr/title.png", "flag": { "index": 12 }, "broken": 3 }
+        }))
+        .unwrap();
+
+        let image = |path: Option<&str>, atlas_index| Some(LocalizedImageSource { path: path.map(str::to_string), atlas_index });
+        assert_eq!(i18n.image("title"), image(Some("images/fr/title.png"), None));
+        assert_eq!(i18n.image("stamp"), image(Some("images/stamps.png"), Some(0)));
+        assert_eq!(i18n.image("flag"), image(None, Some(12)));
+        assert_eq!(i18n.image("broken"), None);
+        assert_eq!(i18n.image("unknown"), None);
+        assert_eq!(i18n.completeness("fr"), 1.0);
+    }
+
+    #[test]
+    fn audio_follows_the_audio_language_with_fallback() {
+        let mut i18n = make_i18n("fr", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({ "_audio": { "intro": "audio/en/intro.ogg", "jingle": "audio/jingle.ogg" } }))
+            .unwrap();
+        i18n.merge_pack("fr", &serde_json::json!({ "_audio": { "intro": "audio/fr/intro.ogg" } })).unwrap();
+        i18n.merge_pack("ja", &serde_json::json!({ "_audio": { "intro": "audio/ja/intro.ogg" } })).unwrap();
+
+        assert_eq!(i18n.audio_lang(), "fr");
+        assert_eq!(i18n.audio("intro"), Some("audio/fr/intro.ogg"));
+        assert_eq!(i18n.audio("jingle"), Some("audio/jingle.ogg"));
+        assert_eq!(i18n.audio("outro"), None);
+
+        i18n.try_set_audio_lang(Some("ja")).unwrap();
+        assert_eq!(i18n.audio("intro"), Some("audio/ja/intro.ogg"));
+        assert_eq!(i18n.audio("jingle"), Some("audio/jingle.ogg"));
+        assert_eq!(i18n.try_set_audio_lang(Some("xx")), Err(I18nError::LocaleNotFound("xx".into())));
+        assert_eq!(i18n.audio_lang(), "ja");
+
+        i18n.try_set_audio_lang(None).unwrap();
+        assert_eq!(i18n.audio("intro"), Some("audio/fr/intro.ogg"));
+
+        // Regional voices fall back to their parent locale first.
+        i18n.merge_pack("fr-CA", &serde_json::json!({ "_audio": { "jingle": "audio/fr-CA/jingle.ogg" } })).unwrap();
+        i18n.try_set_audio_lang(Some("fr-CA")).unwrap();
+        assert_eq!(i18n.audio("intro"), Some("audio/fr/intro.ogg"));
+        assert_eq!(i18n.audio("jingle"), Some("audio/fr-CA/jingle.ogg"));
+    }
+
+    #[test]
+    fn nested_groups_are_read_with_dotted_keys() {
+        let mut i18n = make_i18n("fr", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({ "ui": { "menu": {
+            "title": "Menu",
+            "counts": { "items": { "one": "{{count}} item", "other": "{{count}} items" }, "coins": { "other": "{{count}} coins" } },
+            "settings": {
+                "audio": { "volume": "Volume", "mute": "Mute" },
+                "video": { "title": "Video", "title@max": 12, "fps": { "one": "{{count}} frame", "other": "{{count}} frames" } }
+            }
+        } } }))
+        .unwrap();
+        i18n.merge_pack("fr", &serde_json::json!({ "ui": { "menu": {
+            "title": "Menu",
+            "settings": { "audio": { "volume": "Volume sonore" }, "video": { "title": "Vidéo" } }
+        } } }))
+        .unwrap();
+
+        let t = i18n.translation("ui");
+        assert_eq!(t.t("menu.title"), "Menu");
+        assert_eq!(t.t("menu.settings.audio.volume"), "Volume sonore");
+        assert_eq!(t.t("menu.settings.audio.mute"), "Mute");
+        assert_eq!(t.t("menu.settings.video.title"), "Vidéo");
+        assert_eq!(t.t_with_plural("menu.settings.video.fps", 30), "30 frames");
+        assert_eq!(t.t_with_plural("menu.counts.items", 1), "1 item");
+        assert_eq!(t.max_len("menu.settings.video.title"), Some(12));
+        assert_eq!(
+            t.try_t("menu.settings.audio.music"),
+            Err(I18nError::MissingKey { file: "ui".into(), key: "menu.settings.audio.music".into() })
+        );
+
+        let deep = serde_json::json!({ "ui": { "menu": { "a": { "b": { "c": 1 } } } } });
+        assert!(matches!(
+            i18n.merge_pack("fr", &deep),
+            Err(I18nError::InvalidPackFile { reason, .. }) if reason.contains("'menu.a.b'")
+        ));
+
+        // Plural, gender and register keys make a map, unless `"@group"`
+        // says otherwise.
+        let ambiguous = serde_json::json!({ "ui": { "difficulty": { "other": "Custom", "modes": { "easy": "Easy" } } } });
+        assert!(matches!(
+            i18n.merge_pack("en", &ambiguous),
+            Err(I18nError::InvalidPackFile { reason, .. }) if reason.contains("'difficulty'")
+        ));
+        let marked = serde_json::json!({ "ui": { "difficulty": { "@group": true, "other": "Custom", "modes": { "easy": "Easy" } } } });
+        i18n.merge_pack("en", &marked).unwrap();
+        let t = i18n.translation("ui");
+        assert_eq!(t.t("difficulty.other"), "Custom");
+        assert_eq!(t.t("difficulty.modes.easy"), "Easy");
+        assert!(!i18n.translations.langs["en"]["ui"].contains_key("difficulty.@group"));
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn explain_traces_languages_branches_and_placeholders() {
+        let mut i18n = make_i18n("fr", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({ "ui": {
+            "items": { "one": "{{count}} item", "other": "{{count}} items" },
+            "hello": "Hello {{name}}, {{title|heading}}"
+        } }))
+        .unwrap();
+        i18n.merge_pack("fr", &serde_json::json!({ "ui": { "items": "objets" } })).unwrap();
+
+        let explanation = i18n.explain("ui", "items", Explain::Plural(3));
+        let outcomes: Vec<_> = explanation.steps.iter().map(|step| (step.lang.as_str(), step.outcome)).collect();
+        assert_eq!(outcomes, [("fr", StepOutcome::NoMatch), ("en", StepOutcome::Found)]);
+        assert_eq!(explanation.served_by.as_deref(), Some("en"));
+        assert_eq!(explanation.branch.as_deref(), Some("other"));
+        assert_eq!(explanation.placeholders, [("count".to_string(), Some("3".to_string()))]);
+        assert_eq!(explanation.text, "3 items");
+        assert_eq!(
+            explanation.to_string(),
+            "ui.items (plural 3) → \"3 items\"\n  fr: no match for this lookup\n  en: found, branch \"other\"\n  \
+             template: \"{{count}} items\"\n  {{count}} = \"3\"\n"
+        );
+
+        let explanation = i18n.explain("ui", "hello", Explain::Args(i18n_args! { name = "Ana" }));
+        assert_eq!(explanation.steps[0].outcome, StepOutcome::NoKey);
+        assert_eq!(
+            explanation.placeholders,
+            [("name".to_string(), Some("Ana".to_string())), ("title".to_string(), None)]
+        );
+        assert_eq!(explanation.text, "Hello Ana, {{title|heading}}");
+
+        let explanation = i18n.explain("hud", "hp", Explain::Text);
+        assert!(explanation.steps.iter().all(|step| step.outcome == StepOutcome::NoFile));
+        assert_eq!((explanation.served_by, explanation.text.as_str()), (None, "Traduction manquante"));
+        // Replaying lookups logs nothing.
+        assert!(!logs_contain("not found") && !logs_contain("placeholder"));
+    }
+
+    #[test]
+    #[cfg(feature = "yaml")]
+    fn yaml_parses_like_the_equivalent_json() {
+        let yaml = r#"
+# comment
+greeting: Hello # trailing comment
+"quoted key": 'It''s {{name}}'
+escaped: "Tab\there\u00e9"
+apples:
+  one: "{{count}} apple"
+  other: "{{count}} apples"
+folded: >-
+  one
+  line
+
+  two
+button@max: 12
+empty:
+list:
+- a
+- "b"
+"#;
+        assert_eq!(
+            parse_yaml(yaml).unwrap(),
+            serde_json::json!({
+                "greeting": "Hello",
+                "quoted key": "It's {{name}}",
+                "escaped": "Tab\there\u{e9}",
+                "apples": { "one": "{{count}} apple", "other": "{{count}} apples" },
+                "folded": "one line\ntwo",
+                "button@max": 12,
+                "empty": null,
+                "list": ["a", "b"]
+            })
+        );
+        // Values starting with `{` are flow mappings unless quoted.
+        assert!(parse_yaml("a: {{name}}").is_err());
+        assert_eq!(parse_yaml("a: \"{{name}}\"").unwrap(), serde_json::json!({ "a": "{{name}}" }));
+        assert_eq!(parse_yaml("levels:\n  1: Forest").unwrap(), serde_json::json!({ "levels": { "1": "Forest" } }));
+        assert!(parse_yaml("a: 1\n  b: 2").unwrap_err().contains("line 2"));
+        assert_eq!(parse_yaml("a: 1\na: 2").unwrap_err(), "duplicate entry with key \"a\"");
+        assert!(parse_yaml("a: 'open").unwrap_err().contains("while scanning a quoted scalar"));
+    }
+
+    #[cfg(feature = "fluent")]
+    #[test]
+    fn fluent_files_convert_to_the_json_shape() {
+        let ftl = r#"
+-brand = Dawnforge
+    .gender = feminine
+app-title = { -brand }
+welcome = Welcome to { app-title }, { $name }!
+login = Log in
+    .placeholder = Email
+apples = You have { NUMBER($count) ->
+        [0] no apples
+        [one] one apple
+       *[many] { $count } apples
+    }.
+guests = { $gender ->
+        [male] { $count ->
+            [one] his guest
+           *[other] his { $count } guests
+        }
+       *[female] { $count ->
+            [one] her guest
+           *[other] her { $count } guests
+        }
+    }
+saved = { -brand.gender ->
+        [feminine] Elle est sauvegardée
+       *[other] Il est sauvegardé
+    }
+quote = { "{" }literal{ "!" }
+"#;
+        assert_eq!(
+            parse_fluent(ftl).unwrap(),
+            serde_json::json!({
+                "app-title": "Dawnforge",
+                "welcome": "Welcome to Dawnforge, {{name}}!",
+                "login": "Log in",
+                "login.placeholder": "Email",
+                "apples": {
+                    "0": "You have no apples.",
+                    "one": "You have one apple.",
+                    "many": "You have {{count}} apples.",
+                    "other": "You have {{count}} apples."
+                },
+                "guests": {
+                    "male": { "one": "his guest", "other": "his {{count}} guests" },
+                    "female": { "one": "her guest", "other": "her {{count}} guests" },
+                    "other": { "one": "her guest", "other": "her {{count}} guests" }
+                },
+                "saved": "Elle est sauvegardée",
+                "quote": "{literal!"
+            })
+        );
+        assert_eq!(parse_fluent("a = { b }\nb = { a }").unwrap_err(), "'a': reference cycle");
+        assert_eq!(parse_fluent("a = { UPPER($x) }").unwrap_err(), "'a': unsupported function 'UPPER'");
+        assert!(parse_fluent("ok = fine\nbroken = {").unwrap_err().starts_with("line 2: "));
+
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({ "ui": parse_fluent(ftl).unwrap() })).unwrap();
+        let ui = i18n.translation("ui");
+        assert_eq!(ui.t_with_plural("apples", 0), "You have no apples.");
+        assert_eq!(ui.t_with_plural("apples", 3), "You have 3 apples.");
+        assert_eq!(ui.t_with_gender_and_plural("guests", "male", 2), "his 2 guests");
+        assert_eq!(ui.t_with_args("welcome", i18n_args! { name = "Ana" }), "Welcome to Dawnforge, Ana!");
+    }
+
+    #[cfg(feature = "gettext")]
+    #[test]
+    fn gettext_catalogs_convert_to_the_json_shape() {
+        let po = r#"
+msgid ""
+msgstr ""
+"Language: ru\n"
+"Plural-Forms: nplurals=3; plural=(n%10==1 && n%100!=11 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2);\n"
+
+#: src/menu.rs:12
+msgctxt "menu"
+msgid "quit"
+msgstr "Выход"
+
+msgid "welcome"
+msgstr ""
+"Добро пожаловать, "
+"{{name}}!\n"
+
+#, fuzzy
+msgid "draft"
+msgstr "Черновик"
+
+msgid "untranslated"
+msgstr ""
+
+msgid "apples"
+msgid_plural "apples"
+msgstr[0] "{{count}} яблоко"
+msgstr[1] "{{count}} яблока"
+msgstr[2] "{{count}} яблок"
+
+#~ msgid "old"
+#~ msgstr "Старый"
+"#;
+        assert_eq!(
+            parse_po(po).unwrap(),
+            serde_json::json!({
+                "menu\u{4}quit": "Выход",
+                "welcome": "Добро пожаловать, {{name}}!\n",
+                "apples": {
+                    "one": "{{count}} яблоко",
+                    "few": "{{count}} яблока",
+                    "many": "{{count}} яблок",
+                    "other": "{{count}} яблок"
+                }
+            })
+        );
+        let plural = "msgid \"a\"\nmsgid_plural \"b\"\nmsgstr[0] \"x\"\nmsgstr[1] \"y\"\n";
+        assert_eq!(parse_po(plural).unwrap_err(), "plural entries need a 'Language' header");
+        assert_eq!(parse_po("msgid \"a\"\nmsgfoo \"b\"").unwrap_err(), "line 2: unknown keyword 'msgfoo'");
+
+        // A little-endian .mo file; English puts 0 in "other", this formula
+        // does not.
+        let entries = [
+            ("", "Language: en\nPlural-Forms: nplurals=2; plural=(n > 1);\n"),
+            ("menu\u{4}quit", "Leave"),
+            ("apple\0apples", "{{count}} apple\0{{count}} apples"),
+        ];
+        let count = entries.len() as u32;
+        let mut mo = [0x950412de, 0, count, 28, 28 + 8 * count, 0, 0].map(u32::to_le_bytes).concat();
+        let mut strings = Vec::new();
+        for text in entries.iter().map(|(original, _)| original).chain(entries.iter().map(|(_, translation)| translation)) {
+            let offset = 28 + 16 * count + strings.len() as u32;
+            mo.extend([text.len() as u32, offset].map(u32::to_le_bytes).concat());
+            strings.extend(text.as_bytes());
+            strings.push(0);
+        }
+        mo.extend(strings);
+        assert_eq!(
+            parse_mo(&mo).unwrap(),
+            serde_json::json!({
+                "menu\u{4}quit": "Leave",
+                "apple": { "one": "{{count}} apple", "other": "{{count}} apples", "0": "{{count}} apple" }
+            })
+        );
+        assert_eq!(parse_mo(b"nope").unwrap_err(), "not a .mo file");
+
+        let mut i18n = make_i18n("ru", "en", LangMap::new());
+        i18n.merge_pack("ru", &serde_json::json!({ "ui": parse_po(po).unwrap() })).unwrap();
+        i18n.merge_pack("en", &serde_json::json!({ "ui": parse_mo(&mo).unwrap() })).unwrap();
+        assert_eq!(i18n.translation("ui").t_with_plural("apples", 22), "22 яблока");
+        assert_eq!(i18n.translation("ui").t(&gettext_key("menu", "quit")), "Выход");
+        i18n.set_lang("en");
+        assert_eq!(i18n.translation("ui").t_with_plural("apple", 0), "0 apple");
+        assert_eq!(i18n.translation("ui").t_with_plural("apple", 2), "2 apples");
+    }
+
+    #[cfg(feature = "gettext")]
+    #[test]
+    #[tracing_test::traced_test]
+    fn gettext_msgids_keep_at_signs_and_dots() {
+        let po = r#"
+msgid "Contact support@example.com"
+msgstr "Écrivez à support@example.com"
+
+msgid "Loading..."
+msgstr "Chargement…"
+
+msgid "menu.quit"
+msgstr "Quitter le menu"
+
+msgctxt "menu"
+msgid "quit"
+msgstr "Quitter"
+"#;
+        let mut i18n = make_i18n("fr", "fr", LangMap::new());
+        i18n.merge_pack("fr", &serde_json::json!({ "ui": parse_po(po).unwrap() })).unwrap();
+        let t = i18n.translation("ui");
+        assert_eq!(t.t("Contact support@example.com"), "Écrivez à support@example.com");
+        assert_eq!(t.t("Loading..."), "Chargement…");
+        assert_eq!(t.t("menu.quit"), "Quitter le menu");
+        assert_eq!(t.t(&gettext_key("menu", "quit")), "Quitter");
+        assert!(!i18n.translations.annotations.contains_key("ui"));
+        assert!(!logs_contain("no known annotation"));
+    }
+
+    #[test]
+    fn key_macro_accepts_the_keys_of_the_default_language() {
+        let bundle = serde_json::json!({
+            "en": {
+                "ui": {
+                    "title": "Dawnforge",
+                    "title@max": 12,
+                    "items": { "one": "an item", "other": "{{count}} items" },
+                    "greeting": { "casual": "Hey", "polite": "Hello" },
+                    "menu": { "quit": "Quit", "settings": { "audio": "Audio" } },
+                    "support@example.com": "Mail us",
+                    "credits": { "@file": "credits.txt", "@text": "…" },
+                    "lines": ["a", "b"]
+                },
+                "_glossary": { "term": "Dawnforge" },
+                "_images": { "logo": "images/logo.png" }
+            },
+            "fr": { "ui": { "french_only": "Seulement en français" } }
+        });
+        let source = key_macro::key_macro(&bundle, "en");
+        let arms: Vec<&str> = source.lines().filter(|line| line.trim_start().starts_with("(\"")).collect();
+        // Plural maps and groups only hold keys; register maps read as text.
+        let keys = [
+            "greeting",
+            "greeting.casual",
+            "greeting.polite",
+            "items.one",
+            "items.other",
+            "menu.quit",
+            "menu.settings.audio",
+            "support@example.com",
+            "title",
+        ];
+        assert_eq!(arms.len(), keys.len(), "{}", source);
+        for (arm, key) in arms.iter().zip(keys) {
+            assert!(arm.contains(&format!("(\"ui\", \"{key}\") => {{ $crate::CheckedKey::new(\"ui\", \"{key}\") }}")), "{}", arm);
+        }
+        assert!(source.contains("of the 'en' messages"));
+        assert!(key_macro::key_macro(&serde_json::Value::Null, "en").contains("macro_rules! t {"));
+        let registers = [SpeechRegister::Casual, SpeechRegister::Polite, SpeechRegister::Formal].map(SpeechRegister::key);
+        assert_eq!(key_macro::REGISTER_KEYS, registers);
+    }
+
+    #[test]
+    fn key_enums_name_every_key() {
+        let bundle = serde_json::json!({
+            "en": {
+                "ui": {
+                    "welcome": "Welcome",
+                    "menu": { "quit": "Quit" },
+                    "items": { "one": "an item", "other": "{{count}} items" },
+                    "404": "Not found",
+                    "title@max": 12
+                },
+                "item-names": { "sword": "Sword" },
+                "_glossary": { "term": "Dawnforge" }
+            }
+        });
+        let source = key_macro::key_enums(&bundle, "en").unwrap();
+        assert!(source.contains("pub enum ItemNamesKey {"), "{}", source);
+        assert!(source.contains("pub enum UiKey {"), "{}", source);
+        for arm in [
+            "Self::ItemsOne => \"items.one\",",
+            "Self::K404 => \"404\",",
+            "Self::MenuQuit => \"menu.quit\",",
+            "Self::Welcome => \"welcome\",",
+        ] {
+            assert!(source.contains(arm), "{} not in {}", arm, source);
+        }
+        assert!(!source.contains("Self::Menu =>") && !source.contains("Self::Items =>"), "{}", source);
+        assert!(source.contains("const FILE: &'static str = \"item-names\";"));
+        assert!(!source.contains("Glossary"));
+        assert_eq!(key_macro::key_enums(&serde_json::Value::Null, "en").as_deref(), Ok(""));
+    }
+
+    #[cfg(feature = "typed-keys")]
+    #[test]
+    fn typed_keys_are_looked_up_in_their_file() {
+        #[derive(Clone, Copy)]
+        struct Quit;
+        impl TypedKey for Quit {
+            const FILE: &'static str = "menu";
+
+            fn key(self) -> &'static str {
+                "quit"
+            }
+        }
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({ "menu": { "quit": "Quit" }, "ui": { "quit": "Leave" } })).unwrap();
+        assert_eq!(i18n.tk(Quit), "Quit");
+        assert_eq!(i18n.translation("ui").tk(Quit), "Leave");
+    }
+
+    #[test]
+    fn key_enums_fail_on_clashing_names() {
+        let keys = serde_json::json!({ "en": { "ui": { "menu": { "quit": "Quit" }, "menu_quit": "Quit the menu" } } });
+        let error = key_macro::key_enums(&keys, "en").unwrap_err();
+        assert!(error.contains("'menu.quit'") && error.contains("'menu_quit'") && error.contains("`MenuQuit`"), "{}", error);
+        let files = serde_json::json!({ "en": { "item-names": { "a": "A" }, "item_names": { "b": "B" } } });
+        assert!(key_macro::key_enums(&files, "en").unwrap_err().contains("`ItemNamesKey`"));
+    }
+
+    #[test]
+    fn user_terms_fill_placeholders_with_their_grammar() {
+        let mut i18n = make_i18n("ru", "ru", LangMap::new());
+        i18n.merge_pack("ru", &serde_json::json!({ "events": {
+            "celebrate": "Жители {{city.genitive}} празднуют",
+            "taken": "{{city}} {{city.gender, select, f{взята} other{взят}}}",
+        }}))
+        .unwrap();
+        let dictionary = i18n.user_dictionary_mut();
+        dictionary.insert("city_12", UserTerm::new("Тверь").with("gender", "f").with("genitive", "Твери"));
+        dictionary.insert("city_13", UserTerm::new("Орёл"));
+
+        assert_eq!(i18n.t_with_terms("events", "celebrate", &[("city", "city_12")]), "Жители Твери празднуют");
+        assert_eq!(i18n.t_with_terms("events", "taken", &[("city", "city_12")]), "Тверь взята");
+        // Skipped grammar: case forms read as the text, selects take `other`.
+        assert_eq!(i18n.t_with_terms("events", "celebrate", &[("city", "city_13")]), "Жители Орёл празднуют");
+        assert_eq!(i18n.t_with_terms("events", "taken", &[("city", "city_13")]), "Орёл взят");
+        assert_eq!(i18n.t_with_terms("events", "celebrate", &[("city", "nope")]), "Жители {{city.genitive}} празднуют");
+
+        let mut store = MemoryPackStore::default();
+        i18n.user_dictionary().save(&mut store);
+        let saved: serde_json::Value = serde_json::from_str(&store.get_item("bevy-intl/dictionary").unwrap()).unwrap();
+        assert_eq!(saved["city_13"], serde_json::json!({ "text": "Орёл" }));
+        assert_eq!(UserDictionary::load(&store), *i18n.user_dictionary());
+        assert!(UserDictionary::load(&MemoryPackStore::default()).is_empty());
+    }
+
+    #[test]
+    fn iter_lists_every_loaded_translation() {
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({ "ui": { "ok": "OK", "apples": { "one": "an apple", "other": "apples" } } }))
+            .unwrap();
+        i18n.merge_pack("fr", &serde_json::json!({ "ui": { "ok": "D'accord" }, "_glossary": {} })).unwrap();
+
+        let mut entries: Vec<(&str, &str, &str, Vec<&str>)> = i18n
+            .iter()
+            .map(|(lang, file, key, value)| {
+                let mut strings = value.strings();
+                strings.sort();
+                (lang, file, key, strings)
+            })
+            .collect();
+        entries.sort();
+        assert_eq!(
+            entries,
+            [
+                ("en", "ui", "apples", vec!["an apple", "apples"]),
+                ("en", "ui", "ok", vec!["OK"]),
+                ("fr", "ui", "ok", vec!["D'accord"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn translation_keys_resolve_inside_arguments() {
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({
+            "hud": { "status_line": "You are {{status}}" },
+            "status": { "poisoned": "poisoned" }
+        }))
+        .unwrap();
+        i18n.merge_pack("fr", &serde_json::json!({
+            "hud": { "status_line": "Vous êtes {{status}}" },
+            "status": { "poisoned": "empoisonné" }
+        }))
+        .unwrap();
+
+        let line = |i18n: &I18n| {
+            i18n.translation("hud").t_with_args("status_line", i18n_args! { status = i18n.key("status", "poisoned") })
+        };
+        assert_eq!(line(&i18n), "You are poisoned");
+        i18n.set_lang("fr");
+        assert_eq!(line(&i18n), "Vous êtes empoisonné");
+        assert_eq!(format!("{:>4}", i18n.key("status", "missing")), "Traduction manquante");
+    }
+
+    #[test]
+    #[cfg(feature = "formatting")]
+    fn headings_follow_the_capitalization_of_the_language() {
+        assert_eq!(HeadingCase::Title.apply("return to the main menu"), "Return to the Main Menu");
+        assert_eq!(HeadingCase::Title.apply("the lord Of the rings"), "The Lord of the Rings");
+        assert_eq!(HeadingCase::Title.apply("buy an iPhone for NPCs"), "Buy an iPhone for NPCs");
+        assert_eq!(HeadingCase::Sentence.apply("« retour au menu principal"), "« Retour au menu principal");
+
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({ "ui": { "title": "{{s|heading}}", "odd": "{{s|shout}}" } })).unwrap();
+        i18n.merge_pack("fr", &serde_json::json!({ "ui": { "title": "{{s|heading}}" } })).unwrap();
+        let title = |i18n: &I18n| i18n.translation("ui").t_with_args("title", i18n_args! { s = "sword of the dawn" });
+        assert_eq!(title(&i18n), "Sword of the Dawn");
+        assert_eq!(i18n.translation("ui").t_with_args("odd", i18n_args! { s = "hey" }), "hey");
+        assert_eq!(i18n.translation("ui").t("title"), "{{s|heading}}");
+        i18n.set_lang("fr");
+        assert_eq!(title(&i18n), "Sword of the dawn");
+        assert_eq!(i18n.heading_case("épée de l'aube"), "Épée de l'aube");
+        assert_eq!(format_template("{{s|heading}}", |_| Some("a b".into())), "a b");
+    }
+
+    #[test]
+    fn strings_copied_from_the_source_count_as_untranslated() {
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        i18n.source_copies = SourceCopyPolicy::Untranslated;
+        i18n.merge_pack("en", &serde_json::json!({ "ui": { "play": "Play", "quit": "Quit", "score": "{{count}}" } }))
+            .unwrap();
+        i18n.merge_pack("fr", &serde_json::json!({ "ui": { "play": "Jouer", "quit": "Quit", "score": "{{count}}" } }))
+            .unwrap();
+        i18n.merge_pack("en-GB", &serde_json::json!({ "ui": { "play": "Play", "quit": "Quit", "score": "{{count}}" } }))
+            .unwrap();
+
+        assert!(i18n.is_source_copy("fr", "ui", "quit"));
+        assert!(!i18n.is_source_copy("fr", "ui", "score"));
+        assert_eq!(i18n.source_copies("fr"), ["ui.quit"]);
+        assert!(i18n.source_copies("en-GB").is_empty());
+        assert!((i18n.completeness("fr") - 2.0 / 3.0).abs() < 1e-6);
+        assert_eq!(i18n.completeness("en-GB"), 1.0);
+
+        i18n.source_copies = SourceCopyPolicy::Translated;
+        assert_eq!(i18n.completeness("fr"), 1.0);
+
+        let mut translations = (*i18n.translations).clone();
+        qa::drop_source_copies(&mut translations, "en");
+        assert!(!translations.langs["fr"]["ui"].contains_key("quit"));
+        assert!(translations.langs["fr"]["ui"].contains_key("score"));
+        assert!(translations.langs["en-GB"]["ui"].contains_key("quit"));
+    }
+
+    #[test]
+    fn same_ok_keys_are_not_source_copies() {
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        i18n.merge_pack("en", &serde_json::json!({ "ui": { "ok": "OK", "ok@same_ok": true, "quit": "Quit", "boom": "Boom" } }))
+            .unwrap();
+        i18n.merge_pack("fr", &serde_json::json!({ "ui": { "ok": "OK", "quit": "Quit", "boom": "Boom", "boom@same_ok": true } }))
+            .unwrap();
+
+        assert_eq!(i18n.source_copies("fr"), ["ui.quit"]);
+        assert!(!i18n.is_source_copy("fr", "ui", "ok"));
+        assert!(i18n.translations.annotations["ui"]["boom"].same_ok);
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn keys_with_an_unknown_attribute_after_at_load_as_text() {
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        let pack = serde_json::json!({ "ui": {
+            "mail@support": "Write to us",
+            "title": "Dawnforge",
+            "title@max": 12,
+            "menu": { "help@faq": "FAQ", "quit": "Quit" }
+        } });
+        i18n.merge_pack("en", &pack).unwrap();
+
+        let t = i18n.translation("ui");
+        assert_eq!(t.t("mail@support"), "Write to us");
+        assert_eq!(t.t("menu.help@faq"), "FAQ");
+        assert_eq!(i18n.translations.annotations["ui"]["title"].max_len, Some(12));
+        assert!(!i18n.translations.annotations["ui"].contains_key("mail"));
+        assert!(logs_contain("'mail@support' in 'ui' has no known annotation '@support'"));
+    }
+
+    #[test]
+    fn search_ignores_case_and_diacritics_the_way_the_language_does() {
+        let mut i18n = make_i18n("en", "en", LangMap::new());
+        assert!(i18n.matches("pokemon", "Pokémon"));
+        assert!(i18n.matches("POKÉ", "pokemon"));
+        assert!(i18n.matches("strasse", "Hauptstraße"));
+        assert!(i18n.matches("encyclopaedia", "Encyclopædia"));
+        assert!(i18n.matches("cafe", "Cafe\u{301}"));
+        assert!(i18n.matches("", "anything"));
+        assert!(!i18n.matches("potion", "Pokémon"));
+
+        i18n.current_lang = "sv".to_string();
+        assert!(!i18n.matches("hall", "Häll"));
+        assert!(i18n.matches("hall", "Hall"));
+        assert!(i18n.matches("pokemon", "Pokémon"));
+
+        i18n.current_lang = "tr".to_string();
+        assert_eq!(i18n.search_key("İSTANBUL"), "istanbul");
+        assert!(!i18n.matches("istanbul", "ISTANBUL"));
+        assert!(i18n.matches("ıstanbul", "ISTANBUL"));
+    }
+
+    #[test]
+    fn available_languages_sort_by_their_own_name() {
+        let mut langs = LangMap::new();
+        for lang in ["ja", "fr", "en", "de"] {
+            langs.insert(lang.to_string(), FileMap::new());
+        }
+        let mut i18n = make_i18n("en", "en", langs);
+        i18n.merge_pack("dwarvish", &serde_json::json!({ "_meta": { "name": "Khuzdul" } })).unwrap();
+        i18n.merge_pack("pt-BR", &serde_json::json!({ "ui": { "ok": "OK" } })).unwrap();
+
+        assert_eq!(i18n.language_name("fr"), "Français");
+        assert_eq!(i18n.language_name("dwarvish"), "Khuzdul");
+        assert_eq!(i18n.language_name("pt-BR"), "Português (Brasil)");
+        assert_eq!(i18n.language_name("tlh"), "tlh");
+        assert_eq!(i18n.available_languages_sorted(), ["de", "en", "fr", "dwarvish", "pt-BR", "ja"]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "fs-loader", not(target_arch = "wasm32")))]
+    fn json_files_are_streamed_entry_by_entry() {
+        let temp = tempfile::tempdir().unwrap();
+        let en = temp.path().join("en");
+        std::fs::create_dir_all(&en).unwrap();
+        std::fs::write(en.join("credits.txt"), "Made by us").unwrap();
+        let dialogue = r#"{
+            "intro": "Hello",
+            "apples": { "one": "{{count}} apple", "other": "{{count}} apples" },
+            "guard@gender": "f",
+            "intro@max": 5,
+            "lines": ["a", "b"],
+            "credits": { "@file": "credits.txt" }
+        }"#;
+        std::fs::write(en.join("dialogue.json"), dialogue).unwrap();
+        std::fs::write(en.join("broken.json"), r#"{ "a": "b" "#).unwrap();
+
+        let mut translations = Translations::default();
+        stream::load_json_file(&mut translations, "en", "dialogue", &en.join("dialogue.json")).unwrap();
+        assert!(matches!(&translations.langs["en"]["dialogue"]["intro"], SectionValue::Text(t) if t == "Hello"));
+        assert!(matches!(translations.langs["en"]["dialogue"]["apples"], SectionValue::Map(_)));
+        assert_eq!(translations.genders["en"]["dialogue"]["guard"], "f");
+        assert_eq!(translations.annotations["dialogue"]["intro"].max_len, Some(5));
+        assert_eq!(translations.raw["en"]["dialogue"]["lines"], serde_json::json!(["a", "b"]));
+        assert_eq!(translations.documents["en"]["dialogue"]["credits"], "Made by us");
+
+        let error = stream::load_json_file(&mut translations, "en", "broken", &en.join("broken.json")).unwrap_err();
+        assert!(error.to_string().starts_with("en/broken.json: "));
+        assert!(!translations.langs["en"].contains_key("broken"));
+    }
 }
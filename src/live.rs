@@ -0,0 +1,168 @@
+//! Live translation sessions (`live-edit` feature, desktop dev builds).
+//!
+//! [`LiveEditPlugin`] connects to a WebSocket on the translator's machine —
+//! typically the server behind a browser-based editing page — and applies
+//! every string edit pushed through it, so translators see their work in the
+//! running game during review sessions. Each text frame holds one edit, or an
+//! array of edits:
+//!
+//! ```json
+//! { "lang": "fr", "file": "ui", "key": "play", "value": "Jouer" }
+//! ```
+//!
+//! `value` takes any shape a translation file accepts (a string, a plural
+//! map…). Edits are applied as [`I18nCommand::MergePack`]s, so labels refresh
+//! at once and malformed values are rejected like any language pack. They
+//! only live in memory: saving them to the translation files is the editing
+//! page's job.
+//!
+//! The connection is retried every few seconds, so the game and the editor
+//! can be started in any order. Enable the feature for development builds
+//! only:
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_intl::{I18nPlugin, LiveEditPlugin};
+//!
+//! App::new()
+//!     .add_plugins(DefaultPlugins)
+//!     .add_plugins(I18nPlugin::default())
+//!     .add_plugins(LiveEditPlugin { url: "ws://127.0.0.1:9470".into() })
+//!     .run();
+//! ```
+
+use std::sync::Mutex;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+use serde_json::Value;
+use tracing::{info, warn};
+use tungstenite::Message as Frame;
+
+use crate::{I18nCommand, apply_i18n_commands};
+
+/// Delay between connection attempts.
+const RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// One string edit pushed by the editing page, see the [module docs](self).
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct LiveEdit {
+    pub lang: String,
+    pub file: String,
+    pub key: String,
+    pub value: Value,
+}
+
+impl LiveEdit {
+    /// The edits of a text frame: one edit or an array of edits.
+    pub fn parse(frame: &str) -> Result<Vec<LiveEdit>, String> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Edits {
+            One(LiveEdit),
+            Many(Vec<LiveEdit>),
+        }
+        match serde_json::from_str(frame).map_err(|e| e.to_string())? {
+            Edits::One(edit) => Ok(vec![edit]),
+            Edits::Many(edits) => Ok(edits),
+        }
+    }
+
+    /// The language pack [`I18nCommand::MergePack`] applies.
+    pub fn into_command(self) -> I18nCommand {
+        let mut file = serde_json::Map::new();
+        file.insert(self.key, self.value);
+        let mut pack = serde_json::Map::new();
+        pack.insert(self.file, Value::Object(file));
+        I18nCommand::MergePack { lang: self.lang, pack: Value::Object(pack) }
+    }
+}
+
+/// Applies string edits received from a WebSocket, see the
+/// [module docs](self). Add it after [`I18nPlugin`](crate::I18nPlugin).
+#[derive(Debug, Clone)]
+pub struct LiveEditPlugin {
+    /// Default: `"ws://127.0.0.1:9470"`.
+    pub url: String,
+}
+
+impl Default for LiveEditPlugin {
+    fn default() -> Self {
+        Self { url: "ws://127.0.0.1:9470".to_string() }
+    }
+}
+
+impl Plugin for LiveEditPlugin {
+    fn build(&self, app: &mut App) {
+        let (sender, receiver) = mpsc::channel();
+        let url = self.url.clone();
+        thread::Builder::new()
+            .name("bevy-intl-live-edit".to_string())
+            .spawn(move || receive_edits(&url, &sender))
+            .expect("failed to spawn the live edit thread");
+        app.insert_resource(LiveEditReceiver(Mutex::new(receiver)))
+            .add_message::<I18nCommand>()
+            .add_systems(Update, forward_live_edits.before(apply_i18n_commands));
+    }
+}
+
+/// Edits received by the connection thread.
+#[derive(Resource)]
+struct LiveEditReceiver(Mutex<Receiver<LiveEdit>>);
+
+/// Turns the received edits into [`I18nCommand`]s.
+fn forward_live_edits(receiver: Res<LiveEditReceiver>, mut commands: MessageWriter<I18nCommand>) {
+    let receiver = receiver.0.lock().unwrap_or_else(|e| e.into_inner());
+    for edit in receiver.try_iter() {
+        info!("live edit: [{}] {}.{}", edit.lang, edit.file, edit.key);
+        commands.write(edit.into_command());
+    }
+}
+
+/// Connects to `url` and forwards its edits until the app is gone,
+/// reconnecting whenever the connection is lost.
+fn receive_edits(url: &str, sender: &Sender<LiveEdit>) {
+    let mut reported = false;
+    loop {
+        let mut socket = match tungstenite::connect(url) {
+            Ok((socket, _)) => socket,
+            Err(e) => {
+                if !reported {
+                    warn!("live edit: cannot connect to {} ({}); retrying", url, e);
+                    reported = true;
+                }
+                thread::sleep(RETRY_DELAY);
+                continue;
+            }
+        };
+        info!("live edit: connected to {}", url);
+        reported = false;
+        loop {
+            let text = match socket.read() {
+                Ok(Frame::Text(text)) => text,
+                Ok(_) => continue,
+                Err(e) => {
+                    warn!("live edit: connection to {} lost ({}); reconnecting", url, e);
+                    break;
+                }
+            };
+            let edits = match LiveEdit::parse(text.as_str()) {
+                Ok(edits) => edits,
+                Err(e) => {
+                    warn!("live edit: ignoring malformed frame: {}", e);
+                    continue;
+                }
+            };
+            for edit in edits {
+                if sender.send(edit).is_err() {
+                    // The app has exited.
+                    return;
+                }
+            }
+        }
+        thread::sleep(RETRY_DELAY);
+    }
+}
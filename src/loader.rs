@@ -0,0 +1,629 @@
+//! Loading translations from the messages folder, the bundled data and
+//! overlays into a [`Translations`] store.
+
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+use tracing::warn;
+
+#[cfg(all(any(feature = "fs-loader", feature = "tools"), not(target_arch = "wasm32")))]
+use crate::overlay;
+#[cfg(all(feature = "fs-loader", not(target_arch = "wasm32")))]
+use crate::stream;
+use crate::reserved::{GROUP_MARKER, KEY_ATTRIBUTES, is_asset_manifest, is_translation_file, split_annotation};
+use crate::store::{DocumentMap, GenderMap, RawSectionMap, SectionMap, is_key_group, parse_section_value};
+use crate::{I18nDiagnostic, SectionValue, TranslationFormats, Translations, filter, glossary, keys, schema, sentinel, subkey};
+
+// Environment variable naming the overlay folder when `I18nConfig::overlay`
+// is not set; `build.rs` reads it too, to bundle the overlay.
+pub(crate) const OVERLAY_ENV: &str = "BEVY_INTL_OVERLAY";
+
+// Loading from filesystem (dev/desktop mode). When the folder cannot be read
+// (e.g. deleted by the player), the bundled translations are used if any.
+#[cfg(all(feature = "fs-loader", not(target_arch = "wasm32")))]
+pub(crate) fn load_filesystem_translations(
+    messages_folder: &str,
+    formats: &TranslationFormats,
+    diagnostics: &mut Vec<I18nDiagnostic>,
+) -> (Translations, Vec<String>) {
+    load_filesystem_translations_or(messages_folder, formats, || load_bundled_data().ok(), diagnostics)
+}
+
+#[cfg(all(feature = "fs-loader", not(target_arch = "wasm32")))]
+pub(crate) fn load_filesystem_translations_or(
+    messages_folder: &str,
+    formats: &TranslationFormats,
+    bundle: impl FnOnce() -> Option<Translations>,
+    diagnostics: &mut Vec<I18nDiagnostic>,
+) -> (Translations, Vec<String>) {
+    match load_translation_from_fs(messages_folder, formats) {
+        Ok(translations) => build_translations(translations),
+        Err(e) => {
+            if let Some(bundled) = bundle().filter(|bundled| !bundled.langs.is_empty()) {
+                warn!(
+                    "Failed to load translations from '{}': {}; using the bundled translations",
+                    messages_folder, e
+                );
+                diagnostics.push(I18nDiagnostic::BundledFallback {
+                    messages_folder: messages_folder.to_string(),
+                    error: e.to_string(),
+                });
+                return build_translations(bundled);
+            }
+            warn!("Failed to load translations from '{}': {}", messages_folder, e);
+            create_error_translations()
+        }
+    }
+}
+
+#[cfg(any(not(feature = "fs-loader"), target_arch = "wasm32"))]
+pub(crate) fn load_filesystem_translations(
+    _messages_folder: &str,
+    _formats: &TranslationFormats,
+    _diagnostics: &mut Vec<I18nDiagnostic>,
+) -> (Translations, Vec<String>) {
+    // Filesystem loading is unavailable on WASM and without the `fs-loader`
+    // feature. Returning error_translations here (rather than calling
+    // load_bundled_translations) avoids the infinite recursion that would
+    // occur if bundled data is also empty.
+    warn!("Filesystem loading not available on WASM or without the fs-loader feature");
+    create_error_translations()
+}
+
+// Loading from bundled translations (bundled at build time)
+#[cfg_attr(any(not(feature = "fs-loader"), target_arch = "wasm32"), allow(unused_variables, clippy::ptr_arg))]
+pub(crate) fn load_bundled_translations(
+    formats: &TranslationFormats,
+    diagnostics: &mut Vec<I18nDiagnostic>,
+) -> (Translations, Vec<String>) {
+    match load_bundled_data() {
+        Ok(translations) => {
+            if translations.langs.is_empty() {
+                #[cfg(all(feature = "fs-loader", not(target_arch = "wasm32")))]
+                {
+                    warn!("Bundled translations empty, falling back to filesystem");
+                    return load_filesystem_translations("messages", formats, diagnostics);
+                }
+                #[cfg(any(not(feature = "fs-loader"), target_arch = "wasm32"))]
+                {
+                    warn!("Bundled translations empty, and no filesystem to fall back to");
+                    return create_error_translations();
+                }
+            }
+            build_translations(translations)
+        }
+        Err(e) => {
+            warn!("Failed to load bundled translations: {}", e);
+            create_error_translations()
+        }
+    }
+}
+
+// Shared helper returning the Translations struct + sorted locale list
+fn build_translations(translations: Translations) -> (Translations, Vec<String>) {
+    let mut locale_list: Vec<String> = translations.langs.keys().cloned().collect();
+    locale_list.sort();
+    (translations, locale_list)
+}
+
+// Checks that `merge_pack` can store a file completely: an object whose map
+// values hold strings (one level, or two for gender × plural). Reserved
+// `_`-prefixed files are free-form.
+pub(crate) fn validate_pack_file(json: &Value) -> Result<(), String> {
+    let Some(entries) = json.as_object() else {
+        return Err("must be a JSON object of translations".to_string());
+    };
+    validate_entries(entries, "")
+}
+
+// Groups of keys are checked like files, `prefix` naming the group.
+fn validate_entries(entries: &Map<String, Value>, prefix: &str) -> Result<(), String> {
+    let is_string_map = |value: &Value| value.as_object().is_some_and(|map| map.values().all(Value::is_string));
+    for (key, value) in entries {
+        let Some(map) = value.as_object() else { continue };
+        if split_annotation(key).is_some() || map.contains_key("@text") {
+            continue;
+        }
+        if is_key_group(map) {
+            validate_entries(map, &format!("{}{}.", prefix, key))?;
+            continue;
+        }
+        let valid = map.values().all(Value::is_string) || map.values().all(is_string_map);
+        if !valid {
+            return Err(format!("has a malformed value for '{}{}'", prefix, key));
+        }
+    }
+    Ok(())
+}
+
+// Shared by every loader and `merge_pack`: store one parsed file under `lang`
+// (keys already loaded for that file are kept unless overridden), routing
+// reserved `_`-prefixed files other than asset manifests to the raw metadata
+// map.
+pub(crate) fn insert_translation_file(translations: &mut Translations, lang: &str, file_name: &str, json: &Value) {
+    if !is_translation_file(file_name) && !is_asset_manifest(file_name) {
+        if file_name == keys::KEYS_FILE {
+            let migrations = keys::parse_key_migrations(json);
+            for (file, file_migrations) in &migrations {
+                translations.former_keys.insert(file.clone(), file_migrations.former_keys());
+            }
+            translations.keys.extend(migrations);
+        }
+        if file_name == glossary::GLOSSARY_FILE {
+            translations.glossaries.insert(lang.to_string(), glossary::parse_glossary(json));
+        }
+        if file_name == filter::FILTER_FILE {
+            match filter::parse_filter(json) {
+                Ok(word_filter) => {
+                    translations.filters.insert(lang.to_string(), word_filter);
+                }
+                Err(e) => {
+                    warn!("Ignoring invalid {}.json of '{}': {}", file_name, lang, e);
+                    translations.filters.remove(lang);
+                }
+            }
+        }
+        translations
+            .meta
+            .entry(lang.to_string())
+            .or_default()
+            .insert(file_name.to_string(), json.clone());
+        return;
+    }
+
+    let mut builder = FileBuilder::new(translations, lang, file_name);
+    if let Some(obj) = json.as_object() {
+        builder.reserve(obj.len());
+        for (key, value) in obj {
+            builder.insert(key.clone(), value.clone());
+        }
+    }
+    builder.finish();
+}
+
+// Builds one translation file entry by entry, so large files can be stored
+// while they are parsed (see `stream`). Entries are moved, not copied.
+pub(crate) struct FileBuilder<'a> {
+    translations: &'a mut Translations,
+    lang: &'a str,
+    file_name: &'a str,
+    sections: SectionMap,
+    raw: RawSectionMap,
+    documents: DocumentMap,
+    genders: GenderMap,
+    // `"@schema"` of the file, 1 until it is read.
+    schema: u64,
+}
+
+impl<'a> FileBuilder<'a> {
+    pub(crate) fn new(translations: &'a mut Translations, lang: &'a str, file_name: &'a str) -> Self {
+        Self {
+            translations,
+            lang,
+            file_name,
+            sections: SectionMap::new(),
+            raw: RawSectionMap::new(),
+            documents: DocumentMap::new(),
+            genders: GenderMap::new(),
+            schema: 1,
+        }
+    }
+
+    // Expected number of entries.
+    pub(crate) fn reserve(&mut self, entries: usize) {
+        self.sections.reserve(entries);
+    }
+
+    // Entries of older schemas are migrated first, see `schema`.
+    pub(crate) fn insert(&mut self, key: String, mut value: Value) {
+        if key == schema::SCHEMA_KEY {
+            match schema::parse_schema(&value) {
+                Ok(version) if version > schema::TRANSLATION_SCHEMA => {
+                    warn!("'{}': {}", self.file_name, schema::newer_schema(version));
+                    self.schema = version;
+                }
+                Ok(version) => self.schema = version,
+                Err(e) => warn!("'{}': {}", self.file_name, e),
+            }
+            return;
+        }
+        if self.schema < schema::TRANSLATION_SCHEMA && split_annotation(&key).is_none() && !is_asset_manifest(self.file_name) {
+            schema::migrate_entry(self.schema, &mut value);
+        }
+        self.insert_entry(key, value);
+    }
+
+    fn insert_entry(&mut self, key: String, value: Value) {
+        if is_asset_manifest(self.file_name) {
+            self.raw.insert(key, value);
+            return;
+        }
+        match split_annotation(&key) {
+            Some((base, "gender")) => {
+                match value {
+                    Value::String(gender) => {
+                        self.genders.insert(base.to_string(), gender);
+                    }
+                    _ => warn!("'{}@gender' in '{}' must be a string", base, self.file_name),
+                }
+                return;
+            }
+            Some((base, attr)) => {
+                insert_key_annotation(self.translations, self.file_name, base, attr, &value);
+                return;
+            }
+            None => {
+                // Only suffixes that look like a misspelled attribute, not
+                // e-mail addresses in gettext msgids.
+                let looks_like_attribute =
+                    |attr: &str| !attr.is_empty() && attr.chars().all(|c| c.is_ascii_lowercase() || c == '_');
+                if let Some((_, attr)) = key.rsplit_once('@').filter(|(_, attr)| looks_like_attribute(attr)) {
+                    warn!(
+                        "'{}' in '{}' has no known annotation '@{}' (expected {}); loaded as a translation key",
+                        key,
+                        self.file_name,
+                        attr,
+                        KEY_ATTRIBUTES.join(", ")
+                    );
+                }
+            }
+        }
+        let value = match value {
+            Value::Object(group) if is_key_group(&group) => {
+                for (sub_key, sub_value) in group.into_iter().filter(|(sub_key, _)| sub_key != GROUP_MARKER) {
+                    self.insert_entry(format!("{}.{}", key, sub_key), sub_value);
+                }
+                return;
+            }
+            value => value,
+        };
+        if let Some(text) = value.get("@text").and_then(Value::as_str) {
+            self.documents.insert(key, text.to_string());
+            return;
+        }
+        if let Value::String(text) = value {
+            self.sections.insert(key, SectionValue::Text(text));
+            return;
+        }
+        if let Some(section_value) = parse_section_value(&value) {
+            subkey::warn_unknown_plural_keys(self.file_name, &key, &section_value);
+            self.sections.insert(key, section_value);
+            return;
+        }
+        self.raw.insert(key, value);
+    }
+
+    pub(crate) fn finish(self) {
+        let Self { translations, lang, file_name, sections, raw, documents, genders, .. } = self;
+        translations
+            .raw
+            .entry(lang.to_string())
+            .or_default()
+            .entry(file_name.to_string())
+            .or_default()
+            .extend(raw);
+        translations
+            .documents
+            .entry(lang.to_string())
+            .or_default()
+            .entry(file_name.to_string())
+            .or_default()
+            .extend(documents);
+        translations
+            .genders
+            .entry(lang.to_string())
+            .or_default()
+            .entry(file_name.to_string())
+            .or_default()
+            .extend(genders);
+        let loaded = translations
+            .langs
+            .entry(lang.to_string())
+            .or_default()
+            .entry(file_name.to_string())
+            .or_default();
+        if loaded.is_empty() {
+            // Keeps the capacity reserved for the file.
+            *loaded = sections;
+        } else {
+            loaded.extend(sections);
+        }
+    }
+}
+
+// The translations bundled by build.rs, as `{ lang: { file: entries } }`
+// JSON (`{}` when there was no messages folder).
+pub(crate) const BUNDLED_TRANSLATIONS: &str = include_str!(
+    concat!(env!("OUT_DIR"), "/all_translations.json")
+);
+
+// Load bundled data (generated by build.rs)
+fn load_bundled_data() -> Result<Translations, Box<dyn std::error::Error>> {
+    // Check if bundled translations are empty (happens when bevy-intl is built standalone)
+    let value: Value = serde_json::from_str(BUNDLED_TRANSLATIONS)?;
+    if !matches!(value.as_object(), Some(obj) if !obj.is_empty()) {
+        // Return empty translations - will fall back to filesystem loading
+        return Ok(Translations::default());
+    }
+    
+    parse_translation_value(value)
+}
+
+// Parse a JSON Value to Translations
+pub(crate) fn parse_translation_value(value: Value) -> Result<Translations, Box<dyn std::error::Error>> {
+    let mut translations = Translations::default();
+
+    if let Some(langs_obj) = value.as_object() {
+        for (lang_code, files_value) in langs_obj {
+            translations.langs.entry(lang_code.clone()).or_default();
+
+            if let Some(files_obj) = files_value.as_object() {
+                for (file_name, sections_value) in files_obj {
+                    insert_translation_file(&mut translations, lang_code, file_name, sections_value);
+                }
+            }
+        }
+    }
+
+    Ok(translations)
+}
+
+// Filesystem version
+#[cfg(all(feature = "fs-loader", not(target_arch = "wasm32")))]
+fn load_translation_from_fs(messages_folder: &str, formats: &TranslationFormats) -> std::io::Result<Translations> {
+    let mut translations = Translations::default();
+    let langs = read_translation_folder(messages_folder, formats, |lang, file_name, path| {
+        stream::load_json_file(&mut translations, lang, file_name, path).map(|()| true)
+    })?;
+    for (lang_code, files) in langs {
+        translations.langs.entry(lang_code.clone()).or_default();
+        for (file_name, json) in files {
+            insert_translation_file(&mut translations, &lang_code, &file_name, &json);
+        }
+    }
+    Ok(translations)
+}
+
+// Parsed files of every language folder in `messages_folder`, by language.
+// Translation `.json` files read by the built-in parser that share their
+// name with no other file are first offered to `stream(lang, file_name, path)`; those it
+// loads itself (returning `true`) are left out of the result.
+#[cfg(all(feature = "fs-loader", not(target_arch = "wasm32")))]
+#[allow(clippy::type_complexity)]
+fn read_translation_folder(
+    messages_folder: &str,
+    formats: &TranslationFormats,
+    mut stream: impl FnMut(&str, &str, &std::path::Path) -> std::io::Result<bool>,
+) -> std::io::Result<Vec<(String, Vec<(String, Value)>)>> {
+    use crate::merge::MergedFiles;
+    use std::fs;
+    use std::path::Path;
+
+    let message_dir = Path::new(messages_folder);
+
+    if !message_dir.exists() {
+        return Err(
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("{} folder not found", messages_folder)
+            )
+        );
+    }
+
+    let mut langs = Vec::new();
+
+    for folder_entry in fs::read_dir(message_dir)? {
+        let folder = folder_entry?;
+        let lang_code = folder.file_name().to_string_lossy().to_string();
+
+        // Sorted, so the same file wins key collisions on every platform.
+        let mut paths = fs::read_dir(folder.path())?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<std::io::Result<Vec<_>>>()?;
+        paths.sort();
+
+        let parsed = |path: &Path| path.is_file() && path.extension().and_then(|e| e.to_str()).is_some_and(|e| formats.supports(e));
+        let mut merged = MergedFiles::default();
+        for path in &paths {
+            let Some(extension) = path.extension().and_then(|e| e.to_str()).filter(|_| parsed(path)) else {
+                continue;
+            };
+            let file_name = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("unknown")
+                .to_string();
+            let file_path = path.file_name().and_then(|s| s.to_str()).unwrap_or("unknown");
+
+            let shares_name = paths
+                .iter()
+                .any(|other| other != path && other.file_stem() == path.file_stem() && parsed(other));
+            if extension == "json"
+                && is_translation_file(&file_name)
+                && formats.builtin_json()
+                && !shares_name
+                && stream(&lang_code, &file_name, path)?
+            {
+                continue;
+            }
+
+            let content = fs::read(path)?;
+            let Some(result) = formats.parse(extension, &content) else {
+                continue;
+            };
+            let mut json = result.map_err(|e| {
+                std::io::Error::new(std::io::ErrorKind::InvalidData, format!("{}/{}: {}", lang_code, file_path, e))
+            })?;
+
+            inline_documents(&mut json, &folder.path());
+            merged.add(&lang_code, &file_name, file_path, json, |issue| warn!("{}", issue));
+        }
+        langs.push((lang_code, merged.into_files().collect()));
+    }
+
+    Ok(langs)
+}
+
+// The messages folder as `build.rs` bundles it, `{ lang: { file: entries } }`:
+// only JSON and YAML files, with the `overlay` folder next to it applied.
+// A missing overlay folder is an error, not `NotFound`: the messages folder
+// is there to compare with.
+#[cfg(all(feature = "tools", not(target_arch = "wasm32")))]
+pub(crate) fn read_bundle_source(messages_folder: &str, overlay: Option<&str>) -> std::io::Result<Value> {
+    use std::path::Path;
+
+    let formats = TranslationFormats::bundled();
+    let as_json = |langs: Vec<(String, Vec<(String, Value)>)>| -> Value {
+        Value::Object(langs.into_iter().map(|(lang, files)| (lang, Value::Object(files.into_iter().collect()))).collect())
+    };
+    let mut langs = as_json(read_translation_folder(messages_folder, &formats, |_, _, _| Ok(false))?);
+    if let Some(overlay) = overlay {
+        let folder = overlay::overlay_folder(Path::new(messages_folder), overlay);
+        if !folder.is_dir() {
+            return Err(std::io::Error::other(format!("overlay folder '{}' not found", folder.display())));
+        }
+        let overlay_langs = as_json(read_translation_folder(&folder.to_string_lossy(), &formats, |_, _, _| Ok(false))?);
+        overlay::merge_overlay(&mut langs, &overlay_langs, |issue| warn!("Overlay '{}' {}", overlay, issue));
+    }
+    Ok(langs)
+}
+
+// Apply the keys of the `overlay` folder, next to the messages folder, over
+// the loaded translations. The overlay only replaces entries: languages and
+// keys it has but the messages folder lacks are reported and ignored.
+#[cfg(all(feature = "fs-loader", not(target_arch = "wasm32")))]
+pub(crate) fn apply_overlay(
+    translations: &mut Translations,
+    messages_folder: &str,
+    overlay: &str,
+    formats: &TranslationFormats,
+) {
+    let folder = overlay::overlay_folder(std::path::Path::new(messages_folder), overlay);
+    let langs = match read_translation_folder(&folder.to_string_lossy(), formats, |_, _, _| Ok(false)) {
+        Ok(langs) => langs,
+        Err(e) => {
+            warn!("Failed to load the overlay '{}': {}", folder.display(), e);
+            return;
+        }
+    };
+    let tree: Map<String, Value> =
+        langs.into_iter().map(|(lang, files)| (lang, Value::Object(files.into_iter().collect()))).collect();
+    let entries = overlay::overlay_entries(
+        &Value::Object(tree),
+        |lang| translations.langs.contains_key(lang),
+        |lang, file, key| defines(translations, lang, file, key),
+        |issue| warn!("Overlay '{}' {}", overlay, issue),
+    );
+    for (lang, file_name, entries) in entries {
+        let mut json = Value::Object(entries);
+        // Reserved files are stored whole: merge the overlay into them.
+        if let Some(Value::Object(reserved)) = translations.meta.get(&lang).and_then(|meta| meta.get(&file_name)) {
+            let mut merged = reserved.clone();
+            merged.extend(json.as_object().into_iter().flatten().map(|(key, value)| (key.clone(), value.clone())));
+            json = Value::Object(merged);
+        }
+        insert_translation_file(translations, &lang, &file_name, &json);
+    }
+}
+
+// Whether the messages folder loaded into `translations` has `key` in `file`
+// of `lang`: a string or map, a key group, a document, another value or an
+// entry of a reserved file.
+#[cfg(all(feature = "fs-loader", not(target_arch = "wasm32")))]
+fn defines(translations: &Translations, lang: &str, file: &str, key: &str) -> bool {
+    let group = format!("{}.", key);
+    let is_section = translations.langs.get(lang).and_then(|files| files.get(file)).is_some_and(|sections| {
+        sections.contains_key(key) || sections.keys().any(|section| section.starts_with(&group))
+    });
+    is_section
+        || translations.raw.get(lang).and_then(|files| files.get(file)).is_some_and(|raw| raw.contains_key(key))
+        || translations.documents.get(lang).and_then(|files| files.get(file)).is_some_and(|docs| docs.contains_key(key))
+        || translations.meta.get(lang).and_then(|files| files.get(file)).and_then(|json| json.get(key)).is_some()
+}
+
+#[cfg(any(not(feature = "fs-loader"), target_arch = "wasm32"))]
+pub(crate) fn apply_overlay(
+    _translations: &mut Translations,
+    _messages_folder: &str,
+    overlay: &str,
+    _formats: &TranslationFormats,
+) {
+    warn!(
+        "Overlay '{}' cannot be read on WASM or without the fs-loader feature; set {} when building to bundle it",
+        overlay, OVERLAY_ENV
+    );
+}
+
+// Read the text of every `{"@file": "credits.txt"}` value from the language
+// folder into an `"@text"` entry, the form `build.rs` bundles.
+#[cfg(all(feature = "fs-loader", not(target_arch = "wasm32")))]
+fn inline_documents(json: &mut Value, lang_dir: &std::path::Path) {
+    let Some(obj) = json.as_object_mut() else { return };
+    for (key, value) in obj.iter_mut() {
+        inline_document(key, value, lang_dir);
+    }
+}
+
+// Read the text of a `{"@file": "credits.txt"}` entry into its `"@text"`.
+#[cfg(all(feature = "fs-loader", not(target_arch = "wasm32")))]
+pub(crate) fn inline_document(key: &str, value: &mut Value, lang_dir: &std::path::Path) {
+    let Some(doc) = value.as_object_mut() else { return };
+    if doc.contains_key("@text") {
+        return;
+    }
+    let Some(path) = doc.get("@file").and_then(Value::as_str).map(str::to_string) else { return };
+    match std::fs::read_to_string(lang_dir.join(&path)) {
+        Ok(text) => {
+            doc.insert("@text".to_string(), Value::String(text));
+        }
+        Err(e) => warn!("document '{}' for key '{}' could not be read: {}", path, key, e),
+    }
+}
+
+// Record one `"key@attr": value` annotation for `file`.
+fn insert_key_annotation(
+    translations: &mut Translations,
+    file_name: &str,
+    key: &str,
+    attr: &str,
+    value: &Value,
+) {
+    let annotations = translations
+        .annotations
+        .entry(file_name.to_string())
+        .or_default()
+        .entry(key.to_string())
+        .or_default();
+
+    match attr {
+        "max" => match value.as_u64() {
+            Some(max) => {
+                let max = max as usize;
+                annotations.max_len = Some(annotations.max_len.map_or(max, |m| m.min(max)));
+            }
+            None => warn!("'{}@max' in '{}' must be a non-negative integer", key, file_name),
+        },
+        "same_ok" => match value.as_bool() {
+            Some(same_ok) => annotations.same_ok |= same_ok,
+            None => warn!("'{}@same_ok' in '{}' must be a boolean", key, file_name),
+        },
+        _ => warn!("unknown annotation '{}@{}' in '{}'", key, attr, file_name),
+    }
+}
+
+// Default error translations. Only "en" is listed as available, but the
+// "error" key is stored in every built-in language so that it shows in the
+// configured default language.
+fn create_error_translations() -> (Translations, Vec<String>) {
+    let mut lang_map = HashMap::new();
+    for (lang, text) in sentinel::error_texts() {
+        let mut section_map = HashMap::new();
+        section_map.insert("error".to_string(), SectionValue::Text(text.to_string()));
+
+        let mut file_map = HashMap::new();
+        file_map.insert("error".to_string(), section_map);
+        lang_map.insert(lang.to_string(), file_map);
+    }
+
+    (Translations { langs: lang_map, ..Default::default() }, vec!["en".to_string()])
+}
@@ -1,4 +1,263 @@
-pub static LOCALES: [&str; 732] = [
+//! Standard locale codes.
+
+use crate::direction::is_right_to_left;
+
+/// Helpers for locale codes.
+///
+/// ```rust
+/// use bevy_intl::Locale;
+///
+/// assert!(Locale::is_standard("fr-BE"));
+/// assert!(Locale::is_standard("pt_BR"));
+/// assert!(!Locale::is_standard("dwarvish"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Locale;
+
+impl Locale {
+    /// Whether `code` is a standard CLDR locale (`"fr"`, `"fr-BE"`,
+    /// `"zh-Hant-TW"`…). `_` is accepted as a separator.
+    pub fn is_standard(code: &str) -> bool {
+        if code.contains('_') {
+            return LOCALES.binary_search(&code.replace('_', "-").as_str()).is_ok();
+        }
+        LOCALES.binary_search(&code).is_ok()
+    }
+
+    /// Every standard locale code known to the crate, sorted.
+    pub fn standard_codes() -> &'static [&'static str] {
+        &LOCALES
+    }
+}
+
+/// Reading direction of a language.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TextDirection {
+    #[default]
+    LeftToRight,
+    RightToLeft,
+}
+
+impl Locale {
+    /// Reading direction of a standard locale: right-to-left for Arabic,
+    /// Hebrew, Persian, Urdu and other RTL scripts, left-to-right otherwise.
+    pub fn direction(code: &str) -> TextDirection {
+        if is_right_to_left(code) { TextDirection::RightToLeft } else { TextDirection::LeftToRight }
+    }
+
+    /// Canonical parent of a regional or script variant, following CLDR
+    /// parent locales: `de-AT` → `de`, `es-MX` → `es-419` → `es`,
+    /// `pt-AO` → `pt-PT` → `pt`, `zh-HK` → `zh-Hant`. `None` for a bare
+    /// language, and for `zh-Hant` (Traditional Chinese does not fall back
+    /// to Simplified). `_` is accepted as a separator; the result uses `-`.
+    ///
+    /// ```rust
+    /// use bevy_intl::Locale;
+    ///
+    /// assert_eq!(Locale::parent("es_AR").as_deref(), Some("es-419"));
+    /// assert_eq!(Locale::parent("es-419").as_deref(), Some("es"));
+    /// assert_eq!(Locale::parent("de"), None);
+    /// ```
+    pub fn parent(code: &str) -> Option<String> {
+        let code = code.replace('_', "-");
+        let (rest, last) = code.rsplit_once('-')?;
+        let parent = match (rest, last) {
+            ("es", region) if LATIN_AMERICAN_SPANISH.contains(&region) => "es-419",
+            ("pt", region) if EUROPEAN_PORTUGUESE.contains(&region) => "pt-PT",
+            ("zh", "HK" | "MO" | "TW") => "zh-Hant",
+            ("zh", "Hant") => return None,
+            _ => rest,
+        };
+        Some(parent.to_string())
+    }
+
+    /// Whether two codes name the same locale, ignoring case and `-` / `_`.
+    pub(crate) fn same(a: &str, b: &str) -> bool {
+        a.len() == b.len()
+            && a.chars().zip(b.chars()).all(|(x, y)| {
+                x.eq_ignore_ascii_case(&y) || (matches!(x, '-' | '_') && matches!(y, '-' | '_'))
+            })
+    }
+
+    /// Name of a language in that language ("Deutsch", "日本語"), for
+    /// language pickers. Regional variants with a common name of their own
+    /// (`pt-BR`, `zh-Hant`…) get it; other codes use the name of their base
+    /// language. `None` for languages the crate has no name for.
+    ///
+    /// ```rust
+    /// use bevy_intl::Locale;
+    ///
+    /// assert_eq!(Locale::endonym("fr-BE"), Some("Français"));
+    /// assert_eq!(Locale::endonym("pt_BR"), Some("Português (Brasil)"));
+    /// assert_eq!(Locale::endonym("dwarvish"), None);
+    /// ```
+    pub fn endonym(code: &str) -> Option<&'static str> {
+        let find = |code: &str| ENDONYMS.iter().find(|(known, _)| Locale::same(known, code)).map(|(_, name)| *name);
+        find(code).or_else(|| find(code.split(['-', '_']).next().unwrap_or(code)))
+    }
+}
+
+// Regions whose Spanish inherits from Latin American Spanish (`es-419`).
+const LATIN_AMERICAN_SPANISH: [&str; 22] = [
+    "AR", "BO", "BR", "BZ", "CL", "CO", "CR", "CU", "DO", "EC", "GT", "HN", "MX", "NI", "PA", "PE", "PR", "PY",
+    "SV", "US", "UY", "VE",
+];
+
+// Regions whose Portuguese inherits from European Portuguese (`pt-PT`).
+const EUROPEAN_PORTUGUESE: [&str; 10] = ["AO", "CH", "CV", "GQ", "GW", "LU", "MO", "MZ", "ST", "TL"];
+
+// Names of common languages and regional variants in their own language.
+const ENDONYMS: &[(&str, &str)] = &[
+    ("af", "Afrikaans"),
+    ("am", "አማርኛ"),
+    ("ar", "العربية"),
+    ("az", "Azərbaycan"),
+    ("be", "Беларуская"),
+    ("bg", "Български"),
+    ("bn", "বাংলা"),
+    ("bs", "Bosanski"),
+    ("ca", "Català"),
+    ("cs", "Čeština"),
+    ("cy", "Cymraeg"),
+    ("da", "Dansk"),
+    ("de", "Deutsch"),
+    ("el", "Ελληνικά"),
+    ("en", "English"),
+    ("en-GB", "English (UK)"),
+    ("en-US", "English (US)"),
+    ("eo", "Esperanto"),
+    ("es", "Español"),
+    ("es-419", "Español (Latinoamérica)"),
+    ("es-MX", "Español (México)"),
+    ("et", "Eesti"),
+    ("eu", "Euskara"),
+    ("fa", "فارسی"),
+    ("fi", "Suomi"),
+    ("fil", "Filipino"),
+    ("fr", "Français"),
+    ("fr-CA", "Français (Canada)"),
+    ("ga", "Gaeilge"),
+    ("gl", "Galego"),
+    ("gu", "ગુજરાતી"),
+    ("he", "עברית"),
+    ("hi", "हिन्दी"),
+    ("hr", "Hrvatski"),
+    ("hu", "Magyar"),
+    ("hy", "Հայերեն"),
+    ("id", "Bahasa Indonesia"),
+    ("is", "Íslenska"),
+    ("it", "Italiano"),
+    ("ja", "日本語"),
+    ("ka", "ქართული"),
+    ("kk", "Қазақ тілі"),
+    ("km", "ខ្មែរ"),
+    ("kn", "ಕನ್ನಡ"),
+    ("ko", "한국어"),
+    ("lo", "ລາວ"),
+    ("lt", "Lietuvių"),
+    ("lv", "Latviešu"),
+    ("mk", "Македонски"),
+    ("ml", "മലയാളം"),
+    ("mn", "Монгол"),
+    ("mr", "मराठी"),
+    ("ms", "Bahasa Melayu"),
+    ("my", "မြန်မာ"),
+    ("nb", "Norsk bokmål"),
+    ("ne", "नेपाली"),
+    ("nl", "Nederlands"),
+    ("nn", "Norsk nynorsk"),
+    ("pa", "ਪੰਜਾਬੀ"),
+    ("pl", "Polski"),
+    ("pt", "Português"),
+    ("pt-BR", "Português (Brasil)"),
+    ("pt-PT", "Português (Portugal)"),
+    ("ro", "Română"),
+    ("ru", "Русский"),
+    ("si", "සිංහල"),
+    ("sk", "Slovenčina"),
+    ("sl", "Slovenščina"),
+    ("sq", "Shqip"),
+    ("sr", "Српски"),
+    ("sv", "Svenska"),
+    ("sw", "Kiswahili"),
+    ("ta", "தமிழ்"),
+    ("te", "తెలుగు"),
+    ("th", "ไทย"),
+    ("tr", "Türkçe"),
+    ("uk", "Українська"),
+    ("ur", "اردو"),
+    ("uz", "Oʻzbek"),
+    ("vi", "Tiếng Việt"),
+    ("zh", "中文"),
+    ("zh-CN", "简体中文"),
+    ("zh-Hans", "简体中文"),
+    ("zh-Hant", "繁體中文"),
+    ("zh-TW", "繁體中文"),
+    ("zu", "isiZulu"),
+];
+
+/// A fictional or constructed language ("simlish", "dwarvish"…) declared in
+/// `I18nConfig::custom_locales`.
+///
+/// Declared locales are not reported as unknown codes, and their direction,
+/// plural rules and base fallback are used like those of a standard locale.
+///
+/// ```rust
+/// use bevy_intl::{CustomLocale, TextDirection};
+///
+/// let dwarvish = CustomLocale::new("dwarvish")
+///     .direction(TextDirection::RightToLeft)
+///     .plural_rules_from("de")
+///     .fallback("en");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CustomLocale {
+    /// Folder name of the language, e.g. `"dwarvish"`.
+    pub name: String,
+    pub direction: TextDirection,
+    /// Standard locale whose CLDR plural rules the language follows. `None`
+    /// uses the basic `one` / `other` defaults.
+    pub plural_rules_from: Option<String>,
+    /// Language to fall back to for missing keys, instead of the global
+    /// fallback language.
+    pub fallback: Option<String>,
+}
+
+impl CustomLocale {
+    /// A left-to-right language named `name`, with the basic plural rules
+    /// and the global fallback.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            direction: TextDirection::LeftToRight,
+            plural_rules_from: None,
+            fallback: None,
+        }
+    }
+
+    pub fn direction(mut self, direction: TextDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub fn plural_rules_from(mut self, locale: impl Into<String>) -> Self {
+        self.plural_rules_from = Some(locale.into());
+        self
+    }
+
+    pub fn fallback(mut self, locale: impl Into<String>) -> Self {
+        self.fallback = Some(locale.into());
+        self
+    }
+
+    /// Locale the language is based on, its [`fallback`](Self::fallback):
+    /// number formats, the keyboard layout and IME input follow it.
+    pub fn base_locale(&self) -> Option<&str> {
+        self.fallback.as_deref()
+    }
+}
+
+pub(crate) static LOCALES: [&str; 732] = [
     "af",
     "af-NA",
     "af-ZA",
@@ -0,0 +1,161 @@
+//! Verbosity and rate limiting of lookup warnings.
+//!
+//! A label with a missing key is rendered again and again; logging every
+//! attempt floods the console. [`LogPolicy`] sets the level of each category
+//! of lookup warning and, by default, reports each key once.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use tracing::{debug, info, trace, warn};
+
+use crate::I18n;
+
+/// Level at which a category of warnings is logged.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LogLevel {
+    /// Not logged at all.
+    Off,
+    Trace,
+    Debug,
+    Info,
+    #[default]
+    Warn,
+}
+
+/// Kind of lookup warning, see [`LogPolicy`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LogCategory {
+    /// A key (or its plural / gender form) that cannot be resolved.
+    MissingKey,
+    /// A `{{placeholder}}` without a value.
+    Placeholder,
+    /// A translation longer than its `"key@max"` annotation.
+    Length,
+    /// A gender selector outside [`I18nConfig::gender_keys`](crate::I18nConfig::gender_keys).
+    Gender,
+}
+
+/// How lookup warnings are logged, see [`I18nConfig::log_policy`](crate::I18nConfig::log_policy).
+///
+/// ```rust
+/// use bevy_intl::{I18nConfig, LogLevel, LogPolicy};
+///
+/// let config = I18nConfig {
+///     // Missing keys are expected while translations are in progress.
+///     log_policy: LogPolicy { missing_key: LogLevel::Debug, ..Default::default() },
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LogPolicy {
+    /// Default: [`LogLevel::Warn`].
+    pub missing_key: LogLevel,
+    /// Default: [`LogLevel::Warn`].
+    pub placeholder: LogLevel,
+    /// Default: [`LogLevel::Warn`].
+    pub length: LogLevel,
+    /// Default: [`LogLevel::Warn`].
+    pub gender: LogLevel,
+    /// Whether each key (or placeholder name) of a file is reported once per
+    /// category instead of on every lookup. Default: `true`.
+    pub once_per_key: bool,
+}
+
+impl Default for LogPolicy {
+    fn default() -> Self {
+        Self {
+            missing_key: LogLevel::Warn,
+            placeholder: LogLevel::Warn,
+            length: LogLevel::Warn,
+            gender: LogLevel::Warn,
+            once_per_key: true,
+        }
+    }
+}
+
+impl LogPolicy {
+    /// Level of `category`.
+    pub fn level(&self, category: LogCategory) -> LogLevel {
+        match category {
+            LogCategory::MissingKey => self.missing_key,
+            LogCategory::Placeholder => self.placeholder,
+            LogCategory::Length => self.length,
+            LogCategory::Gender => self.gender,
+        }
+    }
+}
+
+/// A [`LogPolicy`] and the keys already reported, shared by snapshots.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct I18nLog {
+    pub(crate) policy: LogPolicy,
+    reported: Arc<Mutex<HashSet<(LogCategory, String, String)>>>,
+}
+
+impl I18nLog {
+    pub(crate) fn new(policy: LogPolicy) -> Self {
+        Self { policy, reported: Arc::default() }
+    }
+
+    /// The log of the lookups of `file`.
+    pub(crate) fn scope<'a>(&'a self, file: impl Into<Cow<'a, str>>) -> LogScope<'a> {
+        LogScope { log: self, file: file.into() }
+    }
+
+    /// Logs `message` about `key` of `file` as the policy says.
+    fn log(&self, category: LogCategory, file: &str, key: &str, message: fmt::Arguments<'_>) {
+        let level = self.policy.level(category);
+        if level == LogLevel::Off {
+            return;
+        }
+        if self.policy.once_per_key {
+            let mut reported = self.reported.lock().unwrap_or_else(|e| e.into_inner());
+            if !reported.insert((category, file.to_string(), key.to_string())) {
+                return;
+            }
+        }
+        match level {
+            LogLevel::Off => {}
+            LogLevel::Trace => trace!("{}", message),
+            LogLevel::Debug => debug!("{}", message),
+            LogLevel::Info => info!("{}", message),
+            LogLevel::Warn => warn!("{}", message),
+        }
+    }
+}
+
+/// An [`I18nLog`] for the lookups of one file: the same key of two files is
+/// reported for each.
+#[derive(Clone, Debug)]
+pub(crate) struct LogScope<'a> {
+    log: &'a I18nLog,
+    file: Cow<'a, str>,
+}
+
+impl LogScope<'_> {
+    /// Name of the file.
+    pub(crate) fn file(&self) -> &str {
+        &self.file
+    }
+
+    /// Logs `message` about `key` as the policy says.
+    pub(crate) fn log(&self, category: LogCategory, key: &str, message: fmt::Arguments<'_>) {
+        self.log.log(category, &self.file, key, message);
+    }
+}
+
+impl I18n {
+    /// Current lookup warning policy, initially `I18nConfig::log_policy`.
+    pub fn log_policy(&self) -> LogPolicy {
+        self.log.policy
+    }
+
+    /// Changes the lookup warning policy. Keys already reported stay
+    /// reported.
+    pub fn set_log_policy(&mut self, policy: LogPolicy) {
+        self.log.policy = policy;
+    }
+}
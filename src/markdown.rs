@@ -0,0 +1,275 @@
+//! Localized markdown rendered as Bevy text.
+//!
+//! Available with the `markdown` feature. Patch notes and help screens can be
+//! written per language in markdown (usually as `"@file"` documents) and shown
+//! with an [`I18nMarkdown`] component, which builds a tree of styled
+//! `TextSpan` children under its `Text` and rebuilds it when the language
+//! changes.
+//!
+//! A small subset is supported — what in-game documents need:
+//!
+//! - `#`, `##`, `###` headings
+//! - `**bold**` and `*italic*`
+//! - `-`, `*`, `+` bullet lists (indented by two spaces per level) and `1.`
+//!   numbered lists
+//! - paragraphs separated by blank lines
+//!
+//! Anything else is kept as plain text. [`parse_markdown`] exposes the
+//! parsed spans for custom rendering.
+
+use bevy::prelude::*;
+
+use crate::{I18n, RefreshI18nText};
+
+/// A run of text with a single style, produced by [`parse_markdown`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MarkdownSpan {
+    pub text: String,
+    /// Heading level (1 for `#`), `None` for body text.
+    pub heading: Option<u8>,
+    pub bold: bool,
+    pub italic: bool,
+}
+
+/// Splits `source` into styled spans. Line breaks are part of the span
+/// texts, so concatenating them gives the displayed text.
+pub fn parse_markdown(source: &str) -> Vec<MarkdownSpan> {
+    let mut spans = Vec::new();
+    let mut paragraph = String::new();
+    let mut blank_before = false;
+
+    for line in source.lines() {
+        let trimmed = line.trim_start();
+        let indent = (line.len() - trimmed.len()) / 2;
+        let block = if trimmed.is_empty() {
+            None
+        } else if let Some((level, text)) = heading(trimmed) {
+            Some((Some(level), String::new(), text))
+        } else if let Some(text) = ["- ", "* ", "+ "].iter().find_map(|m| trimmed.strip_prefix(m)) {
+            Some((None, format!("{}• ", "  ".repeat(indent)), text))
+        } else if let Some((number, text)) = numbered(trimmed) {
+            Some((None, format!("{}{}. ", "  ".repeat(indent), number), text))
+        } else {
+            // Paragraph lines are joined with spaces.
+            if !paragraph.is_empty() {
+                paragraph.push(' ');
+            }
+            paragraph.push_str(trimmed);
+            continue;
+        };
+
+        flush_paragraph(&mut spans, &mut paragraph, &mut blank_before);
+        match block {
+            None => blank_before = !spans.is_empty(),
+            Some((level, prefix, text)) => {
+                if blank_before {
+                    push_text(&mut spans, "\n", None, false, false);
+                    blank_before = false;
+                }
+                push_text(&mut spans, &prefix, level, false, false);
+                parse_inline(&mut spans, text, level, false, false);
+                push_text(&mut spans, "\n", level, false, false);
+            }
+        }
+    }
+    flush_paragraph(&mut spans, &mut paragraph, &mut blank_before);
+
+    // No trailing line break after the last block.
+    if let Some(last) = spans.last_mut() {
+        if last.text.ends_with('\n') {
+            last.text.pop();
+        }
+        if last.text.is_empty() {
+            spans.pop();
+        }
+    }
+    spans
+}
+
+fn heading(line: &str) -> Option<(u8, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    let text = line[level..].strip_prefix(' ')?;
+    (1..=6).contains(&level).then_some((level as u8, text.trim()))
+}
+
+fn numbered(line: &str) -> Option<(&str, &str)> {
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    let text = line[digits..].strip_prefix(". ")?;
+    (digits > 0).then_some((&line[..digits], text))
+}
+
+fn flush_paragraph(spans: &mut Vec<MarkdownSpan>, paragraph: &mut String, blank_before: &mut bool) {
+    if paragraph.is_empty() {
+        return;
+    }
+    if *blank_before {
+        push_text(spans, "\n", None, false, false);
+        *blank_before = false;
+    }
+    let text = std::mem::take(paragraph);
+    parse_inline(spans, &text, None, false, false);
+    push_text(spans, "\n", None, false, false);
+}
+
+/// Parses `**bold**` / `*italic*`; markers without a closing pair are text.
+fn parse_inline(spans: &mut Vec<MarkdownSpan>, text: &str, heading: Option<u8>, bold: bool, italic: bool) {
+    let mut rest = text;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('*') else {
+            push_text(spans, rest, heading, bold, italic);
+            return;
+        };
+        let marker = if rest[start..].starts_with("**") { "**" } else { "*" };
+        let inner_start = start + marker.len();
+        let Some(len) = rest[inner_start..].find(marker).filter(|&len| len > 0) else {
+            push_text(spans, &rest[..inner_start], heading, bold, italic);
+            rest = &rest[inner_start..];
+            continue;
+        };
+        push_text(spans, &rest[..start], heading, bold, italic);
+        let inner = &rest[inner_start..inner_start + len];
+        if marker == "**" {
+            parse_inline(spans, inner, heading, true, italic);
+        } else {
+            parse_inline(spans, inner, heading, bold, true);
+        }
+        rest = &rest[inner_start + len + marker.len()..];
+    }
+}
+
+/// Appends `text`, merging it into the previous span when the style matches.
+fn push_text(spans: &mut Vec<MarkdownSpan>, text: &str, heading: Option<u8>, bold: bool, italic: bool) {
+    if text.is_empty() {
+        return;
+    }
+    match spans.last_mut() {
+        Some(last) if last.heading == heading && last.bold == bold && last.italic == italic => {
+            last.text.push_str(text);
+        }
+        _ => spans.push(MarkdownSpan { text: text.to_string(), heading, bold, italic }),
+    }
+}
+
+/// Fonts used by [`I18nMarkdown`]. Insert your own resource to change them.
+#[derive(Resource, Clone, Debug)]
+pub struct MarkdownTheme {
+    /// Body text font; headings, bold and italic text are derived from it.
+    pub body: TextFont,
+    /// Font sizes of `#`, `##` and `###` headings (deeper ones use the last).
+    pub heading_sizes: [FontSize; 3],
+    /// Font face for bold text and headings. `None` keeps the body face with
+    /// a bold weight, which only variable fonts render.
+    pub bold_font: Option<Handle<Font>>,
+    /// Font face for italic text; `None` keeps the body face.
+    pub italic_font: Option<Handle<Font>>,
+}
+
+impl Default for MarkdownTheme {
+    fn default() -> Self {
+        Self {
+            body: TextFont { font_size: FontSize::Px(18.0), ..default() },
+            heading_sizes: [FontSize::Px(32.0), FontSize::Px(26.0), FontSize::Px(22.0)],
+            bold_font: None,
+            italic_font: None,
+        }
+    }
+}
+
+impl MarkdownTheme {
+    /// Font of one parsed span.
+    pub fn font(&self, span: &MarkdownSpan) -> TextFont {
+        let mut font = self.body.clone();
+        if let Some(level) = span.heading {
+            font.font_size = self.heading_sizes[(level.clamp(1, 3) - 1) as usize];
+        }
+        if span.bold || span.heading.is_some() {
+            font.weight = FontWeight::BOLD;
+            if let Some(bold) = &self.bold_font {
+                font.font = bold.clone().into();
+            }
+        }
+        if span.italic {
+            if let Some(italic) = &self.italic_font {
+                font.font = italic.clone().into();
+            }
+        }
+        font
+    }
+}
+
+/// Renders the markdown stored at `file` / `key` — a `"@file"` document or a
+/// plain translation — as styled `TextSpan` children of this `Text`.
+///
+/// Like [`I18nText`](crate::I18nText), it is immutable: re-insert it to show
+/// another key.
+#[derive(Component, Clone, Debug, PartialEq, Eq)]
+#[component(immutable)]
+#[require(Text)]
+pub struct I18nMarkdown {
+    pub file: String,
+    pub key: String,
+}
+
+impl I18nMarkdown {
+    pub fn new(file: impl Into<String>, key: impl Into<String>) -> Self {
+        Self { file: file.into(), key: key.into() }
+    }
+}
+
+/// Marker on the `TextSpan` children spawned by [`I18nMarkdown`].
+#[derive(Component, Clone, Copy, Debug)]
+pub struct I18nMarkdownSpan;
+
+fn rebuild(
+    commands: &mut Commands,
+    i18n: &I18n,
+    theme: &MarkdownTheme,
+    entity: Entity,
+    markdown: &I18nMarkdown,
+    children: Option<&Children>,
+    spans: &Query<(), With<I18nMarkdownSpan>>,
+) {
+    for child in children.into_iter().flat_map(|c| c.iter()) {
+        if spans.contains(child) {
+            commands.entity(child).despawn();
+        }
+    }
+
+    let t = i18n.translation(&markdown.file);
+    let source = match t.document(&markdown.key) {
+        Some(document) => document.to_string(),
+        None => t.t(&markdown.key),
+    };
+    for span in parse_markdown(&source) {
+        let font = theme.font(&span);
+        commands.spawn((TextSpan(span.text), font, I18nMarkdownSpan, ChildOf(entity)));
+    }
+}
+
+/// Observer building the spans of an inserted [`I18nMarkdown`].
+pub(crate) fn render_inserted_markdown(
+    insert: On<Insert, I18nMarkdown>,
+    mut commands: Commands,
+    i18n: Res<I18n>,
+    theme: Res<MarkdownTheme>,
+    query: Query<(&I18nMarkdown, Option<&Children>)>,
+    spans: Query<(), With<I18nMarkdownSpan>>,
+) {
+    if let Ok((markdown, children)) = query.get(insert.entity) {
+        rebuild(&mut commands, &i18n, &theme, insert.entity, markdown, children, &spans);
+    }
+}
+
+/// Observer rebuilding every [`I18nMarkdown`] on [`RefreshI18nText`].
+pub(crate) fn refresh_markdown(
+    _refresh: On<RefreshI18nText>,
+    mut commands: Commands,
+    i18n: Res<I18n>,
+    theme: Res<MarkdownTheme>,
+    query: Query<(Entity, &I18nMarkdown, Option<&Children>)>,
+    spans: Query<(), With<I18nMarkdownSpan>>,
+) {
+    for (entity, markdown, children) in &query {
+        rebuild(&mut commands, &i18n, &theme, entity, markdown, children, &spans);
+    }
+}
@@ -0,0 +1,52 @@
+//! Merging of the files of one language that share a name across formats
+//! (`ui.json` and `ui.yaml`).
+//!
+//! A key defined in more than one of them is reported, and the first file in
+//! path order wins. The module only depends on `std` and `serde_json`, so
+//! `build.rs` shares it to bundle mixed-format folders the way the loader
+//! reads them.
+
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value};
+
+/// Translation files of one language, merged by name across formats.
+#[derive(Default)]
+pub(crate) struct MergedFiles {
+    /// File name → merged content.
+    files: BTreeMap<String, Map<String, Value>>,
+    /// File name → key → file the key came from.
+    origins: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl MergedFiles {
+    /// Adds the parsed content of `path` (e.g. `ui.yaml`), the file named
+    /// `name` in language `lang`. Files must be added in path order; content
+    /// that is not a mapping and keys already defined are passed to
+    /// `report` and left out.
+    pub(crate) fn add(&mut self, lang: &str, name: &str, path: &str, content: Value, mut report: impl FnMut(String)) {
+        let merged = self.files.entry(name.to_string()).or_default();
+        let origins = self.origins.entry(name.to_string()).or_default();
+        let Value::Object(entries) = content else {
+            report(format!("'{}/{}' is not a mapping of translations; ignored", lang, path));
+            return;
+        };
+        for (key, value) in entries {
+            match origins.get(&key) {
+                Some(first) => report(format!(
+                    "'{}' of '{}/{}' is defined in both '{}' and '{}'; using '{}'",
+                    key, lang, name, first, path, first
+                )),
+                None => {
+                    origins.insert(key.clone(), path.to_string());
+                    merged.insert(key, value);
+                }
+            }
+        }
+    }
+
+    /// Merged files, by name.
+    pub(crate) fn into_files(self) -> impl Iterator<Item = (String, Value)> {
+        self.files.into_iter().map(|(name, merged)| (name, Value::Object(merged)))
+    }
+}
@@ -0,0 +1,172 @@
+//! Report of the strings players actually ran into untranslated.
+//!
+//! Logs only say a key is missing once, among everything else. A
+//! [`MissingTranslations`] report records every `(lang, file, key)` lookup
+//! that was served by a fallback language or could not be resolved at all,
+//! so a QA pass through the game ends with the list of strings to
+//! translate, as JSON:
+//!
+//! ```json
+//! [
+//!     { "lang": "fr", "file": "ui", "key": "quit", "served_by": "en" },
+//!     { "lang": "fr", "file": "ui", "key": "tutorial.skip", "served_by": null }
+//! ]
+//! ```
+//!
+//! With Bevy, insert the resource (it is off by default); the report is
+//! written when the app exits if it has an output path:
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_intl::{I18nPlugin, MissingTranslations};
+//!
+//! App::new()
+//!     .add_plugins(I18nPlugin::default())
+//!     .insert_resource(MissingTranslations::new().write_on_exit("missing_strings.json"))
+//!     .run();
+//! ```
+//!
+//! Without Bevy, register it with [`I18n::set_missing_translations`] and call
+//! [`MissingTranslations::write_json`].
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+#[cfg(feature = "bevy")]
+use bevy::prelude::*;
+use serde::Serialize;
+use serde_json::Value;
+#[cfg(feature = "bevy")]
+use tracing::{info, warn};
+
+use crate::I18n;
+
+/// A lookup recorded by [`MissingTranslations`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct MissingTranslation {
+    /// Language the string was looked up in.
+    pub lang: String,
+    pub file: String,
+    pub key: String,
+    /// Language of the fallback chain that provided the string, `None` when
+    /// no language had it.
+    pub served_by: Option<String>,
+}
+
+// (lang, file, key) → language that served it.
+type Entries = BTreeMap<(String, String, String), Option<String>>;
+
+/// Opt-in record of the lookups that fell through to a fallback language or
+/// failed, see the [module docs](self). Clones share the same entries.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "bevy", derive(Resource))]
+pub struct MissingTranslations {
+    /// Where the report is written when the app exits (`None`: not written).
+    pub output: Option<PathBuf>,
+    entries: Arc<Mutex<Entries>>,
+}
+
+impl MissingTranslations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn write_on_exit(mut self, path: impl Into<PathBuf>) -> Self {
+        self.output = Some(path.into());
+        self
+    }
+
+    /// Recorded lookups, sorted by language, file and key.
+    pub fn entries(&self) -> Vec<MissingTranslation> {
+        self.lock()
+            .iter()
+            .map(|((lang, file, key), served_by)| MissingTranslation {
+                lang: lang.clone(),
+                file: file.clone(),
+                key: key.clone(),
+                served_by: served_by.clone(),
+            })
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Forgets the recorded lookups, e.g. after a language pack was merged.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// The report as a JSON array of [`MissingTranslation`]s.
+    pub fn to_json(&self) -> Value {
+        serde_json::to_value(self.entries()).unwrap_or_default()
+    }
+
+    /// Writes the report to `path` as pretty-printed JSON.
+    pub fn write_json(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(&self.to_json())?;
+        std::fs::write(path, json)
+    }
+
+    /// Records a lookup of `file.key` in `lang`; the first record of a key
+    /// wins.
+    pub(crate) fn record(&self, lang: &str, file: &str, key: &str, served_by: Option<&str>) {
+        let mut entries = self.lock();
+        let id = (lang.to_string(), file.to_string(), key.to_string());
+        entries.entry(id).or_insert_with(|| served_by.map(str::to_string));
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Entries> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl I18n {
+    /// Records fallback hits and missing keys into `report` from now on,
+    /// replacing the previous report. `I18nPlugin` does it for a
+    /// [`MissingTranslations`] resource.
+    pub fn set_missing_translations(&mut self, report: MissingTranslations) {
+        self.analytics.missing = Some(report);
+    }
+
+    /// Stops recording missing translations.
+    pub fn clear_missing_translations(&mut self) {
+        self.analytics.missing = None;
+    }
+
+    /// The report registered with
+    /// [`set_missing_translations`](Self::set_missing_translations).
+    pub fn missing_translations(&self) -> Option<&MissingTranslations> {
+        self.analytics.missing.as_ref()
+    }
+}
+
+/// Registers an inserted or replaced [`MissingTranslations`] resource with
+/// [`I18n`].
+#[cfg(feature = "bevy")]
+pub fn register_missing_translations(report: Option<Res<MissingTranslations>>, mut i18n: ResMut<I18n>) {
+    if let Some(report) = report.filter(|report| report.is_changed()) {
+        i18n.set_missing_translations(report.clone());
+    }
+}
+
+/// Writes the [`MissingTranslations`] report to its output path when the app
+/// exits.
+#[cfg(feature = "bevy")]
+pub fn write_missing_translations_on_exit(report: Option<Res<MissingTranslations>>, mut exits: MessageReader<AppExit>) {
+    if exits.read().count() == 0 {
+        return;
+    }
+    let Some(report) = report else { return };
+    let Some(path) = &report.output else { return };
+    match report.write_json(path) {
+        Ok(()) => info!("{} missing translations written to {}", report.len(), path.display()),
+        Err(e) => warn!("cannot write missing translations to {}: {}", path.display(), e),
+    }
+}
@@ -0,0 +1,211 @@
+//! Localized display names of game objects.
+//!
+//! Items, characters and places usually know their own translation key. By
+//! implementing [`DisplayName`], they can be passed straight to
+//! [`I18n::t_with_names`], which looks their names up in the current language
+//! before substituting them:
+//!
+//! ```rust,no_run
+//! use bevy_intl::{DisplayName, I18n};
+//!
+//! enum Item { Sword, Bow }
+//!
+//! impl DisplayName for Item {
+//!     fn name_file(&self) -> &str { "items" }
+//!     fn key(&self) -> &str {
+//!         match self {
+//!             Item::Sword => "sword",
+//!             Item::Bow => "bow",
+//!         }
+//!     }
+//! }
+//!
+//! # fn toast(i18n: &I18n) {
+//! // "obtained": "You obtained {{item}}!"
+//! let text = i18n.t_with_names("messages", "obtained", &[("item", &Item::Sword)]);
+//! # }
+//! ```
+//!
+//! Names can declare their grammatical gender per language
+//! (`"sword": "épée", "sword@gender": "f"`). `t_with_names` passes it as
+//! `<placeholder>.gender`, so words around the name can agree with it:
+//! `"{{item}} {{item.gender, select, f{détruite} other{détruit}}}"`.
+//!
+//! Entities can carry an [`I18nName`] component instead. Content enums can
+//! derive [`Localize`] (`derive` feature) to get both traits from their
+//! variant names.
+//!
+//! A placeholder can also take another translation, e.g. a status effect in
+//! `"status_line": "You are {{status}}"`: pass [`I18n::key`] as its argument
+//! instead of translating it first.
+//!
+//! Types whose localized form is more than a name (an achievement with its
+//! progress, a price with its currency) implement [`LocalizedDisplay`]
+//! instead. Either way, `value.localized(&i18n)` is a [`Localized`] value
+//! that prints in the current language with `format!("{}", …)`.
+
+use std::fmt;
+
+#[cfg(feature = "bevy")]
+use bevy::prelude::Component;
+
+use crate::I18n;
+
+/// Something with a localized name stored at `name_file` / `key`.
+pub trait DisplayName {
+    /// Translation file holding the name, e.g. `"items"`.
+    fn name_file(&self) -> &str;
+    /// Key of the name inside [`name_file`](Self::name_file).
+    fn key(&self) -> &str;
+}
+
+/// Content enums with one translation per variant, usually derived with
+/// `#[derive(Localize)]` (`derive` feature): `Weapon::FireSword` maps to the
+/// key `weapon.fire_sword` of the `enums` file, both configurable with
+/// `#[localize(file = "...", prefix = "...")]` on the enum and
+/// `#[localize(rename = "...")]` on a variant. As a [`DisplayName`], every
+/// variant can be printed with [`localized`](LocalizedDisplay::localized).
+pub trait Localize: DisplayName {}
+
+/// Something that knows how to write itself in the current language.
+/// Implemented for every [`DisplayName`].
+///
+/// ```rust
+/// use std::fmt;
+/// use bevy_intl::{I18n, LocalizedDisplay};
+///
+/// struct Achievement { key: &'static str, progress: u32 }
+///
+/// impl LocalizedDisplay for Achievement {
+///     fn fmt_localized(&self, i18n: &I18n, f: &mut fmt::Formatter) -> fmt::Result {
+///         let name = i18n.translation("achievements").t(self.key);
+///         write!(f, "{} ({}%)", name, self.progress)
+///     }
+/// }
+///
+/// # fn log(i18n: &I18n, achievement: &Achievement) {
+/// println!("unlocked {}", achievement.localized(i18n));
+/// # }
+/// ```
+pub trait LocalizedDisplay {
+    /// Writes this value in the current language of `i18n`.
+    fn fmt_localized(&self, i18n: &I18n, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// This value paired with `i18n`, printable with `format!` and
+    /// `to_string()`.
+    fn localized<'a>(&'a self, i18n: &'a I18n) -> Localized<'a, Self> {
+        Localized { value: self, i18n }
+    }
+}
+
+impl<T: DisplayName + ?Sized> LocalizedDisplay for T {
+    fn fmt_localized(&self, i18n: &I18n, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&i18n.translation(self.name_file()).t(self.key()))
+    }
+}
+
+/// A value displayed in the current language, see
+/// [`LocalizedDisplay::localized`].
+pub struct Localized<'a, T: ?Sized> {
+    value: &'a T,
+    i18n: &'a I18n,
+}
+
+impl<T: LocalizedDisplay + ?Sized> fmt::Display for Localized<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt_localized(self.i18n, f)
+    }
+}
+
+impl<T: ?Sized> Clone for Localized<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for Localized<'_, T> {}
+
+/// A translation used as a placeholder argument, see [`I18n::key`]. It is
+/// resolved in the current language when the message is formatted.
+#[derive(Clone, Copy)]
+pub struct TranslationKey<'a> {
+    i18n: &'a I18n,
+    file: &'a str,
+    key: &'a str,
+}
+
+impl fmt::Display for TranslationKey<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&self.i18n.translation(self.file).t(self.key))
+    }
+}
+
+/// A localized name given as data, e.g. on an entity.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy", derive(Component))]
+pub struct I18nName {
+    pub file: String,
+    pub key: String,
+}
+
+impl I18nName {
+    pub fn new(file: impl Into<String>, key: impl Into<String>) -> Self {
+        Self { file: file.into(), key: key.into() }
+    }
+}
+
+impl DisplayName for I18nName {
+    fn name_file(&self) -> &str {
+        &self.file
+    }
+
+    fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl I18n {
+    /// The translation at `file` / `key`, to pass as a placeholder argument:
+    ///
+    /// ```rust,no_run
+    /// # use bevy_intl::{I18n, i18n_args};
+    /// # fn hud(i18n: &I18n) {
+    /// // "status_line": "You are {{status}}", "poisoned": "poisoned"
+    /// let text = i18n
+    ///     .translation("hud")
+    ///     .t_with_args("status_line", i18n_args! { status = i18n.key("status", "poisoned") });
+    /// # }
+    /// ```
+    pub fn key<'a>(&'a self, file: &'a str, key: &'a str) -> TranslationKey<'a> {
+        TranslationKey { i18n: self, file, key }
+    }
+
+    /// Localized name of `named` in the current language.
+    pub fn display_name(&self, named: &dyn DisplayName) -> String {
+        self.translation(named.name_file()).t(named.key())
+    }
+
+    /// Grammatical gender of `named` in the current language, from its
+    /// `"key@gender"` entry.
+    pub fn display_gender(&self, named: &dyn DisplayName) -> Option<String> {
+        self.translation(named.name_file()).gender(named.key()).map(str::to_string)
+    }
+
+    /// Translates `file` / `key`, replacing each `{{placeholder}}` listed in
+    /// `names` with the localized name of its object, and `placeholder.gender`
+    /// in select placeholders with its gender.
+    pub fn t_with_names(&self, file: &str, key: &str, names: &[(&str, &dyn DisplayName)]) -> String {
+        let mut resolved: Vec<(String, String)> = Vec::with_capacity(names.len() * 2);
+        for (placeholder, named) in names {
+            resolved.push((placeholder.to_string(), self.display_name(*named)));
+            if let Some(gender) = self.display_gender(*named) {
+                resolved.push((format!("{}.gender", placeholder), gender));
+            }
+        }
+        let args: Vec<(&str, &dyn ToString)> = resolved
+            .iter()
+            .map(|(placeholder, value)| (placeholder.as_str(), value as &dyn ToString))
+            .collect();
+        self.translation(file).t_with_args(key, &args)
+    }
+}
@@ -0,0 +1,238 @@
+//! Locale-aware integer formatting.
+//!
+//! Counts substituted into plural translations go through the current
+//! language's [`NumberFormat`], so `12435` renders as `12,435` in English,
+//! `12 435` in French and `12.435` in German. Counts may be negative (score
+//! deltas, temperatures); [`SignDisplay`] controls whether positive values
+//! get an explicit plus sign. [`FormatOverrides`] replaces parts of the
+//! locale conventions when the product requires a fixed format.
+
+use crate::{I18n, Locale};
+
+/// Digit grouping conventions of a locale, see [`Locale::number_format`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// Separator between digit groups.
+    pub group_separator: char,
+    /// Size of the rightmost group (3 almost everywhere).
+    pub primary_group: usize,
+    /// Size of every other group (2 in Indian numbering: `12,34,567`).
+    pub secondary_group: usize,
+    /// Integers are grouped only when they have at least
+    /// `primary_group + min_grouping_digits` digits: Spanish and Polish write
+    /// `1234` but `12 345`.
+    pub min_grouping_digits: usize,
+    /// Sign of negative numbers (`-`, or `−` U+2212 in e.g. Swedish).
+    pub minus_sign: char,
+    /// Sign of positive numbers, when shown.
+    pub plus_sign: char,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            group_separator: ',',
+            primary_group: 3,
+            secondary_group: 3,
+            min_grouping_digits: 1,
+            minus_sign: '-',
+            plus_sign: '+',
+        }
+    }
+}
+
+/// When a sign is written in front of a number, see
+/// [`NumberFormat::format_signed`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SignDisplay {
+    /// Only negative numbers: `-5`, `0`, `5`.
+    #[default]
+    Auto,
+    /// Every number: `-5`, `+0`, `+5`.
+    Always,
+    /// Every number but zero: `-5`, `0`, `+5`.
+    ExceptZero,
+}
+
+impl NumberFormat {
+    /// Plain digits with an ASCII minus, no grouping.
+    pub const UNGROUPED: Self = Self {
+        group_separator: ',',
+        primary_group: 3,
+        secondary_group: 3,
+        min_grouping_digits: usize::MAX,
+        minus_sign: '-',
+        plus_sign: '+',
+    };
+
+    fn with_separator(group_separator: char) -> Self {
+        Self { group_separator, ..Self::default() }
+    }
+
+    /// Formats `value` with digit grouping.
+    pub fn format_integer(&self, value: i128) -> String {
+        let digits = value.unsigned_abs().to_string();
+        let mut out = String::with_capacity(digits.len() + digits.len() / 2 + 1);
+        if value < 0 {
+            out.push(self.minus_sign);
+        }
+        if digits.len() < self.primary_group.saturating_add(self.min_grouping_digits) {
+            out.push_str(&digits);
+            return out;
+        }
+
+        // Group boundaries, counted from the right.
+        let mut boundaries = Vec::new();
+        let mut at = self.primary_group;
+        while at < digits.len() {
+            boundaries.push(digits.len() - at);
+            at += self.secondary_group.max(1);
+        }
+        for (i, c) in digits.char_indices() {
+            if boundaries.contains(&i) {
+                out.push(self.group_separator);
+            }
+            out.push(c);
+        }
+        out
+    }
+
+    /// Formats `value` like [`format_integer`](Self::format_integer), with a
+    /// plus sign in front of non-negative values as `sign` requires.
+    pub fn format_signed(&self, value: i128, sign: SignDisplay) -> String {
+        let plus = match sign {
+            SignDisplay::Auto => false,
+            SignDisplay::Always => value >= 0,
+            SignDisplay::ExceptZero => value > 0,
+        };
+        let formatted = self.format_integer(value);
+        if plus { format!("{}{}", self.plus_sign, formatted) } else { formatted }
+    }
+}
+
+/// Parts of [`NumberFormat`] fixed regardless of the language, see
+/// [`I18nConfig::format_overrides`](crate::I18nConfig::format_overrides).
+/// `None` keeps the locale's convention.
+///
+/// ```rust
+/// # use bevy_intl::{FormatOverrides, Locale};
+/// // Always `1,234,567`, whatever the language.
+/// let overrides = FormatOverrides { group_separator: Some(','), ..Default::default() };
+/// assert_eq!(overrides.apply(Locale::number_format("fr")).format_integer(1234567), "1,234,567");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FormatOverrides {
+    pub group_separator: Option<char>,
+    pub primary_group: Option<usize>,
+    pub secondary_group: Option<usize>,
+    /// `Some(usize::MAX)` never groups digits.
+    pub min_grouping_digits: Option<usize>,
+    pub minus_sign: Option<char>,
+    pub plus_sign: Option<char>,
+}
+
+impl FormatOverrides {
+    /// `format` with the overridden parts replaced.
+    pub fn apply(&self, format: NumberFormat) -> NumberFormat {
+        NumberFormat {
+            group_separator: self.group_separator.unwrap_or(format.group_separator),
+            primary_group: self.primary_group.unwrap_or(format.primary_group),
+            secondary_group: self.secondary_group.unwrap_or(format.secondary_group),
+            min_grouping_digits: self.min_grouping_digits.unwrap_or(format.min_grouping_digits),
+            minus_sign: self.minus_sign.unwrap_or(format.minus_sign),
+            plus_sign: self.plus_sign.unwrap_or(format.plus_sign),
+        }
+    }
+}
+
+/// Integer types accepted as plural counts. Plural forms are chosen from the
+/// absolute value, so `-1` uses the `"one"` form.
+pub trait PluralCount: Copy {
+    fn to_i128(self) -> i128;
+}
+
+macro_rules! impl_plural_count {
+    ($($ty:ty),*) => {
+        $(impl PluralCount for $ty {
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+        })*
+    };
+}
+
+impl_plural_count!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl Locale {
+    /// Digit grouping used by a locale (CLDR conventions for Latin digits);
+    /// unknown codes use `1,234,567`.
+    pub fn number_format(code: &str) -> NumberFormat {
+        let mut parts = code.split(['-', '_']);
+        let language = parts.next().unwrap_or(code);
+        let region = parts.find(|p| p.len() == 2 || p.chars().all(|c| c.is_ascii_digit()));
+
+        let grouping = match (language, region) {
+            ("de" | "it", Some("CH" | "LI")) => NumberFormat::with_separator('\u{2019}'),
+            ("es", Some("MX" | "US" | "419")) => NumberFormat::default(),
+            ("pt", Some("PT")) => NumberFormat { min_grouping_digits: 2, ..NumberFormat::with_separator('\u{a0}') },
+            ("en", Some("IN")) | ("hi" | "bn" | "mr" | "gu" | "ta" | "te" | "kn" | "ml" | "pa" | "ne", _) => {
+                NumberFormat { secondary_group: 2, ..NumberFormat::default() }
+            }
+            ("es" | "pl" | "et", _) => NumberFormat {
+                min_grouping_digits: 2,
+                ..NumberFormat::with_separator(if language == "es" { '.' } else { '\u{a0}' })
+            },
+            ("fr", _) => NumberFormat::with_separator('\u{202f}'),
+            (
+                "de" | "it" | "nl" | "pt" | "tr" | "id" | "da" | "el" | "hr" | "sl" | "ro" | "sr" | "vi"
+                | "is" | "ca" | "az" | "mk" | "bs",
+                _,
+            ) => NumberFormat::with_separator('.'),
+            (
+                "ru" | "uk" | "cs" | "sk" | "sv" | "fi" | "nb" | "no" | "nn" | "bg" | "hu" | "lv" | "lt"
+                | "kk" | "be" | "hy" | "ka" | "sq" | "ky" | "uz",
+                _,
+            ) => NumberFormat::with_separator('\u{a0}'),
+            _ => NumberFormat::default(),
+        };
+        let minus_sign = match language {
+            "sv" | "fi" | "nb" | "no" | "nn" | "et" | "lt" | "sl" | "hr" | "eu" | "fo" => '\u{2212}',
+            _ => '-',
+        };
+        NumberFormat { minus_sign, ..grouping }
+    }
+}
+
+impl I18n {
+    /// Number format of the current language, with
+    /// [`I18nConfig::format_overrides`](crate::I18nConfig::format_overrides)
+    /// applied. Custom locales format numbers like their
+    /// [`base_locale`](crate::CustomLocale::base_locale).
+    pub fn number_format(&self) -> NumberFormat {
+        let format = match self.custom_locale(&self.current_lang) {
+            Some(custom) => custom.base_locale().map_or_else(NumberFormat::default, Locale::number_format),
+            None => Locale::number_format(&self.current_lang),
+        };
+        self.format_overrides.apply(format)
+    }
+
+    /// Format of `{{count}}`: the language's, or raw digits when
+    /// `group_counts` is off. Overridden signs apply either way.
+    #[cfg(feature = "formatting")]
+    pub(crate) fn count_format(&self) -> NumberFormat {
+        if self.group_counts {
+            return self.number_format();
+        }
+        let signs = FormatOverrides {
+            minus_sign: self.format_overrides.minus_sign,
+            plus_sign: self.format_overrides.plus_sign,
+            ..FormatOverrides::default()
+        };
+        signs.apply(NumberFormat::UNGROUPED)
+    }
+
+    /// Formats `value` with the current language's digit grouping.
+    pub fn format_integer(&self, value: i128) -> String {
+        self.number_format().format_integer(value)
+    }
+}
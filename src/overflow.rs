@@ -0,0 +1,128 @@
+//! Ellipsizing overflow for localized labels.
+//!
+//! Add [`I18nOverflow`] with [`OverflowMode::Ellipsize`] to a text entity
+//! whose `Node` has a bounded width. Text is laid out on a single line, and
+//! when it is wider than the node [`ellipsize_i18n_text`] truncates it on
+//! grapheme boundaries and appends the active locale's ellipsis. The full
+//! string is kept in [`I18nFullText`]; add an `Interaction` to the entity and
+//! it is shown as a tooltip on hover.
+
+use bevy::prelude::*;
+use bevy::text::{LineBreak, TextLayoutInfo};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::I18n;
+
+/// How a localized label behaves when it is wider than its node.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OverflowMode {
+    /// Leave the text alone (Bevy's default layout applies).
+    #[default]
+    Clip,
+    /// Single line, truncated with the locale's ellipsis.
+    Ellipsize,
+}
+
+/// Overflow behavior for a text entity (typically one with an `I18nText`).
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct I18nOverflow(pub OverflowMode);
+
+/// Untruncated text of an ellipsized label, maintained by
+/// [`ellipsize_i18n_text`].
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+pub struct I18nFullText {
+    /// The complete string, as last written by the translation systems.
+    pub full: String,
+    /// The string currently displayed (equal to `full` when not truncated).
+    pub shown: String,
+}
+
+impl I18nFullText {
+    /// Whether the displayed text is truncated.
+    pub fn is_truncated(&self) -> bool {
+        self.full != self.shown
+    }
+}
+
+impl I18n {
+    /// Ellipsis used when truncating text in the current language.
+    pub fn ellipsis(&self) -> &'static str {
+        ellipsis_for(self.get_lang())
+    }
+}
+
+fn ellipsis_for(lang: &str) -> &'static str {
+    match lang.split(['-', '_']).next().unwrap_or(lang) {
+        // Chinese typography uses a double-width ellipsis.
+        "zh" => "……",
+        _ => "…",
+    }
+}
+
+type EllipsizeQueryData<'a> = (
+    Entity,
+    &'a I18nOverflow,
+    &'a mut Text,
+    &'a mut TextLayout,
+    Ref<'a, TextLayoutInfo>,
+    &'a ComputedNode,
+    Option<&'a mut I18nFullText>,
+);
+
+/// Bevy system applying [`OverflowMode::Ellipsize`].
+///
+/// Works from the previous frame's `TextLayoutInfo`; a label converges within
+/// a few frames after its text changes.
+pub fn ellipsize_i18n_text(
+    mut commands: Commands,
+    i18n: Res<I18n>,
+    mut query: Query<EllipsizeQueryData>,
+) {
+    for (entity, overflow, mut text, mut layout, info, node, full) in &mut query {
+        if overflow.0 != OverflowMode::Ellipsize {
+            continue;
+        }
+        if layout.linebreak != LineBreak::NoWrap {
+            layout.linebreak = LineBreak::NoWrap;
+        }
+
+        let Some(mut full) = full else {
+            commands.entity(entity).insert(I18nFullText {
+                full: text.0.clone(),
+                shown: text.0.clone(),
+            });
+            continue;
+        };
+
+        // Text rewritten by someone else (e.g. a language refresh): start over.
+        if text.0 != full.shown {
+            full.full = text.0.clone();
+            full.shown = text.0.clone();
+            continue;
+        }
+
+        if !info.is_changed() {
+            continue;
+        }
+        let available = node.content_box().width();
+        if available <= 0.0 || info.size.x <= available + 0.5 {
+            continue;
+        }
+
+        let ellipsis = i18n.ellipsis();
+        let graphemes: Vec<&str> = full.full.graphemes(true).collect();
+        let shown_count = full
+            .shown
+            .strip_suffix(ellipsis)
+            .unwrap_or(&full.shown)
+            .graphemes(true)
+            .count();
+        let estimate = (shown_count as f32 * available / info.size.x).floor() as usize;
+        let keep = estimate.min(shown_count.saturating_sub(1));
+
+        let mut shown = graphemes[..keep.min(graphemes.len())].concat().trim_end().to_string();
+        shown.push_str(ellipsis);
+        full.shown = shown.clone();
+        text.0 = shown;
+    }
+}
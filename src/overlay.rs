@@ -0,0 +1,73 @@
+//! Overlay folders: folders laid out like the messages folder whose keys
+//! replace the messages folder's, for build flavors (`messages-demo/`).
+//!
+//! An overlay only replaces: languages and keys it has but the messages
+//! folder lacks are reported and ignored, as they usually mean a key was
+//! renamed. The module only depends on `std` and `serde_json`, so `build.rs`
+//! shares it to bundle an overlay the way `I18nConfig::overlay` applies it
+//! at runtime.
+
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value};
+
+use crate::reserved::split_annotation;
+
+/// The folder `overlay` names: a folder next to `messages_folder`
+/// (`assets/messages-demo` for `assets/messages`), or `overlay` itself when
+/// it is absolute.
+pub(crate) fn overlay_folder(messages_folder: &Path, overlay: &str) -> PathBuf {
+    messages_folder.parent().unwrap_or(Path::new("")).join(overlay)
+}
+
+/// The entries of an `overlay` tree (`{ lang: { file: entries } }`) that
+/// replace an entry of the messages folder, by language and file.
+/// `has_lang(lang)` and `defines(lang, file, key)` tell what the messages
+/// folder has; other languages and keys are passed to `report` and left
+/// out. Annotations of kept keys and file settings (`"@schema"`) are kept.
+pub(crate) fn overlay_entries(
+    overlay: &Value,
+    has_lang: impl Fn(&str) -> bool,
+    defines: impl Fn(&str, &str, &str) -> bool,
+    mut report: impl FnMut(String),
+) -> Vec<(String, String, Map<String, Value>)> {
+    let mut kept = Vec::new();
+    for (lang, files) in overlay.as_object().into_iter().flatten() {
+        if !has_lang(lang) {
+            report(format!("has a '{}' folder, which is not a language of the messages folder; ignored", lang));
+            continue;
+        }
+        for (file, entries) in files.as_object().into_iter().flatten() {
+            let mut replaced = Map::new();
+            for (key, value) in entries.as_object().into_iter().flatten() {
+                let base = split_annotation(key).map_or(key.as_str(), |(base, _)| base);
+                if key.starts_with('@') || defines(lang, file, base) {
+                    replaced.insert(key.clone(), value.clone());
+                } else if base == key {
+                    report(format!("overrides '{}/{}.{}', which the messages folder does not define; ignored", lang, file, key));
+                }
+            }
+            kept.push((lang.clone(), file.clone(), replaced));
+        }
+    }
+    kept
+}
+
+/// Applies an `overlay` tree to a `translations` tree, both
+/// `{ lang: { file: entries } }`, as [`overlay_entries`] selects them. File
+/// settings stay the messages folder's.
+#[cfg_attr(not(feature = "tools"), allow(dead_code))]
+pub(crate) fn merge_overlay(translations: &mut Value, overlay: &Value, report: impl FnMut(String)) {
+    let entries = overlay_entries(
+        overlay,
+        |lang| translations.get(lang).is_some(),
+        |lang, file, key| translations.get(lang).and_then(|files| files.get(file)).and_then(|file| file.get(key)).is_some(),
+        report,
+    );
+    let Some(langs) = translations.as_object_mut() else { return };
+    for (lang, file, replaced) in entries {
+        let Some(Value::Object(files)) = langs.get_mut(&lang) else { continue };
+        let Some(Value::Object(target)) = files.get_mut(&file) else { continue };
+        target.extend(replaced.into_iter().filter(|(key, _)| !key.starts_with('@')));
+    }
+}
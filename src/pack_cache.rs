@@ -0,0 +1,179 @@
+//! Client-side cache for downloaded language packs.
+//!
+//! Games that fetch language packs at runtime (e.g. on the web, instead of
+//! bundling every language into the WASM binary) can keep the downloaded JSON
+//! in a [`PackCache`] so returning players don't download it again. Packs are
+//! stored under their content hash ([`pack_hash`]); a [`PackManifest`] served
+//! next to the packs says which hash is current for each language, so a new
+//! translation release invalidates stale entries automatically.
+//!
+//! ```rust,no_run
+//! # use bevy_intl::{I18n, MemoryPackStore, PackCache, PackManifest};
+//! # fn download(_lang: &str) -> String { String::new() }
+//! # fn load(i18n: &mut I18n, cache: &mut PackCache<MemoryPackStore>, manifest: &PackManifest) {
+//! let pack = match cache.get(manifest, "fr") {
+//!     Some(pack) => pack,
+//!     None => {
+//!         let body = download("fr");
+//!         cache.insert(&body);
+//!         serde_json::from_str(&body).unwrap()
+//!     }
+//! };
+//! i18n.merge_pack("fr", &pack).unwrap();
+//! cache.prune(manifest);
+//! # }
+//! ```
+//!
+//! On `wasm32`, [`LocalStoragePackStore`] persists packs in the browser's
+//! `localStorage`; elsewhere, implement [`PackStore`] over any key-value store.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde_json::Value;
+use tracing::warn;
+
+/// Prefix of every key written to a [`PackStore`].
+const KEY_PREFIX: &str = "bevy-intl/pack/";
+
+/// Lists the current pack of each language, as published with the packs:
+///
+/// ```json
+/// { "version": "2026.10.1", "packs": { "fr": "3b1f0c9a6d2e4f87", "de": "…" } }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct PackManifest {
+    /// Release identifier, for display and logging.
+    #[serde(default)]
+    pub version: String,
+    /// Language code → [`pack_hash`] of its current pack.
+    pub packs: HashMap<String, String>,
+}
+
+/// Content hash identifying a pack: 64-bit FNV-1a of the raw JSON text, as
+/// 16 lowercase hex digits. Not cryptographic — it only detects changes.
+pub fn pack_hash(content: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in content.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    format!("{:016x}", hash)
+}
+
+/// Key-value storage behind a [`PackCache`].
+pub trait PackStore {
+    fn get_item(&self, key: &str) -> Option<String>;
+    fn set_item(&mut self, key: &str, value: &str);
+    fn remove_item(&mut self, key: &str);
+    /// Every key currently stored.
+    fn keys(&self) -> Vec<String>;
+}
+
+/// In-memory [`PackStore`], lost when the game exits.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryPackStore(HashMap<String, String>);
+
+impl PackStore for MemoryPackStore {
+    fn get_item(&self, key: &str) -> Option<String> {
+        self.0.get(key).cloned()
+    }
+
+    fn set_item(&mut self, key: &str, value: &str) {
+        self.0.insert(key.to_string(), value.to_string());
+    }
+
+    fn remove_item(&mut self, key: &str) {
+        self.0.remove(key);
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.0.keys().cloned().collect()
+    }
+}
+
+/// [`PackStore`] backed by the browser's `localStorage`. Browsers typically
+/// allow about 5 MB per origin; writes over quota are logged and skipped.
+#[cfg(target_arch = "wasm32")]
+pub struct LocalStoragePackStore {
+    storage: web_sys::Storage,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl LocalStoragePackStore {
+    /// `None` when `localStorage` is unavailable (no window, private mode…).
+    pub fn new() -> Option<Self> {
+        let storage = web_sys::window()?.local_storage().ok()??;
+        Some(Self { storage })
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl PackStore for LocalStoragePackStore {
+    fn get_item(&self, key: &str) -> Option<String> {
+        self.storage.get_item(key).ok()?
+    }
+
+    fn set_item(&mut self, key: &str, value: &str) {
+        if self.storage.set_item(key, value).is_err() {
+            warn!("could not cache language pack '{}' (storage full?)", key);
+        }
+    }
+
+    fn remove_item(&mut self, key: &str) {
+        let _ = self.storage.remove_item(key);
+    }
+
+    fn keys(&self) -> Vec<String> {
+        let len = self.storage.length().unwrap_or(0);
+        (0..len).filter_map(|i| self.storage.key(i).ok()?).collect()
+    }
+}
+
+/// Language packs cached by content hash, see the [module docs](self).
+#[derive(Clone, Debug, Default)]
+pub struct PackCache<S> {
+    store: S,
+}
+
+impl<S: PackStore> PackCache<S> {
+    pub fn new(store: S) -> Self {
+        Self { store }
+    }
+
+    /// The cached pack of `lang`, if the manifest lists one and it is stored
+    /// intact. Corrupted entries are removed.
+    pub fn get(&mut self, manifest: &PackManifest, lang: &str) -> Option<Value> {
+        let hash = manifest.packs.get(lang)?;
+        let key = format!("{}{}", KEY_PREFIX, hash);
+        let content = self.store.get_item(&key)?;
+        if pack_hash(&content) != *hash {
+            warn!("cached language pack for '{}' is corrupted, discarding it", lang);
+            self.store.remove_item(&key);
+            return None;
+        }
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Caches a downloaded pack and returns its hash.
+    pub fn insert(&mut self, content: &str) -> String {
+        let hash = pack_hash(content);
+        self.store.set_item(&format!("{}{}", KEY_PREFIX, hash), content);
+        hash
+    }
+
+    /// Removes cached packs the manifest no longer references.
+    pub fn prune(&mut self, manifest: &PackManifest) {
+        for key in self.store.keys() {
+            let Some(hash) = key.strip_prefix(KEY_PREFIX) else { continue };
+            if !manifest.packs.values().any(|h| h == hash) {
+                self.store.remove_item(&key);
+            }
+        }
+    }
+
+    /// The underlying store.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+}
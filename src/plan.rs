@@ -0,0 +1,58 @@
+//! Lookup plans reused across [`I18n::translation`](crate::I18n::translation)
+//! calls.
+//!
+//! Building an `I18nPartial` resolves the languages a file is looked up in:
+//! the fallback of a custom locale, overlays, loaded parent locales and the
+//! configured chain, filtered by the file's policy. UI code calling
+//! `translation("hud")` every frame would redo that work, and its
+//! allocations, each time, so the result is kept per file. The plans are
+//! dropped when the current language changes, and when the fallback chain or
+//! the loaded languages change.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use crate::MissingKeyStrategy;
+
+/// How lookups in one file proceed for the current language.
+pub(crate) struct FilePlan {
+    /// Languages tried after the current one; empty when the file's policy
+    /// disables fallback.
+    pub(crate) fallbacks: Vec<String>,
+    pub(crate) missing_key: MissingKeyStrategy,
+}
+
+/// [`FilePlan`]s of the language they were made for, by file.
+#[derive(Default)]
+pub(crate) struct PlanCache(RwLock<Plans>);
+
+#[derive(Default)]
+struct Plans {
+    lang: String,
+    files: HashMap<String, Arc<FilePlan>>,
+}
+
+impl PlanCache {
+    /// The plan of `file` in `lang`, made with `make` if there is none yet.
+    pub(crate) fn get(&self, lang: &str, file: &str, make: impl FnOnce() -> FilePlan) -> Arc<FilePlan> {
+        {
+            let plans = self.0.read().unwrap_or_else(|e| e.into_inner());
+            if let Some(plan) = plans.files.get(file).filter(|_| plans.lang == lang) {
+                return Arc::clone(plan);
+            }
+        }
+        let plan = Arc::new(make());
+        let mut plans = self.0.write().unwrap_or_else(|e| e.into_inner());
+        if plans.lang != lang {
+            plans.lang = lang.to_string();
+            plans.files.clear();
+        }
+        plans.files.insert(file.to_string(), Arc::clone(&plan));
+        plan
+    }
+
+    /// Drops every plan, after a change they depend on.
+    pub(crate) fn clear(&mut self) {
+        self.0.get_mut().unwrap_or_else(|e| e.into_inner()).files.clear();
+    }
+}
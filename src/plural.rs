@@ -0,0 +1,120 @@
+//! Plural category selection.
+//!
+//! Categories come from the CLDR rules of each language with the
+//! `plurals-cldr` feature (on by default). Without it no rules are loaded and
+//! every language uses [`basic_plural_category`], which keeps the CLDR data
+//! out of builds whose languages only need `one` and `other`.
+
+use std::collections::HashMap;
+
+#[cfg(feature = "plurals-cldr")]
+use intl_pluralrules::PluralRuleType;
+#[cfg(feature = "plurals-cldr")]
+pub(crate) use intl_pluralrules::PluralRules;
+#[cfg(feature = "plurals-cldr")]
+use tracing::warn;
+#[cfg(feature = "plurals-cldr")]
+use unic_langid::LanguageIdentifier;
+
+use crate::{CustomLocale, PluralForm};
+
+/// The rules of a language; there are none without the `plurals-cldr`
+/// feature.
+#[cfg(not(feature = "plurals-cldr"))]
+#[derive(Clone)]
+pub(crate) enum PluralRules {}
+
+/// CLDR cardinal rules of every locale that has some; the others are logged
+/// and left out.
+#[cfg(feature = "plurals-cldr")]
+pub(crate) fn build_plural_rules(locales: &[String]) -> HashMap<String, PluralRules> {
+    let mut map = HashMap::new();
+    for lang in locales {
+        match lang.parse::<LanguageIdentifier>() {
+            Ok(langid) => match PluralRules::create(langid, PluralRuleType::CARDINAL) {
+                Ok(rules) => {
+                    map.insert(lang.clone(), rules);
+                }
+                Err(e) => warn!("no CLDR plural rules for '{}': {}", lang, e),
+            },
+            Err(e) => warn!("could not parse '{}' as a language identifier: {}", lang, e),
+        }
+    }
+    map
+}
+
+#[cfg(not(feature = "plurals-cldr"))]
+pub(crate) fn build_plural_rules(_locales: &[String]) -> HashMap<String, PluralRules> {
+    HashMap::new()
+}
+
+/// CLDR ordinal rules of `lang`, if it has some.
+#[cfg(feature = "plurals-cldr")]
+fn ordinal_rules(lang: &str) -> Option<PluralRules> {
+    let langid = lang.parse::<LanguageIdentifier>().ok()?;
+    PluralRules::create(langid, PluralRuleType::ORDINAL).ok()
+}
+
+#[cfg(not(feature = "plurals-cldr"))]
+fn ordinal_rules(_lang: &str) -> Option<PluralRules> {
+    None
+}
+
+/// Cardinal and ordinal rules of every language of `langs`, built once at
+/// load. Custom locales take the rules of their `plural_rules_from` locale,
+/// or none.
+pub(crate) fn language_rules<'a>(
+    langs: impl IntoIterator<Item = &'a String>,
+    custom_locales: &[CustomLocale],
+) -> (HashMap<String, PluralRules>, HashMap<String, PluralRules>) {
+    let mut cardinal = HashMap::new();
+    let mut ordinal = HashMap::new();
+    for lang in langs {
+        // A custom name may happen to parse as a language identifier; the
+        // declared rules win.
+        let rules_from = match custom_locales.iter().find(|c| &c.name == lang) {
+            Some(custom) => custom.plural_rules_from.as_ref(),
+            None => Some(lang),
+        };
+        let Some(from) = rules_from else { continue };
+        if let Some(rules) = build_plural_rules(std::slice::from_ref(from)).remove(from) {
+            cardinal.insert(lang.clone(), rules);
+        }
+        if let Some(rules) = ordinal_rules(from) {
+            ordinal.insert(lang.clone(), rules);
+        }
+    }
+    (cardinal, ordinal)
+}
+
+/// Resolve a plural category for `|count|` using CLDR rules when available,
+/// falling back to anglo-centric defaults.
+pub(crate) fn select_plural_form(rules: Option<&PluralRules>, count: i128) -> PluralForm {
+    let count = u64::try_from(count.unsigned_abs()).unwrap_or(u64::MAX);
+    #[cfg(feature = "plurals-cldr")]
+    if let Some(rules) = rules {
+        match rules.select(count) {
+            Ok(cat) => return PluralForm::from(cat),
+            Err(e) => warn!("CLDR plural rule selection failed: {}", e),
+        }
+    }
+    #[cfg(not(feature = "plurals-cldr"))]
+    if let Some(rules) = rules {
+        match *rules {}
+    }
+    basic_plural_category(usize::try_from(count).unwrap_or(usize::MAX))
+}
+
+/// Anglo-centric plural category fallback used when no per-language CLDR
+/// rules are available. The CLDR-correct path is registered at runtime via
+/// [`I18n`]'s plural rules; this function only acts as a last resort, and
+/// for every language without the `plurals-cldr` feature.
+pub(crate) fn basic_plural_category(count: usize) -> PluralForm {
+    match count {
+        0 => PluralForm::Zero,
+        1 => PluralForm::One,
+        2 => PluralForm::Two,
+        3..=10 => PluralForm::Few,
+        _ => PluralForm::Many,
+    }
+}
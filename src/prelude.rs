@@ -0,0 +1,20 @@
+//! The types most games use, for a single glob import:
+//!
+//! ```rust
+//! use bevy_intl::prelude::*;
+//! ```
+//!
+//! It brings the resource and its config, the lookup macros and traits and,
+//! with the `bevy` feature, the plugin, the text components and the events
+//! sent on language changes. Everything else stays available at the crate
+//! root.
+
+pub use crate::i18n_args;
+pub use crate::{
+    CheckedKey, DisplayName, I18n, I18nConfig, I18nDiagnostic, I18nError, I18nPartial, Localize, LocalizedDisplay,
+    SectionValue,
+};
+#[cfg(feature = "bevy")]
+pub use crate::{
+    I18nCommand, I18nMode, I18nPlugin, I18nText, I18nTextSpan, LanguageAppExt, LanguageChanged, RefreshI18nText,
+};
@@ -0,0 +1,313 @@
+//! Quality checks over the loaded translations.
+//!
+//! These helpers are meant for QA tooling and debug builds: they walk every
+//! language and report problems with file / key references instead of
+//! waiting for a player to open the broken screen.
+
+use std::fmt;
+
+#[cfg(feature = "bevy")]
+use bevy::prelude::*;
+#[cfg(feature = "bevy")]
+use tracing::warn;
+
+use crate::concat::concatenation_smells;
+use crate::freeze::canonical_source;
+use crate::{I18n, Locale, Segment, TextDirection, Translations, parse_template};
+
+/// A translation longer than the `"key@max"` length declared for its key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LengthViolation {
+    pub lang: String,
+    pub file: String,
+    pub key: String,
+    /// Declared maximum, in characters.
+    pub max: usize,
+    /// Actual length of the longest offending string, in characters.
+    pub len: usize,
+}
+
+impl fmt::Display for LengthViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{}] {}.{} is {} characters long (max {})",
+            self.lang, self.file, self.key, self.len, self.max
+        )
+    }
+}
+
+/// A translation whose placeholder syntax is broken, see
+/// [`I18n::check_message_syntax`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageSyntaxError {
+    pub lang: String,
+    pub file: String,
+    pub key: String,
+    /// What is wrong, e.g. `malformed placeholder "{{ name }}"`.
+    pub message: String,
+}
+
+impl fmt::Display for MessageSyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}.{}: {}", self.lang, self.file, self.key, self.message)
+    }
+}
+
+/// A translation that looks like a fragment of a concatenated sentence, see
+/// [`I18n::check_concatenation`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConcatenationSmell {
+    pub lang: String,
+    pub file: String,
+    pub key: String,
+    /// Which rule it breaks, e.g. `ends with "{{item}}:"; …`.
+    pub message: String,
+}
+
+impl fmt::Display for ConcatenationSmell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}.{}: {}", self.lang, self.file, self.key, self.message)
+    }
+}
+
+/// Syntax errors of `template`. The parser keeps anything it cannot read as
+/// literal text, so leftover `{{` / `}}` in text are the errors.
+fn syntax_errors(template: &str, errors: &mut Vec<String>) {
+    for segment in parse_template(template) {
+        match segment {
+            Segment::Text(text) => {
+                if let Some(start) = text.find("{{") {
+                    let excerpt: String = text[start..].chars().take(24).collect();
+                    errors.push(format!("malformed placeholder {:?}", excerpt));
+                } else if text.contains("}}") {
+                    errors.push("unmatched '}}'".to_string());
+                }
+            }
+            Segment::Placeholder(_) | Segment::Transformed { .. } => {}
+            Segment::Select(select) => {
+                if select.case(None).is_none() {
+                    errors.push(format!("select '{}' has no 'other' case", select.name));
+                }
+                for (_, text) in select.cases() {
+                    syntax_errors(text, errors);
+                }
+            }
+        }
+    }
+}
+
+/// Language code without its region or script, e.g. `pt` for `pt-BR`.
+fn base_language(lang: &str) -> &str {
+    lang.split(['-', '_']).next().unwrap_or(lang)
+}
+
+/// Whether `lang`'s own value of `file` / `key` is identical to the
+/// `source` language's, i.e. was most likely copied instead of translated.
+/// Regional variants of the source (`en-GB` for `en`), values without any
+/// letter (`"{{count}}"`, `"100%"`) and keys annotated `"key@same_ok": true`
+/// are never copies.
+pub(crate) fn is_source_copy(translations: &Translations, source: &str, lang: &str, file: &str, key: &str) -> bool {
+    if Locale::same(base_language(lang), base_language(source)) {
+        return false;
+    }
+    let same_ok = translations.annotations.get(file).and_then(|keys| keys.get(key)).is_some_and(|a| a.same_ok);
+    if same_ok {
+        return false;
+    }
+    let lookup = |lang: &str| translations.langs.get(lang)?.get(file)?.get(key);
+    let (Some(value), Some(source_value)) = (lookup(lang), lookup(source)) else {
+        return false;
+    };
+    value.strings().iter().any(|s| has_letters(s)) && canonical_source(value) == canonical_source(source_value)
+}
+
+/// Whether the literal text of `template`, placeholders left out, contains
+/// a letter.
+fn has_letters(template: &str) -> bool {
+    parse_template(template).any(|segment| match segment {
+        Segment::Text(text) => text.chars().any(char::is_alphabetic),
+        _ => false,
+    })
+}
+
+/// Removes every source copy from the loaded translations, so lookups use
+/// the fallback chain, see [`SourceCopyPolicy::Fallback`](crate::SourceCopyPolicy::Fallback).
+pub(crate) fn drop_source_copies(translations: &mut Translations, source: &str) {
+    let mut copies = Vec::new();
+    for (lang, files) in &translations.langs {
+        for (file, sections) in files {
+            for key in sections.keys() {
+                if is_source_copy(translations, source, lang, file, key) {
+                    copies.push((lang.clone(), file.clone(), key.clone()));
+                }
+            }
+        }
+    }
+    for (lang, file, key) in copies {
+        if let Some(sections) = translations.langs.get_mut(&lang).and_then(|files| files.get_mut(&file)) {
+            sections.remove(&key);
+        }
+        if let Some(raw) = translations.raw.get_mut(&lang).and_then(|files| files.get_mut(&file)) {
+            raw.remove(&key);
+        }
+    }
+}
+
+impl I18n {
+    /// Whether `lang`'s translation of `file` / `key` is identical to the
+    /// source (first fallback) language's and therefore most likely an
+    /// untranslated copy. Regional variants of the source language, strings
+    /// without letters and keys annotated `"key@same_ok": true` are never
+    /// reported.
+    pub fn is_source_copy(&self, lang: &str, file: &str, key: &str) -> bool {
+        is_source_copy(&self.translations, self.get_fallback_lang(), lang, file, key)
+    }
+
+    /// `file.key` of every translation of `lang` that is a copy of the source
+    /// language, see [`is_source_copy`](Self::is_source_copy). Sorted.
+    pub fn source_copies(&self, lang: &str) -> Vec<String> {
+        let Some(files) = self.translations.langs.get(lang) else {
+            return Vec::new();
+        };
+        let mut copies: Vec<String> = files
+            .iter()
+            .flat_map(|(file, sections)| {
+                sections
+                    .keys()
+                    .filter(move |key| self.is_source_copy(lang, file, key))
+                    .map(move |key| format!("{}.{}", file, key))
+            })
+            .collect();
+        copies.sort();
+        copies
+    }
+
+    /// Parses every loaded string and reports broken placeholders: `{{`
+    /// that does not start a valid placeholder (`{{ name }}`, `{{name}`),
+    /// stray `}}` and select placeholders without an `other` case. Results
+    /// are sorted by language, file and key.
+    pub fn check_message_syntax(&self) -> Vec<MessageSyntaxError> {
+        let mut errors = Vec::new();
+        for lang in self.available_languages() {
+            let Some(files) = self.translations.langs.get(lang) else {
+                continue;
+            };
+            let mut file_names: Vec<&String> = files.keys().collect();
+            file_names.sort();
+            for file in file_names {
+                let sections = &files[file];
+                let mut keys: Vec<&String> = sections.keys().collect();
+                keys.sort();
+                for key in keys {
+                    let mut messages = Vec::new();
+                    for template in sections[key].strings() {
+                        syntax_errors(template, &mut messages);
+                    }
+                    messages.sort();
+                    messages.dedup();
+                    errors.extend(messages.into_iter().map(|message| MessageSyntaxError {
+                        lang: lang.clone(),
+                        file: file.clone(),
+                        key: key.clone(),
+                        message,
+                    }));
+                }
+            }
+        }
+        errors
+    }
+
+    /// Reports translations that look like pieces of a sentence assembled
+    /// in code, which cannot be translated with another word order: a
+    /// trailing `"{{name}}:"`, leading or trailing spaces, a trailing `,`
+    /// `;` or dash and, in right-to-left languages, leading punctuation that
+    /// bidi reordering moves. Results are sorted by language, file and key.
+    pub fn check_concatenation(&self) -> Vec<ConcatenationSmell> {
+        let mut smells = Vec::new();
+        for lang in self.available_languages() {
+            let Some(files) = self.translations.langs.get(lang) else {
+                continue;
+            };
+            let direction = self.custom_locale(lang).map_or_else(|| Locale::direction(lang), |c| c.direction);
+            let rtl = direction == TextDirection::RightToLeft;
+            let mut file_names: Vec<&String> = files.keys().collect();
+            file_names.sort();
+            for file in file_names {
+                let sections = &files[file];
+                let mut keys: Vec<&String> = sections.keys().collect();
+                keys.sort();
+                for key in keys {
+                    let mut messages: Vec<String> =
+                        sections[key].strings().into_iter().flat_map(|s| concatenation_smells(s, rtl)).collect();
+                    messages.sort();
+                    messages.dedup();
+                    smells.extend(messages.into_iter().map(|message| ConcatenationSmell {
+                        lang: lang.clone(),
+                        file: file.clone(),
+                        key: key.clone(),
+                        message,
+                    }));
+                }
+            }
+        }
+        smells
+    }
+
+    /// Checks every language against the `"key@max"` length annotations.
+    ///
+    /// Placeholders are counted as written in the template (`{{name}}` is 8
+    /// characters), and for plural / gender maps the longest variant is
+    /// reported. Results are sorted by language, file and key.
+    pub fn check_max_lengths(&self) -> Vec<LengthViolation> {
+        let mut violations = Vec::new();
+
+        for lang in self.available_languages() {
+            let Some(files) = self.translations.langs.get(lang) else {
+                continue;
+            };
+            let mut file_names: Vec<&String> = self.translations.annotations.keys().collect();
+            file_names.sort();
+            for file in file_names {
+                let Some(sections) = files.get(file) else {
+                    continue;
+                };
+                let annotations = &self.translations.annotations[file];
+                let mut keys: Vec<&String> = annotations.keys().collect();
+                keys.sort();
+                for key in keys {
+                    let (Some(max), Some(value)) = (annotations[key].max_len, sections.get(key))
+                    else {
+                        continue;
+                    };
+                    let len = value.strings().iter().map(|s| s.chars().count()).max().unwrap_or(0);
+                    if len > max {
+                        violations.push(LengthViolation {
+                            lang: lang.clone(),
+                            file: file.clone(),
+                            key: key.clone(),
+                            max,
+                            len,
+                        });
+                    }
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+/// Startup system logging every [`MessageSyntaxError`] at once, added by
+/// `I18nPlugin` when [`I18nConfig::preflight`](crate::I18nConfig::preflight)
+/// is set.
+#[cfg(feature = "bevy")]
+pub fn preflight_i18n_messages(i18n: Res<I18n>) {
+    let errors = i18n.check_message_syntax();
+    for error in &errors {
+        warn!("message syntax: {}", error);
+    }
+    if !errors.is_empty() {
+        warn!("{} translations have syntax errors", errors.len());
+    }
+}
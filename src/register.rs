@@ -0,0 +1,68 @@
+//! Speech registers (politeness levels).
+//!
+//! Japanese and Korean conjugate for politeness, and many games switch the
+//! narrative tone globally. A key can hold one variant per register instead
+//! of a plain string:
+//!
+//! ```json
+//! { "greeting": { "casual": "やあ！", "polite": "こんにちは。", "formal": "ようこそお越しくださいました。" } }
+//! ```
+//!
+//! Every text lookup then picks the variant of [`I18n::speech_register`].
+//! Languages without registers keep plain strings.
+
+use std::collections::HashMap;
+
+use crate::{I18n, SubKey};
+
+/// Politeness level of translated text, see [`I18n::set_speech_register`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SpeechRegister {
+    /// Plain speech between friends (タメ口, 반말).
+    Casual,
+    /// Everyday polite speech (です・ます, 해요체).
+    #[default]
+    Polite,
+    /// Honorific speech (敬語, 합쇼체).
+    Formal,
+}
+
+impl SpeechRegister {
+    /// Key of this register in a register map.
+    pub fn key(self) -> &'static str {
+        match self {
+            SpeechRegister::Casual => "casual",
+            SpeechRegister::Polite => "polite",
+            SpeechRegister::Formal => "formal",
+        }
+    }
+
+    /// Registers to try, in order, when this one is requested: the closest
+    /// level first.
+    fn preference(self) -> [SpeechRegister; 3] {
+        match self {
+            SpeechRegister::Casual => [SpeechRegister::Casual, SpeechRegister::Polite, SpeechRegister::Formal],
+            SpeechRegister::Polite => [SpeechRegister::Polite, SpeechRegister::Formal, SpeechRegister::Casual],
+            SpeechRegister::Formal => [SpeechRegister::Formal, SpeechRegister::Polite, SpeechRegister::Casual],
+        }
+    }
+
+    /// Variant of a register map for this register, or the closest one the
+    /// map provides. `None` when the map has no register keys.
+    pub(crate) fn pick(self, variants: &HashMap<SubKey, String>) -> Option<&String> {
+        self.preference().iter().find_map(|register| variants.get(&SubKey::Register(*register)))
+    }
+}
+
+impl I18n {
+    /// Current speech register, initially `I18nConfig::speech_register`.
+    pub fn speech_register(&self) -> SpeechRegister {
+        self.speech_register
+    }
+
+    /// Switches the speech register game-wide. In Bevy, prefer
+    /// `I18nCommand::SetSpeechRegister` so `I18nText` labels are refreshed.
+    pub fn set_speech_register(&mut self, register: SpeechRegister) {
+        self.speech_register = register;
+    }
+}
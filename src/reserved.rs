@@ -0,0 +1,41 @@
+//! Names reserved by the translation file format: `_`-prefixed files and
+//! `"key@attr"` annotations.
+//!
+//! The module only depends on `std`, so `build.rs` shares it (through
+//! `key_macro.rs`) to tell translations from reserved entries the way the
+//! loader does.
+
+/// Reserved per-language settings file (`_meta.json`).
+pub(crate) const META_FILE: &str = "_meta";
+/// Reserved per-language image mapping (`_images.json`), read by
+/// `I18n::image`. Its entries are not translations.
+pub(crate) const IMAGES_FILE: &str = "_images";
+/// Reserved per-language audio clips (`_audio.json`), read by
+/// `I18n::audio`. Its entries are not translations.
+pub(crate) const AUDIO_FILE: &str = "_audio";
+
+/// Whether `file` maps keys to assets ([`IMAGES_FILE`], [`AUDIO_FILE`])
+/// instead of holding translations.
+pub(crate) fn is_asset_manifest(file: &str) -> bool {
+    file == IMAGES_FILE || file == AUDIO_FILE
+}
+
+/// Whether `file` holds translations: it is not reserved (`_`-prefixed),
+/// asset manifests included.
+pub(crate) fn is_translation_file(file: &str) -> bool {
+    !file.starts_with('_')
+}
+
+/// Entry marking an object as a group of keys (`"@group": true`), whatever
+/// its keys look like.
+pub(crate) const GROUP_MARKER: &str = "@group";
+
+/// Attributes of `"key@attr"` annotations.
+pub(crate) const KEY_ATTRIBUTES: [&str; 3] = ["gender", "max", "same_ok"];
+
+/// The key and attribute of a `"key@attr"` annotation. Keys whose suffix
+/// after `@` is no known attribute (`"support@example.com"`) are
+/// translation keys.
+pub(crate) fn split_annotation(key: &str) -> Option<(&str, &str)> {
+    key.rsplit_once('@').filter(|(_, attr)| KEY_ATTRIBUTES.contains(attr))
+}
@@ -0,0 +1,115 @@
+//! Versioned conventions of translation files, and migrations between them.
+//!
+//! A file declares the conventions it is written in with a top-level
+//! `"@schema"` number; files without one are schema 1. Files of an older
+//! schema keep loading: each of their entries is migrated to the current
+//! schema, [`TRANSLATION_SCHEMA`], as it is loaded. `bevy-intl-cli migrate`
+//! rewrites the files themselves with [`migrate_file`].
+//!
+//! | Schema | Changes |
+//! |--------|---------|
+//! | 1 | Exact counts of plural maps are bare numbers: `"0": "no apples"`. |
+//! | 2 | Exact counts are ICU-style, `"=0": "no apples"`, so they no longer look like case keys (`"levels": { "1": "Forest" }`). A bare number is a case key. |
+//!
+//! ```json
+//! { "@schema": 2,
+//!   "apples": { "=0": "No apples", "one": "{{count}} apple", "other": "{{count}} apples" } }
+//! ```
+//!
+//! Migrations work entry by entry, so streamed files are migrated too. Keep
+//! `"@schema"` before the entries of a streamed file: entries read before it
+//! are migrated as schema 1.
+
+use serde_json::Value;
+
+use crate::PluralForm;
+use crate::reserved::split_annotation;
+
+/// Key of a translation file holding its schema.
+pub(crate) const SCHEMA_KEY: &str = "@schema";
+
+/// Schema of the translation files this version reads natively and
+/// [`migrate_file`] writes.
+pub const TRANSLATION_SCHEMA: u64 = MIGRATIONS.len() as u64 + 1;
+
+/// Migrates one entry of a file from schema `n + 1` to schema `n + 2`.
+const MIGRATIONS: [fn(&mut Value); 1] = [explicit_exact_counts];
+
+/// The `"@schema"` of a translation file, 1 when it has none. Fails when
+/// the schema is not a positive integer.
+pub fn file_schema(file: &Value) -> Result<u64, String> {
+    file.get(SCHEMA_KEY).map_or(Ok(1), parse_schema)
+}
+
+/// Rewrites the entries of `file` to the conventions of
+/// [`TRANSLATION_SCHEMA`] and sets its `"@schema"`, returning the schema it
+/// was in. Fails, leaving `file` untouched, on a schema newer than this
+/// version of bevy-intl reads.
+///
+/// ```rust
+/// # use bevy_intl::{TRANSLATION_SCHEMA, migrate_file};
+/// let mut file = serde_json::json!({ "apples": { "0": "No apples", "other": "{{count}} apples" } });
+/// assert_eq!(migrate_file(&mut file), Ok(1));
+/// assert_eq!(file["apples"]["=0"], "No apples");
+/// assert_eq!(file["@schema"], TRANSLATION_SCHEMA);
+/// ```
+pub fn migrate_file(file: &mut Value) -> Result<u64, String> {
+    let schema = file_schema(file)?;
+    if schema > TRANSLATION_SCHEMA {
+        return Err(newer_schema(schema));
+    }
+    let entries = file.as_object_mut().ok_or("must be a JSON object of translations")?;
+    for (key, value) in entries.iter_mut() {
+        if split_annotation(key).is_none() {
+            migrate_entry(schema, value);
+        }
+    }
+    entries.insert(SCHEMA_KEY.to_string(), TRANSLATION_SCHEMA.into());
+    Ok(schema)
+}
+
+pub(crate) fn parse_schema(value: &Value) -> Result<u64, String> {
+    value
+        .as_u64()
+        .filter(|schema| *schema > 0)
+        .ok_or_else(|| format!("'{}' must be a positive integer, not {}", SCHEMA_KEY, value))
+}
+
+pub(crate) fn newer_schema(schema: u64) -> String {
+    format!(
+        "translation schema {} is newer than this version of bevy-intl reads ({}); update bevy-intl",
+        schema, TRANSLATION_SCHEMA
+    )
+}
+
+/// Migrates the value of one entry of a schema `schema` file to
+/// [`TRANSLATION_SCHEMA`].
+pub(crate) fn migrate_entry(schema: u64, value: &mut Value) {
+    let from = usize::try_from(schema.saturating_sub(1)).unwrap_or(usize::MAX);
+    for migrate in MIGRATIONS.iter().skip(from) {
+        migrate(value);
+    }
+}
+
+// 1 → 2: `"0"` → `"=0"` in plural maps, the maps with a plural category.
+// Maps of numbers alone are left as they are: they may be case maps.
+fn explicit_exact_counts(value: &mut Value) {
+    let Some(map) = value.as_object_mut() else { return };
+    let is_plural_map =
+        map.values().all(Value::is_string) && map.keys().any(|key| PluralForm::from_key(key).is_some());
+    if !is_plural_map {
+        map.values_mut().for_each(explicit_exact_counts);
+        return;
+    }
+    let counts: Vec<String> = map
+        .keys()
+        .filter(|key| key.parse::<i128>().is_ok_and(|n| n.to_string() == **key))
+        .filter(|key| !map.contains_key(&format!("={}", key)))
+        .cloned()
+        .collect();
+    for count in counts {
+        if let Some(text) = map.remove(&count) {
+            map.insert(format!("={}", count), text);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! Screenshot matrix for localization QA.
+//!
+//! Translation vendors review every screen in every language. Inserting a
+//! [`ScreenshotMatrix`] resource makes the [`run_screenshot_matrix`] system go
+//! through each UI state × available language: it switches the language,
+//! sends a [`ScreenshotMatrixStep`] so the game can show the state, waits for
+//! the UI to settle and saves `<output_dir>/<state>_<lang>.png`.
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_intl::{ScreenshotMatrix, ScreenshotMatrixStep};
+//!
+//! fn setup(mut commands: Commands) {
+//!     commands.insert_resource(
+//!         ScreenshotMatrix::new(["main_menu", "inventory"]).output_dir("screenshots").exit_when_done(true),
+//!     );
+//! }
+//!
+//! fn show_state(mut steps: MessageReader<ScreenshotMatrixStep>) {
+//!     for step in steps.read() {
+//!         // Open the screen named `step.state`.
+//!     }
+//! }
+//! ```
+
+use std::path::PathBuf;
+
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{Screenshot, save_to_disk};
+
+use crate::I18n;
+
+/// Sent when the matrix moves to a new state / language pair, after the
+/// language has been switched. Show `state` before the screenshot is taken.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct ScreenshotMatrixStep {
+    pub state: String,
+    pub lang: String,
+}
+
+/// Screenshots to take, and the progress of [`run_screenshot_matrix`].
+#[derive(Resource, Debug, Clone)]
+pub struct ScreenshotMatrix {
+    /// Names of the UI states, used in file names.
+    pub states: Vec<String>,
+    /// Folder receiving the screenshots (default: `screenshots`).
+    pub output_dir: PathBuf,
+    /// Frames to wait between a step and its screenshot (default: 1), so
+    /// refreshed text is laid out and rendered.
+    pub settle_frames: u32,
+    /// Sends `AppExit::Success` once every screenshot has been requested.
+    pub exit_when_done: bool,
+    step: usize,
+    waited: Option<u32>,
+    original_lang: Option<String>,
+    finished: bool,
+}
+
+impl ScreenshotMatrix {
+    pub fn new<S: Into<String>>(states: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            states: states.into_iter().map(Into::into).collect(),
+            output_dir: PathBuf::from("screenshots"),
+            settle_frames: 1,
+            exit_when_done: false,
+            step: 0,
+            waited: None,
+            original_lang: None,
+            finished: false,
+        }
+    }
+
+    pub fn output_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.output_dir = dir.into();
+        self
+    }
+
+    pub fn settle_frames(mut self, frames: u32) -> Self {
+        self.settle_frames = frames;
+        self
+    }
+
+    pub fn exit_when_done(mut self, exit: bool) -> Self {
+        self.exit_when_done = exit;
+        self
+    }
+
+    /// File written for `state` in `lang`.
+    pub fn path(&self, state: &str, lang: &str) -> PathBuf {
+        self.output_dir.join(format!("{}_{}.png", state, lang))
+    }
+
+    /// Whether every screenshot has been requested. The original language is
+    /// restored at that point.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+/// Advances the [`ScreenshotMatrix`] resource by one frame, if present.
+pub fn run_screenshot_matrix(
+    matrix: Option<ResMut<ScreenshotMatrix>>,
+    mut i18n: ResMut<I18n>,
+    mut steps: MessageWriter<ScreenshotMatrixStep>,
+    mut exit: MessageWriter<AppExit>,
+    mut commands: Commands,
+) {
+    let Some(mut matrix) = matrix else {
+        return;
+    };
+    if matrix.finished {
+        return;
+    }
+
+    let langs = i18n.available_languages().to_vec();
+    if matrix.step >= matrix.states.len() * langs.len() {
+        if let Some(original) = matrix.original_lang.take() {
+            if i18n.get_lang() != original {
+                i18n.set_lang(&original);
+            }
+        }
+        matrix.finished = true;
+        if matrix.exit_when_done {
+            exit.write(AppExit::Success);
+        }
+        return;
+    }
+
+    let state = matrix.states[matrix.step / langs.len()].clone();
+    let lang = &langs[matrix.step % langs.len()];
+    match matrix.waited {
+        None => {
+            if matrix.original_lang.is_none() {
+                matrix.original_lang = Some(i18n.get_lang().to_string());
+            }
+            if i18n.get_lang() != lang {
+                i18n.set_lang(lang);
+            }
+            steps.write(ScreenshotMatrixStep { state, lang: lang.clone() });
+            matrix.waited = Some(0);
+        }
+        Some(waited) if waited < matrix.settle_frames => matrix.waited = Some(waited + 1),
+        Some(_) => {
+            commands.spawn(Screenshot::primary_window()).observe(save_to_disk(matrix.path(&state, lang)));
+            matrix.step += 1;
+            matrix.waited = None;
+        }
+    }
+}
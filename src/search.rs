@@ -0,0 +1,111 @@
+//! Locale-aware matching for in-game search boxes.
+//!
+//! Players rarely type accents or the exact case: "pokemon" should find
+//! "Pokémon", and "strasse" should find "Straße". [`I18n::matches`] folds the
+//! query and the candidate before comparing them — lowercase, diacritics
+//! removed, ligatures and `ß` expanded — following the conventions of the
+//! current language:
+//!
+//! - letters the language treats as distinct are kept (`å ä ö` in Swedish
+//!   and Finnish, `æ ø å` in Danish and Norwegian, `ñ` in Spanish);
+//! - Turkish and Azerbaijani lowercase `I` to dotless `ı` and `İ` to `i`,
+//!   and keep `ı` distinct.
+//!
+//! Only precomposed Latin letters and combining marks are folded; other
+//! scripts are compared after lowercasing.
+
+use crate::I18n;
+
+/// Base letter of each lowercase Latin letter with a diacritic.
+const DIACRITICS: &[(char, &str)] = &[
+    ('a', "àáâãäåāăą"),
+    ('c', "çćĉċč"),
+    ('d', "ďđ"),
+    ('e', "èéêëēĕėęě"),
+    ('g', "ĝğġģ"),
+    ('h', "ĥħ"),
+    ('i', "ìíîïĩīĭįı"),
+    ('j', "ĵ"),
+    ('k', "ķ"),
+    ('l', "ĺļľŀł"),
+    ('n', "ñńņňŉ"),
+    ('o', "òóôõöøōŏő"),
+    ('r', "ŕŗř"),
+    ('s', "śŝşšș"),
+    ('t', "ţťŧț"),
+    ('u', "ùúûüũūŭůűų"),
+    ('w', "ŵ"),
+    ('y', "ýÿŷ"),
+    ('z', "źżž"),
+];
+
+/// Letters written as several letters once folded.
+const EXPANSIONS: &[(char, &str)] = &[('ß', "ss"), ('æ', "ae"), ('œ', "oe"), ('þ', "th")];
+
+/// Letters with a diacritic that `lang` sorts as letters of their own.
+fn distinct_letters(lang: &str) -> &'static str {
+    match lang.split(['-', '_']).next().unwrap_or(lang) {
+        "sv" | "fi" => "åäö",
+        "da" | "nb" | "nn" | "no" => "æøå",
+        "es" => "ñ",
+        "tr" | "az" => "ı",
+        _ => "",
+    }
+}
+
+/// `text` folded for matching in `lang`, see the [module docs](self).
+pub fn fold_for_search(text: &str, lang: &str) -> String {
+    let distinct = distinct_letters(lang);
+    let dotless_i = distinct.contains('ı');
+    let mut folded = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            'I' if dotless_i => folded.push('ı'),
+            'İ' => folded.push('i'),
+            // Combining marks of decomposed text.
+            '\u{300}'..='\u{36f}' => {}
+            _ => {
+                for lower in c.to_lowercase() {
+                    fold_letter(lower, distinct, &mut folded);
+                }
+            }
+        }
+    }
+    folded
+}
+
+fn fold_letter(c: char, distinct: &str, folded: &mut String) {
+    if distinct.contains(c) {
+        folded.push(c);
+    } else if let Some((_, expansion)) = EXPANSIONS.iter().find(|(letter, _)| *letter == c) {
+        folded.push_str(expansion);
+    } else if let Some((base, _)) = DIACRITICS.iter().find(|(_, accented)| accented.contains(c)) {
+        folded.push(*base);
+    } else {
+        folded.push(c);
+    }
+}
+
+impl I18n {
+    /// Whether `candidate` contains `query`, ignoring case and diacritics
+    /// as the current language does (see [`fold_for_search`]). An empty
+    /// query matches everything.
+    ///
+    /// ```rust,no_run
+    /// # use bevy_intl::I18n;
+    /// # fn search(i18n: &I18n) {
+    /// assert!(i18n.matches("pokemon", "Pokémon"));
+    /// assert!(i18n.matches("strasse", "Hauptstraße"));
+    /// # }
+    /// ```
+    pub fn matches(&self, query: &str, candidate: &str) -> bool {
+        self.search_key(candidate).contains(&self.search_key(query))
+    }
+
+    /// `text` folded for matching in the current language. Store it next to
+    /// long lists (inventory, encyclopedia) to avoid folding every entry on
+    /// each keystroke, and compare it with the folded query.
+    pub fn search_key(&self, text: &str) -> String {
+        fold_for_search(text, self.get_lang())
+    }
+}
@@ -0,0 +1,62 @@
+//! Line-breaking support for languages written without spaces.
+//!
+//! Thai, Lao, Khmer and Burmese do not separate words with spaces, so Bevy's
+//! layout has no break opportunity inside a sentence and long strings
+//! overflow. With the `segmentation` feature, text rendered by the reactive
+//! components in those languages goes through ICU's dictionary-based word
+//! segmenter and gets a zero-width space (U+200B) at every word boundary.
+//! Without the feature, [`I18n::prepare_for_layout`] returns text unchanged.
+
+use crate::I18n;
+
+/// Zero-width space, inserted at word boundaries.
+pub const ZERO_WIDTH_SPACE: char = '\u{200B}';
+
+/// Whether `lang` is written without spaces between words and needs
+/// dictionary-based segmentation to wrap.
+pub fn needs_word_segmentation(lang: &str) -> bool {
+    matches!(
+        lang.split(['-', '_']).next().unwrap_or(lang),
+        "th" | "lo" | "km" | "my"
+    )
+}
+
+/// Inserts a zero-width space at every word boundary of `text` that is not
+/// already next to whitespace.
+#[cfg(feature = "segmentation")]
+pub fn insert_word_breaks(text: &str) -> String {
+    use icu_segmenter::WordSegmenter;
+    use icu_segmenter::options::WordBreakInvariantOptions;
+
+    let segmenter = WordSegmenter::new_dictionary(WordBreakInvariantOptions::default());
+    let mut out = String::with_capacity(text.len() + text.len() / 4);
+    let mut last = 0;
+    for bp in segmenter.segment_str(text) {
+        if bp == 0 || bp == text.len() {
+            continue;
+        }
+        out.push_str(&text[last..bp]);
+        let before = text[..bp].chars().next_back();
+        let after = text[bp..].chars().next();
+        let is_gap = |c: Option<char>| c.is_none_or(|c| c.is_whitespace() || c == ZERO_WIDTH_SPACE);
+        if !is_gap(before) && !is_gap(after) {
+            out.push(ZERO_WIDTH_SPACE);
+        }
+        last = bp;
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+impl I18n {
+    /// Prepares rendered text for Bevy's layout: inserts word breaks when the
+    /// current language needs segmentation and the `segmentation` feature is
+    /// enabled; otherwise returns `text` as is.
+    pub fn prepare_for_layout(&self, text: String) -> String {
+        #[cfg(feature = "segmentation")]
+        if needs_word_segmentation(self.get_lang()) {
+            return insert_word_breaks(&text);
+        }
+        text
+    }
+}
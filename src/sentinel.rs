@@ -0,0 +1,197 @@
+//! Built-in translations of the crate's own placeholder texts.
+//!
+//! Unresolved lookups ([`MissingKeyStrategy::Placeholder`](crate::MissingKeyStrategy))
+//! and the error translations used when no translation could be loaded show
+//! a short sentinel text. It is picked in the current language when the
+//! crate ships it, so even a failure state matches the rest of the UI;
+//! other languages get English.
+
+use crate::Locale;
+
+/// Placeholder texts shown instead of a translation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Sentinel {
+    Missing,
+    MissingPlural,
+    MissingGender,
+    MissingGenderPlural,
+    MissingDialogueLine,
+    Error,
+}
+
+/// Sentinel texts per language, in [`Sentinel`] order. English first.
+const SENTINELS: &[(&str, [&str; 6])] = &[
+    (
+        "en",
+        [
+            "Missing translation",
+            "Missing plural translation",
+            "Missing gender translation",
+            "Missing gender+plural translation",
+            "Missing dialogue line",
+            "Translation Error",
+        ],
+    ),
+    (
+        "ar",
+        [
+            "ترجمة مفقودة",
+            "ترجمة الجمع مفقودة",
+            "ترجمة الجنس مفقودة",
+            "ترجمة الجنس والجمع مفقودة",
+            "سطر حوار مفقود",
+            "خطأ في الترجمة",
+        ],
+    ),
+    (
+        "de",
+        [
+            "Fehlende Übersetzung",
+            "Fehlende Pluralübersetzung",
+            "Fehlende geschlechtsspezifische Übersetzung",
+            "Fehlende geschlechtsspezifische Pluralübersetzung",
+            "Fehlende Dialogzeile",
+            "Übersetzungsfehler",
+        ],
+    ),
+    (
+        "es",
+        [
+            "Traducción faltante",
+            "Traducción en plural faltante",
+            "Traducción con género faltante",
+            "Traducción con género y plural faltante",
+            "Línea de diálogo faltante",
+            "Error de traducción",
+        ],
+    ),
+    (
+        "fr",
+        [
+            "Traduction manquante",
+            "Traduction au pluriel manquante",
+            "Traduction genrée manquante",
+            "Traduction genrée au pluriel manquante",
+            "Réplique manquante",
+            "Erreur de traduction",
+        ],
+    ),
+    (
+        "it",
+        [
+            "Traduzione mancante",
+            "Traduzione plurale mancante",
+            "Traduzione di genere mancante",
+            "Traduzione di genere e plurale mancante",
+            "Battuta di dialogo mancante",
+            "Errore di traduzione",
+        ],
+    ),
+    (
+        "ja",
+        [
+            "翻訳がありません",
+            "複数形の翻訳がありません",
+            "性別の翻訳がありません",
+            "性別と複数形の翻訳がありません",
+            "台詞がありません",
+            "翻訳エラー",
+        ],
+    ),
+    (
+        "ko",
+        ["번역 없음", "복수형 번역 없음", "성별 번역 없음", "성별 및 복수형 번역 없음", "대사 없음", "번역 오류"],
+    ),
+    (
+        "nl",
+        [
+            "Ontbrekende vertaling",
+            "Ontbrekende meervoudsvertaling",
+            "Ontbrekende geslachtsvertaling",
+            "Ontbrekende geslachts- en meervoudsvertaling",
+            "Ontbrekende dialoogregel",
+            "Vertaalfout",
+        ],
+    ),
+    (
+        "pl",
+        [
+            "Brak tłumaczenia",
+            "Brak tłumaczenia liczby mnogiej",
+            "Brak tłumaczenia dla rodzaju",
+            "Brak tłumaczenia dla rodzaju i liczby",
+            "Brak kwestii dialogowej",
+            "Błąd tłumaczenia",
+        ],
+    ),
+    (
+        "pt",
+        [
+            "Tradução ausente",
+            "Tradução no plural ausente",
+            "Tradução de gênero ausente",
+            "Tradução de gênero e plural ausente",
+            "Fala de diálogo ausente",
+            "Erro de tradução",
+        ],
+    ),
+    (
+        "ru",
+        [
+            "Нет перевода",
+            "Нет перевода для множественного числа",
+            "Нет перевода для рода",
+            "Нет перевода для рода и числа",
+            "Нет реплики диалога",
+            "Ошибка перевода",
+        ],
+    ),
+    (
+        "tr",
+        [
+            "Eksik çeviri",
+            "Eksik çoğul çeviri",
+            "Eksik cinsiyet çevirisi",
+            "Eksik cinsiyet ve çoğul çevirisi",
+            "Eksik diyalog satırı",
+            "Çeviri hatası",
+        ],
+    ),
+    (
+        "uk",
+        [
+            "Немає перекладу",
+            "Немає перекладу для множини",
+            "Немає перекладу для роду",
+            "Немає перекладу для роду й числа",
+            "Немає репліки діалогу",
+            "Помилка перекладу",
+        ],
+    ),
+    ("zh", ["缺少翻译", "缺少复数翻译", "缺少性别翻译", "缺少性别和复数翻译", "缺少对白", "翻译错误"]),
+    ("zh-Hant", ["缺少翻譯", "缺少複數翻譯", "缺少性別翻譯", "缺少性別和複數翻譯", "缺少對白", "翻譯錯誤"]),
+];
+
+impl Sentinel {
+    /// Text of this sentinel in `lang`, or in its closest parent locale
+    /// (`zh-TW` → `zh-Hant`, `pt-BR` → `pt`); English otherwise.
+    pub(crate) fn text(self, lang: &str) -> &'static str {
+        let find = |code: &str| SENTINELS.iter().find(|(known, _)| Locale::same(known, code));
+        let mut code = lang.to_string();
+        let texts = loop {
+            if let Some((_, texts)) = find(&code) {
+                break texts;
+            }
+            match Locale::parent(&code) {
+                Some(parent) => code = parent,
+                None => break &SENTINELS[0].1,
+            }
+        };
+        texts[self as usize]
+    }
+}
+
+/// The [`Sentinel::Error`] text of every built-in language.
+pub(crate) fn error_texts() -> impl Iterator<Item = (&'static str, &'static str)> {
+    SENTINELS.iter().map(|(lang, texts)| (*lang, texts[Sentinel::Error as usize]))
+}
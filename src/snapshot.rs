@@ -0,0 +1,74 @@
+//! Read-only views of [`I18n`] for background work.
+//!
+//! An [`I18nSnapshot`] freezes the translations and language settings at the
+//! time it is taken. It is cheap to create (the translations are shared, not
+//! copied), `Send + Sync + Clone`, and outlives the ECS resource borrow, so it
+//! can be moved into async tasks: loading-screen tips, report generation,
+//! server notifications.
+//!
+//! Later changes to the resource (`set_lang`, `merge_pack`, …) do not affect
+//! existing snapshots.
+
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::I18n;
+
+/// Immutable, thread-safe copy of an [`I18n`], see [`I18n::snapshot`].
+///
+/// Derefs to [`I18n`], so every read-only method is available:
+///
+/// ```rust,no_run
+/// # use bevy_intl::I18n;
+/// # fn spawn(i18n: &I18n) {
+/// let snapshot = i18n.snapshot();
+/// std::thread::spawn(move || {
+///     let tip = snapshot.translation("tips").t("loading_1");
+///     println!("[{}] {}", snapshot.get_lang(), tip);
+/// });
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct I18nSnapshot {
+    i18n: Arc<I18n>,
+}
+
+impl Deref for I18nSnapshot {
+    type Target = I18n;
+
+    fn deref(&self) -> &I18n {
+        &self.i18n
+    }
+}
+
+impl I18n {
+    /// Takes a read-only [`I18nSnapshot`] of the current translations and
+    /// language settings. The translations themselves are shared, not copied.
+    pub fn snapshot(&self) -> I18nSnapshot {
+        I18nSnapshot {
+            i18n: Arc::new(I18n {
+                translations: Arc::clone(&self.translations),
+                current_lang: self.current_lang.clone(),
+                locale_folders_list: self.locale_folders_list.clone(),
+                fallback_chain: self.fallback_chain.clone(),
+                plural_rules: self.plural_rules.clone(),
+                ordinal_rules: self.ordinal_rules.clone(),
+                custom_locales: self.custom_locales.clone(),
+                file_policies: self.file_policies.clone(),
+                group_counts: self.group_counts,
+                zero_form: self.zero_form,
+                count_sign: self.count_sign,
+                format_overrides: self.format_overrides,
+                speech_register: self.speech_register,
+                log: self.log.clone(),
+                diagnostics: self.diagnostics.clone(),
+                analytics: self.analytics.clone(),
+                gender_keys: self.gender_keys.clone(),
+                source_copies: self.source_copies,
+                audio_lang: self.audio_lang.clone(),
+                user_dictionary: self.user_dictionary.clone(),
+                plans: Default::default(),
+            }),
+        }
+    }
+}
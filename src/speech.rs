@@ -0,0 +1,89 @@
+//! Text-to-speech hooks.
+//!
+//! Screen readers and voiced menus need the localized text *and* its
+//! language to pick a voice. Register a [`TextToSpeech`] backend with the
+//! [`I18nSpeech`] resource, then send [`SpeakLocalized`] messages: the
+//! [`speak_localized`] system resolves them in the current language and
+//! forwards the text with its BCP 47 tag (`pt-BR`, not `pt_BR`).
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_intl::{I18nSpeech, SpeakLocalized};
+//!
+//! fn setup(mut commands: Commands) {
+//!     commands.insert_resource(I18nSpeech::new(|text: &str, locale: &str| {
+//!         println!("[{}] {}", locale, text); // call the platform TTS here
+//!     }));
+//! }
+//!
+//! fn on_focus(mut speak: MessageWriter<SpeakLocalized>) {
+//!     speak.write(SpeakLocalized::new("menu", "play"));
+//! }
+//! ```
+
+use bevy::prelude::*;
+
+use crate::I18n;
+
+/// A text-to-speech integration. Implemented for `Fn(&str, &str)` closures.
+pub trait TextToSpeech: Send + Sync + 'static {
+    /// Speaks `text`, written in the language tagged `locale`.
+    fn speak(&self, text: &str, locale: &str);
+}
+
+impl<F: Fn(&str, &str) + Send + Sync + 'static> TextToSpeech for F {
+    fn speak(&self, text: &str, locale: &str) {
+        self(text, locale)
+    }
+}
+
+/// The registered [`TextToSpeech`] backend. Without it, [`SpeakLocalized`]
+/// messages are dropped.
+#[derive(Resource)]
+pub struct I18nSpeech(pub Box<dyn TextToSpeech>);
+
+impl I18nSpeech {
+    pub fn new(backend: impl TextToSpeech) -> Self {
+        Self(Box::new(backend))
+    }
+}
+
+/// Asks the [`I18nSpeech`] backend to speak `file` / `key` in the current
+/// language.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct SpeakLocalized {
+    pub file: String,
+    pub key: String,
+    /// Named arguments substituted like in `t_with_args`.
+    pub args: Vec<(String, String)>,
+}
+
+impl SpeakLocalized {
+    pub fn new(file: impl Into<String>, key: impl Into<String>) -> Self {
+        Self { file: file.into(), key: key.into(), args: Vec::new() }
+    }
+
+    pub fn arg(mut self, name: impl Into<String>, value: impl ToString) -> Self {
+        self.args.push((name.into(), value.to_string()));
+        self
+    }
+}
+
+/// Resolves [`SpeakLocalized`] messages and forwards them to [`I18nSpeech`].
+pub fn speak_localized(
+    mut messages: MessageReader<SpeakLocalized>,
+    i18n: Res<I18n>,
+    speech: Option<Res<I18nSpeech>>,
+) {
+    let Some(speech) = speech else {
+        messages.clear();
+        return;
+    };
+    let locale = i18n.get_lang().replace('_', "-");
+    for message in messages.read() {
+        let args: Vec<(&str, &dyn ToString)> =
+            message.args.iter().map(|(name, value)| (name.as_str(), value as &dyn ToString)).collect();
+        let text = i18n.translation(&message.file).t_with_args(&message.key, &args);
+        speech.0.speak(&text, &locale);
+    }
+}
@@ -0,0 +1,206 @@
+//! Language packs fetched on demand, for web builds with many languages.
+//!
+//! `build.rs` normally bundles every language into the binary, so each
+//! player downloads the strings of all of them. With
+//! `BEVY_INTL_SPLIT_BUNDLE=assets/i18n` set at build time, it writes one
+//! `<lang>.json` pack per language and a `manifest.json` into that folder
+//! instead, and bundles only the default language (`BEVY_INTL_DEFAULT_LANG`,
+//! `en` by default). [`I18nSplitBundlePlugin`] then loads the current
+//! language and the fallback chain through the `AssetServer` — with `fetch`
+//! on the web — and merges them as they arrive:
+//!
+//! ```rust,no_run
+//! use bevy::prelude::*;
+//! use bevy_intl::{I18nPlugin, I18nSplitBundlePlugin, i18n_packs_ready};
+//!
+//! # fn main_menu() {}
+//! App::new()
+//!     .add_plugins(DefaultPlugins)
+//!     .add_plugins(I18nPlugin::default())
+//!     // assets/i18n/fr.json, assets/i18n/de.json, …
+//!     .add_plugins(I18nSplitBundlePlugin::default())
+//!     .add_systems(Update, main_menu.run_if(i18n_packs_ready))
+//!     .run();
+//! ```
+//!
+//! Switch to a language that may not be loaded yet with
+//! [`I18nLanguagePacks::switch_to`]: the pack is fetched first, and the
+//! language changes once it is merged. Until then the labels keep the
+//! current language.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+
+use crate::{I18n, I18nCommand, PackManifest, TranslationFile, TranslationFileLoader, apply_i18n_commands};
+
+/// Fetches language packs written by `build.rs`, see the
+/// [module docs](self). Add it after [`I18nPlugin`](crate::I18nPlugin).
+#[derive(Debug, Clone)]
+pub struct I18nSplitBundlePlugin {
+    /// Folder of the asset directory holding the packs. Default: `"i18n"`.
+    pub folder: String,
+}
+
+impl Default for I18nSplitBundlePlugin {
+    fn default() -> Self {
+        Self { folder: "i18n".to_string() }
+    }
+}
+
+impl Plugin for I18nSplitBundlePlugin {
+    fn build(&self, app: &mut App) {
+        // `I18nAssetPlugin` registers them too.
+        if !app.world().contains_resource::<Assets<TranslationFile>>() {
+            app.init_asset::<TranslationFile>().init_asset_loader::<TranslationFileLoader>();
+        }
+        app.insert_resource(I18nLanguagePacks::new(&self.folder))
+            .add_message::<I18nCommand>()
+            .add_systems(Startup, request_configured_languages)
+            .add_systems(Update, load_language_packs.before(apply_i18n_commands));
+    }
+}
+
+/// Progress of a language pack, see [`I18nLanguagePacks::state`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PackState {
+    /// Requested and not merged yet.
+    Loading,
+    /// Merged into [`I18n`], or bundled.
+    Ready,
+    /// The pack could not be loaded; the language stays unavailable.
+    Failed,
+}
+
+/// Language packs requested from the folder of [`I18nSplitBundlePlugin`].
+#[derive(Resource, Debug)]
+pub struct I18nLanguagePacks {
+    folder: String,
+    manifest: Option<Handle<TranslationFile>>,
+    languages: Vec<String>,
+    packs: BTreeMap<String, PackState>,
+    handles: BTreeMap<String, Handle<TranslationFile>>,
+    switch_to: Option<String>,
+}
+
+impl I18nLanguagePacks {
+    fn new(folder: &str) -> Self {
+        Self {
+            folder: folder.trim_end_matches('/').to_string(),
+            manifest: None,
+            languages: Vec::new(),
+            packs: BTreeMap::new(),
+            handles: BTreeMap::new(),
+            switch_to: None,
+        }
+    }
+
+    /// Fetches the pack of `lang`, unless it was requested before or is
+    /// bundled.
+    pub fn request(&mut self, lang: &str) {
+        self.packs.entry(lang.to_string()).or_insert(PackState::Loading);
+    }
+
+    /// Fetches the pack of `lang` if needed, then switches to it with an
+    /// [`I18nCommand::SetLanguage`]. A later call replaces a pending switch.
+    pub fn switch_to(&mut self, lang: &str) {
+        self.request(lang);
+        self.switch_to = Some(lang.to_string());
+    }
+
+    /// Progress of the pack of `lang`, `None` if it was never requested.
+    pub fn state(&self, lang: &str) -> Option<PackState> {
+        self.packs.get(lang).copied()
+    }
+
+    /// Whether every requested pack is merged (or failed) and no language
+    /// switch is pending.
+    pub fn is_ready(&self) -> bool {
+        self.switch_to.is_none() && !self.packs.values().any(|state| *state == PackState::Loading)
+    }
+
+    /// Languages listed by the `manifest.json` of the folder, sorted; empty
+    /// until it is loaded. Use it for language pickers, as
+    /// [`I18n::available_languages`] only knows the merged ones.
+    pub fn languages(&self) -> &[String] {
+        &self.languages
+    }
+}
+
+/// Run condition: true once the requested language packs are merged, see
+/// [`I18nLanguagePacks::is_ready`]. Also true without
+/// [`I18nSplitBundlePlugin`].
+pub fn i18n_packs_ready(packs: Option<Res<I18nLanguagePacks>>) -> bool {
+    packs.is_none_or(|packs| packs.is_ready())
+}
+
+/// Requests the current language and the fallback chain at startup.
+fn request_configured_languages(mut packs: ResMut<I18nLanguagePacks>, i18n: Res<I18n>) {
+    packs.request(i18n.get_lang());
+    for lang in i18n.fallback_chain() {
+        packs.request(lang);
+    }
+}
+
+/// Loads the manifest and the requested packs, merges those that arrived
+/// with [`I18nCommand::MergePack`] and applies a pending switch once its
+/// language is ready.
+fn load_language_packs(
+    mut packs: ResMut<I18nLanguagePacks>,
+    server: Res<AssetServer>,
+    files: Res<Assets<TranslationFile>>,
+    i18n: Res<I18n>,
+    mut commands: MessageWriter<I18nCommand>,
+) {
+    let packs = &mut *packs;
+    let manifest = packs
+        .manifest
+        .get_or_insert_with(|| server.load(format!("{}/manifest.json", packs.folder)));
+    if packs.languages.is_empty() {
+        if let Some(TranslationFile(json)) = files.get(&*manifest) {
+            match serde_json::from_value::<PackManifest>(json.clone()) {
+                Ok(manifest) => {
+                    packs.languages = manifest.packs.into_keys().collect();
+                    packs.languages.sort();
+                }
+                Err(e) => warn!("invalid language pack manifest in '{}': {}", packs.folder, e),
+            }
+        }
+    }
+
+    for (lang, state) in &mut packs.packs {
+        if *state != PackState::Loading {
+            continue;
+        }
+        if !packs.handles.contains_key(lang) && i18n.available_languages().contains(lang) {
+            *state = PackState::Ready;
+            continue;
+        }
+        let handle = packs
+            .handles
+            .entry(lang.clone())
+            .or_insert_with(|| server.load(format!("{}/{}.json", packs.folder, lang)));
+        if let Some(TranslationFile(pack)) = files.get(&*handle) {
+            commands.write(I18nCommand::MergePack { lang: lang.clone(), pack: pack.clone() });
+            *state = PackState::Ready;
+            packs.handles.remove(lang);
+        } else if server.load_state(&*handle).is_failed() {
+            warn!("could not load the '{}' language pack from '{}'", lang, packs.folder);
+            *state = PackState::Failed;
+            packs.handles.remove(lang);
+        }
+    }
+
+    let Some(lang) = packs.switch_to.clone() else { return };
+    match packs.packs.get(&lang) {
+        Some(PackState::Ready) => {
+            commands.write(I18nCommand::SetLanguage(lang));
+            packs.switch_to = None;
+        }
+        Some(PackState::Failed) => {
+            warn!("cannot switch to '{}': its language pack failed to load", lang);
+            packs.switch_to = None;
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,205 @@
+//! Storage of loaded translations: languages → files → keys → values.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::reserved::{GROUP_MARKER, split_annotation};
+use crate::keys::FormerKeys;
+use crate::{GlossaryTerm, KeyMigrations, SubKey, WordFilter};
+
+/// Represents a value in a translation file.
+/// 
+/// Can be either a simple text string or a nested map for plurals/genders.
+/// 
+/// # Examples
+/// 
+/// Simple text:
+/// ```json
+/// "greeting": "Hello"
+/// ```
+/// 
+/// Nested map for plurals:
+/// ```json
+/// "items": {
+///   "one": "One item",
+///   "many": "{{count}} items"
+/// }
+/// ```
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum SectionValue {
+    /// A simple text value
+    Text(String),
+    /// A two-level nested map for combining gender and plural (or any other
+    /// two-axis discriminator), e.g. `{ "male": { "one": "...", "few": "..." } }`.
+    /// `untagged` deserialization tries this variant before [`Self::Map`], so
+    /// pure-string objects fall through to `Map` automatically.
+    Nested(HashMap<SubKey, HashMap<SubKey, String>>),
+    /// A single-level map of key-value pairs (for plurals OR genders alone)
+    Map(HashMap<SubKey, String>),
+}
+
+impl SectionValue {
+    /// Every string stored in this value, whatever its shape (useful for QA
+    /// checks that scan all translations).
+    pub fn strings(&self) -> Vec<&str> {
+        match self {
+            SectionValue::Text(s) => vec![s.as_str()],
+            SectionValue::Map(m) => m.values().map(String::as_str).collect(),
+            SectionValue::Nested(n) => n
+                .values()
+                .flat_map(|inner| inner.values().map(String::as_str))
+                .collect(),
+        }
+    }
+}
+
+/// A mapping of translation keys to their values within a file.
+pub(crate) type SectionMap = HashMap<String, SectionValue>;
+/// A mapping of file names to their section maps.
+pub(crate) type FileMap = HashMap<String, SectionMap>;
+/// A mapping of language codes to file maps.
+pub(crate) type LangMap = HashMap<String, FileMap>;
+/// A mapping of language codes to reserved (`_`-prefixed) files, kept as raw
+/// JSON. Asset manifests are reserved too, but stored like translation files.
+pub(crate) type MetaMap = HashMap<String, HashMap<String, Value>>;
+/// A mapping of keys to their original JSON values within a file.
+pub(crate) type RawSectionMap = HashMap<String, Value>;
+/// A mapping of keys to `"@file"` document texts within a file.
+pub(crate) type DocumentMap = HashMap<String, String>;
+/// A mapping of keys to their grammatical gender (`"key@gender"`) within a file.
+pub(crate) type GenderMap = HashMap<String, String>;
+
+/// Contains all translations loaded from filesystem or bundled data.
+/// 
+/// Organized as: `languages -> files -> keys -> values`
+///
+/// Files whose name starts with `_` (e.g. `_glossary.json`) are reserved for
+/// project metadata: they are not translation namespaces and are kept as raw
+/// JSON in [`meta`](Self::meta) instead.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Translations {
+    /// Map of language codes to their translation data
+    pub langs: LangMap,
+    /// Map of language codes to their reserved files (name including the `_`)
+    #[serde(default)]
+    pub meta: MetaMap,
+    /// Per-key annotations (`"key@attr"` entries), merged across languages:
+    /// `files -> keys -> annotations`
+    #[serde(default)]
+    pub annotations: HashMap<String, HashMap<String, KeyAnnotations>>,
+    /// Original JSON of the values that are not translations (arrays,
+    /// numbers, objects that are no plural or gender map…), and of the
+    /// entries of asset manifests: `languages -> files -> keys -> value`
+    #[serde(default)]
+    pub raw: HashMap<String, HashMap<String, RawSectionMap>>,
+    /// Texts of `{"@file": "..."}` documents: `languages -> files -> keys -> text`
+    #[serde(default)]
+    pub documents: HashMap<String, HashMap<String, DocumentMap>>,
+    /// Grammatical genders declared with `"key@gender"` entries. Unlike
+    /// annotations they differ per language: `languages -> files -> keys -> gender`
+    #[serde(default)]
+    pub genders: HashMap<String, HashMap<String, GenderMap>>,
+    /// Stable IDs and renamed keys from `_keys.json`: `files -> migrations`
+    #[serde(default)]
+    pub keys: HashMap<String, KeyMigrations>,
+    /// Former names of the renamed keys of `keys`: `files -> keys -> former keys`
+    #[serde(default)]
+    pub(crate) former_keys: HashMap<String, FormerKeys>,
+    /// Terms of `_glossary.json`, parsed once: `languages -> term ids -> term`
+    #[serde(default)]
+    pub glossaries: HashMap<String, HashMap<String, GlossaryTerm>>,
+    /// Word lists of `_filter.json`, parsed once: `languages -> filter`
+    #[serde(default)]
+    pub filters: HashMap<String, WordFilter>,
+}
+
+/// Metadata attached to a translation key with `"key@attr"` entries.
+///
+/// ```json
+/// { "button_ok": "OK", "button_ok@max": 12 }
+/// ```
+///
+/// Annotations describe the key rather than one translation, so they can be
+/// declared in any language file; when several languages declare the same
+/// constraint the strictest one wins.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct KeyAnnotations {
+    /// Maximum display length in characters (`"key@max": 12`).
+    pub max_len: Option<usize>,
+    /// Whether translations may be identical to the source language's —
+    /// brand names, "OK", onomatopoeia — without being reported as source
+    /// copies (`"key@same_ok": true`).
+    #[serde(default)]
+    pub same_ok: bool,
+}
+
+/// Whether an object groups keys (`"menu": { "title": "Menu", "audio": { … } }`,
+/// loaded as `menu.title`, `menu.audio`…) rather than being a plural, gender
+/// or register map. A `"@group": true` entry makes it a group. Otherwise maps
+/// win: an object with a plural category, exact count, gender or register
+/// key is a map. Other objects are groups when they hold `"key@attr"`
+/// annotations, mix strings and objects, or nest deeper than a gender ×
+/// plural map; objects of strings stay maps, whose cases lookups also read
+/// as `key.case`. Other values (numbers, arrays, `"@file"` documents) make
+/// it auxiliary data.
+pub(crate) fn is_key_group(obj: &Map<String, Value>) -> bool {
+    if obj.get(GROUP_MARKER).is_some_and(|marker| marker == true) {
+        return true;
+    }
+    if obj.keys().any(|key| key.starts_with('@') || !matches!(SubKey::from(key.as_str()), SubKey::Other(_))) {
+        return false;
+    }
+    let (annotations, entries): (Vec<_>, Vec<_>) = obj.iter().partition(|(key, _)| split_annotation(key).is_some());
+    if entries.is_empty() || !entries.iter().all(|(_, value)| value.is_string() || value.is_object()) {
+        return false;
+    }
+    let strings = entries.iter().filter(|(_, value)| value.is_string()).count();
+    let is_string_map = |value: &Value| value.as_object().is_some_and(|map| map.values().all(Value::is_string));
+    !annotations.is_empty()
+        || (strings > 0 && strings < entries.len())
+        || entries.iter().any(|(_, value)| value.is_object() && !is_string_map(value))
+}
+
+/// Convert a `serde_json::Value` into a [`SectionValue`], picking the best
+/// variant based on shape:
+///
+/// - String → [`SectionValue::Text`]
+/// - Object whose values are *all* objects → [`SectionValue::Nested`] (gender × plural)
+/// - Otherwise object → [`SectionValue::Map`] (single-axis: plural OR gender)
+/// - Anything else (number, array, null) → `None` (entry is skipped)
+pub(crate) fn parse_section_value(val: &Value) -> Option<SectionValue> {
+    if let Some(text) = val.as_str() {
+        return Some(SectionValue::Text(text.to_string()));
+    }
+    let obj = val.as_object()?;
+
+    let has_only_object_values = !obj.is_empty()
+        && obj.values().all(|v| v.is_object());
+
+    if has_only_object_values {
+        let mut nested = HashMap::new();
+        for (k, v) in obj {
+            if let Some(inner_obj) = v.as_object() {
+                let mut inner = HashMap::new();
+                for (ik, iv) in inner_obj {
+                    if let Some(s) = iv.as_str() {
+                        inner.insert(SubKey::from(ik.as_str()), s.to_string());
+                    }
+                }
+                nested.insert(SubKey::from(k.as_str()), inner);
+            }
+        }
+        return Some(SectionValue::Nested(nested));
+    }
+
+    let mut map = HashMap::new();
+    for (k, v) in obj {
+        if let Some(s) = v.as_str() {
+            map.insert(SubKey::from(k.as_str()), s.to_string());
+        }
+    }
+    Some(SectionValue::Map(map))
+}
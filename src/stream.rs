@@ -0,0 +1,66 @@
+//! Streaming load of large `.json` translation files.
+//!
+//! Parsing a file into one `serde_json::Value` first keeps the file text, the
+//! whole value tree and the finished translations in memory at once — a big
+//! spike for a 30 MB `dialogue.json`. Files read with the built-in JSON
+//! parser are instead deserialized straight from a buffered reader, one
+//! top-level entry at a time, and each entry is moved into the translations
+//! as soon as it is parsed.
+
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::Path;
+
+use serde::Deserializer;
+use serde::de::{MapAccess, Visitor};
+use serde_json::Value;
+
+use crate::Translations;
+use crate::loader::{FileBuilder, inline_document};
+
+/// Rough size of one entry in a translation file, used to pre-size the key
+/// map from the file size.
+const BYTES_PER_ENTRY: u64 = 64;
+
+/// Calls its function with every top-level entry of a JSON object.
+struct EntryVisitor<F>(F);
+
+impl<'de, F: FnMut(String, Value)> Visitor<'de> for EntryVisitor<F> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a mapping of translations")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(mut self, mut map: A) -> Result<(), A::Error> {
+        while let Some((key, value)) = map.next_entry::<String, Value>()? {
+            (self.0)(key, value);
+        }
+        Ok(())
+    }
+}
+
+/// Loads the `.json` file at `path` as the file `file_name` of `lang`, entry
+/// by entry. `"@file"` documents are read relative to the file's folder.
+pub(crate) fn load_json_file(translations: &mut Translations, lang: &str, file_name: &str, path: &Path) -> io::Result<()> {
+    let file = File::open(path)?;
+    let expected_entries = file.metadata().map_or(0, |m| m.len() / BYTES_PER_ENTRY);
+    let lang_dir = path.parent().unwrap_or(Path::new("."));
+
+    let mut builder = FileBuilder::new(translations, lang, file_name);
+    builder.reserve(usize::try_from(expected_entries).unwrap_or(0));
+    let mut deserializer = serde_json::Deserializer::from_reader(BufReader::new(file));
+    deserializer
+        .deserialize_map(EntryVisitor(|key: String, mut value: Value| {
+            inline_document(&key, &mut value, lang_dir);
+            builder.insert(key, value);
+        }))
+        .and_then(|()| deserializer.end())
+        .map_err(|e| {
+            let file = path.file_name().and_then(|f| f.to_str()).unwrap_or("unknown");
+            io::Error::new(io::ErrorKind::InvalidData, format!("{}/{}: {}", lang, file, e))
+        })?;
+    builder.finish();
+    Ok(())
+}
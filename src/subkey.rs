@@ -0,0 +1,228 @@
+//! Interned keys of plural, gender and register maps.
+//!
+//! The keys of `{ "one": "...", "other": "..." }`-style values are parsed
+//! once at load into [`SubKey`]s, so choosing a plural form at runtime
+//! compares enums instead of hashing strings, and typos such as `"ohter"`
+//! are reported when the file is loaded.
+
+use std::collections::HashMap;
+use std::fmt;
+
+#[cfg(feature = "plurals-cldr")]
+use intl_pluralrules::PluralCategory;
+use serde::Deserialize;
+use tracing::warn;
+
+use crate::store::SectionMap;
+use crate::{SectionValue, SpeechRegister};
+
+/// A CLDR plural category.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PluralForm {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralForm {
+    /// Every category, in CLDR order.
+    pub const ALL: [PluralForm; 6] =
+        [PluralForm::Zero, PluralForm::One, PluralForm::Two, PluralForm::Few, PluralForm::Many, PluralForm::Other];
+
+    /// Key of this category in a plural map (`"one"`, `"few"`…).
+    pub fn key(self) -> &'static str {
+        match self {
+            PluralForm::Zero => "zero",
+            PluralForm::One => "one",
+            PluralForm::Two => "two",
+            PluralForm::Few => "few",
+            PluralForm::Many => "many",
+            PluralForm::Other => "other",
+        }
+    }
+
+    /// The category named `key`, if any.
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|form| form.key() == key)
+    }
+}
+
+#[cfg(feature = "plurals-cldr")]
+impl From<PluralCategory> for PluralForm {
+    fn from(category: PluralCategory) -> Self {
+        match category {
+            PluralCategory::ZERO => PluralForm::Zero,
+            PluralCategory::ONE => PluralForm::One,
+            PluralCategory::TWO => PluralForm::Two,
+            PluralCategory::FEW => PluralForm::Few,
+            PluralCategory::MANY => PluralForm::Many,
+            PluralCategory::OTHER => PluralForm::Other,
+        }
+    }
+}
+
+impl fmt::Display for PluralForm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+/// Built-in grammatical gender selector of a gender map. Projects can
+/// accept other selectors with
+/// [`I18nConfig::gender_keys`](crate::I18nConfig::gender_keys).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Gender {
+    Male,
+    Female,
+    Neutral,
+}
+
+impl Gender {
+    /// Every built-in gender, the default
+    /// [`I18nConfig::gender_keys`](crate::I18nConfig::gender_keys).
+    pub const ALL: [Gender; 3] = [Gender::Male, Gender::Female, Gender::Neutral];
+
+    /// Key of this gender in a gender map.
+    pub fn key(self) -> &'static str {
+        match self {
+            Gender::Male => "male",
+            Gender::Female => "female",
+            Gender::Neutral => "neutral",
+        }
+    }
+}
+
+/// A key of a map value, parsed at load.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(from = "String")]
+pub enum SubKey {
+    /// An exact count (`"=0"`, `"=1"`, `"=-1"`…), tried before plural
+    /// categories. Schema 1 files write them `"0"`; they are migrated as
+    /// they load, see [`migrate_file`](crate::migrate_file).
+    Exact(i128),
+    Plural(PluralForm),
+    Gender(Gender),
+    Register(SpeechRegister),
+    /// Any other key: `select` cases, project-specific genders…
+    Other(String),
+}
+
+impl SubKey {
+    /// The key as written in the translation file; exact counts without
+    /// their `=`.
+    pub fn as_str(&self) -> std::borrow::Cow<'_, str> {
+        match self {
+            SubKey::Exact(count) => count.to_string().into(),
+            SubKey::Plural(form) => form.key().into(),
+            SubKey::Gender(gender) => gender.key().into(),
+            SubKey::Register(register) => register.key().into(),
+            SubKey::Other(key) => key.as_str().into(),
+        }
+    }
+
+    /// Whether this key selects a plural form.
+    pub fn is_plural(&self) -> bool {
+        matches!(self, SubKey::Exact(_) | SubKey::Plural(_))
+    }
+}
+
+impl From<&str> for SubKey {
+    fn from(key: &str) -> Self {
+        if let Some(form) = PluralForm::from_key(key) {
+            return SubKey::Plural(form);
+        }
+        // Only canonical numbers, so the key reads back unchanged. A bare
+        // number (`"1"` of `"levels": { "1": "Forest" }`) is a case key:
+        // schema 1 counts are migrated to `"=1"` before they get here.
+        let count = key.strip_prefix('=').and_then(|count| count.parse::<i128>().ok().filter(|n| n.to_string() == count));
+        if let Some(count) = count {
+            return SubKey::Exact(count);
+        }
+        match key {
+            "male" => SubKey::Gender(Gender::Male),
+            "female" => SubKey::Gender(Gender::Female),
+            "neutral" => SubKey::Gender(Gender::Neutral),
+            "casual" => SubKey::Register(SpeechRegister::Casual),
+            "polite" => SubKey::Register(SpeechRegister::Polite),
+            "formal" => SubKey::Register(SpeechRegister::Formal),
+            _ => SubKey::Other(key.to_string()),
+        }
+    }
+}
+
+impl From<String> for SubKey {
+    fn from(key: String) -> Self {
+        match SubKey::from(key.as_str()) {
+            SubKey::Other(_) => SubKey::Other(key),
+            parsed => parsed,
+        }
+    }
+}
+
+impl From<PluralForm> for SubKey {
+    fn from(form: PluralForm) -> Self {
+        SubKey::Plural(form)
+    }
+}
+
+impl fmt::Display for SubKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.as_str())
+    }
+}
+
+/// Warns about the unknown keys of plural maps — maps with an exact count or
+/// a plural category — which are most likely misspelled categories.
+pub(crate) fn warn_unknown_plural_keys(file: &str, key: &str, value: &SectionValue) {
+    let check = |forms: &HashMap<SubKey, String>| {
+        if !forms.keys().any(SubKey::is_plural) {
+            return;
+        }
+        for form in forms.keys().filter(|form| matches!(form, SubKey::Other(_))) {
+            warn!(
+                "Unknown plural form '{}' in '{}.{}' (expected zero, one, two, few, many, other or an exact count such as =0)",
+                form, file, key
+            );
+        }
+    };
+    match value {
+        SectionValue::Text(_) => {}
+        SectionValue::Map(forms) => check(forms),
+        SectionValue::Nested(nested) => nested.values().for_each(check),
+    }
+}
+
+/// Warns about gender maps of `files` (language, file name, keys) using
+/// selectors outside `gender_keys`. A map counts as a gender map when it has
+/// no plural key and at least one built-in or configured gender key.
+pub(crate) fn warn_unknown_gender_keys<'a>(
+    files: impl IntoIterator<Item = (&'a str, &'a str, &'a SectionMap)>,
+    gender_keys: &[String],
+) {
+    let configured = |key: &str| gender_keys.iter().any(|gender| gender == key);
+    let check = |lang: &str, file: &str, key: &str, selectors: Vec<&SubKey>| {
+        let is_gender_map = !selectors.iter().any(|s| s.is_plural())
+            && selectors.iter().any(|s| matches!(s, SubKey::Gender(_)) || configured(&s.as_str()));
+        if !is_gender_map {
+            return;
+        }
+        for selector in selectors.into_iter().filter(|s| !configured(&s.as_str())) {
+            warn!(
+                "Unknown gender '{}' in '{}/{}.{}' (expected one of {:?})",
+                selector, lang, file, key, gender_keys
+            );
+        }
+    };
+    for (lang, file, sections) in files {
+        for (key, value) in sections {
+            match value {
+                SectionValue::Text(_) => {}
+                SectionValue::Map(forms) => check(lang, file, key, forms.keys().collect()),
+                SectionValue::Nested(nested) => check(lang, file, key, nested.keys().collect()),
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! A headless `App` with the plugin set up, for tests of games using
+//! bevy-intl.
+//!
+//! ```rust,ignore
+//! use bevy_intl::{I18nTestApp, I18nText};
+//!
+//! #[test]
+//! fn title_follows_the_language() {
+//!     let dir = tempfile::tempdir().unwrap();
+//!     // … write dir/en/ui.json and dir/fr/ui.json …
+//!     let mut app = I18nTestApp::new().with_messages_dir(dir.path());
+//!     let title = app.spawn_text(I18nText::new("ui", "title"));
+//!     app.assert_text(title, "Dawnforge");
+//!     app.switch_lang("fr");
+//!     app.assert_text(title, "L'Aube-Forge");
+//! }
+//! ```
+//!
+//! Add the `testing` feature to the bevy-intl dev-dependency to use it.
+
+use std::path::Path;
+
+use bevy::prelude::*;
+use serde_json::Value;
+
+use crate::{I18n, I18nCommand, I18nConfig, I18nPlugin};
+
+/// A `MinimalPlugins` app with [`I18nPlugin`], see the [module docs](self).
+///
+/// Translations are read from an empty folder unless
+/// [`with_messages_dir`](Self::with_messages_dir) sets one, so a test never
+/// picks up the game's `messages` folder or bundle. Unknown locale folders
+/// (`test`, `qps-ploc`…) are not reported, and preflight and hot reload are
+/// off. The plugin is added on the first call that uses the app, so the
+/// `with_*` methods configure it.
+pub struct I18nTestApp {
+    app: App,
+    config: I18nConfig,
+    packs: Vec<(String, Value)>,
+    started: bool,
+}
+
+impl Default for I18nTestApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl I18nTestApp {
+    /// An app without translations: add them with
+    /// [`with_messages_dir`](Self::with_messages_dir) or
+    /// [`with_pack`](Self::with_pack).
+    pub fn new() -> Self {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        let config = I18nConfig {
+            use_bundled_translations: false,
+            messages_folder: empty_messages_folder(),
+            warn_unknown_locales: false,
+            preflight: false,
+            hot_reload: false,
+            ..Default::default()
+        };
+        Self { app, config, packs: Vec::new(), started: false }
+    }
+
+    /// Reads translations from `dir`, laid out like the `messages` folder.
+    pub fn with_messages_dir(mut self, dir: impl AsRef<Path>) -> Self {
+        self.config.messages_folder = dir.as_ref().to_string_lossy().into_owned();
+        self
+    }
+
+    /// Starts in `lang` instead of `en`.
+    pub fn with_lang(mut self, lang: impl Into<String>) -> Self {
+        self.config.default_lang = lang.into();
+        self
+    }
+
+    /// Changes the rest of the configuration, e.g. the fallback chain.
+    pub fn with_config(mut self, configure: impl FnOnce(&mut I18nConfig)) -> Self {
+        configure(&mut self.config);
+        self
+    }
+
+    /// Merges `pack` into `lang` at startup, see [`I18n::merge_pack`]: small
+    /// tests need no messages folder.
+    pub fn with_pack(mut self, lang: impl Into<String>, pack: Value) -> Self {
+        self.packs.push((lang.into(), pack));
+        self
+    }
+
+    /// The app, with the plugin added.
+    pub fn app(&mut self) -> &mut App {
+        if !self.started {
+            self.started = true;
+            self.app.add_plugins(I18nPlugin::with_config(self.config.clone()));
+            let mut i18n = self.app.world_mut().resource_mut::<I18n>();
+            for (lang, pack) in &self.packs {
+                if let Err(e) = i18n.merge_pack(lang, pack) {
+                    panic!("I18nTestApp: cannot merge the '{}' pack: {}", lang, e);
+                }
+            }
+        }
+        &mut self.app
+    }
+
+    /// Runs one frame.
+    pub fn update(&mut self) {
+        self.app().update();
+    }
+
+    /// Runs `frames` frames.
+    pub fn advance(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.update();
+        }
+    }
+
+    /// Spawns a label (`Text` is required by `I18nText`) and runs a frame, so
+    /// it holds its translation.
+    pub fn spawn_text(&mut self, bundle: impl Bundle) -> Entity {
+        let entity = self.app().world_mut().spawn(bundle).id();
+        self.update();
+        entity
+    }
+
+    /// Switches to `lang` with an [`I18nCommand`] and runs the frames that
+    /// re-render the labels and deliver `LanguageChanged`. Panics if `lang`
+    /// is not loaded.
+    pub fn switch_lang(&mut self, lang: &str) {
+        self.app().world_mut().write_message(I18nCommand::SetLanguage(lang.to_string()));
+        self.advance(2);
+        let current = self.i18n().get_lang();
+        assert_eq!(current, lang, "I18nTestApp: cannot switch to '{}'", lang);
+    }
+
+    pub fn i18n(&mut self) -> &I18n {
+        self.app().world().resource::<I18n>()
+    }
+
+    pub fn i18n_mut(&mut self) -> Mut<'_, I18n> {
+        self.app().world_mut().resource_mut::<I18n>()
+    }
+
+    /// Text shown by `entity`: its `Text` followed by its `TextSpan`
+    /// children.
+    pub fn text(&mut self, entity: Entity) -> String {
+        let world = self.app().world();
+        let mut text = world.get::<Text>(entity).map(|text| text.0.clone()).unwrap_or_default();
+        for child in world.get::<Children>(entity).into_iter().flatten() {
+            if let Some(span) = world.get::<TextSpan>(*child) {
+                text.push_str(&span.0);
+            }
+        }
+        text
+    }
+
+    /// Asserts that `entity` shows `expected`, see [`text`](Self::text).
+    #[track_caller]
+    pub fn assert_text(&mut self, entity: Entity, expected: &str) {
+        let lang = self.i18n().get_lang().to_string();
+        let text = self.text(entity);
+        assert_eq!(text, expected, "text of {} in '{}'", entity, lang);
+    }
+}
+
+/// A folder without languages, read when no messages folder is set: a
+/// missing folder would fall back to the bundled translations.
+fn empty_messages_folder() -> String {
+    let folder = std::env::temp_dir().join("bevy-intl-test-app-empty");
+    if let Err(e) = std::fs::create_dir_all(&folder) {
+        panic!("I18nTestApp: cannot create '{}': {}", folder.display(), e);
+    }
+    folder.to_string_lossy().into_owned()
+}
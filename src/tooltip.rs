@@ -0,0 +1,113 @@
+//! Localized hover tooltips.
+//!
+//! Add an [`I18nTooltip`] to any UI node; while the node is hovered, a child
+//! popup tagged with [`I18nTooltipPopup`] is spawned below it with the
+//! translated text. The string is resolved lazily, at the moment the tooltip
+//! is shown, so item descriptions and ability tooltips always use the current
+//! language without being kept up to date in the background.
+//!
+//! Labels ellipsized by [`crate::I18nOverflow`] that have an `Interaction`
+//! get the same popup with their full, untruncated text.
+
+use bevy::prelude::*;
+
+use crate::{I18n, I18nFullText};
+
+/// Component describing the translation key shown when the node is hovered.
+///
+/// Requires [`Interaction`], which `bevy_ui` updates for hovered / pressed
+/// nodes.
+#[derive(Component, Clone, Debug)]
+#[require(Interaction)]
+pub struct I18nTooltip {
+    /// Translation file (without the `.json` extension), e.g. `"items"`.
+    pub file: String,
+    /// Translation key inside that file, e.g. `"sword_description"`.
+    pub key: String,
+}
+
+impl I18nTooltip {
+    /// Tooltip showing `key` of `file`, resolved when the node is hovered.
+    pub fn new(file: impl Into<String>, key: impl Into<String>) -> Self {
+        Self { file: file.into(), key: key.into() }
+    }
+}
+
+/// Marker on the popup entity spawned by an [`I18nTooltip`].
+///
+/// Query `Added<I18nTooltipPopup>` to restyle popups (font, colors, layout).
+#[derive(Component, Clone, Copy, Debug)]
+pub struct I18nTooltipPopup;
+
+/// Inserted on an [`I18nTooltip`] owner while its popup is shown; holds the
+/// popup entity.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct I18nTooltipActive(pub Entity);
+
+type FullTextQueryData<'a> = (
+    Entity,
+    &'a Interaction,
+    &'a I18nFullText,
+    Option<&'a I18nTooltipActive>,
+);
+
+/// Bevy system that shows / hides tooltip popups as `Interaction` changes.
+pub fn update_i18n_tooltips(
+    mut commands: Commands,
+    i18n: Res<I18n>,
+    query: Query<
+        (Entity, &Interaction, &I18nTooltip, Option<&I18nTooltipActive>),
+        Changed<Interaction>,
+    >,
+    truncated: Query<FullTextQueryData, (Changed<Interaction>, Without<I18nTooltip>)>,
+) {
+    for (entity, interaction, tooltip, active) in &query {
+        match (interaction, active) {
+            (Interaction::Hovered | Interaction::Pressed, None) => {
+                let text = i18n.prepare_for_layout(i18n.translation(&tooltip.file).t(&tooltip.key));
+                show_popup(&mut commands, entity, text);
+            }
+            (Interaction::None, Some(I18nTooltipActive(popup))) => {
+                hide_popup(&mut commands, entity, *popup);
+            }
+            _ => {}
+        }
+    }
+
+    for (entity, interaction, full, active) in &truncated {
+        match (interaction, active) {
+            (Interaction::Hovered | Interaction::Pressed, None) if full.is_truncated() => {
+                show_popup(&mut commands, entity, full.full.clone());
+            }
+            (Interaction::None, Some(I18nTooltipActive(popup))) => {
+                hide_popup(&mut commands, entity, *popup);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn show_popup(commands: &mut Commands, owner: Entity, text: String) {
+    let popup = commands
+        .spawn((
+            I18nTooltipPopup,
+            Text::new(text),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(100.0),
+                left: Val::Px(0.0),
+                padding: UiRect::all(Val::Px(4.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+            GlobalZIndex(i32::MAX),
+            ChildOf(owner),
+        ))
+        .id();
+    commands.entity(owner).insert(I18nTooltipActive(popup));
+}
+
+fn hide_popup(commands: &mut Commands, owner: Entity, popup: Entity) {
+    commands.entity(popup).despawn();
+    commands.entity(owner).remove::<I18nTooltipActive>();
+}
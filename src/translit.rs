@@ -0,0 +1,215 @@
+//! Transliteration of player names and other user text.
+//!
+//! Available with the `transliteration` feature. Leaderboards and chat show
+//! names typed in any script, while a game usually ships fonts for a few.
+//! [`I18n::transliterate`] rewrites text in the script the current font
+//! covers:
+//!
+//! - to [`Script::Latin`], any script is romanized with `deunicode`
+//!   ("Владимир" → "Vladimir", "東京" → "Dong Jing"); Latin letters,
+//!   accents included, are kept;
+//! - to [`Script::Cyrillic`] or [`Script::Greek`], text is first romanized,
+//!   then spelled with a phonetic table ("Sasha" → "Саша"). Letters already
+//!   in the target script are kept.
+//!
+//! Romanization is approximate: Han characters are read as Mandarin, also in
+//! Japanese names, and the way back to Cyrillic or Greek guesses from the
+//! spelling. It is meant for display when glyphs are missing, not for
+//! storing names.
+
+use deunicode::deunicode;
+
+use crate::I18n;
+
+/// Writing system targeted by [`I18n::transliterate`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Script {
+    Latin,
+    Cyrillic,
+    Greek,
+}
+
+impl Script {
+    /// Whether `c` is a letter of this script.
+    fn contains(self, c: char) -> bool {
+        match self {
+            Script::Latin => c.is_ascii() || matches!(c, '\u{c0}'..='\u{24f}' | '\u{1e00}'..='\u{1eff}'),
+            Script::Cyrillic => matches!(c, '\u{400}'..='\u{52f}'),
+            Script::Greek => matches!(c, '\u{370}'..='\u{3ff}' | '\u{1f00}'..='\u{1fff}'),
+        }
+    }
+
+    /// Spelling of lowercase ASCII letters, longest sequences first.
+    fn table(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Script::Latin => &[],
+            Script::Cyrillic => CYRILLIC,
+            Script::Greek => GREEK,
+        }
+    }
+}
+
+/// Russian spelling of Latin letters.
+const CYRILLIC: &[(&str, &str)] = &[
+    ("shch", "щ"),
+    ("zh", "ж"),
+    ("kh", "х"),
+    ("ts", "ц"),
+    ("ch", "ч"),
+    ("sh", "ш"),
+    ("yu", "ю"),
+    ("ya", "я"),
+    ("yo", "ё"),
+    ("ye", "е"),
+    ("a", "а"),
+    ("b", "б"),
+    ("c", "к"),
+    ("d", "д"),
+    ("e", "е"),
+    ("f", "ф"),
+    ("g", "г"),
+    ("h", "х"),
+    ("i", "и"),
+    ("j", "дж"),
+    ("k", "к"),
+    ("l", "л"),
+    ("m", "м"),
+    ("n", "н"),
+    ("o", "о"),
+    ("p", "п"),
+    ("q", "к"),
+    ("r", "р"),
+    ("s", "с"),
+    ("t", "т"),
+    ("u", "у"),
+    ("v", "в"),
+    ("w", "в"),
+    ("x", "кс"),
+    ("y", "й"),
+    ("z", "з"),
+];
+
+/// Modern Greek spelling of Latin letters.
+const GREEK: &[(&str, &str)] = &[
+    ("th", "θ"),
+    ("ph", "φ"),
+    ("ch", "χ"),
+    ("ps", "ψ"),
+    ("ks", "ξ"),
+    ("a", "α"),
+    ("b", "β"),
+    ("c", "κ"),
+    ("d", "δ"),
+    ("e", "ε"),
+    ("f", "φ"),
+    ("g", "γ"),
+    ("h", "χ"),
+    ("i", "ι"),
+    ("j", "τζ"),
+    ("k", "κ"),
+    ("l", "λ"),
+    ("m", "μ"),
+    ("n", "ν"),
+    ("o", "ο"),
+    ("p", "π"),
+    ("q", "κ"),
+    ("r", "ρ"),
+    ("s", "σ"),
+    ("t", "τ"),
+    ("u", "ου"),
+    ("v", "β"),
+    ("w", "ω"),
+    ("x", "ξ"),
+    ("y", "υ"),
+    ("z", "ζ"),
+];
+
+/// `text` written in `target`, see the [module docs](self).
+pub fn transliterate(text: &str, target: Script) -> String {
+    let romanized = romanize(text, target);
+    if target == Script::Latin {
+        return romanized;
+    }
+
+    let chars: Vec<char> = romanized.chars().collect();
+    let mut out = String::with_capacity(romanized.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let rest: String = chars[i..].iter().take(4).collect::<String>().to_ascii_lowercase();
+        match target.table().iter().find(|(latin, _)| rest.starts_with(latin)) {
+            Some((latin, spelled)) => {
+                if chars[i].is_uppercase() {
+                    let mut spelled = spelled.chars();
+                    out.extend(spelled.next().into_iter().flat_map(char::to_uppercase));
+                    out.extend(spelled);
+                } else {
+                    out.push_str(spelled);
+                }
+                i += latin.len();
+            }
+            None => {
+                out.push(chars[i]);
+                i += 1;
+            }
+        }
+    }
+    if target == Script::Greek {
+        out = final_sigma(&out);
+    }
+    out
+}
+
+/// `text` with every run of letters that are neither Latin nor in `keep`
+/// romanized. Accented Latin letters are reduced to ASCII unless the target
+/// is Latin.
+fn romanize(text: &str, keep: Script) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut run = String::new();
+    for c in text.chars() {
+        let kept = keep.contains(c) || (c.is_ascii() && keep != Script::Latin) || !c.is_alphabetic();
+        if kept {
+            flush_run(&mut run, &mut out);
+            out.push(c);
+        } else {
+            run.push(c);
+        }
+    }
+    flush_run(&mut run, &mut out);
+    out
+}
+
+fn flush_run(run: &mut String, out: &mut String) {
+    if !run.is_empty() {
+        out.push_str(deunicode(run).trim_end());
+        run.clear();
+    }
+}
+
+/// `σ` at the end of a word written `ς`.
+fn final_sigma(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    chars
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| {
+            let ends_word = chars.get(i + 1).is_none_or(|next| !next.is_alphabetic());
+            if c == 'σ' && ends_word { 'ς' } else { c }
+        })
+        .collect()
+}
+
+impl I18n {
+    /// Rewrites `text` in the `target` script, e.g. to show player names
+    /// with a font that only covers Latin letters. See [`transliterate`].
+    ///
+    /// ```rust,no_run
+    /// # use bevy_intl::{I18n, Script};
+    /// # fn leaderboard(i18n: &I18n) {
+    /// assert_eq!(i18n.transliterate("Владимир", Script::Latin), "Vladimir");
+    /// assert_eq!(i18n.transliterate("Sasha", Script::Cyrillic), "Саша");
+    /// # }
+    /// ```
+    pub fn transliterate(&self, text: &str, target: Script) -> String {
+        transliterate(text, target)
+    }
+}
@@ -0,0 +1,56 @@
+//! One enum per translation file, generated from the bundled messages folder.
+//!
+//! With the `typed-keys` feature, `build.rs` turns every file of the default
+//! language (`BEVY_INTL_DEFAULT_LANG`, `en` by default) into an enum named
+//! after it, with one variant per text key: `ui.json` gives `UiKey`, and its
+//! keys `welcome` and `menu.quit` give `UiKey::Welcome` and `UiKey::MenuQuit`.
+//! Keys get IDE completion, and a removed key breaks the build where it is
+//! used:
+//!
+//! ```rust,ignore
+//! use bevy_intl::typed_keys::UiKey;
+//!
+//! let welcome = i18n.tk(UiKey::Welcome);
+//! ```
+//!
+//! Characters other than ASCII letters and digits separate words. Keys that
+//! would get the same name (`menu.quit` and `menu_quit`) fail the build
+//! rather than being numbered, so adding a key never renames a variant.
+
+use tracing::warn;
+
+use crate::{I18n, I18nPartial};
+
+include!(concat!(env!("OUT_DIR"), "/typed_keys.rs"));
+
+/// A key of a generated enum, see the [module docs](self).
+pub trait TypedKey: Copy {
+    /// File the enum was generated from.
+    const FILE: &'static str;
+
+    /// The key, dotted for nested groups and plural or gender cases.
+    fn key(self) -> &'static str;
+
+    /// The file and key, e.g. for `I18nText::from`.
+    fn checked(self) -> crate::CheckedKey {
+        crate::CheckedKey::new(Self::FILE, self.key())
+    }
+}
+
+impl I18n {
+    /// Translates a generated key, looking it up in its own file.
+    pub fn tk<K: TypedKey>(&self, key: K) -> String {
+        self.translation(K::FILE).t(key.key())
+    }
+}
+
+impl I18nPartial<'_> {
+    /// [`t`](Self::t) with a generated key. A key of another file is logged
+    /// and looked up in this one all the same.
+    pub fn tk<K: TypedKey>(&self, key: K) -> String {
+        if self.log.file() != K::FILE {
+            warn!("key '{}' of '{}' looked up in '{}'", key.key(), K::FILE, self.log.file());
+        }
+        self.t(key.key())
+    }
+}
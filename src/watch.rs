@@ -0,0 +1,81 @@
+//! Hot reload of the messages folder during development.
+//!
+//! With [`I18nConfig::hot_reload`], [`watch_translation_files`] checks the
+//! modification times of the files under the messages folder (and the
+//! overlay folder) twice a second. When a translator saves, adds or deletes
+//! a file, an [`I18nCommand::Reload`] is sent: the [`I18n`](crate::I18n)
+//! resource is reloaded in place, keeping the current language, and every
+//! `I18nText` label is refreshed.
+//!
+//! Reloading drops language packs merged at runtime. Bundled translations
+//! (WASM, `bundle-only`) are never watched; with
+//! [`I18nAssetPlugin`](crate::I18nAssetPlugin), enable Bevy's `file_watcher`
+//! feature instead.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use bevy::prelude::*;
+use tracing::info;
+
+use crate::loader::OVERLAY_ENV;
+use crate::overlay::overlay_folder;
+use crate::{I18nCommand, I18nConfig};
+
+/// Delay between two checks of the messages folder.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Modification times of the watched files at the last check.
+#[derive(Resource, Default)]
+pub(crate) struct TranslationFileStamps {
+    timer: Option<Timer>,
+    stamps: Vec<(PathBuf, SystemTime)>,
+}
+
+/// Modification time of every file in the language folders of `folder`,
+/// sorted by path. Unreadable entries are skipped.
+fn file_stamps(folder: &Path, stamps: &mut Vec<(PathBuf, SystemTime)>) {
+    let Ok(langs) = fs::read_dir(folder) else { return };
+    for lang in langs.flatten() {
+        let Ok(files) = fs::read_dir(lang.path()) else { continue };
+        for file in files.flatten() {
+            let Ok(modified) = file.metadata().and_then(|m| m.modified()) else { continue };
+            stamps.push((file.path(), modified));
+        }
+    }
+}
+
+/// Bevy system sending [`I18nCommand::Reload`] when a file of the messages
+/// or overlay folder changed, see the [module docs](self). Added by
+/// `I18nPlugin` when [`I18nConfig::hot_reload`] is set.
+pub(crate) fn watch_translation_files(
+    time: Res<Time<Real>>,
+    config: Res<I18nConfig>,
+    mut watched: ResMut<TranslationFileStamps>,
+    mut commands: MessageWriter<I18nCommand>,
+) {
+    if config.use_bundled_translations {
+        return;
+    }
+    let first_check = watched.timer.is_none();
+    let timer = watched.timer.get_or_insert_with(|| Timer::new(POLL_INTERVAL, TimerMode::Repeating));
+    if !first_check && !timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let mut stamps = Vec::new();
+    file_stamps(Path::new(&config.messages_folder), &mut stamps);
+    let overlay = config.overlay.clone().or_else(|| std::env::var(OVERLAY_ENV).ok());
+    if let Some(overlay) = overlay.filter(|folder| !folder.is_empty()) {
+        file_stamps(&overlay_folder(Path::new(&config.messages_folder), &overlay), &mut stamps);
+    }
+    stamps.sort();
+    if stamps != watched.stamps {
+        if !first_check {
+            info!("translation files changed; reloading");
+            commands.write(I18nCommand::Reload);
+        }
+        watched.stamps = stamps;
+    }
+}
@@ -0,0 +1,160 @@
+//! Writing-mode metadata for vertical East Asian text.
+//!
+//! Bevy only lays text out horizontally, but games regularly render vertical
+//! Japanese or Chinese by hand (scrolls, signage, name plates). This module
+//! exposes what such a renderer needs from the locale layer: which writing
+//! modes a locale uses, how each character is oriented in a vertical line
+//! (after Unicode UAX #50), and the vertical presentation forms of CJK
+//! punctuation.
+
+use crate::I18n;
+
+/// Direction in which lines of text are laid out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum WritingMode {
+    /// Horizontal lines, stacked top to bottom.
+    #[default]
+    HorizontalTb,
+    /// Vertical lines, stacked right to left (Japanese, Chinese, Korean).
+    VerticalRl,
+    /// Vertical lines, stacked left to right (traditional Mongolian).
+    VerticalLr,
+}
+
+impl WritingMode {
+    pub fn is_vertical(self) -> bool {
+        self != WritingMode::HorizontalTb
+    }
+}
+
+/// How a character is drawn inside a vertical line (UAX #50 classes).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VerticalOrientation {
+    /// Drawn upright, as in horizontal text.
+    Upright,
+    /// Rotated 90° clockwise (Latin letters, digits, most symbols).
+    Rotated,
+    /// Needs a vertical glyph variant ([`vertical_form`]); upright otherwise.
+    TransformedUpright,
+    /// Needs a vertical glyph variant ([`vertical_form`]); rotated otherwise.
+    TransformedRotated,
+}
+
+/// Writing mode a locale uses for UI text by default.
+pub fn preferred_writing_mode(lang: &str) -> WritingMode {
+    match lang {
+        "mn-Mong" | "mn_Mong" => WritingMode::VerticalLr,
+        _ => WritingMode::HorizontalTb,
+    }
+}
+
+/// Vertical writing mode supported by a locale, if any.
+pub fn vertical_writing_mode(lang: &str) -> Option<WritingMode> {
+    if preferred_writing_mode(lang).is_vertical() {
+        return Some(preferred_writing_mode(lang));
+    }
+    match lang.split(['-', '_']).next().unwrap_or(lang) {
+        "ja" | "zh" | "ko" => Some(WritingMode::VerticalRl),
+        _ => None,
+    }
+}
+
+/// Orientation of `c` in a vertical line.
+///
+/// A simplified version of the UAX #50 property covering the ranges games
+/// actually render: CJK ideographs, kana, hangul and fullwidth forms are
+/// upright, CJK punctuation and small kana are transformed, everything else
+/// is rotated.
+pub fn vertical_orientation(c: char) -> VerticalOrientation {
+    if vertical_form(c).is_some() {
+        return match c {
+            '（' | '）' | '〔' | '〕' | '｛' | '｝' | '【' | '】' | '〈' | '〉' | '《' | '》' | '「'
+            | '」' | '『' | '』' | '—' | '…' | '‥' => {
+                VerticalOrientation::TransformedRotated
+            }
+            _ => VerticalOrientation::TransformedUpright,
+        };
+    }
+    if is_small_kana(c) {
+        return VerticalOrientation::TransformedUpright;
+    }
+    // Long vowel mark and wave dash: no presentation form, fonts provide a
+    // vertical alternate.
+    if matches!(c, 'ー' | '～' | '〜') {
+        return VerticalOrientation::TransformedRotated;
+    }
+    match c as u32 {
+        0x1100..=0x11FF // Hangul Jamo
+        | 0x2E80..=0x2FFF // CJK radicals, Kangxi, description characters
+        | 0x3000..=0x30FF // CJK symbols, hiragana, katakana
+        | 0x3100..=0x31FF // Bopomofo, Hangul compatibility, katakana ext.
+        | 0x3200..=0x4DBF // Enclosed CJK, compatibility, ext. A
+        | 0x4E00..=0x9FFF // CJK unified ideographs
+        | 0xAC00..=0xD7AF // Hangul syllables
+        | 0xF900..=0xFAFF // CJK compatibility ideographs
+        | 0xFE10..=0xFE1F // Vertical forms
+        | 0xFE30..=0xFE4F // CJK compatibility forms
+        | 0xFF01..=0xFF60 // Fullwidth ASCII variants
+        | 0x20000..=0x3FFFF => VerticalOrientation::Upright,
+        _ => VerticalOrientation::Rotated,
+    }
+}
+
+/// Vertical presentation form of a CJK punctuation mark, if Unicode defines
+/// one (U+FE10–U+FE19, U+FE30–U+FE44).
+pub fn vertical_form(c: char) -> Option<char> {
+    Some(match c {
+        '，' => '︐',
+        '、' => '︑',
+        '。' => '︒',
+        '：' => '︓',
+        '；' => '︔',
+        '！' => '︕',
+        '？' => '︖',
+        '〖' => '︗',
+        '〗' => '︘',
+        '…' => '︙',
+        '‥' => '︰',
+        '—' => '︱',
+        '（' => '︵',
+        '）' => '︶',
+        '｛' => '︷',
+        '｝' => '︸',
+        '〔' => '︹',
+        '〕' => '︺',
+        '【' => '︻',
+        '】' => '︼',
+        '《' => '︽',
+        '》' => '︾',
+        '〈' => '︿',
+        '〉' => '﹀',
+        '「' => '﹁',
+        '」' => '﹂',
+        '『' => '﹃',
+        '』' => '﹄',
+        _ => return None,
+    })
+}
+
+/// Small kana (ぁ, ッ, ゃ…), which are shifted towards the top-right of
+/// their cell in vertical Japanese.
+pub fn is_small_kana(c: char) -> bool {
+    matches!(
+        c,
+        'ぁ' | 'ぃ' | 'ぅ' | 'ぇ' | 'ぉ' | 'っ' | 'ゃ' | 'ゅ' | 'ょ' | 'ゎ' | 'ゕ' | 'ゖ'
+            | 'ァ' | 'ィ' | 'ゥ' | 'ェ' | 'ォ' | 'ッ' | 'ャ' | 'ュ' | 'ョ' | 'ヮ' | 'ヵ' | 'ヶ'
+            | 'ㇰ'..='ㇿ'
+    )
+}
+
+impl I18n {
+    /// Preferred writing mode of the current language.
+    pub fn writing_mode(&self) -> WritingMode {
+        preferred_writing_mode(self.get_lang())
+    }
+
+    /// Vertical writing mode supported by the current language, if any.
+    pub fn vertical_writing_mode(&self) -> Option<WritingMode> {
+        vertical_writing_mode(self.get_lang())
+    }
+}
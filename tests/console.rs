@@ -0,0 +1,56 @@
+//! Test the `i18n` console commands.
+#![cfg(feature = "console")]
+
+use std::fs;
+
+use bevy::prelude::*;
+use bevy_intl::{I18n, I18nConfig, I18nConsoleInput, I18nConsoleOutput, I18nPlugin};
+use tempfile::tempdir;
+
+#[derive(Resource, Default)]
+struct Replies(Vec<String>);
+
+fn capture_replies(mut reader: MessageReader<I18nConsoleOutput>, mut replies: ResMut<Replies>) {
+    replies.0.extend(reader.read().map(|reply| reply.0.clone()));
+}
+
+#[test]
+fn console_switches_language_and_reports_coverage() {
+    let temp = tempdir().unwrap();
+    for (lang, content) in [("en", r#"{ "play": "Play", "quit": "Quit" }"#), ("fr", r#"{ "play": "Jouer" }"#)] {
+        let dir = temp.path().join(lang);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("menu.json"), content).unwrap();
+    }
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(I18nPlugin::with_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        default_lang: "en".into(),
+        fallback_chain: vec!["en".into()],
+        warn_unknown_locales: false,
+        ..Default::default()
+    }));
+    app.init_resource::<Replies>();
+    app.add_systems(Update, capture_replies);
+
+    for line in ["say hello", "i18n lang fr", "i18n missing fr", "i18n coverage", "i18n lang xx", "i18n"] {
+        app.world_mut().write_message(I18nConsoleInput(line.into()));
+    }
+    app.update();
+    app.update();
+
+    assert_eq!(app.world().resource::<I18n>().get_lang(), "fr");
+    assert_eq!(
+        app.world().resource::<Replies>().0,
+        vec![
+            "language: fr".to_string(),
+            "fr: 1 missing\nmenu.quit".to_string(),
+            "en 100.0% (0 missing)\nfr 50.0% (1 missing)".to_string(),
+            "unknown language 'xx' (available: en, fr)".to_string(),
+            "usage: i18n lang <code> | i18n missing [lang] | i18n reload | i18n coverage".to_string(),
+        ]
+    );
+}
@@ -0,0 +1,53 @@
+//! `#[derive(Localize)]` on content enums.
+
+use std::fs;
+
+use bevy_intl::{DisplayName, I18n, I18nConfig, Localize, LocalizedDisplay};
+use pretty_assertions::assert_eq;
+use tempfile::tempdir;
+
+#[derive(Localize)]
+enum Weapon {
+    Sword,
+    FireSword,
+    #[localize(rename = "longbow")]
+    Bow(#[allow(dead_code)] u32),
+}
+
+#[derive(Localize)]
+#[localize(file = "items", prefix = "potions")]
+enum Potion {
+    Healing,
+}
+
+#[test]
+fn derived_keys_follow_enum_and_variant_names() {
+    assert_eq!((Weapon::Sword.name_file(), Weapon::Sword.key()), ("enums", "weapon.sword"));
+    assert_eq!(Weapon::FireSword.key(), "weapon.fire_sword");
+    assert_eq!(Weapon::Bow(3).key(), "weapon.longbow");
+    assert_eq!((Potion::Healing.name_file(), Potion::Healing.key()), ("items", "potions.healing"));
+}
+
+#[test]
+fn derived_enums_are_localized() {
+    let temp = tempdir().unwrap();
+    let dir = temp.path().join("fr");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("enums.json"), r#"{ "weapon.fire_sword": "Épée de feu" }"#).unwrap();
+    fs::write(dir.join("ui.json"), r#"{ "equipped": "{{weapon}} équipée" }"#).unwrap();
+
+    let i18n = I18n::from_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        default_lang: "fr".into(),
+        fallback_chain: vec!["fr".into()],
+        warn_unknown_locales: false,
+        ..Default::default()
+    });
+
+    assert_eq!(format!("{}", Weapon::FireSword.localized(&i18n)), "Épée de feu");
+    assert_eq!(
+        i18n.t_with_names("ui", "equipped", &[("weapon", &Weapon::FireSword)]),
+        "Épée de feu équipée"
+    );
+}
@@ -1,11 +1,16 @@
 //! End-to-end test: spin up a Bevy `App` with `I18nPlugin`, point it at a
 //! tempdir of fixture JSON, and verify that translations resolve correctly.
+#![cfg(feature = "bevy")]
 
 use std::fs;
 
 use bevy::prelude::*;
-use bevy_intl::{I18n, I18nConfig, I18nPlugin, i18n_args};
-use tempfile::tempdir;
+use bevy_intl::{
+    CustomLocale, DialogueLine, I18n, I18nAssetPlugin, I18nConfig, I18nLanguagePacks, I18nPlugin, I18nSplitBundlePlugin,
+    PackState, TextDirection, i18n_args, i18n_packs_ready,
+};
+use pretty_assertions::assert_eq;
+use tempfile::{TempDir, tempdir};
 
 fn write_fixture(dir: &std::path::Path, lang: &str, file: &str, content: &str) {
     let lang_dir = dir.join(lang);
@@ -13,41 +18,54 @@ fn write_fixture(dir: &std::path::Path, lang: &str, file: &str, content: &str) {
     fs::write(lang_dir.join(format!("{}.json", file)), content).unwrap();
 }
 
-#[test]
-fn loads_translations_from_disk_and_resolves_keys() {
+/// An app with `I18nPlugin` reading `fixtures` (`(lang, file, json)`) from a
+/// temporary messages folder with `config`. The folder is removed when the
+/// returned `TempDir` is dropped.
+fn app_with_fixtures(fixtures: &[(&str, &str, &str)], config: I18nConfig) -> (App, TempDir) {
     let temp = tempdir().unwrap();
-    write_fixture(
-        temp.path(),
-        "en",
-        "ui",
-        r#"{
-            "greeting": "Hello",
-            "welcome": "Hi {{name}}, you have {{count}} messages",
-            "guests": {
-                "male":   { "one": "{{count}} guest (M)", "other": "{{count}} guests (M)" },
-                "female": { "one": "{{count}} guest (F)", "other": "{{count}} guests (F)" }
-            }
-        }"#,
-    );
-    write_fixture(
-        temp.path(),
-        "fr",
-        "ui",
-        r#"{
-            "greeting": "Bonjour"
-        }"#,
-    );
-
-    let messages = temp.path().to_string_lossy().into_owned();
-
+    for (lang, file, content) in fixtures {
+        write_fixture(temp.path(), lang, file, content);
+    }
     let mut app = App::new();
     app.add_plugins(I18nPlugin::with_config(I18nConfig {
         use_bundled_translations: false,
-        messages_folder: messages,
-        default_lang: "fr".into(),
-        fallback_lang: "en".into(),
-        warn_unknown_locales: true,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        ..config
     }));
+    (app, temp)
+}
+
+#[test]
+fn loads_translations_from_disk_and_resolves_keys() {
+    let (app, _messages) = app_with_fixtures(
+        &[
+            (
+                "en",
+                "ui",
+                r#"{
+                    "greeting": "Hello",
+                    "welcome": "Hi {{name}}, you have {{count}} messages",
+                    "guests": {
+                        "male":   { "one": "{{count}} guest (M)", "other": "{{count}} guests (M)" },
+                        "female": { "one": "{{count}} guest (F)", "other": "{{count}} guests (F)" }
+                    }
+                }"#,
+            ),
+            (
+                "fr",
+                "ui",
+                r#"{
+                    "greeting": "Bonjour"
+                }"#,
+            ),
+        ],
+        I18nConfig {
+            default_lang: "fr".into(),
+            fallback_chain: vec!["en".into()],
+            warn_unknown_locales: true,
+            ..Default::default()
+        },
+    );
 
     let i18n = app.world().resource::<I18n>();
 
@@ -79,8 +97,9 @@ fn missing_messages_folder_falls_back_to_error_translations() {
         use_bundled_translations: false,
         messages_folder: "this-path-does-not-exist-xyz".into(),
         default_lang: "en".into(),
-        fallback_lang: "en".into(),
+        fallback_chain: vec!["en".into()],
         warn_unknown_locales: false,
+        ..Default::default()
     }));
 
     let i18n = app.world().resource::<I18n>();
@@ -88,3 +107,317 @@ fn missing_messages_folder_falls_back_to_error_translations() {
     let langs: Vec<&str> = i18n.available_languages().iter().map(String::as_str).collect();
     assert_eq!(langs, vec!["en"]);
 }
+
+#[test]
+fn resolves_dialogue_lines_with_speaker_gender_and_count() {
+    let (app, _messages) = app_with_fixtures(
+        &[
+            ("en", "characters", r#"{ "smith": "Blacksmith" }"#),
+            (
+                "en",
+                "dialogue",
+                r#"{
+                    "offer": {
+                        "male":   { "one": "One blade for you, sir {{player}}.", "other": "{{count}} blades for you, sir {{player}}." },
+                        "female": { "one": "One blade for you, lady {{player}}.", "other": "{{count}} blades for you, lady {{player}}." }
+                    },
+                    "bye": "Farewell, {{player}}."
+                }"#,
+            ),
+        ],
+        I18nConfig::default(),
+    );
+    let i18n = app.world().resource::<I18n>();
+
+    let line = i18n.resolve_dialogue(
+        &DialogueLine::new("dialogue", "offer")
+            .speaker("characters", "smith")
+            .gender("female")
+            .count(3)
+            .arg("player", "Ana"),
+    );
+    assert_eq!(line.speaker.as_deref(), Some("Blacksmith"));
+    assert_eq!(line.text, "3 blades for you, lady Ana.");
+
+    let bye = i18n.resolve_dialogue(&DialogueLine::new("dialogue", "bye").arg("player", "Ana"));
+    assert_eq!(bye.speaker, None);
+    assert_eq!(bye.text, "Farewell, Ana.");
+}
+
+#[test]
+fn glossary_flags_non_canonical_variants() {
+    let (app, _messages) = app_with_fixtures(
+        &[
+            ("en", "_glossary", r#"{ "dawn_sword": { "term": "Sword of Dawn", "variants": ["Dawn Sword"] } }"#),
+            (
+                "en",
+                "items",
+                r#"{
+                    "good": "You found the Sword of Dawn!",
+                    "bad": "You found the dawn sword!"
+                }"#,
+            ),
+        ],
+        I18nConfig::default(),
+    );
+    let i18n = app.world().resource::<I18n>();
+
+    // Reserved files are not translation namespaces.
+    assert_eq!(i18n.translation("_glossary").t("dawn_sword"), "Missing translation");
+    assert_eq!(i18n.glossary_term("dawn_sword").as_deref(), Some("Sword of Dawn"));
+
+    let violations = i18n.check_glossary();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].key, "bad");
+    assert_eq!(violations[0].found, "Dawn Sword");
+}
+
+#[test]
+fn max_length_annotations_are_checked_per_language() {
+    let (app, _messages) = app_with_fixtures(
+        &[("en", "ui", r#"{ "ok": "OK", "ok@max": 6 }"#), ("de", "ui", r#"{ "ok": "Einverstanden" }"#)],
+        I18nConfig::default(),
+    );
+    let i18n = app.world().resource::<I18n>();
+
+    // The annotation is not a translation key of its own.
+    assert_eq!(i18n.translation("ui").t("ok@max"), "Missing translation");
+    assert_eq!(i18n.translation("ui").max_len("ok"), Some(6));
+
+    let violations = i18n.check_max_lengths();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].lang, "de");
+    assert_eq!((violations[0].max, violations[0].len), (6, 13));
+}
+
+#[test]
+fn word_filter_follows_active_language() {
+    let (mut app, _messages) = app_with_fixtures(
+        &[
+            ("en", "ui", r#"{ "ok": "OK" }"#),
+            ("ja", "ui", r#"{ "ok": "了解" }"#),
+            ("en", "_filter", r#"{ "words": ["heck"] }"#),
+            ("ja", "_filter", r#"{ "words": ["ばか"], "mask": "●", "match": "substring" }"#),
+        ],
+        I18nConfig::default(),
+    );
+    let mut i18n = app.world_mut().resource_mut::<I18n>();
+
+    assert_eq!(i18n.filter("What the HECK, heckler?"), "What the ****, heckler?");
+    assert!(i18n.is_filtered("heck"));
+    assert!(!i18n.is_filtered("heckler"));
+    // Other languages' lists don't apply.
+    assert_eq!(i18n.filter("おまえはばかだ"), "おまえはばかだ");
+
+    i18n.set_lang("ja");
+    assert_eq!(i18n.filter("おまえはばかだ"), "おまえは●●だ");
+}
+
+#[test]
+fn custom_locales_are_first_class() {
+    let (app, _messages) = app_with_fixtures(
+        &[
+            ("en", "ui", r#"{ "door": "Door", "coins": { "one": "One coin", "other": "{{count}} coins" } }"#),
+            ("de", "ui", r#"{ "door": "Tür", "exit": "Ausgang" }"#),
+            ("dwarvish", "ui", r#"{ "coins": { "one": "Khazâd", "other": "{{count}} khazâdul" } }"#),
+        ],
+        I18nConfig {
+            default_lang: "dwarvish".into(),
+            fallback_chain: vec!["en".into()],
+            custom_locales: vec![
+                CustomLocale::new("dwarvish")
+                    .direction(TextDirection::RightToLeft)
+                    .plural_rules_from("en")
+                    .fallback("de"),
+            ],
+            ..Default::default()
+        },
+    );
+    let i18n = app.world().resource::<I18n>();
+    let t = i18n.translation("ui");
+
+    assert_eq!(i18n.text_direction(), TextDirection::RightToLeft);
+    // English plural rules: 1 → one, 2 → other.
+    assert_eq!(t.t_with_plural("coins", 1), "Khazâd");
+    assert_eq!(t.t_with_plural("coins", 2), "2 khazâdul");
+    // Missing keys use the locale's own fallback, not the global one.
+    assert_eq!(t.t("exit"), "Ausgang");
+}
+
+#[test]
+#[cfg(feature = "yaml")]
+fn language_folders_mix_json_and_yaml_files() {
+    let temp = tempdir().unwrap();
+    write_fixture(temp.path(), "en", "ui", r#"{ "play": "Play", "quit": "Quit (json)" }"#);
+    let en = temp.path().join("en");
+    fs::write(
+        en.join("ui.yaml"),
+        "# migrated keys\nquit: Quit (yaml)\nitems:\n  one: '{{count}} item'\n  other: \"{{count}} items\"\n",
+    )
+    .unwrap();
+    fs::write(
+        en.join("story.yml"),
+        "---\nintro: |\n  Once upon a time,\n  in a land far away.\nintro@max: 80\ntips:\n  - Jump\n  - Run\n",
+    )
+    .unwrap();
+    fs::write(en.join("notes.txt"), "not a translation file").unwrap();
+
+    let i18n = I18n::from_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        ..Default::default()
+    });
+
+    let ui = i18n.translation("ui");
+    assert_eq!(ui.t("play"), "Play");
+    // `ui.json` sorts before `ui.yaml`, so it wins the collision.
+    assert_eq!(ui.t("quit"), "Quit (json)");
+    assert_eq!(ui.t_with_plural("items", 3), "3 items");
+    let story = i18n.translation("story");
+    assert_eq!(story.t("intro"), "Once upon a time,\nin a land far away.\n");
+    assert_eq!(story.raw("tips"), Some(&serde_json::json!(["Jump", "Run"])));
+    assert!(i18n.check_max_lengths().is_empty());
+}
+
+#[test]
+fn overlay_folder_replaces_only_its_keys() {
+    let temp = tempdir().unwrap();
+    let messages = temp.path().join("messages");
+    let demo = temp.path().join("messages-demo");
+    write_fixture(&messages, "en", "shop", r#"{ "buy": "Buy now", "title": "Shop" }"#);
+    write_fixture(&messages, "fr", "shop", r#"{ "buy": "Acheter", "title": "Boutique" }"#);
+    write_fixture(&demo, "en", "shop", r#"{ "buy": "Available in full version" }"#);
+
+    let mut i18n = I18n::from_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: messages.to_string_lossy().into_owned(),
+        overlay: Some(demo.to_string_lossy().into_owned()),
+        ..Default::default()
+    });
+
+    let shop = i18n.translation("shop");
+    assert_eq!(shop.t("buy"), "Available in full version");
+    assert_eq!(shop.t("title"), "Shop");
+    i18n.set_lang("fr");
+    // Languages the overlay does not cover keep their strings.
+    assert_eq!(i18n.translation("shop").t("buy"), "Acheter");
+}
+
+#[test]
+#[cfg(feature = "yaml")]
+fn asset_folder_is_merged_once_loaded() {
+    let temp = tempdir().unwrap();
+    let assets = temp.path().join("assets");
+    write_fixture(&temp.path().join("messages"), "en", "ui", r#"{ "play": "Play" }"#);
+    write_fixture(&assets.join("messages"), "en", "ui", r#"{ "play": "Start", "quit": "Quit" }"#);
+    let fr = assets.join("messages").join("fr");
+    fs::create_dir_all(&fr).unwrap();
+    fs::write(fr.join("ui.yaml"), "play: Jouer\n").unwrap();
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::asset::AssetPlugin {
+        file_path: assets.to_string_lossy().into_owned(),
+        ..Default::default()
+    }));
+    app.add_plugins(I18nPlugin::with_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().join("messages").to_string_lossy().into_owned(),
+        ..Default::default()
+    }));
+    app.add_plugins(I18nAssetPlugin::default());
+    assert_eq!(app.world().resource::<I18n>().translation("ui").t("play"), "Play");
+
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+    while app.world().resource::<I18n>().translation("ui").t("play") != "Start" {
+        assert!(std::time::Instant::now() < deadline, "asset folder was not merged");
+        app.update();
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    let mut i18n = app.world_mut().resource_mut::<I18n>();
+    assert_eq!(i18n.translation("ui").t("quit"), "Quit");
+    i18n.set_lang("fr");
+    assert_eq!(i18n.translation("ui").t("play"), "Jouer");
+}
+
+#[test]
+fn split_bundle_fetches_the_configured_languages_then_switches_on_demand() {
+    let temp = tempdir().unwrap();
+    let assets = temp.path().join("assets");
+    write_fixture(&temp.path().join("messages"), "en", "ui", r#"{ "play": "Play", "quit": "Quit" }"#);
+    let packs = assets.join("i18n");
+    fs::create_dir_all(&packs).unwrap();
+    fs::write(packs.join("fr.json"), r#"{ "ui": { "play": "Jouer" } }"#).unwrap();
+    fs::write(packs.join("de.json"), r#"{ "ui": { "play": "Spielen" } }"#).unwrap();
+    fs::write(packs.join("manifest.json"), r#"{ "version": "1", "packs": { "en": "a", "fr": "b", "de": "c" } }"#)
+        .unwrap();
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::asset::AssetPlugin {
+        file_path: assets.to_string_lossy().into_owned(),
+        ..Default::default()
+    }));
+    app.add_plugins(I18nPlugin::with_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().join("messages").to_string_lossy().into_owned(),
+        default_lang: "fr".into(),
+        fallback_chain: vec!["en".into()],
+        ..Default::default()
+    }));
+    app.add_plugins(I18nSplitBundlePlugin::default());
+    // `fr` is not loaded yet: the fallback answers.
+    assert_eq!(app.world().resource::<I18n>().translation("ui").t("play"), "Play");
+
+    let run_until = |app: &mut App, what: &str, done: fn(&World) -> bool| {
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+        while !done(app.world()) {
+            assert!(std::time::Instant::now() < deadline, "{}", what);
+            app.update();
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    };
+    run_until(&mut app, "fr pack was not merged", |world| {
+        world.resource::<I18n>().translation("ui").t("play") == "Jouer"
+    });
+    let world = app.world_mut();
+    let packs = world.resource::<I18nLanguagePacks>();
+    assert_eq!(packs.state("en"), Some(PackState::Ready));
+    assert_eq!(packs.state("de"), None);
+    assert!(world.run_system_cached(i18n_packs_ready).unwrap());
+    assert_eq!(world.resource::<I18n>().available_languages_sorted().len(), 2);
+
+    app.world_mut().resource_mut::<I18nLanguagePacks>().switch_to("de");
+    run_until(&mut app, "did not switch to de", |world| world.resource::<I18n>().get_lang() == "de");
+    let world = app.world();
+    assert_eq!(world.resource::<I18n>().translation("ui").t("play"), "Spielen");
+    assert_eq!(world.resource::<I18n>().translation("ui").t("quit"), "Quit");
+    run_until(&mut app, "manifest was not read", |world| !world.resource::<I18nLanguagePacks>().languages().is_empty());
+    assert_eq!(app.world().resource::<I18nLanguagePacks>().languages(), ["de", "en", "fr"]);
+}
+
+#[test]
+fn hot_reload_picks_up_edited_files() {
+    let temp = tempdir().unwrap();
+    write_fixture(temp.path(), "en", "ui", r#"{ "play": "Play" }"#);
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(I18nPlugin::with_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        hot_reload: true,
+        ..Default::default()
+    }));
+    app.update();
+    assert_eq!(app.world().resource::<I18n>().translation("ui").t("play"), "Play");
+
+    // Some filesystems only keep modification times to the second.
+    std::thread::sleep(std::time::Duration::from_millis(1100));
+    write_fixture(temp.path(), "en", "ui", r#"{ "play": "Start" }"#);
+
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+    while app.world().resource::<I18n>().translation("ui").t("play") != "Start" {
+        assert!(std::time::Instant::now() < deadline, "edited file was not reloaded");
+        app.update();
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+}
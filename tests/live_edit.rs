@@ -0,0 +1,63 @@
+//! Test live translation sessions against a local WebSocket server.
+#![cfg(feature = "live-edit")]
+
+use std::fs;
+use std::net::TcpListener;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use bevy::prelude::*;
+use bevy_intl::{I18n, I18nConfig, I18nPlugin, LiveEdit, LiveEditPlugin};
+use tempfile::tempdir;
+
+#[test]
+fn edits_pushed_over_the_websocket_are_applied() {
+    let temp = tempdir().unwrap();
+    let dir = temp.path().join("en");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("ui.json"), r#"{ "play": "Play", "quit": "Quit" }"#).unwrap();
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let server = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut socket = tungstenite::accept(stream).unwrap();
+        socket.send("not an edit".into()).unwrap();
+        socket
+            .send(r#"[{ "lang": "en", "file": "ui", "key": "play", "value": "Start" }]"#.into())
+            .unwrap();
+        socket
+            .send(r#"{ "lang": "en", "file": "ui", "key": "lives", "value": { "one": "1 life", "other": "{{count}} lives" } }"#.into())
+            .unwrap();
+        socket
+    });
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(I18nPlugin::with_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        ..Default::default()
+    }));
+    app.add_plugins(LiveEditPlugin { url });
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while app.world().resource::<I18n>().translation("ui").t_with_plural("lives", 3) != "3 lives" {
+        assert!(Instant::now() < deadline, "edits were not applied");
+        app.update();
+        thread::sleep(Duration::from_millis(10));
+    }
+    let ui = app.world().resource::<I18n>().translation("ui");
+    assert_eq!(ui.t("play"), "Start");
+    assert_eq!(ui.t("quit"), "Quit");
+    drop(server.join().unwrap());
+}
+
+#[test]
+fn frames_hold_one_edit_or_an_array() {
+    let edit = LiveEdit { lang: "fr".into(), file: "ui".into(), key: "play".into(), value: "Jouer".into() };
+    let one = r#"{ "lang": "fr", "file": "ui", "key": "play", "value": "Jouer" }"#;
+    assert_eq!(LiveEdit::parse(one), Ok(vec![edit.clone()]));
+    assert_eq!(LiveEdit::parse(&format!("[{one}, {one}]")), Ok(vec![edit.clone(), edit]));
+    assert!(LiveEdit::parse(r#"{ "lang": "fr" }"#).is_err());
+}
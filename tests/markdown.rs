@@ -0,0 +1,79 @@
+//! Test markdown parsing and `I18nMarkdown` span trees.
+#![cfg(feature = "markdown")]
+
+use std::fs;
+
+use bevy::prelude::*;
+use bevy_intl::{I18n, I18nConfig, I18nMarkdown, I18nMarkdownSpan, I18nPlugin, MarkdownSpan, parse_markdown};
+use tempfile::tempdir;
+
+fn span(text: &str, heading: Option<u8>, bold: bool, italic: bool) -> MarkdownSpan {
+    MarkdownSpan { text: text.into(), heading, bold, italic }
+}
+
+#[test]
+fn parses_headings_emphasis_and_lists() {
+    let spans = parse_markdown("# Patch 1.2\n\nFixed **crashes** and\n*rare* bugs.\n\n- New map\n  - Night mode\n2. Done");
+    assert_eq!(
+        spans,
+        vec![
+            span("Patch 1.2\n", Some(1), false, false),
+            span("\nFixed ", None, false, false),
+            span("crashes", None, true, false),
+            span(" and ", None, false, false),
+            span("rare", None, false, true),
+            span(" bugs.\n\n• New map\n  • Night mode\n2. Done", None, false, false),
+        ]
+    );
+
+    // Unclosed markers stay as text.
+    assert_eq!(parse_markdown("5 * 3 = 15"), vec![span("5 * 3 = 15", None, false, false)]);
+}
+
+fn texts(app: &mut App) -> Vec<(String, FontWeight)> {
+    app.world_mut()
+        .query_filtered::<(&TextSpan, &TextFont), With<I18nMarkdownSpan>>()
+        .iter(app.world())
+        .map(|(span, font)| (span.0.clone(), font.weight))
+        .collect()
+}
+
+#[test]
+fn i18n_markdown_rebuilds_spans_on_language_change() {
+    let temp = tempdir().unwrap();
+    for (lang, notes) in [("en", "# News\\n**New** map"), ("fr", "# Nouveautés\\nNouvelle carte")] {
+        let dir = temp.path().join(lang);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("help.json"), format!(r#"{{ "notes": "{}" }}"#, notes)).unwrap();
+    }
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(I18nPlugin::with_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        default_lang: "en".into(),
+        fallback_chain: vec!["en".into()],
+        warn_unknown_locales: false,
+        ..Default::default()
+    }));
+    app.world_mut().spawn(I18nMarkdown::new("help", "notes"));
+    app.update();
+
+    assert_eq!(
+        texts(&mut app),
+        vec![
+            ("News\n".to_string(), FontWeight::BOLD),
+            ("New".to_string(), FontWeight::BOLD),
+            (" map".to_string(), FontWeight::NORMAL),
+        ]
+    );
+
+    app.world_mut().resource_mut::<I18n>().set_lang("fr");
+    app.update();
+    app.update();
+    assert_eq!(
+        texts(&mut app),
+        vec![("Nouveautés\n".to_string(), FontWeight::BOLD), ("Nouvelle carte".to_string(), FontWeight::NORMAL)]
+    );
+}
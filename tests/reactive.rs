@@ -1,11 +1,19 @@
 //! Test that `I18nText` updates and `LanguageChanged` is broadcast when the
 //! active language changes.
+#![cfg(feature = "bevy")]
 
 use std::fs;
 
 use bevy::prelude::*;
-use bevy_intl::{I18n, I18nConfig, I18nMode, I18nPlugin, I18nText, LanguageChanged};
-use tempfile::tempdir;
+use bevy::render::view::screenshot::Screenshot;
+use bevy::text::TextLayoutInfo;
+use bevy_intl::{
+    FitI18nText, HiddenLabels, I18n, I18nCommand, I18nConfig, I18nDiagnostic, I18nError, I18nFullText, I18nMode,
+    I18nOverflow, I18nPlugin, I18nRefreshQueue, I18nSpeech, I18nStale, I18nText, I18nTextSpan, I18nTooltip,
+    I18nTooltipPopup, LanguageChanged, OverflowMode, RefreshBudget, ScreenshotMatrix, ScreenshotMatrixStep,
+    SpeakLocalized, i18n_args,
+};
+use tempfile::{TempDir, tempdir};
 
 fn write_fixture(dir: &std::path::Path, lang: &str, file: &str, content: &str) {
     let lang_dir = dir.join(lang);
@@ -13,6 +21,29 @@ fn write_fixture(dir: &std::path::Path, lang: &str, file: &str, content: &str) {
     fs::write(lang_dir.join(format!("{}.json", file)), content).unwrap();
 }
 
+/// An app with `I18nPlugin` reading `fixtures` (`(lang, file, json)`) from a
+/// temporary messages folder, whose first language is the default one. The
+/// folder is removed when the returned `TempDir` is dropped.
+fn app_with_fixtures(fixtures: &[(&str, &str, &str)]) -> (App, TempDir) {
+    let temp = tempdir().unwrap();
+    for (lang, file, content) in fixtures {
+        write_fixture(temp.path(), lang, file, content);
+    }
+    let lang = fixtures.first().map_or("en", |(lang, _, _)| *lang);
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(I18nPlugin::with_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        default_lang: lang.into(),
+        fallback_chain: vec![lang.into()],
+        warn_unknown_locales: false,
+        ..Default::default()
+    }));
+    (app, temp)
+}
+
 #[derive(Resource, Default)]
 struct CapturedLanguageChanges(Vec<(String, String)>);
 
@@ -27,19 +58,10 @@ fn capture_language_changes(
 
 #[test]
 fn i18n_text_updates_on_language_change_and_emits_message() {
-    let temp = tempdir().unwrap();
-    write_fixture(temp.path(), "en", "ui", r#"{ "greeting": "Hello" }"#);
-    write_fixture(temp.path(), "fr", "ui", r#"{ "greeting": "Bonjour" }"#);
-
-    let mut app = App::new();
-    app.add_plugins(MinimalPlugins);
-    app.add_plugins(I18nPlugin::with_config(I18nConfig {
-        use_bundled_translations: false,
-        messages_folder: temp.path().to_string_lossy().into_owned(),
-        default_lang: "en".into(),
-        fallback_lang: "en".into(),
-        warn_unknown_locales: false,
-    }));
+    let (mut app, _messages) = app_with_fixtures(&[
+        ("en", "ui", r#"{ "greeting": "Hello" }"#),
+        ("fr", "ui", r#"{ "greeting": "Bonjour" }"#),
+    ]);
     app.init_resource::<CapturedLanguageChanges>();
     app.add_systems(Update, capture_language_changes);
 
@@ -73,3 +95,321 @@ fn i18n_text_updates_on_language_change_and_emits_message() {
         captured.0
     );
 }
+
+#[test]
+fn language_changed_is_triggered_for_observers() {
+    let (mut app, _messages) = app_with_fixtures(&[
+        ("en", "ui", r#"{ "greeting": "Hello" }"#),
+        ("fr", "ui", r#"{ "greeting": "Bonjour" }"#),
+    ]);
+    app.init_resource::<CapturedLanguageChanges>();
+    app.add_observer(|changed: On<LanguageChanged>, mut log: ResMut<CapturedLanguageChanges>| {
+        log.0.push((changed.from.clone(), changed.to.clone()));
+    });
+
+    app.update();
+    assert!(app.world().resource::<CapturedLanguageChanges>().0.is_empty());
+
+    app.world_mut().resource_mut::<I18n>().set_lang("fr");
+    app.update();
+    assert_eq!(app.world().resource::<CapturedLanguageChanges>().0, [("en".to_string(), "fr".to_string())]);
+}
+
+#[test]
+fn i18n_text_with_args_fills_placeholders_in_every_language() {
+    let (mut app, _messages) = app_with_fixtures(&[
+        ("en", "ui", r#"{ "welcome": "Welcome, {{name}}!" }"#),
+        ("fr", "ui", r#"{ "welcome": "Bienvenue, {{name}} !" }"#),
+    ]);
+    let entity = app.world_mut().spawn(I18nText::with_args("ui", "welcome", i18n_args! { name = "Ana" })).id();
+
+    app.update();
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Welcome, Ana!");
+    app.world_mut().resource_mut::<I18n>().set_lang("fr");
+    app.update();
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Bienvenue, Ana !");
+}
+
+#[test]
+fn reinserting_i18n_text_renders_immediately() {
+    let (mut app, _messages) = app_with_fixtures(&[("en", "ui", r#"{ "greeting": "Hello", "farewell": "Goodbye" }"#)]);
+
+    // The insert observer renders on spawn, without waiting for a frame.
+    let entity = app.world_mut().spawn(I18nText::new("ui", "greeting")).id();
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Hello");
+
+    app.world_mut().entity_mut(entity).insert(I18nText::new("ui", "farewell"));
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Goodbye");
+}
+
+#[test]
+fn i18n_text_span_children_follow_language_change() {
+    let (mut app, _messages) = app_with_fixtures(&[
+        ("en", "ui", r#"{ "score": "Score: ", "points": "{{count}} points" }"#),
+        ("fr", "ui", r#"{ "score": "Score : ", "points": "{{count}} pts" }"#),
+    ]);
+
+    let root = app.world_mut().spawn(I18nText::new("ui", "score")).id();
+    let span = app
+        .world_mut()
+        .spawn((
+            I18nTextSpan {
+                file: "ui".into(),
+                key: "points".into(),
+                mode: I18nMode::Args(vec![("count".into(), "7".into())]),
+            },
+            ChildOf(root),
+        ))
+        .id();
+    app.update();
+    assert_eq!(app.world().get::<TextSpan>(span).unwrap().0, "7 points");
+
+    app.world_mut().resource_mut::<I18n>().set_lang("fr");
+    app.update();
+    assert_eq!(app.world().get::<Text>(root).unwrap().0, "Score : ");
+    assert_eq!(app.world().get::<TextSpan>(span).unwrap().0, "7 pts");
+}
+
+#[test]
+fn refresh_budget_spreads_a_language_switch_over_frames() {
+    let (mut app, _messages) = app_with_fixtures(&[
+        ("en", "ui", r#"{ "greeting": "Hello" }"#),
+        ("fr", "ui", r#"{ "greeting": "Bonjour" }"#),
+    ]);
+    app.insert_resource(RefreshBudget::Entities(2));
+    for _ in 0..5 {
+        app.world_mut().spawn(I18nText::new("ui", "greeting"));
+    }
+    app.update();
+
+    let translated = |app: &mut App| {
+        let mut texts = app.world_mut().query::<&Text>();
+        texts.iter(app.world()).filter(|text| text.0 == "Bonjour").count()
+    };
+    app.world_mut().resource_mut::<I18n>().set_lang("fr");
+    app.update();
+    assert_eq!(translated(&mut app), 2);
+    assert_eq!(app.world().resource::<I18nRefreshQueue>().len(), 3);
+    app.update();
+    assert_eq!(translated(&mut app), 4);
+    app.update();
+    assert_eq!(translated(&mut app), 5);
+    assert!(app.world().resource::<I18nRefreshQueue>().is_empty());
+}
+
+#[test]
+fn hidden_labels_are_translated_when_shown() {
+    let (mut app, _messages) = app_with_fixtures(&[
+        ("en", "ui", r#"{ "greeting": "Hello" }"#),
+        ("fr", "ui", r#"{ "greeting": "Bonjour" }"#),
+    ]);
+    app.insert_resource(HiddenLabels::Defer);
+    // No visibility propagation here: visibility is set by hand.
+    let shown = app.world_mut().spawn((I18nText::new("ui", "greeting"), InheritedVisibility::VISIBLE)).id();
+    let hidden = app.world_mut().spawn((I18nText::new("ui", "greeting"), InheritedVisibility::HIDDEN)).id();
+    app.update();
+
+    app.world_mut().resource_mut::<I18n>().set_lang("fr");
+    app.update();
+    assert_eq!(app.world().get::<Text>(shown).unwrap().0, "Bonjour");
+    assert_eq!(app.world().get::<Text>(hidden).unwrap().0, "Hello");
+    assert!(app.world().get::<I18nStale>(hidden).is_some());
+
+    app.world_mut().entity_mut(hidden).insert(InheritedVisibility::VISIBLE);
+    app.update();
+    assert_eq!(app.world().get::<Text>(hidden).unwrap().0, "Bonjour");
+    assert!(app.world().get::<I18nStale>(hidden).is_none());
+}
+
+#[test]
+fn i18n_tooltip_spawns_popup_while_hovered() {
+    let (mut app, _messages) = app_with_fixtures(&[("en", "items", r#"{ "sword": "A sharp blade" }"#)]);
+
+    let owner = app.world_mut().spawn(I18nTooltip::new("items", "sword")).id();
+    app.update();
+    assert_eq!(popup_texts(&mut app), Vec::<String>::new());
+
+    *app.world_mut().get_mut::<Interaction>(owner).unwrap() = Interaction::Hovered;
+    app.update();
+    assert_eq!(popup_texts(&mut app), vec!["A sharp blade".to_string()]);
+
+    *app.world_mut().get_mut::<Interaction>(owner).unwrap() = Interaction::None;
+    app.update();
+    assert_eq!(popup_texts(&mut app), Vec::<String>::new());
+}
+
+fn popup_texts(app: &mut App) -> Vec<String> {
+    let mut q = app.world_mut().query_filtered::<&Text, With<I18nTooltipPopup>>();
+    q.iter(app.world()).map(|t| t.0.clone()).collect()
+}
+
+#[test]
+fn fit_i18n_text_shrinks_font_then_wraps() {
+    let (mut app, _messages) = app_with_fixtures(&[("de", "ui", r#"{ "ok": "Einverstanden" }"#)]);
+
+    let entity = app
+        .world_mut()
+        .spawn((
+            I18nText::new("ui", "ok"),
+            FitI18nText::new(100.0, 10.0),
+            TextFont { font_size: FontSize::Px(20.0), ..default() },
+        ))
+        .id();
+    // First frame records the base font size; the initial language refresh
+    // rewrites the text, which the second frame treats as a reset.
+    app.update();
+    app.update();
+
+    // No text pipeline under MinimalPlugins: feed the layout by hand.
+    let set_width = |app: &mut App, width: f32| {
+        let mut layout = app.world_mut().get_mut::<TextLayoutInfo>(entity).unwrap();
+        layout.scale_factor = 1.0;
+        layout.size = Vec2::new(width, 20.0);
+    };
+
+    set_width(&mut app, 160.0);
+    app.update();
+    assert_eq!(app.world().get::<TextFont>(entity).unwrap().font_size, FontSize::Px(12.5));
+
+    set_width(&mut app, 300.0);
+    app.update();
+    assert_eq!(app.world().get::<TextFont>(entity).unwrap().font_size, FontSize::Px(10.0));
+
+    set_width(&mut app, 120.0);
+    app.update();
+    assert_eq!(app.world().get::<Node>(entity).unwrap().max_width, Val::Px(100.0));
+}
+
+#[test]
+fn ellipsized_label_truncates_and_shows_full_text_on_hover() {
+    let (mut app, _messages) = app_with_fixtures(&[("en", "ui", r#"{ "title": "Hello wonderful world" }"#)]);
+
+    let entity = app
+        .world_mut()
+        .spawn((
+            I18nText::new("ui", "title"),
+            I18nOverflow(OverflowMode::Ellipsize),
+            Interaction::None,
+        ))
+        .id();
+    app.update();
+    app.update();
+
+    // No layout under MinimalPlugins: size the node and the text by hand.
+    app.world_mut().get_mut::<ComputedNode>(entity).unwrap().size = Vec2::new(100.0, 20.0);
+    app.world_mut().get_mut::<TextLayoutInfo>(entity).unwrap().size = Vec2::new(210.0, 20.0);
+    app.update();
+
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Hello wond…");
+    let full = app.world().get::<I18nFullText>(entity).unwrap();
+    assert!(full.is_truncated());
+    assert_eq!(full.full, "Hello wonderful world");
+
+    *app.world_mut().get_mut::<Interaction>(entity).unwrap() = Interaction::Hovered;
+    app.update();
+    assert_eq!(popup_texts(&mut app), vec!["Hello wonderful world".to_string()]);
+}
+
+#[test]
+fn i18n_commands_switch_language_and_merge_packs() {
+    let (mut app, _messages) = app_with_fixtures(&[
+        ("en", "ui", r#"{ "greeting": "Hello" }"#),
+        ("fr", "ui", r#"{ "greeting": "Bonjour" }"#),
+    ]);
+
+    let entity = app.world_mut().spawn(I18nText::new("ui", "greeting")).id();
+    app.update();
+
+    app.world_mut().write_message(I18nCommand::SetLanguage("fr".into()));
+    app.update();
+    assert_eq!(app.world().resource::<I18n>().get_lang(), "fr");
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Bonjour");
+
+    // Unknown locales are rejected without touching the current language.
+    app.world_mut().write_message(I18nCommand::SetLanguage("xx".into()));
+    app.update();
+    assert_eq!(app.world().resource::<I18n>().get_lang(), "fr");
+
+    // New strings for the current language re-render labels on the spot.
+    app.world_mut().write_message(I18nCommand::MergePack {
+        lang: "fr".into(),
+        pack: serde_json::json!({ "ui": { "greeting": "Salut" } }),
+    });
+    app.update();
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Salut");
+
+    // A corrupted pack is rejected as a whole and reported.
+    app.init_resource::<CapturedDiagnostics>().add_systems(Update, capture_diagnostics);
+    app.world_mut().write_message(I18nCommand::MergePack {
+        lang: "fr".into(),
+        pack: serde_json::json!({ "ui": { "greeting": "Coucou" }, "items": [] }),
+    });
+    app.update();
+    app.update();
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Salut");
+    let diagnostics = &app.world().resource::<CapturedDiagnostics>().0;
+    assert!(matches!(
+        &diagnostics[..],
+        [I18nDiagnostic::PackRejected(I18nError::InvalidPackFile { file, .. })] if file == "items"
+    ));
+
+    app.world_mut().write_message(I18nCommand::Reload);
+    app.update();
+    assert_eq!(app.world().get::<Text>(entity).unwrap().0, "Bonjour");
+}
+
+#[derive(Resource, Default)]
+struct CapturedDiagnostics(Vec<I18nDiagnostic>);
+
+fn capture_diagnostics(mut reader: MessageReader<I18nDiagnostic>, mut log: ResMut<CapturedDiagnostics>) {
+    log.0.extend(reader.read().cloned());
+}
+
+#[derive(Resource, Default)]
+struct CapturedSteps(Vec<(String, String)>);
+
+fn capture_steps(mut reader: MessageReader<ScreenshotMatrixStep>, mut log: ResMut<CapturedSteps>) {
+    log.0.extend(reader.read().map(|step| (step.state.clone(), step.lang.clone())));
+}
+
+#[test]
+fn screenshot_matrix_visits_every_state_and_language() {
+    let (mut app, _messages) = app_with_fixtures(&[
+        ("en", "ui", r#"{ "greeting": "Hello" }"#),
+        ("fr", "ui", r#"{ "greeting": "Bonjour" }"#),
+    ]);
+    app.init_resource::<CapturedSteps>();
+    app.add_systems(Update, capture_steps);
+    app.insert_resource(ScreenshotMatrix::new(["menu", "shop"]).output_dir("qa"));
+
+    for _ in 0..16 {
+        app.update();
+    }
+
+    let matrix = app.world().resource::<ScreenshotMatrix>();
+    assert!(matrix.is_finished());
+    assert_eq!(matrix.path("shop", "fr"), std::path::Path::new("qa/shop_fr.png"));
+    assert_eq!(
+        app.world().resource::<CapturedSteps>().0,
+        [("menu", "en"), ("menu", "fr"), ("shop", "en"), ("shop", "fr")]
+            .map(|(state, lang)| (state.to_string(), lang.to_string()))
+    );
+    let screenshots = app.world_mut().query::<&Screenshot>().iter(app.world()).count();
+    assert_eq!(screenshots, 4);
+    assert_eq!(app.world().resource::<I18n>().get_lang(), "en");
+}
+
+#[test]
+fn speak_localized_forwards_text_and_locale_to_the_backend() {
+    let (mut app, _messages) = app_with_fixtures(&[("pt_BR", "menu", r#"{ "welcome": "Bem-vindo, {{name}}" }"#)]);
+    let spoken = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let sink = spoken.clone();
+    app.insert_resource(I18nSpeech::new(move |text: &str, locale: &str| {
+        sink.lock().unwrap().push((text.to_string(), locale.to_string()));
+    }));
+
+    app.world_mut().write_message(SpeakLocalized::new("menu", "welcome").arg("name", "Ana"));
+    app.update();
+
+    assert_eq!(*spoken.lock().unwrap(), vec![("Bem-vindo, Ana".to_string(), "pt-BR".to_string())]);
+}
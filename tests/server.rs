@@ -0,0 +1,150 @@
+//! Server-side usage: build `I18n` straight from a config, with no Bevy
+//! `App`, the way a game server localizes notifications and emails.
+
+use std::fs;
+
+use bevy_intl::{I18n, I18nConfig, i18n_args};
+use pretty_assertions::assert_eq;
+use tempfile::tempdir;
+
+#[test]
+fn builds_i18n_without_an_app() {
+    let temp = tempdir().unwrap();
+    for (lang, content) in [
+        ("en", r#"{ "subject": "Welcome, {{name}}!", "gifts": { "one": "One gift", "other": "{{count}} gifts" } }"#),
+        ("fr", r#"{ "subject": "Bienvenue, {{name}} !" }"#),
+    ] {
+        let dir = temp.path().join(lang);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("emails.json"), content).unwrap();
+    }
+
+    let mut i18n = I18n::from_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        default_lang: "fr".into(),
+        fallback_chain: vec!["en".into()],
+        warn_unknown_locales: false,
+        ..Default::default()
+    });
+
+    let t = i18n.translation("emails");
+    assert_eq!(t.t_with_args("subject", i18n_args! { name = "Ana" }), "Bienvenue, Ana !");
+    assert_eq!(t.t_with_plural("gifts", 3), "3 gifts");
+
+    i18n.set_lang("en");
+    assert_eq!(
+        i18n.translation("emails").t_with_args("subject", i18n_args! { name = "Ana" }),
+        "Welcome, Ana!"
+    );
+}
+
+#[test]
+fn configured_fallback_chain_follows_the_regional_parent() {
+    let temp = tempdir().unwrap();
+    for (lang, content) in [
+        ("pt-BR", r#"{ "a": "a-BR" }"#),
+        ("pt", r#"{ "a": "a-PT", "b": "b-PT" }"#),
+        ("es", r#"{ "b": "b-ES", "c": "c-ES" }"#),
+        ("en", r#"{ "c": "c-EN", "d": "d-EN" }"#),
+    ] {
+        let dir = temp.path().join(lang);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("ui.json"), content).unwrap();
+    }
+
+    let i18n = I18n::from_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        default_lang: "pt-BR".into(),
+        fallback_chain: vec!["es".into(), "en".into()],
+        warn_unknown_locales: false,
+        ..Default::default()
+    });
+
+    let t = i18n.translation("ui");
+    assert_eq!(t.t_many(&["a", "b", "c", "d"]), ["a-BR", "b-PT", "c-ES", "d-EN"]);
+    assert_eq!(i18n.fallback_chain(), ["es", "en"]);
+}
+
+#[test]
+fn snapshot_is_frozen_and_sendable() {
+    let temp = tempdir().unwrap();
+    for (lang, content) in [("en", r#"{ "tip": "Drink potions" }"#), ("de", r#"{ "tip": "Trink Tränke" }"#)] {
+        let dir = temp.path().join(lang);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("tips.json"), content).unwrap();
+    }
+
+    let mut i18n = I18n::from_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        default_lang: "de".into(),
+        fallback_chain: vec!["en".into()],
+        warn_unknown_locales: false,
+        ..Default::default()
+    });
+
+    let snapshot = i18n.snapshot();
+    i18n.set_lang("en");
+
+    let tip = std::thread::spawn(move || snapshot.translation("tips").t("tip")).join().unwrap();
+    assert_eq!(tip, "Trink Tränke");
+    assert_eq!(i18n.translation("tips").t("tip"), "Drink potions");
+}
+
+#[test]
+fn documents_are_read_from_the_language_folder() {
+    let temp = tempdir().unwrap();
+    for (lang, credits) in [("en", "Directed by Ana\nMusic by Léo\n"), ("fr", "Réalisé par Ana\n")] {
+        let dir = temp.path().join(lang);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("about.json"), r#"{ "credits": { "@file": "credits.txt" }, "title": "About" }"#).unwrap();
+        fs::write(dir.join("credits.txt"), credits).unwrap();
+    }
+
+    let mut i18n = I18n::from_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        default_lang: "en".into(),
+        fallback_chain: vec!["en".into()],
+        warn_unknown_locales: false,
+        ..Default::default()
+    });
+
+    assert_eq!(i18n.translation("about").document("credits"), Some("Directed by Ana\nMusic by Léo\n"));
+    assert_eq!(i18n.translation("about").document("title"), None);
+    i18n.set_lang("fr");
+    assert_eq!(i18n.translation("about").document("credits"), Some("Réalisé par Ana\n"));
+}
+
+#[cfg(feature = "global")]
+#[test]
+fn global_handle_serves_the_published_translations() {
+    let temp = tempdir().unwrap();
+    for (lang, content) in [("en", r#"{ "quit": "Quit" }"#), ("it", r#"{ "quit": "Esci" }"#)] {
+        let dir = temp.path().join(lang);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("menu.json"), content).unwrap();
+    }
+
+    let mut i18n = I18n::from_config(I18nConfig {
+        use_bundled_translations: false,
+        messages_folder: temp.path().to_string_lossy().into_owned(),
+        default_lang: "en".into(),
+        fallback_chain: vec!["en".into()],
+        warn_unknown_locales: false,
+        ..Default::default()
+    });
+
+    assert!(bevy_intl::global().is_none());
+    bevy_intl::set_global(&i18n);
+    i18n.set_lang("it");
+    let quit = std::thread::spawn(|| bevy_intl::global().unwrap().translation("menu").t("quit")).join().unwrap();
+    assert_eq!(quit, "Quit");
+
+    bevy_intl::set_global(&i18n);
+    assert_eq!(bevy_intl::global().unwrap().translation("menu").t("quit"), "Esci");
+    bevy_intl::clear_global();
+    assert!(bevy_intl::global().is_none());
+}
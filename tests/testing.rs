@@ -0,0 +1,50 @@
+//! Test the `I18nTestApp` harness.
+#![cfg(feature = "testing")]
+
+use std::fs;
+
+use bevy::prelude::*;
+use bevy_intl::{I18nMode, I18nTestApp, I18nText, I18nTextSpan};
+use tempfile::tempdir;
+
+#[test]
+fn test_app_renders_labels_from_a_messages_dir_across_language_switches() {
+    let temp = tempdir().unwrap();
+    for (lang, content) in [
+        ("en", r#"{ "title": "Dawnforge", "apples": { "one": "{{count}} apple", "other": "{{count}} apples" } }"#),
+        ("fr", r#"{ "title": "L'Aube-Forge", "apples": { "one": "{{count}} pomme", "other": "{{count}} pommes" } }"#),
+    ] {
+        let dir = temp.path().join(lang);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("ui.json"), content).unwrap();
+    }
+
+    let mut app = I18nTestApp::new().with_messages_dir(temp.path());
+    let title = app.spawn_text(I18nText::new("ui", "title"));
+    let apples = app.spawn_text(I18nText { file: "ui".into(), key: "apples".into(), mode: I18nMode::Plural(2) });
+    app.assert_text(title, "Dawnforge");
+    app.assert_text(apples, "2 apples");
+
+    app.switch_lang("fr");
+    app.assert_text(title, "L'Aube-Forge");
+    app.assert_text(apples, "2 pommes");
+}
+
+#[test]
+fn test_app_reads_packs_and_spans_without_a_messages_dir() {
+    let mut app = I18nTestApp::new()
+        .with_lang("fr")
+        .with_config(|config| config.fallback_chain = vec!["fr".into()])
+        .with_pack("fr", serde_json::json!({ "hud": { "score": "Score : ", "lives": " vies" } }));
+    let label = app.spawn_text((I18nText::new("hud", "score"), children![TextSpan::new("12"), I18nTextSpan::new("hud", "lives")]));
+    app.assert_text(label, "Score : 12 vies");
+    assert_eq!(app.i18n().get_lang(), "fr");
+}
+
+#[test]
+#[should_panic(expected = "cannot switch to 'de'")]
+fn test_app_switching_to_a_missing_language_panics() {
+    let mut app = I18nTestApp::new().with_pack("en", serde_json::json!({ "ui": { "title": "Dawnforge" } }));
+    app.update();
+    app.switch_lang("de");
+}